@@ -1,10 +1,11 @@
 use cumulus_primitives_core::ParaId;
-use polkacomputelab_runtime::{AccountId, AuraId, Signature};
+use polkacomputelab_runtime::{AccountId, AuraId, Balance, Signature, UNIT};
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
 use sc_service::ChainType;
+use sc_telemetry::TelemetryEndpoints;
 use serde::{Deserialize, Serialize};
 use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_runtime::traits::{Convert, IdentifyAccount, Verify};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
 pub type ChainSpec = sc_service::GenericChainSpec<polkacomputelab_runtime::RuntimeGenesisConfig, Extensions>;
@@ -89,6 +90,8 @@ pub fn development_config() -> ChainSpec {
         ],
         get_account_id_from_seed::<sr25519::Public>("Alice"),
         2000.into(),
+        DEV_ENDOWMENT,
+        false,
     ))
     .build()
 }
@@ -132,23 +135,103 @@ pub fn local_testnet_config() -> ChainSpec {
         ],
         get_account_id_from_seed::<sr25519::Public>("Alice"),
         2000.into(),
+        DEV_ENDOWMENT,
+        false,
     ))
     .with_protocol_id("polkacomputelab")
     .with_properties(properties)
     .build()
 }
 
+/// Telemetry endpoint of the public testnet
+const TESTNET_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
+
+/// Balance of each endowed account on dev and local chains
+const DEV_ENDOWMENT: Balance = 1 << 60;
+
+/// Balance of each endowed testnet account: enough for collator and faucet fees, not for
+/// dominating job escrow
+const TESTNET_ENDOWMENT: Balance = 100_000 * UNIT;
+
+/// Public testnet template, relayed by Westend
+///
+/// Collators are Alice and Bob and sudo is Alice, from the well-known development seeds, under
+/// para ID 2000. Operators replace them, set the registered para ID and add boot nodes in the
+/// exported plain spec before generating the raw spec they publish. Consensus experiments run
+/// from genesis, so the testnet collects per-consensus results from its first epoch.
+///
+/// The `OcwWorkers`, `ProofVerifiers`, `ConditionOracles` and `TelemetryReporters` allow lists
+/// start empty: until sudo fills them, no account can claim jobs, verify proofs, submit
+/// condition events or report telemetry.
+pub fn testnet_config() -> ChainSpec {
+    let mut properties = sc_chain_spec::Properties::new();
+    properties.insert("tokenSymbol".into(), "PCL".into());
+    properties.insert("tokenDecimals".into(), 12.into());
+    properties.insert("ss58Format".into(), 42.into());
+
+    let collators: Vec<(AccountId, AuraId)> = ["Alice", "Bob"]
+        .into_iter()
+        .map(|seed| {
+            (get_account_id_from_seed::<sr25519::Public>(seed), authority_keys_from_seed(seed))
+        })
+        .collect();
+    let sudo = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let endowed = collators
+        .iter()
+        .map(|(account, _)| account.clone())
+        .chain([sudo.clone()])
+        .collect();
+
+    ChainSpec::builder(
+        polkacomputelab_runtime::WASM_BINARY.expect("WASM binary was not built, please build it!"),
+        Extensions { relay_chain: "westend".to_string(), para_id: 2000 },
+    )
+    .with_name("PolkaComputeLab Testnet")
+    .with_id("polkacomputelab_testnet")
+    .with_chain_type(ChainType::Live)
+    .with_genesis_config_patch(testnet_genesis(
+        collators,
+        endowed,
+        sudo,
+        2000.into(),
+        TESTNET_ENDOWMENT,
+        true,
+    ))
+    .with_telemetry_endpoints(
+        TelemetryEndpoints::new(vec![(TESTNET_TELEMETRY_URL.to_string(), 0)])
+            .expect("static values are valid; qed"),
+    )
+    .with_protocol_id("polkacomputelab-testnet")
+    .with_properties(properties)
+    .build()
+}
+
+/// Topic that diagnostics tooling subscribes to
+const DIAGNOSTICS_TOPIC: u64 = 2;
+
+/// Event-hub triggers pre-installed on dev and testnet chains, owned by `root`
+///
+/// Entries are (owner, topic, action_u8, action_param); see `pallet_event_hub::GenesisConfig`.
+fn system_triggers(root: &AccountId) -> Vec<(AccountId, u64, u8, Option<u64>)> {
+    vec![
+        // Forward job failures (including failed verification) to diagnostics (3 = EmitEvent)
+        (root.clone(), polkacomputelab_runtime::event_topics::JOB_FAILED, 3, Some(DIAGNOSTICS_TOPIC)),
+    ]
+}
+
 fn testnet_genesis(
     invulnerables: Vec<(AccountId, AuraId)>,
     endowed_accounts: Vec<AccountId>,
     root: AccountId,
     id: ParaId,
+    endowment: Balance,
+    experiment_mode: bool,
 ) -> serde_json::Value {
     use polkacomputelab_runtime::*;
 
     serde_json::json!({
         "balances": {
-            "balances": endowed_accounts.iter().cloned().map(|k| (k, 1u128 << 60)).collect::<Vec<_>>(),
+            "balances": endowed_accounts.iter().cloned().map(|k| (k, endowment)).collect::<Vec<_>>(),
         },
         "parachainInfo": {
             "parachainId": id,
@@ -156,6 +239,20 @@ fn testnet_genesis(
         "aura": {
             "authorities": invulnerables.iter().map(|x| (x.1.clone())).collect::<Vec<_>>(),
         },
+        "eventHub": {
+            "triggers": system_triggers(&root),
+        },
+        // Aura with the invulnerables registered as validators under the accounts their Aura
+        // keys author as, so slot authorship is credited to them; see
+        // `pallet_consensus_manager::GenesisConfig`
+        "consensusManager": {
+            "consensusType": 0,
+            "validators": invulnerables
+                .iter()
+                .map(|x| AuraAccount::convert(x.1.clone()))
+                .collect::<Vec<_>>(),
+            "experimentMode": experiment_mode,
+        },
         "sudo": {
             "key": Some(root),
         },
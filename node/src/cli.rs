@@ -1,5 +1,5 @@
 use clap::Parser;
-use std::path::PathBuf;
+use std::{num::NonZeroU32, path::PathBuf};
 
 /// Sub-commands supported by the collator.
 #[derive(Debug, Parser)]
@@ -32,6 +32,13 @@ pub enum Subcommand {
     /// Export the genesis wasm of the parachain.
     ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
+    /// Export the jobs of the job registry as JSON or CSV.
+    ExportJobs(crate::export_jobs::ExportJobsCmd),
+
+    /// Key management, including the offchain worker key.
+    #[command(subcommand)]
+    Key(crate::key::KeyCmd),
+
     /// Sub-commands concerned with benchmarking.
     #[cfg(feature = "runtime-benchmarks")]
     Benchmark(frame_benchmarking_cli::BenchmarkCmd),
@@ -41,6 +48,16 @@ pub enum Subcommand {
     TryRuntime(try_runtime_cli::TryRuntimeCmd),
 }
 
+/// Parse a `NAME=VALUE` argument
+fn parse_named<T: From<String>>(arg: &str) -> Result<(String, T), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() && !value.is_empty() => {
+            Ok((name.to_string(), T::from(value.to_string())))
+        },
+        _ => Err(format!("expected NAME=VALUE, got `{}`", arg)),
+    }
+}
+
 #[derive(Debug, Parser)]
 #[command(
     propagate_version = true,
@@ -64,6 +81,59 @@ pub struct Cli {
     #[arg(long)]
     pub no_hardware_benchmarks: bool,
 
+    /// Most jobs the offchain worker executes at once.
+    ///
+    /// Offchain worker runs of consecutive blocks overlap, each on its own thread, when jobs
+    /// outlast a block. Raise this on hardware that can execute several jobs in parallel.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..=64))]
+    pub ocw_max_parallel_jobs: u32,
+
+    /// Time budget of one offchain worker job execution in milliseconds, bounding the HTTP
+    /// requests it makes.
+    #[arg(long, default_value_t = 10_000, value_parser = clap::value_parser!(u64).range(1..))]
+    pub ocw_job_timeout_ms: u64,
+
+    /// Seconds the offchain worker keeps the results it computed, reusing them rather than
+    /// executing a job again, e.g. after a restart.
+    #[arg(long, value_name = "SECONDS", default_value_t = 604_800)]
+    pub ocw_result_cache_ttl: u64,
+
+    /// Drop the results cached by the offchain worker on startup, so every job is executed
+    /// again.
+    #[arg(long)]
+    pub ocw_clear_result_cache: bool,
+
+    /// Serve the mutating and expensive `pcl_` RPC methods (`pcl_job_submit`, `pcl_job_list`
+    /// and the `pcl_history` scans) even on RPC interfaces that deny unsafe methods.
+    ///
+    /// By default they follow `--rpc-methods`: a node exposing its RPC with `--rpc-external`
+    /// serves only the cheap `pcl_` read methods.
+    #[arg(long)]
+    pub rpc_pcl_unsafe: bool,
+
+    /// Calls each RPC connection may make to the `pcl_` methods per minute.
+    ///
+    /// Unlimited by default.
+    #[arg(long, value_name = "CALLS")]
+    pub rpc_pcl_rate_limit: Option<NonZeroU32>,
+
+    /// External executor the offchain worker may delegate jobs to, as `NAME=PATH`.
+    ///
+    /// The command at the absolute PATH runs, without arguments, on jobs whose spec names the
+    /// executor NAME: with the job's input on stdin, its stdout is the job's result. Jobs naming
+    /// executors not configured here are left to other collators. May be repeated.
+    #[arg(long, value_name = "NAME=PATH", value_parser = parse_named::<PathBuf>)]
+    pub external_executor: Vec<(String, PathBuf)>,
+
+    /// OCI container executor, as `NAME=IMAGE`, run like `--external-executor` by the
+    /// `--container-runtime`, without network access. May be repeated.
+    #[arg(long, value_name = "NAME=IMAGE", value_parser = parse_named::<String>)]
+    pub external_container: Vec<(String, String)>,
+
+    /// Container runtime `--external-container` images are run with.
+    #[arg(long, value_name = "PATH", default_value = "docker")]
+    pub container_runtime: PathBuf,
+
     /// Relay chain arguments
     #[arg(raw = true)]
     pub relay_chain_args: Vec<String>,
@@ -1,18 +1,24 @@
 use crate::{
     chain_spec,
     cli::{Cli, RelayChainCli, Subcommand},
+    export_jobs,
+    external_executor::ExternalExecutors,
+    rpc_guard::PclRpcConfig,
     service,
 };
-use cumulus_client_cli::generate_genesis_block;
+use cumulus_client_cli::{generate_genesis_block, RelayChainMode};
 use cumulus_primitives_core::ParaId;
 use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
 use log::info;
-use polkacomputelab_runtime::Block;
+use polkacomputelab_runtime::{pallet_job_registry::ocw::OcwConfig, Block};
 use sc_cli::{
     ChainSpec, CliConfiguration, DefaultConfigurationValues, ImportParams, KeystoreParams,
     NetworkParams, Result, SharedParams, SubstrateCli,
 };
+use sc_client_api::StorageProvider;
 use sc_service::config::{BasePath, PrometheusConfig};
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::StorageKey;
 use sp_runtime::traits::AccountIdConversion;
 use std::net::SocketAddr;
 
@@ -20,6 +26,7 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
     Ok(match id {
         "dev" => Box::new(chain_spec::development_config()),
         "local" => Box::new(chain_spec::local_testnet_config()),
+        "testnet" => Box::new(chain_spec::testnet_config()),
         "" | "polkacomputelab-local" => Box::new(chain_spec::local_testnet_config()),
         path => Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
     })
@@ -191,6 +198,26 @@ pub fn run() -> Result<()> {
                 cmd.run(&*spec)
             })
         },
+        Some(Subcommand::ExportJobs(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            match &cmd.snapshot {
+                Some(path) => runner.sync_run(|_config| {
+                    let spec = load_spec(&path.to_string_lossy())?;
+                    cmd.run(spec.build_storage()?.top.into_iter())
+                }),
+                None => runner.sync_run(|config| {
+                    let PartialComponents { client, .. } = service::new_partial(&config)?;
+                    let hash = match &cmd.at {
+                        Some(at) => client.expect_block_hash_from_id(&at.parse::<Block>()?)?,
+                        None => client.usage_info().chain.best_hash,
+                    };
+                    let prefix = StorageKey(export_jobs::jobs_prefix());
+                    let pairs = client.storage_pairs(hash, Some(&prefix), None)?;
+                    cmd.run(pairs.map(|(key, value)| (key.0, value.0)))
+                }),
+            }
+        },
+        Some(Subcommand::Key(cmd)) => cmd.run(&cli),
         #[cfg(feature = "runtime-benchmarks")]
         Some(Subcommand::Benchmark(cmd)) => {
             let runner = cli.create_runner(cmd)?;
@@ -211,13 +238,22 @@ pub fn run() -> Result<()> {
         },
         #[cfg(feature = "try-runtime")]
         Some(Subcommand::TryRuntime(cmd)) => {
+            use polkacomputelab_runtime::MILLISECS_PER_BLOCK;
+            use try_runtime_cli::block_building_info::timestamp_with_aura_info;
+
             let runner = cli.create_runner(cmd)?;
-            runner.async_run(|config| {
-                let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
-                let task_manager =
-                    sc_service::TaskManager::new(config.tokio_handle.clone(), registry)
-                        .map_err(|e| sc_cli::Error::Service(sc_service::Error::Prometheus(e)))?;
-                Ok((cmd.run::<Block, service::Executor>(config), task_manager))
+            let config = runner.config();
+            let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
+            let task_manager = sc_service::TaskManager::new(config.tokio_handle.clone(), registry)
+                .map_err(|e| sc_cli::Error::Service(sc_service::Error::Prometheus(e)))?;
+            // Blocks built by `fast-forward` carry Aura slots and timestamps of this chain
+            let info_provider = timestamp_with_aura_info(MILLISECS_PER_BLOCK);
+
+            runner.async_run(|_| {
+                Ok((
+                    cmd.run::<Block, service::HostFunctions, _>(Some(info_provider)),
+                    task_manager,
+                ))
             })
         },
         None => {
@@ -263,11 +299,44 @@ pub fn run() -> Result<()> {
                     "Is collating: {}",
                     if config.role.is_authority() { "yes" } else { "no" }
                 );
+                match &collator_options.relay_chain_mode {
+                    RelayChainMode::Embedded => info!("Relay chain: embedded full node"),
+                    RelayChainMode::ExternalRpc(urls) => info!(
+                        "Relay chain: minimal node over RPC at {}",
+                        urls.iter().map(|url| url.as_str()).collect::<Vec<_>>().join(", ")
+                    ),
+                    RelayChainMode::LightClient => info!("Relay chain: embedded light client"),
+                }
 
-                service::start_parachain_node(config, polkadot_config, collator_options, id, hwbench)
-                    .await
-                    .map(|r| r.0)
-                    .map_err(Into::into)
+                let pcl_config = service::PclConfig {
+                    ocw: OcwConfig {
+                        max_parallel_jobs: cli.ocw_max_parallel_jobs,
+                        job_timeout_ms: cli.ocw_job_timeout_ms,
+                        result_cache_ttl_ms: cli.ocw_result_cache_ttl.saturating_mul(1_000),
+                    },
+                    clear_result_cache: cli.ocw_clear_result_cache,
+                    rpc: PclRpcConfig {
+                        allow_unsafe: cli.rpc_pcl_unsafe,
+                        rate_limit: cli.rpc_pcl_rate_limit,
+                    },
+                    external_executors: ExternalExecutors::new(
+                        cli.external_executor.clone(),
+                        cli.external_container.clone(),
+                        cli.container_runtime.clone(),
+                    )?,
+                };
+
+                service::start_parachain_node(
+                    config,
+                    polkadot_config,
+                    collator_options,
+                    id,
+                    hwbench,
+                    pcl_config,
+                )
+                .await
+                .map(|r| r.0)
+                .map_err(Into::into)
             })
         },
     }
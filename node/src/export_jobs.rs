@@ -0,0 +1,141 @@
+//! The `export-jobs` subcommand, writing the job registry out for offline analysis.
+
+use crate::rpc::JobInfo;
+use codec::DecodeAll;
+use pallet_job_registry_runtime_api::Job;
+use polkacomputelab_runtime::{AccountId, BlockNumber};
+use sc_cli::{
+    BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, Result, SharedParams,
+};
+use sp_core::{hashing::twox_128, hexdisplay::HexDisplay};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+/// Format jobs are exported in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// A JSON array of jobs, as returned by `pcl_job_list`
+    Json,
+    /// One job per line, dependencies separated by `;`
+    Csv,
+}
+
+/// Export the jobs of the job registry at a block, or of a state snapshot.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportJobsCmd {
+    /// Block hash or number to read the jobs at. Defaults to the best block.
+    #[arg(long, value_name = "HASH or NUMBER", conflicts_with = "snapshot")]
+    pub at: Option<BlockNumberOrHash>,
+
+    /// Read the jobs from a state snapshot instead of the local database: a chain spec as
+    /// written by `export-state`.
+    #[arg(long, value_name = "PATH")]
+    pub snapshot: Option<PathBuf>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+    pub format: ExportFormat,
+
+    /// File to write the jobs to. Defaults to stdout.
+    #[arg(long, short, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+impl CliConfiguration for ExportJobsCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
+
+/// Storage key prefix of the job registry's `Jobs` map
+pub fn jobs_prefix() -> Vec<u8> {
+    [twox_128(b"JobRegistry"), twox_128(b"Jobs")].concat()
+}
+
+impl ExportJobsCmd {
+    /// Decode the jobs among the storage `pairs` and write them out, in ID order
+    pub fn run(&self, pairs: impl Iterator<Item = (Vec<u8>, Vec<u8>)>) -> Result<()> {
+        let jobs = decode_jobs(pairs)?;
+
+        let mut out: Box<dyn Write> = match &self.output {
+            Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+            None => Box::new(io::stdout().lock()),
+        };
+        match self.format {
+            ExportFormat::Json => {
+                serde_json::to_writer_pretty(&mut out, &jobs).map_err(|e| e.to_string())?;
+                writeln!(out)?;
+            },
+            ExportFormat::Csv => write_csv(&mut out, &jobs)?,
+        }
+        out.flush()?;
+
+        log::info!("Exported {} jobs", jobs.len());
+        Ok(())
+    }
+}
+
+/// Decode the entries of the `Jobs` map among `pairs`, sorted by job ID
+fn decode_jobs(pairs: impl Iterator<Item = (Vec<u8>, Vec<u8>)>) -> Result<Vec<JobInfo>> {
+    let prefix = jobs_prefix();
+
+    let mut jobs = pairs
+        .filter(|(key, _)| key.starts_with(&prefix))
+        .map(|(key, value)| -> Result<JobInfo> {
+            // Blake2_128Concat keys end with the 16 byte hash followed by the encoded ID
+            let id = key
+                .get(prefix.len() + 16..)
+                .and_then(|mut id| u64::decode_all(&mut id).ok())
+                .ok_or_else(|| format!("Invalid job key 0x{}", HexDisplay::from(&key)))?;
+            let job = Job::<AccountId, BlockNumber>::decode_all(&mut &value[..])
+                .map_err(|e| format!("Failed to decode job {}: {}", id, e))?;
+            Ok(JobInfo::new(id, job))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    jobs.sort_by_key(|job| job.id);
+    Ok(jobs)
+}
+
+fn write_csv(out: &mut dyn Write, jobs: &[JobInfo]) -> io::Result<()> {
+    writeln!(out, "id,owner,metadata,dependencies,deadline,status,submittedAt,completedAt")?;
+    for job in jobs {
+        let dependencies: Vec<String> = job.dependencies.iter().map(u64::to_string).collect();
+        writeln!(
+            out,
+            "{},{},0x{},{},{},{},{},{}",
+            job.id,
+            job.owner,
+            HexDisplay::from(&job.metadata.0),
+            dependencies.join(";"),
+            job.deadline,
+            job.status,
+            job.submitted_at,
+            job.completed_at.map(|at| at.to_string()).unwrap_or_default(),
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,289 @@
+//! External executors: native commands and OCI containers that `JobSpec::External` jobs are
+//! delegated to.
+//!
+//! The offchain worker can't run processes, so it queues such jobs in the offchain database.
+//! On every imported block the node takes them from there, runs the executor its operator
+//! configured under the job's executor name with the job's input on stdin, and records what it
+//! wrote to stdout for the offchain worker to submit, signed. Executors are never run unless
+//! configured: their names are the allowlist the offchain worker claims jobs by.
+
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use polkacomputelab_runtime::{
+    opaque::Block,
+    pallet_job_registry::ocw::{external_execution_key, ExternalExecution, EXTERNAL_QUEUE_KEY},
+};
+use sc_client_api::BlockchainEvents;
+use sc_service::SpawnTaskHandle;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+    path::PathBuf,
+    process::{Command, ExitStatus, Stdio},
+    sync::Arc,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Most bytes of stdout kept as a job's result
+pub const MAX_OUTPUT_SIZE: usize = 1024 * 1024;
+
+/// Interval the exit of a running executor is polled at
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// What an executor runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Executor {
+    /// A native command, run without arguments
+    Command(PathBuf),
+    /// An OCI image, run by the container runtime without network access
+    Container(String),
+}
+
+/// Why an executor produced no result
+#[derive(Debug)]
+pub enum ExecutionError {
+    /// No executor of the job's executor name is configured
+    NotConfigured,
+    /// The executor couldn't be started or waited for
+    Io(io::Error),
+    /// The executor outlived the job's timeout and was killed
+    Timeout,
+    /// The executor exited unsuccessfully
+    Failed(ExitStatus),
+    /// The executor wrote more than [`MAX_OUTPUT_SIZE`] bytes
+    OutputTooLarge,
+}
+
+/// The executors configured by the node's operator, by name
+#[derive(Debug, Clone, Default)]
+pub struct ExternalExecutors {
+    executors: BTreeMap<Vec<u8>, Executor>,
+    container_runtime: PathBuf,
+}
+
+impl ExternalExecutors {
+    /// Executors of the given names, checking that commands are absolute paths to files
+    pub fn new(
+        commands: Vec<(String, PathBuf)>,
+        containers: Vec<(String, String)>,
+        container_runtime: PathBuf,
+    ) -> Result<Self, String> {
+        let named = commands
+            .into_iter()
+            .map(|(name, path)| (name, Executor::Command(path)))
+            .chain(containers.into_iter().map(|(name, image)| (name, Executor::Container(image))));
+
+        let mut executors = BTreeMap::new();
+        for (name, executor) in named {
+            if let Executor::Command(path) = &executor {
+                if !path.is_absolute() || !path.is_file() {
+                    return Err(format!(
+                        "External executor `{}`: {} is not an absolute path to a file",
+                        name,
+                        path.display()
+                    ));
+                }
+            }
+            if executors.insert(name.clone().into_bytes(), executor).is_some() {
+                return Err(format!("External executor `{}` is configured twice", name));
+            }
+        }
+
+        Ok(Self { executors, container_runtime })
+    }
+
+    /// Names of the executors, the allowlist of the offchain worker
+    pub fn names(&self) -> Vec<Vec<u8>> {
+        self.executors.keys().cloned().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.executors.is_empty()
+    }
+
+    /// Run the executor named `name` on `input`, returning what it wrote to stdout
+    pub fn execute(
+        &self,
+        name: &[u8],
+        input: &[u8],
+        timeout: Duration,
+    ) -> Result<Vec<u8>, ExecutionError> {
+        let mut command = match self.executors.get(name).ok_or(ExecutionError::NotConfigured)? {
+            Executor::Command(path) => Command::new(path),
+            Executor::Container(image) => {
+                let mut command = Command::new(&self.container_runtime);
+                command.args(["run", "--rm", "--interactive", "--network", "none", image]);
+                command
+            },
+        };
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(ExecutionError::Io)?;
+
+        // Feed stdin and drain stdout on their own threads, so neither blocks on a full pipe
+        let mut stdin = child.stdin.take().expect("stdin is piped; qed");
+        let input = input.to_vec();
+        thread::spawn(move || stdin.write_all(&input));
+        let stdout = child.stdout.take().expect("stdout is piped; qed");
+        let reader = thread::spawn(move || {
+            let mut output = Vec::new();
+            stdout.take(MAX_OUTPUT_SIZE as u64 + 1).read_to_end(&mut output).map(|_| output)
+        });
+
+        let deadline = Instant::now() + timeout;
+        let status = loop {
+            match child.try_wait().map_err(ExecutionError::Io)? {
+                Some(status) => break status,
+                None if Instant::now() >= deadline => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(ExecutionError::Timeout);
+                },
+                None => thread::sleep(POLL_INTERVAL),
+            }
+        };
+
+        // An executor cut off at the output limit may exit on the closed pipe
+        let output = reader
+            .join()
+            .expect("reading stdout doesn't panic; qed")
+            .map_err(ExecutionError::Io)?;
+        if output.len() > MAX_OUTPUT_SIZE {
+            return Err(ExecutionError::OutputTooLarge);
+        }
+        if !status.success() {
+            return Err(ExecutionError::Failed(status));
+        }
+        Ok(output)
+    }
+}
+
+/// Current UNIX time in milliseconds, the time base of the offchain worker's timestamps
+fn unix_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |now| now.as_millis() as u64)
+}
+
+/// Take the jobs the offchain worker queued, marking them running, with their executor name,
+/// input and timeout
+fn take_queued<S: OffchainStorage>(storage: &mut S) -> Vec<(u64, Vec<u8>, Vec<u8>, u64)> {
+    let Some(raw) = storage.get(STORAGE_PREFIX, EXTERNAL_QUEUE_KEY) else { return Vec::new() };
+    let empty = Vec::<u64>::new().encode();
+    // The offchain worker appends to the queue concurrently; a lost race is retried on the
+    // next block
+    if raw == empty ||
+        !storage.compare_and_set(STORAGE_PREFIX, EXTERNAL_QUEUE_KEY, Some(&raw), &empty)
+    {
+        return Vec::new();
+    }
+
+    Vec::<u64>::decode(&mut &raw[..])
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|job_id| {
+            let key = external_execution_key(job_id);
+            let raw = storage.get(STORAGE_PREFIX, &key)?;
+            let ExternalExecution::Queued { executor, input, timeout_ms } =
+                ExternalExecution::decode(&mut &raw[..]).ok()?
+            else {
+                return None;
+            };
+            let running = ExternalExecution::Running {
+                deadline: unix_millis().saturating_add(timeout_ms),
+            };
+            storage.set(STORAGE_PREFIX, &key, &running.encode());
+            Some((job_id, executor, input, timeout_ms))
+        })
+        .collect()
+}
+
+/// Run the jobs the offchain worker queues for the external executors, each on a blocking
+/// task, recording their outcome in the offchain database
+pub async fn run<C, S>(
+    client: Arc<C>,
+    mut storage: S,
+    executors: ExternalExecutors,
+    spawn_handle: SpawnTaskHandle,
+) where
+    C: BlockchainEvents<Block>,
+    S: OffchainStorage + 'static,
+{
+    let executors = Arc::new(executors);
+    let mut imports = client.import_notification_stream();
+
+    while imports.next().await.is_some() {
+        for (job_id, executor, input, timeout_ms) in take_queued(&mut storage) {
+            let executors = executors.clone();
+            let mut storage = storage.clone();
+            spawn_handle.spawn_blocking("pcl-external-job", Some("pcl-external"), async move {
+                let name = String::from_utf8_lossy(&executor);
+                log::info!(target: "external-executor", "Running job {} on `{}`", job_id, name);
+
+                let timeout = Duration::from_millis(timeout_ms);
+                let outcome = match executors.execute(&executor, &input, timeout) {
+                    Ok(output) => {
+                        log::info!(
+                            target: "external-executor",
+                            "Job {} executed by `{}`: {} bytes of output",
+                            job_id,
+                            name,
+                            output.len(),
+                        );
+                        ExternalExecution::Finished { success: true, output }
+                    },
+                    Err(e) => {
+                        log::warn!(
+                            target: "external-executor",
+                            "Job {} failed on `{}`: {:?}", job_id, name, e
+                        );
+                        ExternalExecution::Finished { success: false, output: Vec::new() }
+                    },
+                };
+                storage.set(STORAGE_PREFIX, &external_execution_key(job_id), &outcome.encode());
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn executors_run_on_stdin_within_limits() {
+        // A shell without arguments runs its stdin as a script
+        let sh = PathBuf::from("/bin/sh");
+        let executors =
+            ExternalExecutors::new(vec![("sh".into(), sh.clone())], vec![], "docker".into())
+                .unwrap();
+        let timeout = Duration::from_secs(10);
+
+        assert_eq!(executors.names(), vec![b"sh".to_vec()]);
+        assert_eq!(executors.execute(b"sh", b"echo hello", timeout).unwrap(), b"hello\n");
+        assert!(matches!(
+            executors.execute(b"sh", b"exit 3", timeout),
+            Err(ExecutionError::Failed(status)) if status.code() == Some(3)
+        ));
+        assert!(matches!(
+            executors.execute(b"sh", b"sleep 5", Duration::from_millis(100)),
+            Err(ExecutionError::Timeout)
+        ));
+        assert!(matches!(
+            executors.execute(b"sh", b"head -c 1048577 /dev/zero", timeout),
+            Err(ExecutionError::OutputTooLarge)
+        ));
+        assert!(matches!(
+            executors.execute(b"python", b"", timeout),
+            Err(ExecutionError::NotConfigured)
+        ));
+
+        assert!(ExternalExecutors::new(vec![("sh".into(), "sh".into())], vec![], sh.clone())
+            .is_err());
+        let twice = vec![("sh".into(), sh.clone()), ("sh".into(), sh)];
+        assert!(ExternalExecutors::new(twice, vec![], "docker".into()).is_err());
+    }
+}
@@ -0,0 +1,385 @@
+//! Node-side index of job and event-hub history.
+//!
+//! On-chain storage drops removed jobs and purged events, and pruned nodes drop the state of old
+//! blocks. The indexer records the job and event-hub events of every finalized block in the
+//! client's auxiliary database, bucketed by job owner and by event topic, for the `pcl_history`
+//! RPCs. History starts at the block finalized when the indexer first ran.
+
+use codec::{Codec, Decode, Encode};
+use futures::StreamExt;
+use pallet_event_hub_runtime_api::EventHubApi;
+use pallet_job_registry_runtime_api::JobRegistryApi;
+use polkacomputelab_runtime::{
+    opaque::Block, pallet_event_hub, pallet_job_registry, AccountId, BlockNumber, RuntimeEvent,
+};
+use sc_client_api::{AuxStore, Backend, BlockchainEvents, StorageProvider};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::twox_128, storage::StorageKey, H256};
+use sp_runtime::traits::Header as HeaderT;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Blocks covered by one index bucket
+pub const BUCKET_BLOCKS: BlockNumber = 1_000;
+
+/// Widest block range a history query may span
+pub const MAX_HISTORY_RANGE: BlockNumber = 100_000;
+
+/// Last block indexed
+const LAST_INDEXED_KEY: &[u8] = b"pcl::history::last";
+/// Owner of a job, by job ID
+const JOB_OWNER_PREFIX: &[u8] = b"pcl::history::owner::";
+/// Topic of an event-hub event, by event ID
+const EVENT_TOPIC_PREFIX: &[u8] = b"pcl::history::topic::";
+/// Job history entries, by owner and bucket
+const JOBS_PREFIX: &[u8] = b"pcl::history::jobs::";
+/// Event history entries, by topic and bucket
+const EVENTS_PREFIX: &[u8] = b"pcl::history::events::";
+
+/// What happened to a job
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JobEventKind {
+    Submitted,
+    StatusUpdated,
+    Claimed,
+    Completed,
+    Failed,
+    Removed,
+}
+
+/// A job event, as returned by `pcl_history_jobs`
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobHistoryEntry {
+    pub block: BlockNumber,
+    pub job_id: u64,
+    pub kind: JobEventKind,
+}
+
+/// What happened to an event-hub event
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HubEventKind {
+    Submitted,
+    CrossChainReceived,
+    Derived,
+    Processed,
+    RetryScheduled,
+    DeadLettered,
+    Requeued,
+    Purged,
+    Evicted,
+}
+
+/// An event-hub event, as returned by `pcl_history_events`
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventHistoryEntry {
+    pub block: BlockNumber,
+    pub event_id: u64,
+    pub kind: HubEventKind,
+}
+
+fn bucket(block: BlockNumber) -> BlockNumber {
+    block / BUCKET_BLOCKS
+}
+
+fn job_owner_key(job_id: u64) -> Vec<u8> {
+    [JOB_OWNER_PREFIX, &job_id.to_le_bytes()[..]].concat()
+}
+
+fn event_topic_key(event_id: u64) -> Vec<u8> {
+    [EVENT_TOPIC_PREFIX, &event_id.to_le_bytes()[..]].concat()
+}
+
+fn jobs_key(owner: &AccountId, bucket: BlockNumber) -> Vec<u8> {
+    [JOBS_PREFIX, owner.as_ref(), &bucket.to_be_bytes()[..]].concat()
+}
+
+fn events_key(topic: u64, bucket: BlockNumber) -> Vec<u8> {
+    [EVENTS_PREFIX, &topic.to_le_bytes()[..], &bucket.to_be_bytes()[..]].concat()
+}
+
+fn read<C: AuxStore, T: Decode>(client: &C, key: &[u8]) -> Option<T> {
+    client.get_aux(key).ok().flatten().and_then(|raw| T::decode(&mut &raw[..]).ok())
+}
+
+/// Index writes of one block, committed atomically with the block's number
+struct Batch<'a, C> {
+    client: &'a C,
+    writes: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl<'a, C: AuxStore> Batch<'a, C> {
+    fn new(client: &'a C) -> Self {
+        Self { client, writes: BTreeMap::new() }
+    }
+
+    /// Value at `key`, including writes of this batch
+    fn get<T: Decode>(&self, key: &[u8]) -> Option<T> {
+        match self.writes.get(key) {
+            Some(raw) => T::decode(&mut &raw[..]).ok(),
+            None => read(self.client, key),
+        }
+    }
+
+    fn set(&mut self, key: Vec<u8>, value: &impl Encode) {
+        self.writes.insert(key, value.encode());
+    }
+
+    fn append<E: Codec>(&mut self, key: Vec<u8>, entry: E) {
+        let mut entries: Vec<E> = self.get(&key).unwrap_or_default();
+        entries.push(entry);
+        self.set(key, &entries);
+    }
+
+    fn commit(self) -> sp_blockchain::Result<()> {
+        let writes: Vec<(&[u8], &[u8])> =
+            self.writes.iter().map(|(key, value)| (&key[..], &value[..])).collect();
+        self.client.insert_aux(&writes, &[])
+    }
+}
+
+/// Index every newly finalized block
+pub async fn run_indexer<C, B>(client: Arc<C>)
+where
+    B: Backend<Block>,
+    C: AuxStore
+        + HeaderBackend<Block>
+        + BlockchainEvents<Block>
+        + StorageProvider<Block, B>
+        + ProvideRuntimeApi<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: JobRegistryApi<Block, AccountId, BlockNumber>,
+    C::Api: EventHubApi<Block, AccountId, BlockNumber>,
+{
+    let mut finality = client.finality_notification_stream();
+    let mut last = read::<_, BlockNumber>(&*client, LAST_INDEXED_KEY);
+
+    while let Some(notification) = finality.next().await {
+        let finalized = *notification.header.number();
+        let from = last.map_or(finalized, |last| last + 1);
+
+        for number in from..=finalized {
+            if let Err(e) = index_block(&*client, number) {
+                log::error!(target: "history", "Failed to index block #{}: {}", number, e);
+                break;
+            }
+            last = Some(number);
+        }
+    }
+}
+
+/// Index the events of the finalized block `number`
+///
+/// A block whose events are unavailable, its state pruned, is skipped rather than retried.
+fn index_block<C, B>(client: &C, number: BlockNumber) -> sp_blockchain::Result<()>
+where
+    B: Backend<Block>,
+    C: AuxStore + HeaderBackend<Block> + StorageProvider<Block, B> + ProvideRuntimeApi<Block>,
+    C::Api: JobRegistryApi<Block, AccountId, BlockNumber>,
+    C::Api: EventHubApi<Block, AccountId, BlockNumber>,
+{
+    let mut batch = Batch::new(client);
+
+    let events = client.hash(number)?.map(|hash| (hash, block_events(client, hash)));
+    match events {
+        Some((hash, Ok(events))) => {
+            for event in events {
+                index_event(client, &mut batch, hash, number, event);
+            }
+        },
+        Some((_, Err(e))) => {
+            log::warn!(target: "history", "Events of block #{} not indexed: {}", number, e);
+        },
+        None => log::warn!(target: "history", "Block #{} not found", number),
+    }
+
+    batch.set(LAST_INDEXED_KEY.to_vec(), &number);
+    batch.commit()
+}
+
+/// Runtime events deposited in block `hash`
+fn block_events<C, B>(client: &C, hash: H256) -> Result<Vec<RuntimeEvent>, String>
+where
+    B: Backend<Block>,
+    C: StorageProvider<Block, B>,
+{
+    let key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+    let Some(raw) = client.storage(hash, &key).map_err(|e| e.to_string())? else {
+        return Ok(Vec::new());
+    };
+    let records = Vec::<frame_system::EventRecord<RuntimeEvent, H256>>::decode(&mut &raw.0[..])
+        .map_err(|e| e.to_string())?;
+    Ok(records.into_iter().map(|record| record.event).collect())
+}
+
+fn index_event<C>(
+    client: &C,
+    batch: &mut Batch<'_, C>,
+    hash: H256,
+    number: BlockNumber,
+    event: RuntimeEvent,
+) where
+    C: AuxStore + ProvideRuntimeApi<Block>,
+    C::Api: JobRegistryApi<Block, AccountId, BlockNumber>,
+    C::Api: EventHubApi<Block, AccountId, BlockNumber>,
+{
+    match event {
+        RuntimeEvent::JobRegistry(event) => {
+            use pallet_job_registry::Event;
+
+            let (job_id, kind) = match event {
+                Event::JobSubmitted { job_id, owner } => {
+                    batch.set(job_owner_key(job_id), &owner);
+                    (job_id, JobEventKind::Submitted)
+                },
+                Event::JobStatusUpdated { job_id } => (job_id, JobEventKind::StatusUpdated),
+                Event::JobClaimed { job_id, .. } => (job_id, JobEventKind::Claimed),
+                Event::JobCompleted { job_id, .. } => (job_id, JobEventKind::Completed),
+                Event::JobFailed { job_id } => (job_id, JobEventKind::Failed),
+                Event::JobRemoved { job_id } => (job_id, JobEventKind::Removed),
+                _ => return,
+            };
+            // Jobs submitted before the index started are looked up on-chain
+            let owner = batch.get::<AccountId>(&job_owner_key(job_id)).or_else(|| {
+                let owner = client.runtime_api().job(hash, job_id).ok().flatten()?.owner;
+                batch.set(job_owner_key(job_id), &owner);
+                Some(owner)
+            });
+            if let Some(owner) = owner {
+                let entry = JobHistoryEntry { block: number, job_id, kind };
+                batch.append(jobs_key(&owner, bucket(number)), entry);
+            }
+        },
+        RuntimeEvent::EventHub(event) => {
+            use pallet_event_hub::Event;
+
+            let (event_id, kind) = match event {
+                Event::EventSubmitted { event_id } => (event_id, HubEventKind::Submitted),
+                Event::CrossChainEventReceived { event_id, .. } => {
+                    (event_id, HubEventKind::CrossChainReceived)
+                },
+                Event::DerivedEventEmitted { event_id, topic, .. } => {
+                    batch.set(event_topic_key(event_id), &topic);
+                    (event_id, HubEventKind::Derived)
+                },
+                Event::EventProcessed { event_id } => (event_id, HubEventKind::Processed),
+                Event::EventRetryScheduled { event_id, .. } => {
+                    (event_id, HubEventKind::RetryScheduled)
+                },
+                Event::EventDeadLettered { event_id, .. } => (event_id, HubEventKind::DeadLettered),
+                Event::EventRequeued { event_id } => (event_id, HubEventKind::Requeued),
+                Event::EventPurged { event_id, .. } => (event_id, HubEventKind::Purged),
+                Event::EventEvicted { event_id, .. } => (event_id, HubEventKind::Evicted),
+                _ => return,
+            };
+            // Events without a topic are not indexed
+            let topic = batch.get::<u64>(&event_topic_key(event_id)).or_else(|| {
+                let topic = client.runtime_api().event(hash, event_id).ok().flatten()?.topic?;
+                batch.set(event_topic_key(event_id), &topic);
+                Some(topic)
+            });
+            if let Some(topic) = topic {
+                let entry = EventHistoryEntry { block: number, event_id, kind };
+                batch.append(events_key(topic, bucket(number)), entry);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Indexed events of the jobs of `owner` from block `from` to `to`, inclusive
+pub fn job_history<C: AuxStore>(
+    client: &C,
+    owner: &AccountId,
+    from: BlockNumber,
+    to: BlockNumber,
+) -> Vec<JobHistoryEntry> {
+    (bucket(from)..=bucket(to))
+        .flat_map(|b| read::<_, Vec<JobHistoryEntry>>(client, &jobs_key(owner, b)))
+        .flatten()
+        .filter(|entry| (from..=to).contains(&entry.block))
+        .collect()
+}
+
+/// Indexed events published on `topic` from block `from` to `to`, inclusive
+pub fn event_history<C: AuxStore>(
+    client: &C,
+    topic: u64,
+    from: BlockNumber,
+    to: BlockNumber,
+) -> Vec<EventHistoryEntry> {
+    (bucket(from)..=bucket(to))
+        .flat_map(|b| read::<_, Vec<EventHistoryEntry>>(client, &events_key(topic, b)))
+        .flatten()
+        .filter(|entry| (from..=to).contains(&entry.block))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryAux(Mutex<BTreeMap<Vec<u8>, Vec<u8>>>);
+
+    impl AuxStore for MemoryAux {
+        fn insert_aux<
+            'a,
+            'b: 'a,
+            'c: 'a,
+            I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+            D: IntoIterator<Item = &'a &'b [u8]>,
+        >(
+            &self,
+            insert: I,
+            delete: D,
+        ) -> sp_blockchain::Result<()> {
+            let mut aux = self.0.lock().unwrap();
+            for (key, value) in insert {
+                aux.insert(key.to_vec(), value.to_vec());
+            }
+            for key in delete {
+                aux.remove(*key);
+            }
+            Ok(())
+        }
+
+        fn get_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+    }
+
+    #[test]
+    fn job_history_spans_buckets_within_the_range() {
+        let aux = MemoryAux::default();
+        let owner = AccountId::new([1; 32]);
+        let entry = |block, kind| JobHistoryEntry { block, job_id: 0, kind };
+
+        let mut batch = Batch::new(&aux);
+        batch.append(jobs_key(&owner, bucket(10)), entry(10, JobEventKind::Submitted));
+        batch.append(jobs_key(&owner, bucket(10)), entry(10, JobEventKind::Claimed));
+        batch.commit().unwrap();
+        let mut batch = Batch::new(&aux);
+        batch.append(jobs_key(&owner, bucket(1_500)), entry(1_500, JobEventKind::Completed));
+        batch.append(jobs_key(&owner, bucket(2_500)), entry(2_500, JobEventKind::Removed));
+        batch.commit().unwrap();
+
+        assert_eq!(
+            job_history(&aux, &owner, 10, 2_000),
+            vec![
+                entry(10, JobEventKind::Submitted),
+                entry(10, JobEventKind::Claimed),
+                entry(1_500, JobEventKind::Completed),
+            ],
+        );
+        assert_eq!(job_history(&aux, &owner, 11, 1_499), vec![]);
+        assert!(job_history(&aux, &AccountId::new([2; 32]), 0, 3_000).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+//! The `key` subcommands, extended with `insert-ocw` for the offchain worker key.
+
+use polkacomputelab_runtime::{pallet_job_registry::KEY_TYPE, AccountId};
+use sc_cli::{utils, Error, KeystoreParams, Result, SharedParams, SubstrateCli};
+use sc_keystore::LocalKeystore;
+use sc_service::config::{BasePath, KeystoreConfig};
+use sp_core::{crypto::Ss58Codec, hexdisplay::HexDisplay, sr25519, Pair};
+use sp_keystore::Keystore;
+
+/// Key management subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum KeyCmd {
+    /// Insert the offchain worker key into the keystore, generating one if no URI is given.
+    InsertOcw(InsertOcwCmd),
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    Base(sc_cli::KeySubcommand),
+}
+
+impl KeyCmd {
+    /// Run the key subcommand
+    pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<()> {
+        match self {
+            KeyCmd::InsertOcw(cmd) => cmd.run(cli),
+            KeyCmd::Base(cmd) => cmd.run(cli),
+        }
+    }
+}
+
+/// Insert the sr25519 key offchain workers sign their transactions with, of key type `pcl!`.
+#[derive(Debug, clap::Parser)]
+pub struct InsertOcwCmd {
+    /// Secret URI of the key. A new key is generated, and its phrase printed, if omitted.
+    #[arg(long)]
+    pub suri: Option<String>,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub keystore_params: KeystoreParams,
+}
+
+impl InsertOcwCmd {
+    /// Insert the key into the node's keystore and print its account
+    pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<()> {
+        let suri = match &self.suri {
+            Some(suri) => utils::read_uri(Some(suri))?,
+            None => {
+                let (_, phrase, _) = sr25519::Pair::generate_with_phrase(None);
+                println!("Secret phrase:       {}", phrase);
+                println!("  Store it safely; it is the only way to recover the key.");
+                phrase
+            },
+        };
+
+        let base_path = self
+            .shared_params
+            .base_path()?
+            .unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
+        let chain_id = self.shared_params.chain_id(self.shared_params.is_dev());
+        let chain_spec = cli.load_spec(&chain_id)?;
+        let config_dir = base_path.config_dir(chain_spec.id());
+
+        let KeystoreConfig::Path { path, password } =
+            self.keystore_params.keystore_config(&config_dir)?
+        else {
+            return Err("Only file system keystores are supported".into());
+        };
+
+        let public = utils::pair_from_suri::<sr25519::Pair>(&suri, password.clone())?.public();
+
+        let keystore = LocalKeystore::open(path, password)?;
+        keystore
+            .insert(KEY_TYPE, &suri, public.as_ref())
+            .map_err(|_| Error::KeystoreOperation)?;
+
+        println!("Public key (hex):    0x{}", HexDisplay::from(&public.as_ref()));
+        println!("Account (SS58):      {}", AccountId::from(public).to_ss58check());
+        println!("Fund this account: it pays the fees of the offchain worker transactions.");
+
+        Ok(())
+    }
+}
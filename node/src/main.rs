@@ -1,9 +1,13 @@
 mod chain_spec;
 mod cli;
 mod command;
+mod export_jobs;
+mod external_executor;
+mod history;
+mod key;
 mod rpc;
+mod rpc_guard;
 mod service;
-mod ocw;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
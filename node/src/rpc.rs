@@ -2,12 +2,45 @@
 
 use std::sync::Arc;
 
-use polkacomputelab_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use polkacomputelab_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 
-use sc_transaction_pool_api::TransactionPool;
+use crate::{
+    history::{self, EventHistoryEntry, JobHistoryEntry, MAX_HISTORY_RANGE},
+    rpc_guard::{PclRpcConfig, PclRpcGuard},
+};
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::{
+        error::{INTERNAL_ERROR_CODE, INVALID_PARAMS_CODE},
+        ErrorObjectOwned,
+    },
+    Extensions,
+};
+use pallet_event_hub_runtime_api::EventHubApi;
+use pallet_job_registry_runtime_api::{Job, JobRegistryApi, JobStatus};
+use pallet_job_verifier_runtime_api::{JobResult, VerifierApi};
+use polkacomputelab_runtime::{
+    pallet_job_registry::ocw::{cache_key, CachedResult, OcwMetrics, SubmissionStatus, METRICS_KEY},
+    pallet_job_verifier::{proof_index_key, IndexedProof, ProofType},
+};
+use pallet_telemetry_runtime_api::TelemetryApi;
+use sc_client_api::{AuxStore, BlockchainEvents};
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool, TransactionSource};
+use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::{
+    offchain::{OffchainStorage, STORAGE_PREFIX},
+    Bytes, H256,
+};
+use sp_keystore::KeystorePtr;
+use sp_runtime::{generic::Era, traits::Block as BlockT, MultiSignature, OpaqueExtrinsic};
+use substrate_frame_rpc_system::AccountNonceApi;
+use substrate_prometheus_endpoint::{register, Gauge, PrometheusError, Registry, U64};
 
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
 
@@ -15,39 +48,1156 @@ pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
 /// Full client dependencies
-pub struct FullDeps<C, P> {
+pub struct FullDeps<C, P, S> {
     /// The client instance to use.
     pub client: Arc<C>,
     /// Transaction pool instance.
     pub pool: Arc<P>,
+    /// Keystore holding the key `pcl_job_submit` signs with
+    pub keystore: KeystorePtr,
+    /// Offchain database, holding the proofs `pcl_proof_get` serves and the offchain worker's
+    /// submissions `pcl_verifier_status` reports
+    pub offchain_storage: Option<S>,
+    /// Whether the node indexes submitted proofs into its offchain database
+    pub offchain_indexing: bool,
+    /// Gate and rate limit of the `pcl_` methods
+    pub pcl_rpc: PclRpcConfig,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
 }
 
 /// Instantiate all RPC extensions.
-pub fn create_full<C, P>(
-    deps: FullDeps<C, P>,
+pub fn create_full<C, P, S>(
+    deps: FullDeps<C, P, S>,
 ) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
     C: ProvideRuntimeApi<Block>
         + HeaderBackend<Block>
         + HeaderMetadata<Block, Error = BlockChainError>
+        + AuxStore
         + Send
         + Sync
         + 'static,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: BlockBuilder<Block>,
-    P: TransactionPool + Sync + Send + 'static,
+    C::Api: JobRegistryApi<Block, AccountId, BlockNumber>,
+    C::Api: VerifierApi<Block>,
+    C::Api: TelemetryApi<Block, AccountId>,
+    P: TransactionPool<Block = Block, Hash = <Block as BlockT>::Hash> + Sync + Send + 'static,
+    S: OffchainStorage + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcExtension::new(());
-    let FullDeps { client, pool, deny_unsafe } = deps;
+    let FullDeps {
+        client,
+        pool,
+        keystore,
+        offchain_storage,
+        offchain_indexing,
+        pcl_rpc,
+        deny_unsafe,
+    } = deps;
+    let guard = PclRpcGuard::new(pcl_rpc, deny_unsafe);
 
-    module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(JobRpc::new(client.clone(), guard.clone()).into_rpc())?;
+    module.merge(JobSubmitRpc::new(client.clone(), pool, keystore, guard.clone()).into_rpc())?;
+    module.merge(MetricsRpc::new(client.clone(), guard.clone()).into_rpc())?;
+    module.merge(HistoryRpc::new(client.clone(), guard.clone()).into_rpc())?;
+    module.merge(
+        VerificationRpc::new(client, offchain_storage.clone(), guard.clone()).into_rpc(),
+    )?;
+    module.merge(ProofRpc::new(offchain_storage, offchain_indexing, guard).into_rpc())?;
 
     Ok(module)
 }
+
+/// Most jobs returned by a single `pcl_job_list` or `pcl_job_ready` call
+pub const MAX_JOBS_PER_CALL: u32 = 100;
+
+/// A job as returned by the `pcl_job` RPCs
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobInfo {
+    pub id: u64,
+    pub owner: AccountId,
+    pub metadata: Bytes,
+    pub dependencies: Vec<u64>,
+    pub deadline: BlockNumber,
+    /// `pending`, `inProgress`, `completed`, `verified` or `failed`
+    pub status: String,
+    pub submitted_at: BlockNumber,
+    pub completed_at: Option<BlockNumber>,
+}
+
+/// Name of a job status in RPC results
+fn status_name(status: &JobStatus) -> &'static str {
+    match status {
+        JobStatus::Pending => "pending",
+        JobStatus::InProgress => "inProgress",
+        JobStatus::Completed => "completed",
+        JobStatus::Verified => "verified",
+        JobStatus::Failed => "failed",
+    }
+}
+
+impl JobInfo {
+    pub(crate) fn new(id: u64, job: Job<AccountId, BlockNumber>) -> Self {
+        Self {
+            id,
+            owner: job.owner,
+            metadata: job.metadata.into_inner().into(),
+            dependencies: job.dependencies.into_inner(),
+            deadline: job.deadline,
+            status: status_name(&job.status).into(),
+            submitted_at: job.submitted_at,
+            completed_at: job.completed_at,
+        }
+    }
+}
+
+/// Job queries under the `pcl_job` namespace. Each takes an optional block hash and defaults to
+/// the best block.
+#[rpc(server)]
+pub trait JobApi<BlockHash> {
+    /// Jobs with IDs from `from` (default 0) upwards, in ID order. `limit` defaults to, and is
+    /// capped at, `MAX_JOBS_PER_CALL`. Gated by `--rpc-pcl-unsafe`.
+    #[method(name = "pcl_job_list", with_extensions)]
+    fn job_list(
+        &self,
+        from: Option<u64>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<JobInfo>>;
+
+    /// Job by ID
+    #[method(name = "pcl_job_get", with_extensions)]
+    fn job_get(&self, id: u64, at: Option<BlockHash>) -> RpcResult<Option<JobInfo>>;
+
+    /// IDs of pending jobs whose dependencies are met. `limit` defaults to, and is capped at,
+    /// `MAX_JOBS_PER_CALL`.
+    #[method(name = "pcl_job_ready", with_extensions)]
+    fn job_ready(&self, limit: Option<u32>, at: Option<BlockHash>) -> RpcResult<Vec<u64>>;
+}
+
+/// `JobApiServer` backed by the job registry runtime API
+pub struct JobRpc<C> {
+    client: Arc<C>,
+    guard: PclRpcGuard,
+}
+
+impl<C> JobRpc<C> {
+    pub fn new(client: Arc<C>, guard: PclRpcGuard) -> Self {
+        Self { client, guard }
+    }
+}
+
+/// `at`, or the best block if not given
+fn at_or_best<C: HeaderBackend<Block>>(
+    client: &C,
+    at: Option<<Block as BlockT>::Hash>,
+) -> <Block as BlockT>::Hash {
+    at.unwrap_or_else(|| client.info().best_hash)
+}
+
+/// Map a runtime API failure to an RPC error
+fn runtime_error(e: sp_api::ApiError) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(INTERNAL_ERROR_CODE, "Runtime API call failed", Some(e.to_string()))
+}
+
+fn job_limit(limit: Option<u32>) -> u32 {
+    limit.unwrap_or(MAX_JOBS_PER_CALL).min(MAX_JOBS_PER_CALL)
+}
+
+impl<C> JobApiServer<<Block as BlockT>::Hash> for JobRpc<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: JobRegistryApi<Block, AccountId, BlockNumber>,
+{
+    fn job_list(
+        &self,
+        ext: &Extensions,
+        from: Option<u64>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<JobInfo>> {
+        self.guard.check_unsafe(ext)?;
+        let jobs = self
+            .client
+            .runtime_api()
+            .jobs(at_or_best(&*self.client, at), from.unwrap_or(0), job_limit(limit))
+            .map_err(runtime_error)?;
+        Ok(jobs.into_iter().map(|(id, job)| JobInfo::new(id, job)).collect())
+    }
+
+    fn job_get(
+        &self,
+        ext: &Extensions,
+        id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<JobInfo>> {
+        self.guard.check(ext)?;
+        let at = at_or_best(&*self.client, at);
+        let job = self.client.runtime_api().job(at, id).map_err(runtime_error)?;
+        Ok(job.map(|job| JobInfo::new(id, job)))
+    }
+
+    fn job_ready(
+        &self,
+        ext: &Extensions,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u64>> {
+        self.guard.check(ext)?;
+        self.client
+            .runtime_api()
+            .ready_jobs(at_or_best(&*self.client, at), job_limit(limit))
+            .map_err(runtime_error)
+    }
+}
+
+/// Job submission for scripting against dev chains, without a client SDK.
+///
+/// Unsafe: the node signs with a key of its own keystore, so the method is only served where
+/// the RPC server allows unsafe methods, or with `--rpc-pcl-unsafe`.
+#[rpc(server)]
+pub trait JobSubmitApi<Hash> {
+    /// Submit a job with the first offchain worker (`pcl!`) key of the node's keystore as its
+    /// owner, returning the hash of the `submit_job` transaction
+    #[method(name = "pcl_job_submit", with_extensions)]
+    async fn job_submit(
+        &self,
+        ext: &Extensions,
+        metadata: Bytes,
+        dependencies: Vec<u64>,
+        deadline: BlockNumber,
+    ) -> RpcResult<Hash>;
+}
+
+/// `JobSubmitApiServer` signing with the node's keystore and submitting to its pool
+pub struct JobSubmitRpc<C, P> {
+    client: Arc<C>,
+    pool: Arc<P>,
+    keystore: KeystorePtr,
+    guard: PclRpcGuard,
+}
+
+impl<C, P> JobSubmitRpc<C, P> {
+    pub fn new(client: Arc<C>, pool: Arc<P>, keystore: KeystorePtr, guard: PclRpcGuard) -> Self {
+        Self { client, pool, keystore, guard }
+    }
+}
+
+fn submit_error(message: &str, data: Option<String>) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(INTERNAL_ERROR_CODE, message, data)
+}
+
+#[async_trait]
+impl<C, P> JobSubmitApiServer<<Block as BlockT>::Hash> for JobSubmitRpc<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: sp_api::Core<Block> + AccountNonceApi<Block, AccountId, Nonce>,
+    P: TransactionPool<Block = Block, Hash = <Block as BlockT>::Hash> + Sync + Send + 'static,
+{
+    async fn job_submit(
+        &self,
+        ext: &Extensions,
+        metadata: Bytes,
+        dependencies: Vec<u64>,
+        deadline: BlockNumber,
+    ) -> RpcResult<<Block as BlockT>::Hash> {
+        use polkacomputelab_runtime::{
+            pallet_job_registry::KEY_TYPE, Address, Runtime, RuntimeCall, SignedExtra,
+            SignedPayload, UncheckedExtrinsic,
+        };
+        use sp_api::Core;
+
+        self.guard.check_unsafe(ext)?;
+
+        let public = self.keystore.sr25519_public_keys(KEY_TYPE).into_iter().next().ok_or_else(
+            || submit_error("No offchain worker key in the keystore", Some("pcl!".into())),
+        )?;
+        let account = AccountId::from(public);
+
+        let info = self.client.info();
+        let api = self.client.runtime_api();
+        let version = api.version(info.best_hash).map_err(runtime_error)?;
+        let nonce = api.account_nonce(info.best_hash, account.clone()).map_err(runtime_error)?;
+        let nonce = pool_nonce(&*self.pool, &account, nonce);
+
+        let call = RuntimeCall::JobRegistry(
+            polkacomputelab_runtime::pallet_job_registry::Call::submit_job {
+                metadata: metadata.0,
+                dependencies,
+                deadline,
+            },
+        );
+        // Immortal, so the payload only commits to the genesis hash
+        let extra: SignedExtra = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(Era::Immortal),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_skip_feeless_payment::SkipCheckIfFeeless::from(
+                pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+            ),
+        );
+        let implicit = (
+            (),
+            version.spec_version,
+            version.transaction_version,
+            info.genesis_hash,
+            info.genesis_hash,
+            (),
+            (),
+            (),
+        );
+        let payload = SignedPayload::from_raw(call, extra, implicit);
+
+        let signature = payload
+            .using_encoded(|payload| self.keystore.sr25519_sign(KEY_TYPE, &public, payload))
+            .map_err(|e| submit_error("Failed to sign the transaction", Some(e.to_string())))?
+            .ok_or_else(|| submit_error("Failed to sign the transaction", None))?;
+        let (call, extra, _) = payload.deconstruct();
+        let xt = UncheckedExtrinsic::new_signed(
+            call,
+            Address::Id(account),
+            MultiSignature::Sr25519(signature),
+            extra,
+        );
+        let xt = OpaqueExtrinsic::from_bytes(&xt.encode())
+            .map_err(|e| submit_error("Invalid transaction", Some(e.to_string())))?;
+
+        self.pool
+            .submit_one(info.best_hash, TransactionSource::Local, xt)
+            .await
+            .map_err(|e| submit_error("Transaction rejected", Some(e.to_string())))
+    }
+}
+
+/// Next nonce of `account`: its on-chain `nonce`, past the account's transactions ready in
+/// `pool`
+fn pool_nonce<P: TransactionPool>(pool: &P, account: &AccountId, nonce: Nonce) -> Nonce {
+    let mut nonce = nonce;
+    let mut tag = (account, nonce).encode();
+    for tx in pool.ready() {
+        if tx.provides().first() == Some(&tag) {
+            nonce += 1;
+            tag = (account, nonce).encode();
+        }
+    }
+    nonce
+}
+
+/// Inclusive block range of a `pcl_history` query
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockRange {
+    pub from: BlockNumber,
+    pub to: BlockNumber,
+}
+
+/// Historical queries under `pcl_history`, served from the node's history index rather than
+/// chain state, so they cover removed jobs, purged events and pruned blocks. Ranges may span
+/// at most `MAX_HISTORY_RANGE` blocks. Gated by `--rpc-pcl-unsafe`.
+#[rpc(server)]
+pub trait HistoryApi {
+    /// Events of the jobs owned by `owner` within `range`, in block order
+    #[method(name = "pcl_history_jobs", with_extensions)]
+    fn history_jobs(
+        &self,
+        ext: &Extensions,
+        owner: AccountId,
+        range: BlockRange,
+    ) -> RpcResult<Vec<JobHistoryEntry>>;
+
+    /// Event-hub events published on `topic` within `range`, in block order
+    #[method(name = "pcl_history_events", with_extensions)]
+    fn history_events(
+        &self,
+        ext: &Extensions,
+        topic: u64,
+        range: BlockRange,
+    ) -> RpcResult<Vec<EventHistoryEntry>>;
+}
+
+/// `HistoryApiServer` backed by the history index in the client's auxiliary database
+pub struct HistoryRpc<C> {
+    client: Arc<C>,
+    guard: PclRpcGuard,
+}
+
+impl<C> HistoryRpc<C> {
+    pub fn new(client: Arc<C>, guard: PclRpcGuard) -> Self {
+        Self { client, guard }
+    }
+}
+
+fn check_range(range: BlockRange) -> RpcResult<()> {
+    if range.from > range.to || range.to - range.from >= MAX_HISTORY_RANGE {
+        return Err(ErrorObjectOwned::owned(
+            INVALID_PARAMS_CODE,
+            "Invalid block range",
+            Some(format!("Ranges span at most {} blocks", MAX_HISTORY_RANGE)),
+        ));
+    }
+    Ok(())
+}
+
+impl<C> HistoryApiServer for HistoryRpc<C>
+where
+    C: AuxStore + Send + Sync + 'static,
+{
+    fn history_jobs(
+        &self,
+        ext: &Extensions,
+        owner: AccountId,
+        range: BlockRange,
+    ) -> RpcResult<Vec<JobHistoryEntry>> {
+        self.guard.check_unsafe(ext)?;
+        check_range(range)?;
+        Ok(history::job_history(&*self.client, &owner, range.from, range.to))
+    }
+
+    fn history_events(
+        &self,
+        ext: &Extensions,
+        topic: u64,
+        range: BlockRange,
+    ) -> RpcResult<Vec<EventHistoryEntry>> {
+        self.guard.check_unsafe(ext)?;
+        check_range(range)?;
+        Ok(history::event_history(&*self.client, topic, range.from, range.to))
+    }
+}
+
+/// A proof as returned by `pcl_proof_get`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofInfo {
+    pub job_id: u64,
+    pub submitter: AccountId,
+    pub result_hash: H256,
+    /// `signature`, `merkleRoot` or `hash`
+    pub proof_type: String,
+    pub proof: Bytes,
+    pub submitted_at: BlockNumber,
+}
+
+/// Name of a proof type in RPC results
+fn proof_type_name(proof_type: &ProofType) -> &'static str {
+    match proof_type {
+        ProofType::Signature => "signature",
+        ProofType::MerkleRoot => "merkleRoot",
+        ProofType::Hash => "hash",
+    }
+}
+
+impl ProofInfo {
+    fn new(job_id: u64, proof: IndexedProof<AccountId>) -> Self {
+        Self {
+            job_id,
+            submitter: proof.submitter,
+            result_hash: proof.result_hash,
+            proof_type: proof_type_name(&proof.proof_type).into(),
+            proof: proof.proof.into(),
+            submitted_at: proof.submitted_at,
+        }
+    }
+}
+
+/// Proofs kept in the node's off-chain index, for auditing results after on-chain state is
+/// pruned
+#[rpc(server)]
+pub trait ProofApi {
+    /// Latest proof submitted for a job, if submitted while the node indexed proofs
+    #[method(name = "pcl_proof_get", with_extensions)]
+    fn proof_get(&self, ext: &Extensions, job_id: u64) -> RpcResult<Option<ProofInfo>>;
+}
+
+/// `ProofApiServer` backed by the offchain database
+pub struct ProofRpc<S> {
+    storage: Option<S>,
+    indexing_enabled: bool,
+    guard: PclRpcGuard,
+}
+
+impl<S> ProofRpc<S> {
+    pub fn new(storage: Option<S>, indexing_enabled: bool, guard: PclRpcGuard) -> Self {
+        Self { storage, indexing_enabled, guard }
+    }
+}
+
+impl<S> ProofApiServer for ProofRpc<S>
+where
+    S: OffchainStorage + 'static,
+{
+    fn proof_get(&self, ext: &Extensions, job_id: u64) -> RpcResult<Option<ProofInfo>> {
+        self.guard.check(ext)?;
+        let storage = match &self.storage {
+            Some(storage) if self.indexing_enabled => storage,
+            _ => {
+                return Err(ErrorObjectOwned::owned(
+                    INTERNAL_ERROR_CODE,
+                    "Off-chain indexing is disabled",
+                    Some("Restart the node with `--enable-offchain-indexing true`"),
+                ))
+            },
+        };
+
+        let Some(raw) = storage.get(STORAGE_PREFIX, &proof_index_key(job_id)) else {
+            return Ok(None);
+        };
+        let proof = IndexedProof::<AccountId>::decode(&mut &raw[..]).map_err(|e| {
+            let data = Some(e.to_string());
+            ErrorObjectOwned::owned(INTERNAL_ERROR_CODE, "Invalid indexed proof", data)
+        })?;
+        Ok(Some(ProofInfo::new(job_id, proof)))
+    }
+}
+
+/// A result submitted for a job, as returned by the `pcl_verifier` RPCs
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultInfo {
+    pub job_id: u64,
+    pub result_hash: H256,
+    /// `signature`, `merkleRoot` or `hash`
+    pub proof_type: String,
+    pub submitted_at: BlockNumber,
+    pub verified: bool,
+    /// The proof failed verification and awaits a new one
+    pub rejected: bool,
+}
+
+impl ResultInfo {
+    fn new(job_id: u64, result: JobResult) -> Self {
+        Self {
+            job_id,
+            result_hash: result.result_hash,
+            proof_type: proof_type_name(&result.proof_type).into(),
+            submitted_at: result.submitted_at,
+            verified: result.verified,
+            rejected: result.rejected,
+        }
+    }
+}
+
+/// This node's offchain worker's submission of a job's result
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "state")]
+pub enum LocalSubmission {
+    /// Executed, not submitted yet
+    Pending,
+    /// Submitted to the transaction pool
+    Submitted,
+    /// Submission failed `attempts` times
+    Failed { attempts: u32 },
+}
+
+impl From<SubmissionStatus> for LocalSubmission {
+    fn from(status: SubmissionStatus) -> Self {
+        match status {
+            SubmissionStatus::Pending => Self::Pending,
+            SubmissionStatus::Submitted => Self::Submitted,
+            SubmissionStatus::Failed { attempts } => Self::Failed { attempts },
+        }
+    }
+}
+
+/// Verification status of a job as returned by `pcl_verifier_status`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationStatus {
+    pub job_id: u64,
+    /// `pending`, `inProgress`, `completed`, `verified` or `failed`
+    pub job_status: String,
+    /// Result on chain, `None` until a proof for the job landed
+    pub result: Option<ResultInfo>,
+    /// This node's own submission, `None` unless its offchain worker cached a result of the job
+    pub local_submission: Option<LocalSubmission>,
+}
+
+/// Proof counters as returned by `pcl_verifier_stats`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifierStats {
+    pub proofs_submitted: u64,
+    pub proofs_verified: u64,
+    pub proofs_failed: u64,
+}
+
+/// Result verification under the `pcl_verifier` namespace, for worker operators to follow their
+/// submissions. Each takes an optional block hash and defaults to the best block.
+#[rpc(server)]
+pub trait VerificationApi<BlockHash> {
+    /// Results awaiting verification of jobs with IDs from `from` (default 0) upwards, in ID
+    /// order. `limit` defaults to, and is capped at, `MAX_JOBS_PER_CALL`. Gated by
+    /// `--rpc-pcl-unsafe`.
+    #[method(name = "pcl_verifier_pending", with_extensions)]
+    fn verifier_pending(
+        &self,
+        from: Option<u64>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ResultInfo>>;
+
+    /// Status of a job, the result submitted for it and this node's submission of its own
+    #[method(name = "pcl_verifier_status", with_extensions)]
+    fn verifier_status(
+        &self,
+        job_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<VerificationStatus>>;
+
+    /// Proofs submitted, verified and rejected so far
+    #[method(name = "pcl_verifier_stats", with_extensions)]
+    fn verifier_stats(&self, at: Option<BlockHash>) -> RpcResult<VerifierStats>;
+}
+
+/// `VerificationApiServer` backed by the job verifier runtime API and the offchain database
+pub struct VerificationRpc<C, S> {
+    client: Arc<C>,
+    storage: Option<S>,
+    guard: PclRpcGuard,
+}
+
+impl<C, S> VerificationRpc<C, S> {
+    pub fn new(client: Arc<C>, storage: Option<S>, guard: PclRpcGuard) -> Self {
+        Self { client, storage, guard }
+    }
+}
+
+impl<C, S> VerificationApiServer<<Block as BlockT>::Hash> for VerificationRpc<C, S>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: JobRegistryApi<Block, AccountId, BlockNumber> + VerifierApi<Block>,
+    S: OffchainStorage + 'static,
+{
+    fn verifier_pending(
+        &self,
+        ext: &Extensions,
+        from: Option<u64>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ResultInfo>> {
+        self.guard.check_unsafe(ext)?;
+        let results = self
+            .client
+            .runtime_api()
+            .pending_proofs(at_or_best(&*self.client, at), from.unwrap_or(0), job_limit(limit))
+            .map_err(runtime_error)?;
+        Ok(results.into_iter().map(|(job_id, result)| ResultInfo::new(job_id, result)).collect())
+    }
+
+    fn verifier_status(
+        &self,
+        ext: &Extensions,
+        job_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<VerificationStatus>> {
+        self.guard.check(ext)?;
+        let at = at_or_best(&*self.client, at);
+        let api = self.client.runtime_api();
+        let Some(job) = api.job(at, job_id).map_err(runtime_error)? else { return Ok(None) };
+        let result = api.job_result(at, job_id).map_err(runtime_error)?;
+
+        // The offchain worker's cache is pruned after its TTL, and a corrupt entry is as good
+        // as none
+        let local_submission = self
+            .storage
+            .as_ref()
+            .and_then(|storage| storage.get(STORAGE_PREFIX, &cache_key(job_id)))
+            .and_then(|raw| CachedResult::decode(&mut &raw[..]).ok())
+            .map(|cached| cached.submission.into());
+
+        Ok(Some(VerificationStatus {
+            job_id,
+            job_status: status_name(&job.status).into(),
+            result: result.map(|result| ResultInfo::new(job_id, result)),
+            local_submission,
+        }))
+    }
+
+    fn verifier_stats(
+        &self,
+        ext: &Extensions,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<VerifierStats> {
+        self.guard.check(ext)?;
+        let stats = self
+            .client
+            .runtime_api()
+            .verification_stats(at_or_best(&*self.client, at))
+            .map_err(runtime_error)?;
+        Ok(VerifierStats {
+            proofs_submitted: stats.total_proofs_submitted,
+            proofs_verified: stats.total_proofs_verified,
+            proofs_failed: stats.total_proofs_failed,
+        })
+    }
+}
+
+/// Output format of `pcl_metrics`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsFormat {
+    /// Prometheus text exposition format
+    Prometheus,
+    /// Structured JSON
+    #[default]
+    Json,
+}
+
+/// Chain-level compute metrics as returned by `pcl_metrics`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainMetrics {
+    pub jobs_submitted: u64,
+    pub jobs_completed: u64,
+    pub jobs_failed: u64,
+    pub jobs_verified: u64,
+    /// Job execution time percentiles in blocks
+    pub execution_time_p50: u32,
+    pub execution_time_p95: u32,
+    pub execution_time_p99: u32,
+    pub forks_detected: u32,
+    pub consensus_switches: u32,
+    /// `aura`, `babe` or `custom`
+    pub consensus_type: String,
+    pub escrow_locked: u128,
+    pub bounties_paid: u128,
+    pub deposits_held: u128,
+    pub fees_burned: u128,
+    pub sla_adherence_percent: u8,
+    /// Measured time of the latest block, if recorded
+    pub block_time_ms: Option<u64>,
+    /// Weight utilization of the latest block, if recorded
+    pub block_weight_utilization_percent: Option<u8>,
+    pub xcm_inbound_pages: u64,
+    pub xcm_outbound_messages: u64,
+    pub xcm_outbound_failures: u64,
+    pub cross_chain_events: u64,
+}
+
+impl ChainMetrics {
+    /// Render as Prometheus text, with the names `TelemetryMetrics` registers where shared
+    pub fn to_prometheus(&self) -> String {
+        use std::fmt::Write;
+
+        let gauges: [(&str, &str, Option<u128>); 20] = [
+            ("jobs_submitted_total", "Total jobs submitted", Some(self.jobs_submitted.into())),
+            ("jobs_completed_total", "Total jobs completed", Some(self.jobs_completed.into())),
+            ("jobs_failed_total", "Total jobs failed", Some(self.jobs_failed.into())),
+            ("jobs_verified_total", "Total jobs verified", Some(self.jobs_verified.into())),
+            (
+                "job_execution_time_p50_blocks",
+                "Median job execution time in blocks",
+                Some(self.execution_time_p50.into()),
+            ),
+            (
+                "job_execution_time_p95_blocks",
+                "95th percentile job execution time in blocks",
+                Some(self.execution_time_p95.into()),
+            ),
+            (
+                "job_execution_time_p99_blocks",
+                "99th percentile job execution time in blocks",
+                Some(self.execution_time_p99.into()),
+            ),
+            ("forks_detected_total", "Total forks detected", Some(self.forks_detected.into())),
+            (
+                "consensus_switches_total",
+                "Total consensus switches",
+                Some(self.consensus_switches.into()),
+            ),
+            ("escrow_locked", "Funds locked in job escrow", Some(self.escrow_locked)),
+            ("bounties_paid_total", "Bounties paid out", Some(self.bounties_paid)),
+            ("deposits_held", "Deposits currently held", Some(self.deposits_held)),
+            ("fees_burned_total", "Fees burned", Some(self.fees_burned)),
+            (
+                "sla_adherence_percent",
+                "Jobs of the last completed epoch that met their deadline, in percent",
+                Some(self.sla_adherence_percent.into()),
+            ),
+            (
+                "block_time_ms",
+                "Measured time of the latest block in milliseconds",
+                self.block_time_ms.map(Into::into),
+            ),
+            (
+                "block_weight_utilization_percent",
+                "Weight utilization of the latest block in percent",
+                self.block_weight_utilization_percent.map(Into::into),
+            ),
+            ("xcm_inbound_pages_total", "Inbound XCMP pages", Some(self.xcm_inbound_pages.into())),
+            (
+                "xcm_outbound_messages_total",
+                "Outbound XCM messages delivered",
+                Some(self.xcm_outbound_messages.into()),
+            ),
+            (
+                "xcm_outbound_failures_total",
+                "Outbound XCM messages that failed to deliver",
+                Some(self.xcm_outbound_failures.into()),
+            ),
+            (
+                "cross_chain_events_total",
+                "Cross-chain events received",
+                Some(self.cross_chain_events.into()),
+            ),
+        ];
+
+        let mut out = String::new();
+        for (name, help, value) in gauges {
+            let Some(value) = value else { continue };
+            let _ = writeln!(out, "# HELP polkacomputelab_{name} {help}");
+            let _ = writeln!(out, "# TYPE polkacomputelab_{name} gauge");
+            let _ = writeln!(out, "polkacomputelab_{name} {value}");
+        }
+        let _ = writeln!(out, "# HELP polkacomputelab_consensus_type Current consensus type");
+        let _ = writeln!(out, "# TYPE polkacomputelab_consensus_type gauge");
+        let consensus_type = &self.consensus_type;
+        let _ = writeln!(out, "polkacomputelab_consensus_type{{type=\"{consensus_type}\"}} 1");
+        out
+    }
+}
+
+/// `pcl_metrics` result: Prometheus text or structured JSON
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MetricsResponse {
+    Prometheus(String),
+    Json(ChainMetrics),
+}
+
+/// Chain-level compute metrics under `pcl_metrics`, for Grafana and custom dashboards
+#[rpc(server)]
+pub trait MetricsApi<BlockHash> {
+    /// Telemetry at `at` (default: best block) as Prometheus text or, by default, JSON
+    #[method(name = "pcl_metrics", with_extensions)]
+    fn metrics(
+        &self,
+        ext: &Extensions,
+        format: Option<MetricsFormat>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<MetricsResponse>;
+}
+
+/// `MetricsApiServer` backed by the telemetry runtime API
+pub struct MetricsRpc<C> {
+    client: Arc<C>,
+    guard: PclRpcGuard,
+}
+
+impl<C> MetricsRpc<C> {
+    pub fn new(client: Arc<C>, guard: PclRpcGuard) -> Self {
+        Self { client, guard }
+    }
+}
+
+impl<C> MetricsApiServer<<Block as BlockT>::Hash> for MetricsRpc<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: TelemetryApi<Block, AccountId>,
+{
+    fn metrics(
+        &self,
+        ext: &Extensions,
+        format: Option<MetricsFormat>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<MetricsResponse> {
+        use polkacomputelab_runtime::pallet_consensus_manager::ConsensusType;
+
+        self.guard.check(ext)?;
+        let at = at_or_best(&*self.client, at);
+        let api = self.client.runtime_api();
+        let system = api.system_metrics(at).map_err(runtime_error)?;
+        let block = api
+            .recent_block_performance(at, 1)
+            .map_err(runtime_error)?
+            .into_iter()
+            .next();
+        let xcm = api.xcm_traffic(at).map_err(runtime_error)?;
+
+        let metrics = ChainMetrics {
+            jobs_submitted: system.total_jobs_submitted,
+            jobs_completed: system.total_jobs_completed,
+            jobs_failed: system.total_jobs_failed,
+            jobs_verified: system.total_jobs_verified,
+            execution_time_p50: system.execution_time_percentiles.p50,
+            execution_time_p95: system.execution_time_percentiles.p95,
+            execution_time_p99: system.execution_time_percentiles.p99,
+            forks_detected: system.total_forks,
+            consensus_switches: system.total_consensus_switches,
+            consensus_type: match system.current_consensus {
+                ConsensusType::Aura => "aura",
+                ConsensusType::Babe => "babe",
+                ConsensusType::Custom => "custom",
+            }
+            .into(),
+            escrow_locked: system.total_escrow_locked,
+            bounties_paid: system.total_bounties_paid,
+            deposits_held: system.total_deposits_held,
+            fees_burned: system.total_fees_burned,
+            sla_adherence_percent: system.sla_adherence.deconstruct(),
+            block_time_ms: block.as_ref().map(|block| block.block_time_ms),
+            block_weight_utilization_percent: block
+                .map(|block| block.weight_utilization.deconstruct()),
+            xcm_inbound_pages: xcm.inbound_xcm_pages,
+            xcm_outbound_messages: xcm.outbound_messages,
+            xcm_outbound_failures: xcm.outbound_failures,
+            cross_chain_events: xcm.cross_chain_events,
+        };
+
+        Ok(match format.unwrap_or_default() {
+            MetricsFormat::Prometheus => MetricsResponse::Prometheus(metrics.to_prometheus()),
+            MetricsFormat::Json => MetricsResponse::Json(metrics),
+        })
+    }
+}
+
+/// Pallet telemetry values mirrored onto the node's Prometheus registry.
+///
+/// The chain already keeps the totals, so everything is a gauge set to the on-chain value.
+pub struct TelemetryMetrics {
+    jobs_submitted: Gauge<U64>,
+    jobs_completed: Gauge<U64>,
+    jobs_failed: Gauge<U64>,
+    jobs_verified: Gauge<U64>,
+    execution_time_p50: Gauge<U64>,
+    execution_time_p95: Gauge<U64>,
+    execution_time_p99: Gauge<U64>,
+    forks_detected: Gauge<U64>,
+    consensus_switches: Gauge<U64>,
+    block_time_ms: Gauge<U64>,
+}
+
+impl TelemetryMetrics {
+    /// Register the gauges on `registry`
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let gauge = |name: &str, help: &str| register(Gauge::new(name, help)?, registry);
+
+        Ok(Self {
+            jobs_submitted: gauge("polkacomputelab_jobs_submitted_total", "Total jobs submitted")?,
+            jobs_completed: gauge("polkacomputelab_jobs_completed_total", "Total jobs completed")?,
+            jobs_failed: gauge("polkacomputelab_jobs_failed_total", "Total jobs failed")?,
+            jobs_verified: gauge("polkacomputelab_jobs_verified_total", "Total jobs verified")?,
+            execution_time_p50: gauge(
+                "polkacomputelab_job_execution_time_p50_blocks",
+                "Median job execution time in blocks",
+            )?,
+            execution_time_p95: gauge(
+                "polkacomputelab_job_execution_time_p95_blocks",
+                "95th percentile job execution time in blocks",
+            )?,
+            execution_time_p99: gauge(
+                "polkacomputelab_job_execution_time_p99_blocks",
+                "99th percentile job execution time in blocks",
+            )?,
+            forks_detected: gauge("polkacomputelab_forks_detected_total", "Total forks detected")?,
+            consensus_switches: gauge(
+                "polkacomputelab_consensus_switches_total",
+                "Total consensus switches",
+            )?,
+            block_time_ms: gauge(
+                "polkacomputelab_block_time_ms",
+                "Measured time of the latest block in milliseconds",
+            )?,
+        })
+    }
+}
+
+/// Refresh `metrics` from the telemetry runtime API on every new best block.
+pub async fn run_telemetry_metrics<C>(client: Arc<C>, metrics: TelemetryMetrics)
+where
+    C: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+    C::Api: TelemetryApi<Block, AccountId>,
+{
+    let mut imports = client.import_notification_stream();
+
+    while let Some(notification) = imports.next().await {
+        if !notification.is_new_best {
+            continue;
+        }
+
+        let api = client.runtime_api();
+        let system = match api.system_metrics(notification.hash) {
+            Ok(system) => system,
+            Err(e) => {
+                log::debug!(target: "telemetry", "Failed to query system metrics: {:?}", e);
+                continue;
+            },
+        };
+
+        metrics.jobs_submitted.set(system.total_jobs_submitted);
+        metrics.jobs_completed.set(system.total_jobs_completed);
+        metrics.jobs_failed.set(system.total_jobs_failed);
+        metrics.jobs_verified.set(system.total_jobs_verified);
+        metrics.execution_time_p50.set(system.execution_time_percentiles.p50.into());
+        metrics.execution_time_p95.set(system.execution_time_percentiles.p95.into());
+        metrics.execution_time_p99.set(system.execution_time_percentiles.p99.into());
+        metrics.forks_detected.set(system.total_forks.into());
+        metrics.consensus_switches.set(system.total_consensus_switches.into());
+
+        if let Ok(Some(block)) = api
+            .recent_block_performance(notification.hash, 1)
+            .map(|blocks| blocks.into_iter().next())
+        {
+            metrics.block_time_ms.set(block.block_time_ms);
+        }
+    }
+}
+
+/// Compute activity of this node and of the chain's job queue, on the node's Prometheus
+/// registry.
+///
+/// Queue depths are read through the runtime APIs; execution and proof submission totals are
+/// the [`OcwMetrics`] the offchain workers keep in persistent offchain storage.
+pub struct ComputeMetrics {
+    ready_jobs: Gauge<U64>,
+    pending_events: Gauge<U64>,
+    ocw_executions: Gauge<U64>,
+    ocw_execution_time_ms: Gauge<U64>,
+    ocw_last_execution_time_ms: Gauge<U64>,
+    proofs_submitted: Gauge<U64>,
+    proof_submission_failures: Gauge<U64>,
+}
+
+impl ComputeMetrics {
+    /// Register the gauges on `registry`
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let gauge = |name: &str, help: &str| register(Gauge::new(name, help)?, registry);
+
+        Ok(Self {
+            ready_jobs: gauge(
+                "polkacomputelab_ready_jobs",
+                "Jobs whose dependencies are met, awaiting execution",
+            )?,
+            pending_events: gauge(
+                "polkacomputelab_pending_events",
+                "Event-hub events awaiting processing",
+            )?,
+            ocw_executions: gauge(
+                "polkacomputelab_ocw_executions_total",
+                "Jobs executed by this node's offchain worker",
+            )?,
+            ocw_execution_time_ms: gauge(
+                "polkacomputelab_ocw_execution_time_ms_total",
+                "Total time this node's offchain worker spent executing jobs in milliseconds",
+            )?,
+            ocw_last_execution_time_ms: gauge(
+                "polkacomputelab_ocw_last_execution_time_ms",
+                "Execution time of the latest job executed by this node in milliseconds",
+            )?,
+            proofs_submitted: gauge(
+                "polkacomputelab_ocw_proofs_submitted_total",
+                "Job results this node submitted with their proof",
+            )?,
+            proof_submission_failures: gauge(
+                "polkacomputelab_ocw_proof_submission_failures_total",
+                "Job result submissions of this node that failed",
+            )?,
+        })
+    }
+
+    fn set_ocw(&self, ocw: &OcwMetrics) {
+        self.ocw_executions.set(ocw.executions);
+        self.ocw_execution_time_ms.set(ocw.execution_time_ms_total);
+        self.ocw_last_execution_time_ms.set(ocw.last_execution_time_ms);
+        self.proofs_submitted.set(ocw.proofs_submitted);
+        self.proof_submission_failures.set(ocw.proof_submission_failures);
+    }
+}
+
+/// Refresh `metrics` from the runtime APIs and the offchain worker totals in `storage` on
+/// every new best block.
+pub async fn run_compute_metrics<C, S>(client: Arc<C>, storage: S, metrics: ComputeMetrics)
+where
+    C: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+    C::Api: JobRegistryApi<Block, AccountId, BlockNumber>,
+    C::Api: EventHubApi<Block, AccountId, BlockNumber>,
+    S: OffchainStorage,
+{
+    let mut imports = client.import_notification_stream();
+
+    while let Some(notification) = imports.next().await {
+        if !notification.is_new_best {
+            continue;
+        }
+
+        let api = client.runtime_api();
+        match api.ready_jobs(notification.hash, u32::MAX) {
+            Ok(ready) => metrics.ready_jobs.set(ready.len() as u64),
+            Err(e) => log::debug!(target: "job-registry", "Failed to query ready jobs: {:?}", e),
+        }
+        match api.pending_events(notification.hash, u32::MAX) {
+            Ok(pending) => metrics.pending_events.set(pending.len() as u64),
+            Err(e) => log::debug!(target: "event-hub", "Failed to query pending events: {:?}", e),
+        }
+
+        if let Some(ocw) = storage
+            .get(STORAGE_PREFIX, METRICS_KEY)
+            .and_then(|raw| OcwMetrics::decode(&mut &raw[..]).ok())
+        {
+            metrics.set_ocw(&ocw);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_metrics_render_as_prometheus_text() {
+        let metrics = ChainMetrics {
+            jobs_submitted: 7,
+            consensus_type: "aura".into(),
+            block_time_ms: None,
+            ..Default::default()
+        };
+        let text = metrics.to_prometheus();
+
+        assert!(text.contains(
+            "# TYPE polkacomputelab_jobs_submitted_total gauge\n\
+             polkacomputelab_jobs_submitted_total 7\n"
+        ));
+        assert!(text.contains("polkacomputelab_consensus_type{type=\"aura\"} 1\n"));
+        // Unrecorded values are left out rather than reported as zero
+        assert!(!text.contains("polkacomputelab_block_time_ms"));
+    }
+
+    #[test]
+    fn metrics_format_parses_lowercase() {
+        let format: MetricsFormat = serde_json::from_str("\"prometheus\"").unwrap();
+        assert_eq!(format, MetricsFormat::Prometheus);
+        assert_eq!(MetricsFormat::default(), MetricsFormat::Json);
+    }
+
+    #[test]
+    fn verification_status_serializes_local_submission_by_state() {
+        let status = VerificationStatus {
+            job_id: 3,
+            job_status: "inProgress".into(),
+            result: None,
+            local_submission: Some(SubmissionStatus::Failed { attempts: 2 }.into()),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&status).unwrap(),
+            serde_json::json!({
+                "jobId": 3,
+                "jobStatus": "inProgress",
+                "result": null,
+                "localSubmission": { "state": "failed", "attempts": 2 },
+            })
+        );
+    }
+}
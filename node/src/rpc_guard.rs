@@ -0,0 +1,132 @@
+//! Access control of the `pcl_` RPC methods.
+//!
+//! Mutating and expensive methods (job submission, job and pending proof listing, and history
+//! scans) are gated: they are served where the RPC server allows unsafe methods, or anywhere
+//! once the operator passes `--rpc-pcl-unsafe`. Every `pcl_` method also counts against an
+//! optional per-connection budget of calls per minute, so a public RPC node can expose the cheap
+//! read methods without a single client monopolizing it.
+
+use jsonrpsee::{core::RpcResult, types::ErrorObjectOwned, ConnectionId, Extensions};
+use sc_rpc::DenyUnsafe;
+use std::{
+    collections::HashMap,
+    num::NonZeroU32,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Error code of a call denied by the `--rpc-pcl-unsafe` gate
+pub const PCL_UNSAFE_CODE: i32 = -32_030;
+/// Error code of a call beyond its connection's rate limit
+pub const RATE_LIMITED_CODE: i32 = -32_029;
+
+/// Window the rate limit counts calls over
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Access settings of the `pcl_` RPC methods, from the node's command line
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PclRpcConfig {
+    /// Serve the gated methods even where the RPC server denies unsafe methods
+    pub allow_unsafe: bool,
+    /// Calls each connection may make per minute, unlimited if `None`
+    pub rate_limit: Option<NonZeroU32>,
+}
+
+/// Gate and rate limiter shared by the `pcl_` RPC servers of one RPC server
+#[derive(Clone)]
+pub struct PclRpcGuard {
+    allow_unsafe: bool,
+    deny_unsafe: DenyUnsafe,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl PclRpcGuard {
+    pub fn new(config: PclRpcConfig, deny_unsafe: DenyUnsafe) -> Self {
+        Self {
+            allow_unsafe: config.allow_unsafe,
+            deny_unsafe,
+            limiter: config.rate_limit.map(|limit| Arc::new(RateLimiter::new(limit))),
+        }
+    }
+
+    /// Count a call of a read method against its connection's rate limit
+    pub fn check(&self, ext: &Extensions) -> RpcResult<()> {
+        let Some(limiter) = &self.limiter else { return Ok(()) };
+        if limiter.try_call(ext.get::<ConnectionId>().copied(), Instant::now()) {
+            Ok(())
+        } else {
+            Err(ErrorObjectOwned::owned(
+                RATE_LIMITED_CODE,
+                "Rate limit exceeded",
+                Some(format!("At most {} pcl_ calls per minute", limiter.calls_per_minute)),
+            ))
+        }
+    }
+
+    /// Check a call of a mutating or expensive method against the gate, then the rate limit
+    pub fn check_unsafe(&self, ext: &Extensions) -> RpcResult<()> {
+        if !self.allow_unsafe && self.deny_unsafe.check_if_safe().is_err() {
+            return Err(ErrorObjectOwned::owned(
+                PCL_UNSAFE_CODE,
+                "Method not served on this RPC interface",
+                Some("Start the node with --rpc-pcl-unsafe to serve it".to_string()),
+            ));
+        }
+        self.check(ext)
+    }
+}
+
+/// Fixed-window call counter per connection
+struct RateLimiter {
+    calls_per_minute: NonZeroU32,
+    /// Start of the current window and calls within it, per connection. Calls without a
+    /// connection ID share one window.
+    windows: Mutex<HashMap<Option<ConnectionId>, (Instant, u32)>>,
+}
+
+impl RateLimiter {
+    fn new(calls_per_minute: NonZeroU32) -> Self {
+        Self { calls_per_minute, windows: Mutex::new(HashMap::new()) }
+    }
+
+    /// Record a call of `connection` at `now`, returning whether it is within the limit
+    fn try_call(&self, connection: Option<ConnectionId>, now: Instant) -> bool {
+        let mut windows = self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let expired =
+            |started: Instant| now.saturating_duration_since(started) >= RATE_LIMIT_WINDOW;
+        let current = windows.get(&connection).is_some_and(|(started, _)| !expired(*started));
+        if !current {
+            // Drop the windows of closed connections along with this one's
+            windows.retain(|_, (started, _)| !expired(*started));
+            windows.insert(connection, (now, 0));
+        }
+
+        let (_, calls) = windows.get_mut(&connection).expect("inserted above; qed");
+        if *calls >= self.calls_per_minute.get() {
+            return false;
+        }
+        *calls += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit_is_per_connection_and_window() {
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap());
+        let start = Instant::now();
+        let (first, second) = (Some(ConnectionId(1)), Some(ConnectionId(2)));
+
+        assert!(limiter.try_call(first, start));
+        assert!(limiter.try_call(first, start));
+        assert!(!limiter.try_call(first, start + Duration::from_secs(59)));
+        assert!(limiter.try_call(second, start));
+
+        assert!(limiter.try_call(first, start + RATE_LIMIT_WINDOW));
+        assert_eq!(limiter.windows.lock().unwrap().len(), 1);
+    }
+}
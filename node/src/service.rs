@@ -15,7 +15,9 @@ use cumulus_client_service::{
 use cumulus_primitives_core::{relay_chain::CollatorPair, ParaId};
 use cumulus_relay_chain_interface::{OverseerHandle, RelayChainInterface};
 
-use sc_client_api::Backend;
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use sc_client_api::{Backend, BlockchainEvents};
 use sc_consensus::ImportQueue;
 use sc_executor::{
     HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY,
@@ -24,25 +26,29 @@ use sc_network::NetworkBlock;
 use sc_service::{Configuration, PartialComponents, TFullBackend, TFullClient, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker, TelemetryWorkerHandle};
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
 use sp_keystore::KeystorePtr;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
 use std::{sync::Arc, time::Duration};
 
-use polkacomputelab_runtime::{self, opaque::Block, RuntimeApi};
+use crate::{external_executor::ExternalExecutors, rpc_guard::PclRpcConfig};
 
-/// Native executor type.
-pub struct Executor;
-
-impl sc_executor::NativeExecutionDispatch for Executor {
-    type ExtendHostFunctions = frame_benchmarking::benchmarking::HostFunctions;
-
-    fn dispatch(method: &str, data: &[u8]) -> Option<Vec<u8>> {
-        polkacomputelab_runtime::api::dispatch(method, data)
-    }
+use polkacomputelab_runtime::{
+    self,
+    opaque::Block,
+    pallet_consensus_manager::{ForkEvidence, FORK_EVIDENCE_KEY, MAX_FORK_BRANCH},
+    pallet_job_registry::ocw::{
+        cache_key, OcwConfig, CACHE_INDEX_KEY, EXTERNAL_EXECUTORS_KEY, OCW_CONFIG_KEY,
+    },
+    RuntimeApi,
+};
 
-    fn native_version() -> sc_executor::NativeVersion {
-        polkacomputelab_runtime::native_version()
-    }
-}
+/// Host functions of the runtime when executed outside the node's client, by `try-runtime`:
+/// the node's, and the benchmarking ones `try-runtime` builds runtimes with.
+#[cfg(feature = "try-runtime")]
+pub type HostFunctions =
+    (sp_io::SubstrateHostFunctions, frame_benchmarking::benchmarking::HostFunctions);
 
 type ParachainExecutor = WasmExecutor<sp_io::SubstrateHostFunctions>;
 
@@ -52,6 +58,79 @@ type ParachainBackend = TFullBackend<Block>;
 
 type ParachainBlockImport = TParachainBlockImport<Block, Arc<ParachainClient>, ParachainBackend>;
 
+/// Fork evidence entries kept until the consensus manager's offchain worker picks them up
+const MAX_FORK_EVIDENCE: usize = 16;
+
+/// Record every header imported off the best chain as fork evidence for the consensus
+/// manager's offchain worker to verify and report: a header building on a recorded competing
+/// branch extends it, any other is recorded next to its canonical sibling.
+async fn record_fork_evidence<S: OffchainStorage>(client: Arc<ParachainClient>, mut storage: S) {
+    type Header = <Block as BlockT>::Header;
+
+    let mut imports = client.import_notification_stream();
+
+    while let Some(notification) = imports.next().await {
+        if notification.is_new_best {
+            continue;
+        }
+
+        let header = notification.header;
+        let mut evidence = storage
+            .get(STORAGE_PREFIX, FORK_EVIDENCE_KEY)
+            .and_then(|raw| Vec::<ForkEvidence<Header>>::decode(&mut &raw[..]).ok())
+            .unwrap_or_default();
+
+        let extended = evidence.iter_mut().find(|(_, root, branch)| {
+            branch.len() < MAX_FORK_BRANCH as usize &&
+                branch.last().unwrap_or(root).hash() == *header.parent_hash()
+        });
+        if let Some((_, _, branch)) = extended {
+            log::debug!(
+                target: "consensus-manager",
+                "Competing branch extended to depth {} by {:?}",
+                branch.len() + 2,
+                notification.hash,
+            );
+            branch.push(header);
+        } else {
+            let canonical = match client.hash(*header.number()) {
+                Ok(Some(hash)) if hash != notification.hash => client.header(hash),
+                _ => continue,
+            };
+            let Ok(Some(canonical)) = canonical else { continue };
+            if canonical.parent_hash() != header.parent_hash() {
+                continue;
+            }
+
+            log::info!(
+                target: "consensus-manager",
+                "Competing header {:?} at #{} recorded as fork evidence",
+                notification.hash,
+                header.number(),
+            );
+
+            evidence.push((canonical, header, Vec::new()));
+            if evidence.len() > MAX_FORK_EVIDENCE {
+                evidence.remove(0);
+            }
+        }
+        storage.set(STORAGE_PREFIX, FORK_EVIDENCE_KEY, &evidence.encode());
+    }
+}
+
+/// Drop every result cached by the offchain worker, returning how many were dropped
+fn clear_ocw_result_cache<S: OffchainStorage>(storage: &mut S) -> usize {
+    let index = storage
+        .get(STORAGE_PREFIX, CACHE_INDEX_KEY)
+        .and_then(|raw| Vec::<(u64, u64)>::decode(&mut &raw[..]).ok())
+        .unwrap_or_default();
+    for (job_id, _) in &index {
+        storage.remove(STORAGE_PREFIX, &cache_key(*job_id));
+    }
+    storage.remove(STORAGE_PREFIX, CACHE_INDEX_KEY);
+    index.len()
+}
+
 /// Starts a `ServiceBuilder` for a full service.
 ///
 /// Use this macro if you don't actually need the full service, but just the builder in order to
@@ -141,6 +220,18 @@ pub fn new_partial(
     })
 }
 
+/// Node settings of PolkaComputeLab's own services, from the command line
+pub struct PclConfig {
+    /// Execution limits of the offchain worker
+    pub ocw: OcwConfig,
+    /// Drop the results cached by the offchain worker on startup
+    pub clear_result_cache: bool,
+    /// Gate and rate limit of the `pcl_` RPC methods
+    pub rpc: PclRpcConfig,
+    /// Executors `JobSpec::External` jobs are delegated to
+    pub external_executors: ExternalExecutors,
+}
+
 /// Start a node with the given parachain `Configuration` and relay chain `Configuration`.
 ///
 /// This is the actual implementation that is abstract over the executor and the runtime api.
@@ -151,8 +242,11 @@ async fn start_node_impl(
     collator_options: CollatorOptions,
     para_id: ParaId,
     hwbench: Option<sc_sysinfo::HwBench>,
+    pcl_config: PclConfig,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
     let parachain_config = prepare_node_config(parachain_config);
+    let PclConfig { ocw: ocw_config, clear_result_cache, rpc: pcl_rpc, external_executors } =
+        pcl_config;
 
     let params = new_partial(&parachain_config)?;
     let (block_import, mut telemetry, telemetry_worker_handle) = params.other;
@@ -194,14 +288,92 @@ async fn start_node_impl(
         })
         .await?;
 
+    if parachain_config.offchain_worker.enabled {
+        use futures::FutureExt;
+
+        let ocw_key_type = polkacomputelab_runtime::pallet_job_registry::KEY_TYPE;
+        if params.keystore_container.keystore().sr25519_public_keys(ocw_key_type).is_empty() {
+            log::warn!(
+                "No offchain worker key in the keystore: jobs won't be claimed nor their results \
+                submitted. Insert one with `key insert-ocw`."
+            );
+        }
+
+        // The offchain worker reads its execution limits from the offchain database
+        if let Some(mut offchain_storage) = backend.offchain_storage() {
+            offchain_storage.set(STORAGE_PREFIX, OCW_CONFIG_KEY, &ocw_config.encode());
+            log::info!(
+                "Offchain worker executes up to {} jobs at once, each within {} ms",
+                ocw_config.max_parallel_jobs,
+                ocw_config.job_timeout_ms,
+            );
+            if clear_result_cache {
+                let cleared = clear_ocw_result_cache(&mut offchain_storage);
+                log::info!("Cleared {} results cached by the offchain worker", cleared);
+            }
+
+            // The offchain worker claims external jobs only for the executors run here
+            let names = external_executors.names();
+            offchain_storage.set(STORAGE_PREFIX, EXTERNAL_EXECUTORS_KEY, &names.encode());
+            if !external_executors.is_empty() {
+                let names: Vec<_> =
+                    names.iter().map(|name| String::from_utf8_lossy(name)).collect();
+                log::info!("External executors: {}", names.join(", "));
+                task_manager.spawn_handle().spawn(
+                    "pcl-external-executors",
+                    None,
+                    crate::external_executor::run(
+                        client.clone(),
+                        offchain_storage,
+                        external_executors,
+                        task_manager.spawn_handle(),
+                    ),
+                );
+            }
+        }
+
+        let offchain_workers =
+            sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
+                runtime_api_provider: client.clone(),
+                keystore: Some(params.keystore_container.keystore()),
+                offchain_db: backend.offchain_storage(),
+                transaction_pool: Some(OffchainTransactionPoolFactory::new(
+                    transaction_pool.clone(),
+                )),
+                network_provider: Arc::new(network.clone()),
+                is_validator: validator,
+                enable_http_requests: true,
+                custom_extensions: move |_| vec![],
+            })?;
+        task_manager.spawn_handle().spawn(
+            "offchain-workers-runner",
+            "offchain-work",
+            offchain_workers.run(client.clone(), task_manager.spawn_handle()).boxed(),
+        );
+    }
+
+    if !parachain_config.offchain_worker.indexing_enabled {
+        log::info!(
+            "Off-chain indexing disabled: submitted proofs are not kept for `pcl_proof_get`. \
+            Enable it with `--enable-offchain-indexing true`."
+        );
+    }
+
     let rpc_builder = {
         let client = client.clone();
         let transaction_pool = transaction_pool.clone();
+        let keystore = params.keystore_container.keystore();
+        let offchain_storage = backend.offchain_storage();
+        let offchain_indexing = parachain_config.offchain_worker.indexing_enabled;
 
         Box::new(move |deny_unsafe, _| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: transaction_pool.clone(),
+                keystore: keystore.clone(),
+                offchain_storage: offchain_storage.clone(),
+                offchain_indexing,
+                pcl_rpc,
                 deny_unsafe,
             };
 
@@ -224,6 +396,40 @@ async fn start_node_impl(
         telemetry: telemetry.as_mut(),
     })?;
 
+    if let Some(registry) = prometheus_registry.as_ref() {
+        let metrics = crate::rpc::TelemetryMetrics::register(registry)
+            .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+        task_manager.spawn_handle().spawn(
+            "pallet-telemetry-metrics",
+            None,
+            crate::rpc::run_telemetry_metrics(client.clone(), metrics),
+        );
+
+        if let Some(offchain_storage) = backend.offchain_storage() {
+            let metrics = crate::rpc::ComputeMetrics::register(registry)
+                .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+            task_manager.spawn_handle().spawn(
+                "compute-metrics",
+                None,
+                crate::rpc::run_compute_metrics(client.clone(), offchain_storage, metrics),
+            );
+        }
+    }
+
+    task_manager.spawn_handle().spawn(
+        "pcl-history-indexer",
+        None,
+        crate::history::run_indexer(client.clone()),
+    );
+
+    if let Some(offchain_storage) = backend.offchain_storage() {
+        task_manager.spawn_handle().spawn(
+            "fork-evidence",
+            None,
+            record_fork_evidence(client.clone(), offchain_storage),
+        );
+    }
+
     if let Some(hwbench) = hwbench {
         sc_sysinfo::print_hwbench(&hwbench);
 
@@ -405,8 +611,17 @@ pub async fn start_parachain_node(
     collator_options: CollatorOptions,
     para_id: ParaId,
     hwbench: Option<sc_sysinfo::HwBench>,
+    pcl_config: PclConfig,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
-    start_node_impl(parachain_config, polkadot_config, collator_options, para_id, hwbench).await
+    start_node_impl(
+        parachain_config,
+        polkadot_config,
+        collator_options,
+        para_id,
+        hwbench,
+        pcl_config,
+    )
+    .await
 }
 
 use sp_core::H256 as Hash;
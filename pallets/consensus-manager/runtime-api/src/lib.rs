@@ -0,0 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Consensus Manager Runtime API
+//!
+//! Lets the node RPC and experiment tooling read consensus metrics and experiment results
+//! via `state_call` instead of decoding raw storage.
+
+use pallet_consensus_manager::{ConsensusType, EpochMetricsSummary, ExperimentReport, ForkStats};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait ConsensusManagerApi {
+        /// Consensus type currently active
+        fn consensus_type() -> ConsensusType;
+
+        /// Summaries of the latest `count` closed epochs, newest first
+        fn epoch_summaries(count: u32) -> Vec<(u32, EpochMetricsSummary)>;
+
+        /// Fork statistics
+        fn fork_stats() -> ForkStats;
+
+        /// Per consensus type results of the running or last experiment
+        fn experiment_reports() -> Vec<ExperimentReport>;
+    }
+}
@@ -0,0 +1,404 @@
+//! Benchmarking setup for pallet-consensus-manager
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ConsensusManager;
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{EnsureOrigin, Get, Hooks},
+    BoundedBTreeSet, BoundedVec,
+};
+use frame_system::{
+    pallet_prelude::{BlockNumberFor, HeaderFor},
+    RawOrigin,
+};
+use sp_runtime::{
+    traits::{Hash, Header},
+    Digest, DigestItem, RuntimeAppPublic, SaturatedConversion,
+};
+use sp_std::vec;
+
+/// A fresh key that every slot expects as its author
+fn seal_key<T: Config>() -> T::HeartbeatId {
+    let key = T::HeartbeatId::generate_pair(None);
+    T::AuthorityKeys::set_expected_key(key.clone());
+    key
+}
+
+/// A header at `number` on top of `parent`, claiming `slot`, distinguished by `state` and
+/// sealed by `key`
+fn header<T: Config>(
+    number: u32,
+    parent: T::Hash,
+    slot: u64,
+    state: u32,
+    key: &T::HeartbeatId,
+) -> HeaderFor<T> {
+    let digest = Digest {
+        logs: vec![DigestItem::PreRuntime(sp_consensus_aura::AURA_ENGINE_ID, slot.encode())],
+    };
+    let mut header = HeaderFor::<T>::new(
+        number.into(),
+        Default::default(),
+        T::Hashing::hash_of(&state),
+        parent,
+        digest,
+    );
+    let signature = key.sign(&header.hash()).expect("benchmark key is in the keystore");
+    header
+        .digest_mut()
+        .push(DigestItem::Seal(sp_consensus_aura::AURA_ENGINE_ID, signature.encode()));
+    header
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_consensus() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 1);
+
+        assert_eq!(CurrentConsensus::<T>::get(), ConsensusType::Babe);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn record_metrics() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, 1, 10, 12_000);
+
+        assert!(BlockMetricsHistory::<T>::contains_key(1));
+    }
+
+    #[benchmark]
+    fn record_fork() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 1);
+
+        assert_eq!(ForkStatistics::<T>::get().total_forks, 1);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn note_authorship(m: Linear<0, 16>) {
+        let slot = 100u64;
+        T::BlockAuthorship::set_current_slot(slot);
+        // Every slot since the previous block was skipped, by registered authorities
+        LastSlot::<T>::put(slot - m as u64 - 1);
+        let mut validators = BoundedBTreeSet::new();
+        for authority in T::BlockAuthorship::authorities() {
+            let _ = validators.try_insert(authority);
+        }
+        Validators::<T>::put(validators);
+
+        #[block]
+        {
+            ConsensusManager::<T>::note_authorship(1);
+        }
+
+        assert_eq!(LastSlot::<T>::get(), slot);
+    }
+
+    #[benchmark]
+    fn report_fork(d: Linear<0, MAX_FORK_BRANCH>) {
+        let parent = T::Hashing::hash_of(&0u32);
+        frame_system::BlockHash::<T>::insert(BlockNumberFor::<T>::from(4u32), parent);
+        let key = seal_key::<T>();
+        let header_a = header::<T>(5, parent, 0, 1, &key);
+        let header_b = header::<T>(5, parent, 0, 2, &key);
+        // A competing branch of `d` blocks on top of `header_b`, with a full recent fork list
+        let mut branch = vec![];
+        let mut tip = header_b.hash();
+        for i in 0..d {
+            let next = header::<T>(6 + i, tip, 0, 2, &key);
+            tip = next.hash();
+            branch.push(next);
+        }
+        let fork = ForkEvent { block_number: 1, depth: 1, competing_heads: 2, resolved_at: 1 };
+        RecentForks::<T>::put(BoundedVec::truncate_from(vec![fork; MAX_RECENT_FORKS as usize]));
+
+        #[extrinsic_call]
+        _(RawOrigin::None, header_a, header_b, branch);
+
+        assert!(ReportedForks::<T>::contains_key(5));
+        assert_eq!(ForkStatistics::<T>::get().deepest_fork, d + 1);
+    }
+
+    #[benchmark]
+    fn set_switch_rule() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 1, Some(1_000), Some(10), 2, Some(10));
+
+        assert!(AutoSwitchRule::<T>::exists());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn close_epoch(a: Linear<0, 100>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        // A rule every epoch breaches, proposing and scheduling a switch
+        ConsensusManager::<T>::set_switch_rule(origin, 1, Some(0), None, 1, Some(1))?;
+        // Counted by a running experiment
+        ExperimentSince::<T>::put(1);
+        CurrentEpochMetrics::<T>::put(EpochAccumulator {
+            blocks: 1,
+            total_block_time_ms: 12_000,
+            ..Default::default()
+        });
+
+        // The expired epoch's summary and author distribution are dropped
+        let length = T::MetricsEpochLength::get().max(1);
+        let index = T::EpochSummaryRetention::get().saturating_add(1);
+        let expired = index - T::EpochSummaryRetention::get();
+        EpochSummaries::<T>::insert(
+            expired,
+            EpochMetricsSummary {
+                start_block: 1,
+                end_block: length,
+                blocks: length,
+                avg_block_time_ms: 12_000,
+                max_block_time_ms: 12_000,
+                missed_slots: 0,
+                forks: 0,
+                authors: a,
+                consensus_type: None,
+            },
+        );
+        for i in 0..a {
+            let author: T::AccountId = account("author", i, 0);
+            EpochAuthorBlocks::<T>::insert(expired, author, 1);
+        }
+
+        #[block]
+        {
+            ConsensusManager::<T>::close_epoch(index * length);
+        }
+
+        assert!(EpochSummaries::<T>::contains_key(index));
+        assert!(ScheduledSwitch::<T>::exists());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn close_participation_era(v: Linear<0, 100>) {
+        // Every validator is past its grace period and gets reported
+        for i in 0..v {
+            let validator: T::AccountId = account("validator", i, 0);
+            EraParticipation::<T>::insert(
+                &validator,
+                ValidatorMetrics { blocks_produced: 0, blocks_missed: 10, participation_rate: 0 },
+            );
+            LowParticipationStrikes::<T>::insert(&validator, T::ParticipationGraceEras::get());
+        }
+
+        #[block]
+        {
+            ConsensusManager::<T>::close_participation_era(1);
+        }
+
+        assert_eq!(EraParticipation::<T>::iter_keys().count(), 0);
+        assert_eq!(LowParticipationStrikes::<T>::iter_keys().count(), v as usize);
+    }
+
+    #[benchmark]
+    fn set_consensus_params() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(12_000), Some(1), Some(2));
+
+        assert_eq!(ConsensusParams::<T>::get().block_velocity, Some(2));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn schedule_consensus_switch() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 1, 100);
+
+        assert!(ScheduledSwitch::<T>::exists());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_consensus_switch() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        ScheduledSwitch::<T>::put((100, ConsensusType::Babe));
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert!(!ScheduledSwitch::<T>::exists());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_equivocation() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let slot = 100u64;
+        T::BlockAuthorship::set_current_slot(slot);
+        let key = seal_key::<T>();
+        let parent = T::Hashing::hash_of(&0u32);
+        let proof = EquivocationProof {
+            first_header: header::<T>(5, parent, slot, 1, &key),
+            second_header: header::<T>(5, parent, slot, 2, &key),
+        };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, proof);
+
+        assert!(Equivocations::<T>::contains_key(slot));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn jail() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let validator: T::AccountId = account("validator", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, validator.clone());
+
+        assert!(Jailed::<T>::contains_key(&validator));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unjail() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let validator: T::AccountId = account("validator", 0, 0);
+        Jailed::<T>::insert(&validator, 10);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, validator.clone());
+
+        assert!(!Jailed::<T>::contains_key(&validator));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_experiment_mode() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        // Results of every consensus type from a previous experiment are cleared
+        for consensus_type in 0..3u8 {
+            let consensus_type = ConsensusType::from_u8(consensus_type).expect("valid type");
+            ExperimentResults::<T>::insert(consensus_type, ExperimentTotals::default());
+        }
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, true);
+
+        assert!(ExperimentSince::<T>::exists());
+        assert_eq!(ExperimentResults::<T>::iter_keys().count(), 0);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_validator() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        // The registry one short of full
+        let mut validators = BoundedBTreeSet::new();
+        for i in 1..T::MaxValidators::get() {
+            let _ = validators.try_insert(account("validator", i, 0));
+        }
+        Validators::<T>::put(validators);
+        let validator: T::AccountId = account("validator", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, validator.clone());
+
+        assert!(ConsensusManager::<T>::is_registered(&validator));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn deregister_validator() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let validator: T::AccountId = account("validator", 0, 0);
+        let mut validators = BoundedBTreeSet::new();
+        validators.try_insert(validator.clone()).map_err(|_| BenchmarkError::Weightless)?;
+        Validators::<T>::put(validators);
+        // Every metric the validator can have
+        let metrics =
+            ValidatorMetrics { blocks_produced: 1, blocks_missed: 1, participation_rate: 50 };
+        ValidatorPerformance::<T>::insert(&validator, metrics.clone());
+        EraParticipation::<T>::insert(&validator, metrics);
+        LowParticipationStrikes::<T>::insert(&validator, 1);
+        OfflineCollators::<T>::insert(&validator, 1);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, validator.clone());
+
+        assert!(!ConsensusManager::<T>::is_registered(&validator));
+        assert!(!ValidatorPerformance::<T>::contains_key(&validator));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_finalize() {
+        // A block inside an epoch that prunes the oldest raw metrics; closing the epoch is
+        // weighed separately
+        let bn = ConsensusManager::<T>::raw_metrics_window() + 1;
+        LastBlockTimestamp::<T>::put(1);
+        BlockMetricsHistory::<T>::insert(
+            1,
+            BlockMetrics {
+                block_number: 1,
+                validator_count: 0,
+                block_time_ms: 0,
+                fork_count: 0,
+                consensus_type: ConsensusType::Aura,
+                is_slow: false,
+                is_fast: false,
+            },
+        );
+
+        #[block]
+        {
+            ConsensusManager::<T>::on_finalize(bn.saturated_into());
+        }
+
+        assert!(BlockMetricsHistory::<T>::contains_key(bn));
+        assert!(!BlockMetricsHistory::<T>::contains_key(1));
+    }
+
+    impl_benchmark_test_suite!(ConsensusManager, crate::mock::new_test_ext(), crate::mock::Test);
+}
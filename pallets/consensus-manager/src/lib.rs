@@ -5,6 +5,9 @@
 //! This pallet manages consensus algorithm switching and collects block metrics.
 
 pub use pallet::*;
+pub use polkacomputelab_primitives::{
+    block_time_ms, ConsensusMetricsProvider, ConsensusType, SlotAuthorship,
+};
 
 #[cfg(test)]
 mod mock;
@@ -12,50 +15,63 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 pub mod weights;
 pub use weights::*;
 
+/// Maximum number of skipped slots charged as misses in a single block
+pub const MAX_MISSED_SLOTS_PER_BLOCK: u64 = 16;
+
+/// Persistent offchain storage key where the node records competing headers, as a
+/// SCALE-encoded `Vec<ForkEvidence<Header>>`, for the offchain worker to report
+pub const FORK_EVIDENCE_KEY: &[u8] = b"consensus-manager::fork-evidence";
+
+/// Competing branch headers accepted beyond the branch root, bounding reported fork depth
+pub const MAX_FORK_BRANCH: u32 = 16;
+
+/// Blocks the chain must build past a competing branch's tip before it is reported as settled
+pub const FORK_SETTLEMENT_BLOCKS: u32 = 8;
+
+/// Distinct competing heads counted per fork height
+pub const MAX_COMPETING_HEADS: u32 = 8;
+
+/// Fork events kept in `RecentForks`
+pub const MAX_RECENT_FORKS: u32 = 32;
+
+/// A canonical header, a competing sibling, and the competing branch built on that sibling
+pub type ForkEvidence<Header> = (Header, Header, sp_std::vec::Vec<Header>);
+
+/// Persistent offchain storage key recording the last era this node sent heartbeats for
+pub const HEARTBEAT_ERA_KEY: &[u8] = b"consensus-manager::heartbeat-era";
+
+/// Prefix of the `DigestItem::Other` payload carrying `ConsensusParameters`
+pub const CONSENSUS_PARAMS_DIGEST_ID: [u8; 4] = *b"cnsp";
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
-    use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::SaturatedConversion;
-    use sp_std::vec::Vec;
+    use frame_support::{pallet_prelude::*, traits::UnixTime};
+    use frame_system::{
+        offchain::{CreateBare, SubmitTransaction},
+        pallet_prelude::*,
+    };
+    use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+    use sp_runtime::{
+        offchain::storage::StorageValueRef,
+        traits::{Convert, Header, One, SaturatedConversion, Zero},
+        Percent, Permill, RuntimeAppPublic,
+    };
+    use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
-    /// Consensus algorithm types
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    #[codec(dumb_trait_bound)]
-    pub enum ConsensusType {
-        /// Aura consensus
-        Aura,
-        /// BABE consensus  
-        Babe,
-        /// Custom consensus (for experimentation)
-        Custom,
-    }
-
-    impl Default for ConsensusType {
-        fn default() -> Self {
-            ConsensusType::Aura
-        }
-    }
-
-    impl ConsensusType {
-        /// Convert from u8 representation
-        pub fn from_u8(value: u8) -> Result<Self, ()> {
-            match value {
-                0 => Ok(ConsensusType::Aura),
-                1 => Ok(ConsensusType::Babe),
-                2 => Ok(ConsensusType::Custom),
-                _ => Err(()),
-            }
-        }
-    }
-
     /// Block metrics structure
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct BlockMetrics {
@@ -69,9 +85,14 @@ pub mod pallet {
         pub fork_count: u32,
         /// Consensus type at this block
         pub consensus_type: ConsensusType,
+        /// Block took longer than the expected slot duration allows
+        pub is_slow: bool,
+        /// Block came sooner than the expected slot duration allows
+        pub is_fast: bool,
     }
 
-    /// Validator performance metrics
+    /// Validator performance metrics. In `ValidatorPerformance` the counts are a rolling window:
+    /// each closed participation era is scaled down by `PerformanceDecay`.
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
     pub struct ValidatorMetrics {
         /// Blocks produced
@@ -82,14 +103,349 @@ pub mod pallet {
         pub participation_rate: u8,
     }
 
+    /// Block metrics accumulated over the current metrics epoch
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct EpochAccumulator {
+        /// Blocks recorded
+        pub blocks: u32,
+        /// Sum of block times (ms), for the average
+        pub total_block_time_ms: u64,
+        /// Longest block time (ms)
+        pub max_block_time_ms: u64,
+        /// Slots skipped without a block
+        pub missed_slots: u32,
+        /// Forks reported
+        pub forks: u32,
+        /// Distinct block authors
+        pub authors: u32,
+        /// Consensus switched during the epoch, so no single type produced it
+        pub consensus_switched: bool,
+    }
+
+    impl EpochAccumulator {
+        /// Average block time in milliseconds
+        pub fn avg_block_time_ms(&self) -> u64 {
+            self.total_block_time_ms / self.blocks.max(1) as u64
+        }
+    }
+
+    /// Block metrics of a closed epoch, kept after its raw per-block entries are pruned
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct EpochMetricsSummary {
+        /// First block of the epoch
+        pub start_block: u32,
+        /// Last block of the epoch
+        pub end_block: u32,
+        /// Blocks recorded
+        pub blocks: u32,
+        /// Average block time (ms)
+        pub avg_block_time_ms: u64,
+        /// Longest block time (ms)
+        pub max_block_time_ms: u64,
+        /// Slots skipped without a block
+        pub missed_slots: u32,
+        /// Forks reported
+        pub forks: u32,
+        /// Distinct block authors; per-author counts are in `EpochAuthorBlocks`
+        pub authors: u32,
+        /// Consensus type the epoch ran under, for epochs counted by a running experiment
+        pub consensus_type: Option<ConsensusType>,
+    }
+
+    /// Epoch totals of one consensus type over an experiment
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct ExperimentTotals {
+        /// Epochs counted
+        pub epochs: u32,
+        /// Blocks recorded
+        pub blocks: u32,
+        /// Sum of block times (ms)
+        pub total_block_time_ms: u64,
+        /// Slots skipped without a block
+        pub missed_slots: u32,
+        /// Forks reported
+        pub forks: u32,
+    }
+
+    impl ExperimentTotals {
+        fn add(&mut self, epoch: &EpochAccumulator) {
+            self.epochs = self.epochs.saturating_add(1);
+            self.blocks = self.blocks.saturating_add(epoch.blocks);
+            self.total_block_time_ms =
+                self.total_block_time_ms.saturating_add(epoch.total_block_time_ms);
+            self.missed_slots = self.missed_slots.saturating_add(epoch.missed_slots);
+            self.forks = self.forks.saturating_add(epoch.forks);
+        }
+    }
+
+    /// How one consensus type performed over an experiment, for comparison with the others
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ExperimentReport {
+        /// Consensus type measured
+        pub consensus_type: ConsensusType,
+        /// Epochs counted
+        pub epochs: u32,
+        /// Blocks recorded
+        pub blocks: u32,
+        /// Average block time (ms)
+        pub avg_block_time_ms: u64,
+        /// Forks per block
+        pub fork_rate: Permill,
+        /// Share of slots skipped without a block
+        pub missed_slot_rate: Permill,
+    }
+
+    impl ExperimentReport {
+        fn new(consensus_type: ConsensusType, totals: ExperimentTotals) -> Self {
+            let slots = totals.blocks.saturating_add(totals.missed_slots);
+            Self {
+                consensus_type,
+                epochs: totals.epochs,
+                blocks: totals.blocks,
+                avg_block_time_ms: totals.total_block_time_ms / totals.blocks.max(1) as u64,
+                fork_rate: Permill::from_rational(totals.forks, totals.blocks.max(1)),
+                missed_slot_rate: Permill::from_rational(totals.missed_slots, slots.max(1)),
+            }
+        }
+    }
+
+    /// Consensus parameters for the client to apply, published in a header digest whenever
+    /// they change. `None` leaves the client's own setting in place.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct ConsensusParameters {
+        /// Slot duration hint (ms)
+        pub slot_duration_ms: Option<u64>,
+        /// Maximum authority set size
+        pub max_authorities: Option<u32>,
+        /// Parachain blocks authored per relay chain block
+        pub block_velocity: Option<u32>,
+    }
+
+    impl ConsensusParameters {
+        /// Extract consensus parameters from a header digest item, if it carries them
+        pub fn from_digest_item(item: &sp_runtime::DigestItem) -> Option<Self> {
+            let sp_runtime::DigestItem::Other(data) = item else { return None };
+            let payload = data.strip_prefix(&CONSENSUS_PARAMS_DIGEST_ID[..])?;
+            Self::decode(&mut &payload[..]).ok()
+        }
+    }
+
+    /// Thresholds that make the pallet propose a consensus switch
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct SwitchRule {
+        /// Consensus to propose
+        pub target: ConsensusType,
+        /// Epoch average block time (ms) above which the epoch counts as a breach
+        pub max_avg_block_time_ms: Option<u64>,
+        /// Missed slots per epoch above which the epoch counts as a breach
+        pub max_missed_slots: Option<u32>,
+        /// Consecutive breaching epochs before a switch is proposed
+        pub epochs: u32,
+        /// Blocks after the proposal at which the switch is enacted; `None` only proposes
+        pub enact_after: Option<u32>,
+    }
+
+    impl SwitchRule {
+        fn breached_by(&self, epoch: &EpochAccumulator) -> bool {
+            self.max_avg_block_time_ms.is_some_and(|max| epoch.avg_block_time_ms() > max) ||
+                self.max_missed_slots.is_some_and(|max| epoch.missed_slots > max)
+        }
+    }
+
+    impl ValidatorMetrics {
+        /// Scale the counts down by `keep`, rounding down so old incidents eventually age out
+        fn decay(&mut self, keep: Percent) {
+            self.blocks_produced = keep.mul_floor(self.blocks_produced);
+            self.blocks_missed = keep.mul_floor(self.blocks_missed);
+            self.update_participation();
+        }
+
+        fn update_participation(&mut self) {
+            let total_blocks = self.blocks_produced.saturating_add(self.blocks_missed);
+            self.participation_rate = if total_blocks > 0 {
+                ((self.blocks_produced as u64 * 100) / total_blocks as u64) as u8
+            } else {
+                0
+            };
+        }
+    }
+
+    /// Authority keys by slot, to verify the Aura seals of reported headers
+    pub trait SlotAuthorityKeys<Key> {
+        /// Key of the authority expected to author `slot`
+        fn expected_key(slot: u64) -> Option<Key>;
+        /// Make `key` the expected author of every slot
+        #[cfg(feature = "runtime-benchmarks")]
+        fn set_expected_key(_key: Key) {}
+    }
+
+    impl<Key> SlotAuthorityKeys<Key> for () {
+        fn expected_key(_slot: u64) -> Option<Key> {
+            None
+        }
+    }
+
+    /// Hook invoked when a validator's participation stays below `MinParticipation` for more
+    /// eras than `ParticipationGraceEras` allows, e.g. to slash stake or drop the collator
+    pub trait OnLowParticipation<AccountId> {
+        fn on_low_participation(validator: &AccountId, era: u32, participation_rate: u8);
+    }
+
+    impl<AccountId> OnLowParticipation<AccountId> for () {
+        fn on_low_participation(_validator: &AccountId, _era: u32, _participation_rate: u8) {}
+    }
+
+    /// Liveness signal a collator's offchain worker sends once per participation era, signed
+    /// with its `HeartbeatId` key
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct Heartbeat<AuthorityId> {
+        /// Participation era the heartbeat is for
+        pub era: u32,
+        /// Block the heartbeat was created at
+        pub block_number: u32,
+        /// Signing key
+        pub authority: AuthorityId,
+    }
+
+    /// Two distinct headers authored in the same slot
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct EquivocationProof<Header> {
+        pub first_header: Header,
+        pub second_header: Header,
+    }
+
+    /// An equivocation found by `report_equivocation`
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct EquivocationOffence<AccountId> {
+        /// Authority expected to author the slot
+        pub offender: AccountId,
+        /// Block the offence was reported in
+        pub reported_at: u32,
+    }
+
+    /// Hook invoked for each equivocation offence, e.g. to slash or disable the offender
+    pub trait OffenceHandler<AccountId> {
+        fn on_equivocation(offender: &AccountId, slot: u64);
+    }
+
+    impl<AccountId> OffenceHandler<AccountId> for () {
+        fn on_equivocation(_offender: &AccountId, _slot: u64) {}
+    }
+
+    impl<T: Config> ConsensusMetricsProvider for Pallet<T> {
+        fn consensus_type() -> ConsensusType {
+            CurrentConsensus::<T>::get()
+        }
+
+        fn total_forks() -> u32 {
+            ForkStatistics::<T>::get().total_forks
+        }
+
+        fn consensus_switches() -> u32 {
+            ConsensusSwitches::<T>::decode_len().unwrap_or(0) as u32
+        }
+    }
+
+    /// Hook invoked as validators are jailed and released, e.g. to drop them from and restore
+    /// them to the authority rotation
+    pub trait JailHandler<AccountId> {
+        fn on_jailed(validator: &AccountId, until_era: u32);
+        fn on_unjailed(validator: &AccountId);
+    }
+
+    impl<AccountId> JailHandler<AccountId> for () {
+        fn on_jailed(_validator: &AccountId, _until_era: u32) {}
+        fn on_unjailed(_validator: &AccountId) {}
+    }
+
     #[pallet::config]
-    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+    pub trait Config:
+        frame_system::Config<RuntimeEvent: From<Event<Self>>> + CreateBare<Call<Self>>
+    {
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: crate::weights::WeightInfo;
 
-        /// Maximum number of validator metrics to store
+        /// Maximum number of registered validators
         #[pallet::constant]
         type MaxValidators: Get<u32>;
+
+        /// Consensus switches kept in the history; the oldest are evicted beyond this. Lowering
+        /// it needs a migration trimming `ConsensusSwitches`, which won't decode otherwise.
+        #[pallet::constant]
+        type MaxConsensusSwitches: Get<u32>;
+
+        /// Source of slots and their expected authors
+        type BlockAuthorship: SlotAuthorship<Self::AccountId>;
+
+        /// Origin allowed to switch consensus and record forks
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks per metrics epoch, over which switch rules are evaluated
+        #[pallet::constant]
+        type MetricsEpochLength: Get<u32>;
+
+        /// Closed epochs whose summaries are kept
+        #[pallet::constant]
+        type EpochSummaryRetention: Get<u32>;
+
+        /// Closed epochs whose raw per-block metrics are kept alongside their summaries
+        #[pallet::constant]
+        type RawMetricsEpochs: Get<u32>;
+
+        /// Handler for validators with persistently low participation
+        type OnLowParticipation: OnLowParticipation<Self::AccountId>;
+
+        /// Metrics epochs per participation era
+        #[pallet::constant]
+        type ParticipationEraEpochs: Get<u32>;
+
+        /// Participation rate (percentage) below which an era counts against a validator
+        #[pallet::constant]
+        type MinParticipation: Get<u8>;
+
+        /// Consecutive low-participation eras tolerated before the validator is reported
+        #[pallet::constant]
+        type ParticipationGraceEras: Get<u32>;
+
+        /// Share of a validator's rolling performance kept at each participation era close;
+        /// 100% keeps lifetime totals
+        #[pallet::constant]
+        type PerformanceDecay: Get<Percent>;
+
+        /// Handler for equivocation offences
+        type OffenceHandler: OffenceHandler<Self::AccountId>;
+
+        /// Source of block timestamps, for measuring block times
+        type BlockTimestamp: UnixTime;
+
+        /// Expected block time (ms) while `ConsensusParams` carries no slot duration hint
+        #[pallet::constant]
+        type ExpectedBlockTimeMs: Get<u64>;
+
+        /// Deviation from the expected block time beyond which a block is slow or fast
+        #[pallet::constant]
+        type BlockTimeTolerance: Get<Percent>;
+
+        /// Collators' Aura key, which seals their blocks and signs their heartbeats
+        type HeartbeatId: Parameter + RuntimeAppPublic;
+
+        /// Aura keys expected per slot, to verify the seals of reported headers
+        type AuthorityKeys: SlotAuthorityKeys<Self::HeartbeatId>;
+
+        /// Account a heartbeat key belongs to
+        type HeartbeatAccount: Convert<Self::HeartbeatId, Self::AccountId>;
+
+        /// Consecutive eras without a heartbeat after which a collator is jailed; 0 disables
+        /// automatic jailing
+        #[pallet::constant]
+        type JailAfterMissedEras: Get<u32>;
+
+        /// Eras a jailed validator sits out before it is released
+        #[pallet::constant]
+        type JailCooldownEras: Get<u32>;
+
+        /// Handler for jailed and released validators
+        type JailHandler: JailHandler<Self::AccountId>;
     }
 
     /// Current consensus type
@@ -97,7 +453,7 @@ pub mod pallet {
     #[pallet::getter(fn consensus_type)]
     pub type CurrentConsensus<T: Config> = StorageValue<_, ConsensusType, ValueQuery>;
 
-    /// Block metrics history (limited to recent blocks)
+    /// Block metrics history, pruned `RawMetricsEpochs` epochs after being summarized
     #[pallet::storage]
     #[pallet::getter(fn block_metrics)]
     pub type BlockMetricsHistory<T: Config> = StorageMap<
@@ -107,7 +463,14 @@ pub mod pallet {
         BlockMetrics,
     >;
 
-    /// Validator performance metrics
+    /// Registered validators. Only these are credited, charged, scored and expected to send
+    /// heartbeats; deregistering drops their metrics.
+    #[pallet::storage]
+    #[pallet::getter(fn validators)]
+    pub type Validators<T: Config> =
+        StorageValue<_, BoundedBTreeSet<T::AccountId, T::MaxValidators>, ValueQuery>;
+
+    /// Rolling validator performance, decayed every participation era
     #[pallet::storage]
     #[pallet::getter(fn validator_metrics)]
     pub type ValidatorPerformance<T: Config> = StorageMap<
@@ -118,6 +481,75 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Blocks produced and missed per validator in the current participation era
+    #[pallet::storage]
+    #[pallet::getter(fn era_participation)]
+    pub type EraParticipation<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ValidatorMetrics,
+        ValueQuery,
+    >;
+
+    /// Consecutive eras each validator has spent below `MinParticipation`
+    #[pallet::storage]
+    #[pallet::getter(fn low_participation_strikes)]
+    pub type LowParticipationStrikes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Heartbeats received per era, with the block they arrived in
+    #[pallet::storage]
+    #[pallet::getter(fn received_heartbeat)]
+    pub type ReceivedHeartbeats<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+    >;
+
+    /// Collators that sent no heartbeat, with the era they went offline in. Cleared by their
+    /// next heartbeat.
+    #[pallet::storage]
+    #[pallet::getter(fn offline_since)]
+    pub type OfflineCollators<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+    /// Jailed validators, with the era whose close releases them. Jailed validators are left
+    /// out of `eligible_authorities` and owe no heartbeats.
+    #[pallet::storage]
+    #[pallet::getter(fn jailed_until)]
+    pub type Jailed<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+    /// Timestamp (ms) of the previous block, used to measure block times
+    #[pallet::storage]
+    #[pallet::getter(fn last_block_timestamp)]
+    pub type LastBlockTimestamp<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Slot of the previous block, to detect skipped slots
+    #[pallet::storage]
+    #[pallet::getter(fn last_slot)]
+    pub type LastSlot<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Heads reported at each fork height, to count every competing head once. Entries are
+    /// dropped once the parent falls out of `BlockHashCount` and can no longer be reported.
+    #[pallet::storage]
+    pub type ReportedForks<T: Config> =
+        StorageMap<_, Twox64Concat, u32, BoundedVec<T::Hash, ConstU32<MAX_COMPETING_HEADS>>>;
+
+    /// Most recent fork events, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn recent_forks)]
+    pub type RecentForks<T: Config> =
+        StorageValue<_, BoundedVec<ForkEvent, ConstU32<MAX_RECENT_FORKS>>, ValueQuery>;
+
+    /// Equivocation offences by slot
+    #[pallet::storage]
+    #[pallet::getter(fn equivocation)]
+    pub type Equivocations<T: Config> =
+        StorageMap<_, Twox64Concat, u64, EquivocationOffence<T::AccountId>>;
+
     /// Fork statistics
     #[pallet::storage]
     #[pallet::getter(fn fork_stats)]
@@ -131,17 +563,85 @@ pub mod pallet {
     pub struct ForkStats {
         pub total_forks: u32,
         pub last_fork_block: u32,
+        /// Longest competing branch reported
+        pub deepest_fork: u32,
+    }
+
+    /// A reported fork
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ForkEvent {
+        /// Height the chains diverged at
+        pub block_number: u32,
+        /// Length of the competing branch
+        pub depth: u32,
+        /// Distinct heads reported at this height so far, canonical included
+        pub competing_heads: u32,
+        /// Block the fork was reported in, by which it had resolved
+        pub resolved_at: u32,
     }
 
-    /// Consensus switch history
+    /// Consensus switch history, oldest first
     #[pallet::storage]
     #[pallet::getter(fn consensus_switches)]
     pub type ConsensusSwitches<T: Config> = StorageValue<
         _,
-        BoundedVec<(u32, ConsensusType), ConstU32<100>>,
+        BoundedVec<(u32, ConsensusType), T::MaxConsensusSwitches>,
+        ValueQuery,
+    >;
+
+    /// Rule for metric-driven consensus switch proposals
+    #[pallet::storage]
+    #[pallet::getter(fn switch_rule)]
+    pub type AutoSwitchRule<T: Config> = StorageValue<_, SwitchRule>;
+
+    /// Metrics of the current epoch
+    #[pallet::storage]
+    pub type CurrentEpochMetrics<T: Config> = StorageValue<_, EpochAccumulator, ValueQuery>;
+
+    /// Summaries of closed epochs, by epoch index (epoch 1 ends at `MetricsEpochLength`)
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_summary)]
+    pub type EpochSummaries<T: Config> = StorageMap<_, Twox64Concat, u32, EpochMetricsSummary>;
+
+    /// Blocks authored per epoch and author
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_author_blocks)]
+    pub type EpochAuthorBlocks<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
         ValueQuery,
     >;
 
+    /// Consecutive epochs that breached the switch rule
+    #[pallet::storage]
+    #[pallet::getter(fn breach_streak)]
+    pub type BreachStreak<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Consensus parameters last published to the client
+    #[pallet::storage]
+    #[pallet::getter(fn consensus_params)]
+    pub type ConsensusParams<T: Config> = StorageValue<_, ConsensusParameters, ValueQuery>;
+
+    /// Consensus switch to enact at a block, scheduled by the admin or a switch rule
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_switch)]
+    pub type ScheduledSwitch<T: Config> = StorageValue<_, (u32, ConsensusType)>;
+
+    /// First epoch counted by the running experiment, if any
+    #[pallet::storage]
+    #[pallet::getter(fn experiment_since)]
+    pub type ExperimentSince<T: Config> = StorageValue<_, u32>;
+
+    /// Experiment totals per consensus type. Kept after the experiment stops, until the next
+    /// one starts.
+    #[pallet::storage]
+    pub type ExperimentResults<T: Config> =
+        StorageMap<_, Twox64Concat, ConsensusType, ExperimentTotals, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -149,15 +649,48 @@ pub mod pallet {
         ConsensusChanged {
             block_number: u32,
         },
+        /// Switch history full; the oldest switch was evicted [block_number, consensus_type]
+        ConsensusHistoryTruncated { block_number: u32, consensus_type: ConsensusType },
         /// Block metrics recorded [block_number]
         BlockMetricsRecorded { block_number: u32 },
         /// Fork detected [block_number, fork_count]
         ForkDetected { block_number: u32, fork_count: u32 },
-        /// Validator metrics updated [validator, blocks_produced]
-        ValidatorMetricsUpdated {
-            validator: T::AccountId,
-            blocks_produced: u32,
-        },
+        /// Epoch closed and summarized [epoch, summary]
+        EpochSummarized { epoch: u32, summary: EpochMetricsSummary },
+        /// Validator fell below `MinParticipation` within its grace period
+        /// [validator, era, participation_rate, strikes]
+        LowParticipation { validator: T::AccountId, era: u32, participation_rate: u8, strikes: u32 },
+        /// Validator stayed below `MinParticipation` past its grace period and was reported
+        /// to `OnLowParticipation` [validator, era, participation_rate]
+        LowParticipationReported { validator: T::AccountId, era: u32, participation_rate: u8 },
+        /// Validator registered [validator]
+        ValidatorRegistered { validator: T::AccountId },
+        /// Validator deregistered and its metrics dropped [validator]
+        ValidatorDeregistered { validator: T::AccountId },
+        /// Heartbeat received from a collator [collator, era]
+        HeartbeatReceived { collator: T::AccountId, era: u32 },
+        /// Collator sent no heartbeat during an era [collator, era]
+        CollatorOffline { collator: T::AccountId, era: u32 },
+        /// Validator jailed, automatically or by the admin [validator, until_era]
+        ValidatorJailed { validator: T::AccountId, until_era: u32 },
+        /// Validator released from jail, after its cooldown or by the admin [validator]
+        ValidatorUnjailed { validator: T::AccountId },
+        /// An authority produced two blocks in one slot [offender, slot]
+        EquivocationReported { offender: T::AccountId, slot: u64 },
+        /// Consensus switch scheduled [consensus_type, at_block]
+        ConsensusSwitchScheduled { consensus_type: ConsensusType, at_block: u32 },
+        /// Scheduled consensus switch cancelled [consensus_type, at_block]
+        ConsensusSwitchCancelled { consensus_type: ConsensusType, at_block: u32 },
+        /// Consensus parameters changed and published in the block digest [params]
+        ConsensusParamsChanged { params: ConsensusParameters },
+        /// Switch rule set or cleared [rule]
+        SwitchRuleSet { rule: Option<SwitchRule> },
+        /// Metrics breached the switch rule for enough epochs [target, epochs, scheduled_at]
+        ConsensusSwitchProposed { target: ConsensusType, epochs: u32, scheduled_at: Option<u32> },
+        /// Experiment started, counting epochs from `first_epoch` [first_epoch]
+        ExperimentStarted { first_epoch: u32 },
+        /// Experiment stopped [epochs]
+        ExperimentStopped { epochs: u32 },
     }
 
     #[pallet::error]
@@ -166,33 +699,212 @@ pub mod pallet {
         InvalidConsensusType,
         /// Metrics not found
         MetricsNotFound,
-        /// Too many validators
+        /// Block is already past the raw metrics window and would never be pruned
+        MetricsOutsideRetention,
+        /// The validator registry already holds `MaxValidators`
         TooManyValidators,
+        /// The validator is already registered
+        AlreadyRegistered,
+        /// The validator is not registered
+        NotRegistered,
+        /// Fork evidence doesn't show two distinct children of a recent canonical block
+        InvalidForkEvidence,
+        /// A fork at this height was already reported
+        ForkAlreadyReported,
+        /// Switch rules need at least one breaching epoch
+        InvalidSwitchRule,
+        /// Consensus parameters must be non-zero and within `MaxValidators`
+        InvalidConsensusParams,
+        /// Switches must be scheduled for a future block
+        SwitchNotInFuture,
+        /// A switch is already scheduled; cancel it first
+        SwitchAlreadyScheduled,
+        /// No switch is scheduled
+        NoScheduledSwitch,
+        /// Proof doesn't show two distinct headers from the same slot with a known author
+        InvalidEquivocationProof,
+        /// An equivocation in this slot was already reported
+        EquivocationAlreadyReported,
+        /// Heartbeat is for another era, from a non-authority, or badly signed
+        InvalidHeartbeat,
+        /// The collator already sent a heartbeat this era
+        DuplicateHeartbeat,
+        /// The validator is already jailed
+        AlreadyJailed,
+        /// The validator is not jailed
+        NotJailed,
+        /// An experiment is already running
+        ExperimentRunning,
+        /// No experiment is running
+        NoExperiment,
+    }
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Initial consensus type as its `u8` (0 = Aura, 1 = Babe, 2 = Custom)
+        pub consensus_type: u8,
+        /// Initially registered validators
+        pub validators: Vec<T::AccountId>,
+        /// Initial validator metrics as (validator, blocks_produced, blocks_missed). These
+        /// validators are registered too.
+        pub validator_metrics: Vec<(T::AccountId, u32, u32)>,
+        /// Start an experiment counting from the first epoch
+        pub experiment_mode: bool,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let consensus_type = ConsensusType::from_u8(self.consensus_type)
+                .expect("genesis consensus type must be valid");
+            CurrentConsensus::<T>::put(consensus_type);
+
+            let mut validators = BoundedBTreeSet::<T::AccountId, T::MaxValidators>::new();
+            let with_metrics = self.validator_metrics.iter().map(|(validator, ..)| validator);
+            for validator in self.validators.iter().chain(with_metrics) {
+                validators
+                    .try_insert(validator.clone())
+                    .expect("genesis validators must fit MaxValidators");
+            }
+            Validators::<T>::put(validators);
+
+            for (validator, blocks_produced, blocks_missed) in &self.validator_metrics {
+                let mut metrics = ValidatorMetrics {
+                    blocks_produced: *blocks_produced,
+                    blocks_missed: *blocks_missed,
+                    participation_rate: 0,
+                };
+                metrics.update_participation();
+                ValidatorPerformance::<T>::insert(validator, metrics);
+            }
+
+            if self.experiment_mode {
+                ExperimentSince::<T>::put(1);
+            }
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Enact a due scheduled switch, credit the slot author and charge skipped slots to
+        /// their expected authors
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            let bn: u32 = block_number.saturated_into();
+            // `on_finalize` is charged up front, with the scheduled switch read
+            let mut weight =
+                T::WeightInfo::on_finalize().saturating_add(T::DbWeight::get().reads(1));
+
+            if let Some((at, consensus_type)) = ScheduledSwitch::<T>::get() {
+                if at <= bn {
+                    ScheduledSwitch::<T>::kill();
+                    Self::do_set_consensus(consensus_type, bn);
+                    weight = weight.saturating_add(T::WeightInfo::set_consensus());
+                }
+            }
+
+            let missed = Self::note_authorship(bn);
+            CurrentEpochMetrics::<T>::mutate(|epoch| {
+                epoch.missed_slots = epoch.missed_slots.saturating_add(missed)
+            });
+            weight = weight
+                .saturating_add(T::WeightInfo::note_authorship(missed))
+                .saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            if Self::is_epoch_boundary(bn) {
+                weight = weight.saturating_add(T::WeightInfo::close_epoch(T::MaxValidators::get()));
+                if Self::is_participation_era_end(Self::epoch_index(bn)) {
+                    weight = weight.saturating_add(T::WeightInfo::close_participation_era(
+                        T::MaxValidators::get(),
+                    ));
+                }
+            }
+            weight
+        }
+
         /// Record metrics at the end of each block
         fn on_finalize(block_number: BlockNumberFor<T>) {
             let bn: u32 = block_number.saturated_into();
-            
+
+            let now = T::BlockTimestamp::now().as_millis().saturated_into::<u64>();
+            let block_time_ms = block_time_ms(LastBlockTimestamp::<T>::get(), now);
+            LastBlockTimestamp::<T>::put(now);
+            let (is_slow, is_fast) = Self::block_pace(block_time_ms);
+
             // Record block metrics
             let metrics = BlockMetrics {
                 block_number: bn,
-                validator_count: 0, // Would be populated from actual validator set
-                block_time_ms: 6000, // Default block time
+                validator_count: T::BlockAuthorship::authority_count(),
+                block_time_ms,
                 fork_count: 0,
                 consensus_type: CurrentConsensus::<T>::get(),
+                is_slow,
+                is_fast,
             };
 
+            CurrentEpochMetrics::<T>::mutate(|epoch| {
+                epoch.blocks = epoch.blocks.saturating_add(1);
+                epoch.total_block_time_ms =
+                    epoch.total_block_time_ms.saturating_add(metrics.block_time_ms);
+                epoch.max_block_time_ms = epoch.max_block_time_ms.max(metrics.block_time_ms);
+            });
             BlockMetricsHistory::<T>::insert(bn, metrics);
             Self::deposit_event(Event::BlockMetricsRecorded { block_number: bn });
 
-            // Cleanup old metrics (keep last 1000 blocks)
-            if bn > 1000_u32 {
-                BlockMetricsHistory::<T>::remove(bn - 1000_u32);
+            if Self::is_epoch_boundary(bn) {
+                Self::close_epoch(bn);
+            }
+
+            // A block is summarized by the end of its epoch, so anything older than the raw
+            // window has been summarized
+            if let Some(expired) = bn.checked_sub(Self::raw_metrics_window()) {
+                BlockMetricsHistory::<T>::remove(expired);
+            }
+
+            let window: u32 = T::BlockHashCount::get().saturated_into();
+            if let Some(expired) = bn.checked_sub(window) {
+                ReportedForks::<T>::remove(expired);
+            }
+        }
+
+        /// Send this era's heartbeats, then report the valid fork evidence recorded by the
+        /// node whose competing branch has settled. Unsettled evidence is kept for later.
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            let bn: u32 = block_number.saturated_into();
+            Self::send_heartbeats(bn);
+
+            let mut storage = StorageValueRef::persistent(FORK_EVIDENCE_KEY);
+            let Ok(Some(evidence)) = storage.get::<Vec<ForkEvidence<HeaderFor<T>>>>() else {
+                return;
+            };
+
+            let (settled, pending): (Vec<_>, Vec<_>) =
+                evidence.into_iter().partition(|(_, root, branch)| {
+                    let tip: u32 = (*branch.last().unwrap_or(root).number()).saturated_into();
+                    tip.saturating_add(FORK_SETTLEMENT_BLOCKS) <= bn
+                });
+            if pending.is_empty() {
+                storage.clear();
+            } else {
+                storage.set(&pending);
+            }
+
+            for (header_a, header_b, branch) in settled {
+                if Self::check_fork_evidence(&header_a, &header_b, &branch).is_err() {
+                    continue;
+                }
+                let call = Call::report_fork { header_a, header_b, branch };
+                let xt = T::create_bare(call.into());
+                if SubmitTransaction::<T, Call<T>>::submit_transaction(xt).is_err() {
+                    log::warn!(target: "consensus-manager", "Failed to submit fork report");
+                }
             }
         }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(block_number: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state(block_number.saturated_into())
+        }
     }
 
     #[pallet::call]
@@ -200,7 +912,7 @@ pub mod pallet {
         /// Set consensus type (admin only)
         ///
         /// # Parameters
-        /// - `origin`: Root origin (sudo)
+        /// - `origin`: `AdminOrigin`
         /// - `consensus_type_u8`: The new consensus type (0=Aura, 1=Babe, 2=Custom)
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::set_consensus())]
@@ -208,7 +920,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             consensus_type_u8: u8,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
 
             // Convert u8 to ConsensusType
             let consensus_type = ConsensusType::from_u8(consensus_type_u8)
@@ -216,17 +928,7 @@ pub mod pallet {
 
             let block_number: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
 
-            // Update consensus type
-            CurrentConsensus::<T>::put(consensus_type.clone());
-
-            // Record the switch
-            ConsensusSwitches::<T>::mutate(|switches| {
-                let _ = switches.try_push((block_number, consensus_type.clone()));
-            });
-
-            Self::deposit_event(Event::ConsensusChanged {
-                block_number,
-            });
+            Self::do_set_consensus(consensus_type, block_number);
 
             Ok(())
         }
@@ -248,12 +950,21 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
+            let now: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            ensure!(
+                block_number.saturating_add(Self::raw_metrics_window()) > now,
+                Error::<T>::MetricsOutsideRetention
+            );
+
+            let (is_slow, is_fast) = Self::block_pace(block_time_ms);
             let metrics = BlockMetrics {
                 block_number,
                 validator_count,
                 block_time_ms,
                 fork_count: 0,
                 consensus_type: CurrentConsensus::<T>::get(),
+                is_slow,
+                is_fast,
             };
 
             BlockMetricsHistory::<T>::insert(block_number, metrics);
@@ -265,7 +976,7 @@ pub mod pallet {
         /// Record a fork detection
         ///
         /// # Parameters
-        /// - `origin`: Root or OCW
+        /// - `origin`: `AdminOrigin`; the OCW reports through `report_fork`
         /// - `block_number`: Block where fork was detected
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::record_fork())]
@@ -273,56 +984,447 @@ pub mod pallet {
             origin: OriginFor<T>,
             block_number: u32,
         ) -> DispatchResult {
-            ensure_root(origin)?;
-
-            ForkStatistics::<T>::mutate(|stats| {
-                stats.total_forks = stats.total_forks.saturating_add(1);
-                stats.last_fork_block = block_number;
-            });
-
-            let fork_count = ForkStatistics::<T>::get().total_forks;
+            T::AdminOrigin::ensure_origin(origin)?;
 
-            Self::deposit_event(Event::ForkDetected { block_number, fork_count });
+            // A plain two-way fork; reports with evidence carry their real shape
+            Self::note_fork(block_number, 1, 2);
 
             Ok(())
         }
 
-        /// Update validator metrics
+        /// Set or clear the rule for metric-driven consensus switch proposals
         ///
         /// # Parameters
-        /// - `origin`: Root or validator
-        /// - `validator`: Validator account
-        /// - `blocks_produced`: Number of blocks produced
-        /// - `blocks_missed`: Number of blocks missed
-        #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::update_validator_metrics())]
-        pub fn update_validator_metrics(
+        /// - `origin`: `AdminOrigin`
+        /// - `target_u8`: Consensus to propose (0=Aura, 1=Babe, 2=Custom)
+        /// - `max_avg_block_time_ms`: Breach when the epoch average block time exceeds this
+        /// - `max_missed_slots`: Breach when more slots than this are missed in an epoch
+        /// - `epochs`: Consecutive breaching epochs before proposing
+        /// - `enact_after`: Blocks after the proposal to enact the switch, if at all
+        ///
+        /// Without either threshold the rule is cleared.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_switch_rule())]
+        pub fn set_switch_rule(
             origin: OriginFor<T>,
-            validator: T::AccountId,
-            blocks_produced: u32,
-            blocks_missed: u32,
+            target_u8: u8,
+            max_avg_block_time_ms: Option<u64>,
+            max_missed_slots: Option<u32>,
+            epochs: u32,
+            enact_after: Option<u32>,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let target =
+                ConsensusType::from_u8(target_u8).map_err(|_| Error::<T>::InvalidConsensusType)?;
 
-            let total_blocks = blocks_produced.saturating_add(blocks_missed);
-            let participation_rate = if total_blocks > 0 {
-                ((blocks_produced as u64 * 100) / total_blocks as u64) as u8
+            let rule = if max_avg_block_time_ms.is_none() && max_missed_slots.is_none() {
+                None
             } else {
-                0
+                ensure!(epochs > 0, Error::<T>::InvalidSwitchRule);
+                Some(SwitchRule { target, max_avg_block_time_ms, max_missed_slots, epochs, enact_after })
             };
 
-            let metrics = ValidatorMetrics {
-                blocks_produced,
-                blocks_missed,
-                participation_rate,
-            };
+            AutoSwitchRule::<T>::set(rule.clone());
+            BreachStreak::<T>::kill();
 
-            ValidatorPerformance::<T>::insert(&validator, metrics);
+            Self::deposit_event(Event::SwitchRuleSet { rule });
 
-            Self::deposit_event(Event::ValidatorMetricsUpdated {
-                validator,
-                blocks_produced,
-            });
+            Ok(())
+        }
+
+        /// Set the consensus parameters and publish them to the client in this block's digest
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        /// - `slot_duration_ms`: Slot duration hint
+        /// - `max_authorities`: Maximum authority set size, at most `MaxValidators`
+        /// - `block_velocity`: Parachain blocks per relay chain block
+        ///
+        /// Unset parameters are left to the client.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_consensus_params())]
+        pub fn set_consensus_params(
+            origin: OriginFor<T>,
+            slot_duration_ms: Option<u64>,
+            max_authorities: Option<u32>,
+            block_velocity: Option<u32>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                slot_duration_ms != Some(0) &&
+                    block_velocity != Some(0) &&
+                    max_authorities.map_or(true, |max| max > 0 && max <= T::MaxValidators::get()),
+                Error::<T>::InvalidConsensusParams
+            );
+
+            let params = ConsensusParameters { slot_duration_ms, max_authorities, block_velocity };
+            ConsensusParams::<T>::put(params.clone());
+
+            let mut data = CONSENSUS_PARAMS_DIGEST_ID.to_vec();
+            params.encode_to(&mut data);
+            frame_system::Pallet::<T>::deposit_log(sp_runtime::DigestItem::Other(data));
+
+            Self::deposit_event(Event::ConsensusParamsChanged { params });
+
+            Ok(())
+        }
+
+        /// Schedule a consensus switch at a future block, to line it up with collator upgrades
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        /// - `consensus_type_u8`: The new consensus type (0=Aura, 1=Babe, 2=Custom)
+        /// - `at_block`: Block at whose start the switch is enacted
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::schedule_consensus_switch())]
+        pub fn schedule_consensus_switch(
+            origin: OriginFor<T>,
+            consensus_type_u8: u8,
+            at_block: u32,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let consensus_type = ConsensusType::from_u8(consensus_type_u8)
+                .map_err(|_| Error::<T>::InvalidConsensusType)?;
+
+            let block_number: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            ensure!(at_block > block_number, Error::<T>::SwitchNotInFuture);
+            ensure!(!ScheduledSwitch::<T>::exists(), Error::<T>::SwitchAlreadyScheduled);
+
+            ScheduledSwitch::<T>::put((at_block, consensus_type.clone()));
+
+            Self::deposit_event(Event::ConsensusSwitchScheduled { consensus_type, at_block });
+
+            Ok(())
+        }
+
+        /// Cancel the scheduled consensus switch
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::cancel_consensus_switch())]
+        pub fn cancel_consensus_switch(origin: OriginFor<T>) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (at_block, consensus_type) =
+                ScheduledSwitch::<T>::take().ok_or(Error::<T>::NoScheduledSwitch)?;
+
+            Self::deposit_event(Event::ConsensusSwitchCancelled { consensus_type, at_block });
+
+            Ok(())
+        }
+
+        /// Report an authority for authoring two distinct blocks in the same slot
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        /// - `proof`: The two conflicting headers, carrying Aura slot pre-digests and seals
+        ///
+        /// Both headers must be sealed by the slot's expected author under the current
+        /// authority set, who is the offender.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::report_equivocation())]
+        pub fn report_equivocation(
+            origin: OriginFor<T>,
+            proof: EquivocationProof<HeaderFor<T>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (slot, offender) = Self::check_equivocation_proof(&proof)?;
+            ensure!(
+                !Equivocations::<T>::contains_key(slot),
+                Error::<T>::EquivocationAlreadyReported
+            );
+
+            let reported_at: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            Equivocations::<T>::insert(
+                slot,
+                EquivocationOffence { offender: offender.clone(), reported_at },
+            );
+
+            T::OffenceHandler::on_equivocation(&offender, slot);
+
+            Self::deposit_event(Event::EquivocationReported { offender, slot });
+
+            Ok(())
+        }
+
+        /// Record a collator heartbeat (unsigned, submitted by the OCW)
+        ///
+        /// # Parameters
+        /// - `origin`: None (unsigned)
+        /// - `heartbeat`: Heartbeat for the current era
+        /// - `signature`: Signature of the encoded heartbeat by its authority key
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::heartbeat())]
+        pub fn heartbeat(
+            origin: OriginFor<T>,
+            heartbeat: Heartbeat<T::HeartbeatId>,
+            signature: <T::HeartbeatId as RuntimeAppPublic>::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let collator = Self::check_heartbeat(&heartbeat, &signature)?;
+            let block_number: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            ReceivedHeartbeats::<T>::insert(heartbeat.era, &collator, block_number);
+            OfflineCollators::<T>::remove(&collator);
+
+            Self::deposit_event(Event::HeartbeatReceived { collator, era: heartbeat.era });
+
+            Ok(())
+        }
+
+        /// Jail a validator for `JailCooldownEras` from the current era
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        /// - `validator`: Validator to jail
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::jail())]
+        pub fn jail(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(!Jailed::<T>::contains_key(&validator), Error::<T>::AlreadyJailed);
+
+            let bn: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            Self::do_jail(validator, Self::current_era(bn));
+
+            Ok(())
+        }
+
+        /// Release a jailed validator before its cooldown ends
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        /// - `validator`: Jailed validator
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::unjail())]
+        pub fn unjail(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(Jailed::<T>::contains_key(&validator), Error::<T>::NotJailed);
+
+            Self::do_unjail(validator);
+
+            Ok(())
+        }
+
+        /// Start or stop experiment mode. While running, every full epoch without a consensus
+        /// switch is tagged with its consensus type and added to that type's results. Starting
+        /// clears the previous experiment's results; the epoch in progress is not counted.
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        /// - `enabled`: Start (`true`) or stop (`false`)
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_experiment_mode())]
+        pub fn set_experiment_mode(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            if enabled {
+                ensure!(!ExperimentSince::<T>::exists(), Error::<T>::ExperimentRunning);
+                let bn: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+                let first_epoch = Self::epoch_index(bn.max(1)).saturating_add(1);
+                let _ = ExperimentResults::<T>::clear(u32::MAX, None);
+                ExperimentSince::<T>::put(first_epoch);
+                Self::deposit_event(Event::ExperimentStarted { first_epoch });
+            } else {
+                ensure!(ExperimentSince::<T>::exists(), Error::<T>::NoExperiment);
+                ExperimentSince::<T>::kill();
+                let epochs =
+                    ExperimentResults::<T>::iter_values().map(|totals| totals.epochs).sum();
+                Self::deposit_event(Event::ExperimentStopped { epochs });
+            }
+
+            Ok(())
+        }
+
+        /// Register a validator, up to `MaxValidators`
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        /// - `validator`: Validator to register
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::register_validator())]
+        pub fn register_validator(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Validators::<T>::try_mutate(|validators| {
+                ensure!(!validators.contains(&validator), Error::<T>::AlreadyRegistered);
+                validators
+                    .try_insert(validator.clone())
+                    .map_err(|_| Error::<T>::TooManyValidators)?;
+                Ok::<_, Error<T>>(())
+            })?;
+            Self::deposit_event(Event::ValidatorRegistered { validator });
+
+            Ok(())
+        }
+
+        /// Deregister a validator, dropping its performance, participation, strikes and
+        /// offline record. A jailed validator stays jailed until its cooldown ends.
+        ///
+        /// # Parameters
+        /// - `origin`: `AdminOrigin`
+        /// - `validator`: Registered validator
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::deregister_validator())]
+        pub fn deregister_validator(
+            origin: OriginFor<T>,
+            validator: T::AccountId,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                Validators::<T>::mutate(|validators| validators.remove(&validator)),
+                Error::<T>::NotRegistered
+            );
+
+            ValidatorPerformance::<T>::remove(&validator);
+            EraParticipation::<T>::remove(&validator);
+            LowParticipationStrikes::<T>::remove(&validator);
+            OfflineCollators::<T>::remove(&validator);
+            Self::deposit_event(Event::ValidatorDeregistered { validator });
+
+            Ok(())
+        }
+
+        /// Report a fork with evidence (unsigned, submitted by the OCW)
+        ///
+        /// # Parameters
+        /// - `origin`: None (unsigned)
+        /// - `header_a`, `header_b`: Two distinct headers sharing a recent canonical parent
+        /// - `branch`: Descendants of `header_b`, in order, up to `MAX_FORK_BRANCH`
+        ///
+        /// Further reports at a known height count only if they add a new competing head.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::report_fork(branch.len() as u32))]
+        pub fn report_fork(
+            origin: OriginFor<T>,
+            header_a: HeaderFor<T>,
+            header_b: HeaderFor<T>,
+            branch: Vec<HeaderFor<T>>,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let (height, depth) = Self::check_fork_evidence(&header_a, &header_b, &branch)?;
+            let heads = Self::new_fork_heads(height, &header_a, &header_b)
+                .ok_or(Error::<T>::ForkAlreadyReported)?;
+            let competing_heads = heads.len() as u32;
+            ReportedForks::<T>::insert(height, heads);
+
+            Self::note_fork(height, depth, competing_heads);
+
+            Ok(())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::report_fork { header_a, header_b, branch } => {
+                    let Ok((height, _)) = Self::check_fork_evidence(header_a, header_b, branch)
+                    else {
+                        return InvalidTransaction::BadProof.into();
+                    };
+                    if Self::new_fork_heads(height, header_a, header_b).is_none() {
+                        return InvalidTransaction::Stale.into();
+                    }
+
+                    ValidTransaction::with_tag_prefix("ConsensusManagerFork")
+                        .priority(TransactionPriority::MAX / 2)
+                        .and_provides((height, header_a.hash(), header_b.hash()))
+                        .longevity(5)
+                        .propagate(true)
+                        .build()
+                },
+                Call::heartbeat { heartbeat, signature } => {
+                    let collator = match Self::check_heartbeat(heartbeat, signature) {
+                        Ok(collator) => collator,
+                        Err(Error::<T>::DuplicateHeartbeat) => return InvalidTransaction::Stale.into(),
+                        Err(_) => return InvalidTransaction::BadProof.into(),
+                    };
+
+                    let bn: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+                    let era_end = Self::era_end(heartbeat.era);
+                    ValidTransaction::with_tag_prefix("ConsensusManagerHeartbeat")
+                        .priority(TransactionPriority::MAX / 2)
+                        .and_provides((heartbeat.era, collator))
+                        .longevity(era_end.saturating_sub(bn).max(1).into())
+                        .propagate(true)
+                        .build()
+                },
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
+
+    #[cfg(any(feature = "try-runtime", test))]
+    impl<T: Config> Pallet<T> {
+        /// Check the fork statistics, raw metrics retention and switch history agree with each
+        /// other as of `now`, and that only registered validators have metrics
+        pub(crate) fn do_try_state(now: u32) -> Result<(), sp_runtime::TryRuntimeError> {
+            let stats = ForkStatistics::<T>::get();
+            let recent = RecentForks::<T>::get();
+            if stats.total_forks == 0 {
+                ensure!(
+                    stats == ForkStats::default() && recent.is_empty(),
+                    "Fork statistics recorded without any fork"
+                );
+            }
+            ensure!(recent.len() as u32 <= stats.total_forks, "More recent forks than forks");
+            ensure!(
+                recent.last().map_or(true, |fork| fork.block_number == stats.last_fork_block),
+                "Last fork block is not the most recent fork"
+            );
+            ensure!(
+                recent.iter().all(|fork| fork.depth <= stats.deepest_fork && fork.resolved_at <= now),
+                "Recent fork deeper than the deepest fork, or resolved in the future"
+            );
+            ensure!(
+                recent.windows(2).all(|pair| pair[0].resolved_at <= pair[1].resolved_at),
+                "Recent forks out of order"
+            );
+
+            let window = Self::raw_metrics_window();
+            for (block_number, metrics) in BlockMetricsHistory::<T>::iter() {
+                ensure!(
+                    metrics.block_number == block_number,
+                    "Block metrics stored under another block"
+                );
+                ensure!(
+                    block_number.saturating_add(window) > now,
+                    "Block metrics kept beyond the raw metrics window"
+                );
+            }
+
+            let switches = ConsensusSwitches::<T>::get();
+            ensure!(
+                switches.windows(2).all(|pair| pair[0].0 <= pair[1].0),
+                "Consensus switches out of order"
+            );
+            if let Some((block_number, consensus_type)) = switches.last() {
+                ensure!(*block_number <= now, "Consensus switch recorded in the future");
+                ensure!(
+                    *consensus_type == CurrentConsensus::<T>::get(),
+                    "Current consensus differs from the last recorded switch"
+                );
+            }
+            ensure!(
+                ScheduledSwitch::<T>::get().map_or(true, |(at, _)| at > now),
+                "Scheduled switch overdue"
+            );
+
+            let validators = Validators::<T>::get();
+            ensure!(
+                ValidatorPerformance::<T>::iter_keys()
+                    .chain(EraParticipation::<T>::iter_keys())
+                    .all(|validator| validators.contains(&validator)),
+                "Metrics kept for an unregistered validator"
+            );
 
             Ok(())
         }
@@ -330,6 +1432,511 @@ pub mod pallet {
 
     // Helper functions
     impl<T: Config> Pallet<T> {
+        /// Check that two headers are distinct children of the same recent canonical block and
+        /// that `branch` builds on `header_b` one block at a time, every header sealed by its
+        /// slot's expected author. Returns the fork height and the depth of the competing branch.
+        pub fn check_fork_evidence(
+            header_a: &HeaderFor<T>,
+            header_b: &HeaderFor<T>,
+            branch: &[HeaderFor<T>],
+        ) -> Result<(u32, u32), Error<T>> {
+            let number = *header_a.number();
+            ensure!(
+                *header_b.number() == number &&
+                    header_a.parent_hash() == header_b.parent_hash() &&
+                    header_a.hash() != header_b.hash() &&
+                    !number.is_zero(),
+                Error::<T>::InvalidForkEvidence
+            );
+
+            // The shared parent must be on our chain; `block_hash` covers `BlockHashCount` blocks
+            let parent = frame_system::Pallet::<T>::block_hash(number - One::one());
+            ensure!(
+                parent != T::Hash::default() && parent == *header_a.parent_hash(),
+                Error::<T>::InvalidForkEvidence
+            );
+
+            ensure!(branch.len() as u32 <= MAX_FORK_BRANCH, Error::<T>::InvalidForkEvidence);
+            let mut tip = header_b;
+            for header in branch {
+                ensure!(
+                    *header.parent_hash() == tip.hash() &&
+                        *header.number() == *tip.number() + One::one(),
+                    Error::<T>::InvalidForkEvidence
+                );
+                tip = header;
+            }
+
+            // Checked last: signature verification is the expensive part
+            for header in [header_a, header_b].into_iter().chain(branch) {
+                Self::check_seal(header).ok_or(Error::<T>::InvalidForkEvidence)?;
+            }
+
+            Ok((number.saturated_into(), branch.len() as u32 + 1))
+        }
+
+        /// Heads known at `height` with those of a new report added, or `None` if the report
+        /// adds no new head or the height already has `MAX_COMPETING_HEADS`
+        fn new_fork_heads(
+            height: u32,
+            header_a: &HeaderFor<T>,
+            header_b: &HeaderFor<T>,
+        ) -> Option<BoundedVec<T::Hash, ConstU32<MAX_COMPETING_HEADS>>> {
+            let mut heads = ReportedForks::<T>::get(height).unwrap_or_default();
+            let known = heads.len();
+            for hash in [header_a.hash(), header_b.hash()] {
+                if !heads.contains(&hash) {
+                    heads.try_push(hash).ok()?;
+                }
+            }
+            (heads.len() > known).then_some(heads)
+        }
+
+        /// Whether a block time is slow or fast against the expected slot duration, preferring
+        /// the published slot duration hint. Unmeasured (zero) block times are neither.
+        pub fn block_pace(block_time_ms: u64) -> (bool, bool) {
+            if block_time_ms == 0 {
+                return (false, false);
+            }
+
+            let expected = ConsensusParams::<T>::get()
+                .slot_duration_ms
+                .unwrap_or_else(T::ExpectedBlockTimeMs::get);
+            let tolerance = T::BlockTimeTolerance::get().mul_floor(expected);
+
+            (
+                block_time_ms > expected.saturating_add(tolerance),
+                block_time_ms < expected.saturating_sub(tolerance),
+            )
+        }
+
+        /// Aura slot from a header's pre-runtime digest
+        pub fn header_slot(header: &HeaderFor<T>) -> Option<u64> {
+            header
+                .digest()
+                .logs()
+                .iter()
+                .find_map(|log| log.pre_runtime_try_to::<Slot>(&AURA_ENGINE_ID))
+                .map(u64::from)
+        }
+
+        /// Verify that `header` carries an Aura seal by the expected author of its slot, over
+        /// the hash of the header without the seal. Returns the slot and the author's key.
+        pub fn check_seal(header: &HeaderFor<T>) -> Option<(u64, T::HeartbeatId)> {
+            let slot = Self::header_slot(header)?;
+            let mut unsealed = header.clone();
+            let seal = unsealed.digest_mut().pop()?;
+            let signature = match seal.as_seal() {
+                Some((id, signature)) if id == AURA_ENGINE_ID => signature,
+                _ => return None,
+            };
+            let signature =
+                <T::HeartbeatId as RuntimeAppPublic>::Signature::decode(&mut &signature[..]).ok()?;
+
+            let key = T::AuthorityKeys::expected_key(slot)?;
+            key.verify(&unsealed.hash(), &signature).then_some((slot, key))
+        }
+
+        /// Check that both headers are distinct, claim the same slot and are sealed by its
+        /// expected author, returning the slot and that author's account
+        pub fn check_equivocation_proof(
+            proof: &EquivocationProof<HeaderFor<T>>,
+        ) -> Result<(u64, T::AccountId), Error<T>> {
+            ensure!(
+                proof.first_header.hash() != proof.second_header.hash(),
+                Error::<T>::InvalidEquivocationProof
+            );
+            let first = Self::check_seal(&proof.first_header);
+            let second = Self::check_seal(&proof.second_header);
+            let Some((slot, key)) = first.filter(|first| Some(first) == second.as_ref()) else {
+                return Err(Error::<T>::InvalidEquivocationProof);
+            };
+
+            Ok((slot, T::HeartbeatAccount::convert(key)))
+        }
+
+        /// Switch to `consensus_type` and record the switch
+        fn do_set_consensus(consensus_type: ConsensusType, block_number: u32) {
+            CurrentConsensus::<T>::put(consensus_type.clone());
+
+            CurrentEpochMetrics::<T>::mutate(|epoch| epoch.consensus_switched = true);
+
+            let evicted = ConsensusSwitches::<T>::mutate(|switches| {
+                let evicted = (switches.is_full() && !switches.is_empty()).then(|| switches.remove(0));
+                let _ = switches.try_push((block_number, consensus_type));
+                evicted
+            });
+            if let Some((block_number, consensus_type)) = evicted {
+                Self::deposit_event(Event::ConsensusHistoryTruncated { block_number, consensus_type });
+            }
+
+            Self::deposit_event(Event::ConsensusChanged { block_number });
+        }
+
+        fn is_epoch_boundary(bn: u32) -> bool {
+            let length = T::MetricsEpochLength::get();
+            length > 0 && bn > 0 && bn % length == 0
+        }
+
+        /// Index of the metrics epoch containing `bn`; epoch 1 covers blocks
+        /// `1..=MetricsEpochLength`
+        pub fn epoch_index(bn: u32) -> u32 {
+            let length = T::MetricsEpochLength::get().max(1);
+            bn.saturating_sub(1) / length + 1
+        }
+
+        /// Blocks of raw metrics kept: the current epoch plus `RawMetricsEpochs` closed ones
+        pub(crate) fn raw_metrics_window() -> u32 {
+            T::MetricsEpochLength::get()
+                .max(1)
+                .saturating_mul(T::RawMetricsEpochs::get().saturating_add(1))
+        }
+
+        /// Close the epoch ending at `end_block`: summarize it, drop the summary that left
+        /// retention and check the epoch against the switch rule
+        pub(crate) fn close_epoch(end_block: u32) {
+            let epoch = CurrentEpochMetrics::<T>::take();
+            let index = Self::epoch_index(end_block);
+
+            let counted = !epoch.consensus_switched &&
+                ExperimentSince::<T>::get().is_some_and(|first| index >= first);
+            let consensus_type = counted.then(|| CurrentConsensus::<T>::get());
+            if let Some(consensus_type) = &consensus_type {
+                ExperimentResults::<T>::mutate(consensus_type, |totals| totals.add(&epoch));
+            }
+
+            let summary = EpochMetricsSummary {
+                start_block: end_block.saturating_sub(T::MetricsEpochLength::get()) + 1,
+                end_block,
+                blocks: epoch.blocks,
+                avg_block_time_ms: epoch.avg_block_time_ms(),
+                max_block_time_ms: epoch.max_block_time_ms,
+                missed_slots: epoch.missed_slots,
+                forks: epoch.forks,
+                authors: epoch.authors,
+                consensus_type,
+            };
+            EpochSummaries::<T>::insert(index, summary.clone());
+            Self::deposit_event(Event::EpochSummarized { epoch: index, summary });
+
+            if let Some(expired) = index.checked_sub(T::EpochSummaryRetention::get()) {
+                if EpochSummaries::<T>::take(expired).is_some() {
+                    let _ = EpochAuthorBlocks::<T>::clear_prefix(
+                        expired,
+                        T::MaxValidators::get(),
+                        None,
+                    );
+                }
+            }
+
+            Self::evaluate_switch_rule(&epoch, end_block);
+
+            if Self::is_participation_era_end(index) {
+                Self::close_participation_era(index / T::ParticipationEraEpochs::get());
+            }
+        }
+
+        /// Participation era containing `bn`; era `n` closes with epoch
+        /// `n * ParticipationEraEpochs`
+        pub fn current_era(bn: u32) -> u32 {
+            (Self::epoch_index(bn) - 1) / T::ParticipationEraEpochs::get().max(1) + 1
+        }
+
+        /// Last block of `era`
+        fn era_end(era: u32) -> u32 {
+            era.saturating_mul(T::ParticipationEraEpochs::get().max(1))
+                .saturating_mul(T::MetricsEpochLength::get().max(1))
+        }
+
+        /// Check a heartbeat is for the current era, from a registered authority that hasn't
+        /// sent one yet, and signed by its key. Returns the collator's account.
+        pub fn check_heartbeat(
+            heartbeat: &Heartbeat<T::HeartbeatId>,
+            signature: &<T::HeartbeatId as RuntimeAppPublic>::Signature,
+        ) -> Result<T::AccountId, Error<T>> {
+            let bn: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            let collator = T::HeartbeatAccount::convert(heartbeat.authority.clone());
+            ensure!(
+                heartbeat.era == Self::current_era(bn) &&
+                    Self::is_registered(&collator) &&
+                    T::BlockAuthorship::authorities().contains(&collator),
+                Error::<T>::InvalidHeartbeat
+            );
+            ensure!(
+                !ReceivedHeartbeats::<T>::contains_key(heartbeat.era, &collator),
+                Error::<T>::DuplicateHeartbeat
+            );
+            ensure!(
+                heartbeat.authority.verify(&heartbeat.encode(), signature),
+                Error::<T>::InvalidHeartbeat
+            );
+
+            Ok(collator)
+        }
+
+        /// Sign and submit a heartbeat for every local authority key, once per era
+        fn send_heartbeats(bn: u32) {
+            let era = Self::current_era(bn.max(1));
+            let storage = StorageValueRef::persistent(HEARTBEAT_ERA_KEY);
+            if storage.get::<u32>().ok().flatten().is_some_and(|sent| sent >= era) {
+                return;
+            }
+
+            let authorities = T::BlockAuthorship::authorities();
+            let validators = Validators::<T>::get();
+            for authority in T::HeartbeatId::all() {
+                let collator = T::HeartbeatAccount::convert(authority.clone());
+                if !authorities.contains(&collator) ||
+                    !validators.contains(&collator) ||
+                    ReceivedHeartbeats::<T>::contains_key(era, &collator)
+                {
+                    continue;
+                }
+
+                let heartbeat = Heartbeat { era, block_number: bn, authority };
+                let Some(signature) = heartbeat.authority.sign(&heartbeat.encode()) else {
+                    continue;
+                };
+                let xt = T::create_bare(Call::heartbeat { heartbeat, signature }.into());
+                if SubmitTransaction::<T, Call<T>>::submit_transaction(xt).is_err() {
+                    log::warn!(target: "consensus-manager", "Failed to submit heartbeat");
+                }
+            }
+
+            storage.set(&era);
+        }
+
+        fn is_participation_era_end(epoch: u32) -> bool {
+            let epochs = T::ParticipationEraEpochs::get();
+            epochs > 0 && epoch % epochs == 0
+        }
+
+        /// Score each validator's participation over the era that just ended. Registered
+        /// authorities that sent no heartbeat are marked offline and score zero. Validators below
+        /// `MinParticipation` collect a strike; once past the grace period they are reported.
+        /// Strikes of validators that met the threshold, or left the set, are cleared.
+        /// Rolling performance is then decayed, dropping validators with nothing left.
+        ///
+        /// Jailed validators are neither scored nor expected to send heartbeats. Collators
+        /// without a heartbeat for `JailAfterMissedEras` eras running are jailed, and validators
+        /// whose cooldown ends with this era are released.
+        pub(crate) fn close_participation_era(era: u32) {
+            let keep = T::PerformanceDecay::get();
+            ValidatorPerformance::<T>::translate(|_, mut metrics: ValidatorMetrics| {
+                metrics.decay(keep);
+                (metrics.blocks_produced > 0 || metrics.blocks_missed > 0).then_some(metrics)
+            });
+
+            let previous: BTreeMap<T::AccountId, u32> =
+                LowParticipationStrikes::<T>::drain().collect();
+
+            let mut scores: BTreeMap<T::AccountId, u8> = EraParticipation::<T>::drain()
+                .filter(|(validator, _)| !Jailed::<T>::contains_key(validator))
+                .map(|(validator, mut metrics)| {
+                    metrics.update_participation();
+                    (validator, metrics.participation_rate)
+                })
+                .collect();
+
+            let jail_after = T::JailAfterMissedEras::get();
+            let validators = Validators::<T>::get();
+            for collator in T::BlockAuthorship::authorities() {
+                if !validators.contains(&collator) ||
+                    ReceivedHeartbeats::<T>::contains_key(era, &collator) ||
+                    Jailed::<T>::contains_key(&collator)
+                {
+                    continue;
+                }
+                let since = OfflineCollators::<T>::get(&collator).unwrap_or_else(|| {
+                    OfflineCollators::<T>::insert(&collator, era);
+                    era
+                });
+                scores.insert(collator.clone(), 0);
+                Self::deposit_event(Event::CollatorOffline { collator: collator.clone(), era });
+
+                if jail_after > 0 && era.saturating_sub(since) + 1 >= jail_after {
+                    // Missed eras count afresh once released
+                    OfflineCollators::<T>::remove(&collator);
+                    Self::do_jail(collator, era);
+                }
+            }
+            let _ = ReceivedHeartbeats::<T>::clear_prefix(era, T::MaxValidators::get(), None);
+
+            let released: Vec<T::AccountId> = Jailed::<T>::iter()
+                .filter(|(_, until_era)| *until_era <= era)
+                .map(|(validator, _)| validator)
+                .collect();
+            for validator in released {
+                Self::do_unjail(validator);
+            }
+
+            for (validator, participation_rate) in scores {
+                if participation_rate >= T::MinParticipation::get() {
+                    continue;
+                }
+
+                let strikes = previous.get(&validator).copied().unwrap_or(0).saturating_add(1);
+                LowParticipationStrikes::<T>::insert(&validator, strikes);
+
+                if strikes > T::ParticipationGraceEras::get() {
+                    T::OnLowParticipation::on_low_participation(&validator, era, participation_rate);
+                    Self::deposit_event(Event::LowParticipationReported {
+                        validator,
+                        era,
+                        participation_rate,
+                    });
+                } else {
+                    Self::deposit_event(Event::LowParticipation {
+                        validator,
+                        era,
+                        participation_rate,
+                        strikes,
+                    });
+                }
+            }
+        }
+
+        /// Jail `validator` until `JailCooldownEras` after `era`
+        fn do_jail(validator: T::AccountId, era: u32) {
+            let until_era = era.saturating_add(T::JailCooldownEras::get());
+            Jailed::<T>::insert(&validator, until_era);
+            T::JailHandler::on_jailed(&validator, until_era);
+            Self::deposit_event(Event::ValidatorJailed { validator, until_era });
+        }
+
+        fn do_unjail(validator: T::AccountId) {
+            Jailed::<T>::remove(&validator);
+            T::JailHandler::on_unjailed(&validator);
+            Self::deposit_event(Event::ValidatorUnjailed { validator });
+        }
+
+        /// Whether `validator` is jailed
+        pub fn is_jailed(validator: &T::AccountId) -> bool {
+            Jailed::<T>::contains_key(validator)
+        }
+
+        /// Whether `validator` is registered
+        pub fn is_registered(validator: &T::AccountId) -> bool {
+            Validators::<T>::get().contains(validator)
+        }
+
+        /// Current authorities that are registered and not jailed, for authority rotation
+        pub fn eligible_authorities() -> Vec<T::AccountId> {
+            let validators = Validators::<T>::get();
+            T::BlockAuthorship::authorities()
+                .into_iter()
+                .filter(|authority| validators.contains(authority) && !Self::is_jailed(authority))
+                .collect()
+        }
+
+        /// Check a closed epoch against the switch rule, proposing (and optionally
+        /// scheduling) a switch once enough epochs have breached it
+        fn evaluate_switch_rule(epoch: &EpochAccumulator, end_block: u32) {
+            let Some(rule) = AutoSwitchRule::<T>::get() else { return };
+
+            if !rule.breached_by(epoch) {
+                BreachStreak::<T>::kill();
+                return;
+            }
+
+            let streak = BreachStreak::<T>::mutate(|streak| {
+                *streak = streak.saturating_add(1);
+                *streak
+            });
+            if streak < rule.epochs || CurrentConsensus::<T>::get() == rule.target {
+                return;
+            }
+            BreachStreak::<T>::kill();
+
+            let scheduled_at = rule.enact_after.map(|delay| {
+                let at = end_block.saturating_add(delay.max(1));
+                ScheduledSwitch::<T>::put((at, rule.target.clone()));
+                at
+            });
+
+            Self::deposit_event(Event::ConsensusSwitchProposed {
+                target: rule.target,
+                epochs: streak,
+                scheduled_at,
+            });
+        }
+
+        /// Count a fork at `block_number` and add it to the recent forks
+        fn note_fork(block_number: u32, depth: u32, competing_heads: u32) {
+            ForkStatistics::<T>::mutate(|stats| {
+                stats.total_forks = stats.total_forks.saturating_add(1);
+                stats.last_fork_block = block_number;
+                stats.deepest_fork = stats.deepest_fork.max(depth);
+            });
+
+            let resolved_at: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            RecentForks::<T>::mutate(|forks| {
+                if forks.is_full() {
+                    forks.remove(0);
+                }
+                let fork = ForkEvent { block_number, depth, competing_heads, resolved_at };
+                let _ = forks.try_push(fork);
+            });
+
+            let fork_count = ForkStatistics::<T>::get().total_forks;
+            CurrentEpochMetrics::<T>::mutate(|epoch| epoch.forks = epoch.forks.saturating_add(1));
+
+            Self::deposit_event(Event::ForkDetected { block_number, fork_count });
+        }
+
+        /// Credit the current slot's author and charge the expected authors of any slots
+        /// skipped since the previous block, if registered. Returns the number of missed slots
+        /// charged.
+        pub(crate) fn note_authorship(bn: u32) -> u32 {
+            let slot = T::BlockAuthorship::current_slot();
+            let last = LastSlot::<T>::get();
+            LastSlot::<T>::put(slot);
+            let validators = Validators::<T>::get();
+
+            // Nothing to compare against on the first block, or without a slot source
+            let mut missed = 0u32;
+            if last > 0 && slot > last {
+                let first_skipped = (last + 1).max(slot.saturating_sub(MAX_MISSED_SLOTS_PER_BLOCK));
+                for skipped in first_skipped..slot {
+                    let expected = T::BlockAuthorship::expected_author(skipped);
+                    if let Some(validator) = expected.filter(|v| validators.contains(v)) {
+                        ValidatorPerformance::<T>::mutate(&validator, |metrics| {
+                            metrics.blocks_missed = metrics.blocks_missed.saturating_add(1);
+                            metrics.update_participation();
+                        });
+                        EraParticipation::<T>::mutate(&validator, |metrics| {
+                            metrics.blocks_missed = metrics.blocks_missed.saturating_add(1)
+                        });
+                        missed += 1;
+                    }
+                }
+            }
+
+            let author = T::BlockAuthorship::expected_author(slot);
+            if let Some(author) = author.filter(|author| validators.contains(author)) {
+                ValidatorPerformance::<T>::mutate(&author, |metrics| {
+                    metrics.blocks_produced = metrics.blocks_produced.saturating_add(1);
+                    metrics.update_participation();
+                });
+                EraParticipation::<T>::mutate(&author, |metrics| {
+                    metrics.blocks_produced = metrics.blocks_produced.saturating_add(1)
+                });
+
+                let epoch = Self::epoch_index(bn);
+                let first_block = EpochAuthorBlocks::<T>::mutate(epoch, &author, |blocks| {
+                    *blocks = blocks.saturating_add(1);
+                    *blocks == 1
+                });
+                if first_block {
+                    CurrentEpochMetrics::<T>::mutate(|epoch| {
+                        epoch.authors = epoch.authors.saturating_add(1)
+                    });
+                }
+            }
+
+            missed
+        }
+
         /// Get current consensus type
         pub fn get_consensus_type() -> ConsensusType {
             CurrentConsensus::<T>::get()
@@ -340,6 +1947,30 @@ pub mod pallet {
             BlockMetricsHistory::<T>::get(block_number)
         }
 
+        /// Get the summaries of the last `count` closed epochs, newest first
+        pub fn get_epoch_summaries(count: u32) -> Vec<(u32, EpochMetricsSummary)> {
+            let bn: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            let current = Self::epoch_index(bn.max(1));
+            let oldest = current.saturating_sub(T::EpochSummaryRetention::get()).max(1);
+            (oldest..=current)
+                .rev()
+                .filter_map(|epoch| EpochSummaries::<T>::get(epoch).map(|summary| (epoch, summary)))
+                .take(count as usize)
+                .collect()
+        }
+
+        /// Per consensus type results of the running or last experiment
+        pub fn get_experiment_reports() -> Vec<ExperimentReport> {
+            ExperimentResults::<T>::iter()
+                .map(|(consensus_type, totals)| ExperimentReport::new(consensus_type, totals))
+                .collect()
+        }
+
+        /// Get blocks authored per author in `epoch`
+        pub fn get_epoch_author_distribution(epoch: u32) -> Vec<(T::AccountId, u32)> {
+            EpochAuthorBlocks::<T>::iter_prefix(epoch).collect()
+        }
+
         /// Get fork statistics
         pub fn get_fork_stats() -> ForkStats {
             ForkStatistics::<T>::get()
@@ -350,6 +1981,17 @@ pub mod pallet {
             ValidatorPerformance::<T>::get(validator)
         }
 
+        /// Validators by rolling participation rate, then blocks produced, best first
+        pub fn get_validator_rankings() -> Vec<(T::AccountId, ValidatorMetrics)> {
+            let mut rankings: Vec<_> = ValidatorPerformance::<T>::iter().collect();
+            rankings.sort_by(|(_, a), (_, b)| {
+                b.participation_rate
+                    .cmp(&a.participation_rate)
+                    .then(b.blocks_produced.cmp(&a.blocks_produced))
+            });
+            rankings
+        }
+
         /// Get consensus switch history
         pub fn get_consensus_history() -> Vec<(u32, ConsensusType)> {
             ConsensusSwitches::<T>::get().to_vec()
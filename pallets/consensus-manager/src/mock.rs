@@ -1,9 +1,101 @@
 use crate as pallet_consensus_manager;
-use frame_support::{parameter_types, traits::{ConstU16, ConstU32, ConstU64}};
+use frame_support::{parameter_types, traits::{ConstU16, ConstU32, ConstU64, ConstU8}};
+use frame_system::EnsureRoot;
 use sp_core::H256;
-use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, BuildStorage};
+use sp_runtime::{
+    testing::UintAuthorityId,
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Percent,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+/// Round-robin slot authors
+pub const AUTHORS: [u64; 3] = [10, 20, 30];
+
+parameter_types! {
+    pub static CurrentSlot: u64 = 0;
+    pub static LowParticipationReports: Vec<(u64, u32, u8)> = vec![];
+    pub static Offences: Vec<(u64, u64)> = vec![];
+    /// Seal key set by benchmarks for every slot
+    pub static BenchmarkSealKey: Option<UintAuthorityId> = None;
+    pub const BlockTimeTolerance: Percent = Percent::from_percent(20);
+    pub const PerformanceDecay: Percent = Percent::from_percent(50);
+}
+
+/// Blocks 6s apart
+pub struct MockTime;
+
+impl frame_support::traits::UnixTime for MockTime {
+    fn now() -> core::time::Duration {
+        core::time::Duration::from_millis(System::block_number() * 6_000)
+    }
+}
+
+pub struct MockAuthorship;
+
+impl crate::SlotAuthorship<u64> for MockAuthorship {
+    fn current_slot() -> u64 {
+        CurrentSlot::get()
+    }
+
+    fn expected_author(slot: u64) -> Option<u64> {
+        Some(AUTHORS[(slot % AUTHORS.len() as u64) as usize])
+    }
+
+    fn authority_count() -> u32 {
+        AUTHORS.len() as u32
+    }
+
+    fn authorities() -> Vec<u64> {
+        AUTHORS.to_vec()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_slot(slot: u64) {
+        CurrentSlot::set(slot);
+    }
+}
+
+/// Authors seal with the `UintAuthorityId` of their account
+impl crate::SlotAuthorityKeys<UintAuthorityId> for MockAuthorship {
+    fn expected_key(slot: u64) -> Option<UintAuthorityId> {
+        use crate::SlotAuthorship;
+
+        BenchmarkSealKey::get().or_else(|| Self::expected_author(slot).map(UintAuthorityId))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_expected_key(key: UintAuthorityId) {
+        BenchmarkSealKey::set(Some(key));
+    }
+}
+
+pub struct MockLowParticipation;
+
+impl crate::OnLowParticipation<u64> for MockLowParticipation {
+    fn on_low_participation(validator: &u64, era: u32, participation_rate: u8) {
+        LowParticipationReports::mutate(|reports| reports.push((*validator, era, participation_rate)));
+    }
+}
+
+pub struct MockOffenceHandler;
+
+impl crate::OffenceHandler<u64> for MockOffenceHandler {
+    fn on_equivocation(offender: &u64, slot: u64) {
+        Offences::mutate(|offences| offences.push((*offender, slot)));
+    }
+}
+
+/// Heartbeat keys are the collator account IDs
+pub struct HeartbeatAccount;
+
+impl sp_runtime::traits::Convert<UintAuthorityId, u64> for HeartbeatAccount {
+    fn convert(id: UintAuthorityId) -> u64 {
+        id.0
+    }
+}
 
 frame_support::construct_runtime!(
     pub enum Test {
@@ -38,13 +130,58 @@ impl frame_system::Config for Test {
     type MaxConsumers = ConstU32<16>;
 }
 
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = Extrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateBare<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_bare(call: RuntimeCall) -> Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
 impl pallet_consensus_manager::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type MaxValidators = ConstU32<100>;
+    type MaxConsensusSwitches = ConstU32<3>;
+    type BlockAuthorship = MockAuthorship;
+    type AdminOrigin = EnsureRoot<u64>;
+    type MetricsEpochLength = ConstU32<10>;
+    type EpochSummaryRetention = ConstU32<2>;
+    type RawMetricsEpochs = ConstU32<1>;
+    type OnLowParticipation = MockLowParticipation;
+    type ParticipationEraEpochs = ConstU32<2>;
+    type MinParticipation = ConstU8<50>;
+    type ParticipationGraceEras = ConstU32<1>;
+    type OffenceHandler = MockOffenceHandler;
+    type BlockTimestamp = MockTime;
+    type ExpectedBlockTimeMs = ConstU64<6_000>;
+    type BlockTimeTolerance = BlockTimeTolerance;
+    type PerformanceDecay = PerformanceDecay;
+    type JailAfterMissedEras = ConstU32<2>;
+    type JailCooldownEras = ConstU32<1>;
+    type JailHandler = ();
+    type HeartbeatId = UintAuthorityId;
+    type AuthorityKeys = MockAuthorship;
+    type HeartbeatAccount = HeartbeatAccount;
 }
 
+/// Test externalities with every author registered
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_consensus_manager::GenesisConfig::<Test> {
+        validators: AUTHORS.to_vec(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     t.into()
 }
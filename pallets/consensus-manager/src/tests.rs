@@ -1,5 +1,6 @@
-use crate::{mock::*, ConsensusType, Event};
-use frame_support::{assert_ok};
+use crate::{mock::*, ConsensusType, Event, WeightInfo};
+use frame_support::{assert_ok, traits::Hooks};
+use sp_core::H256;
 
 #[test]
 fn set_consensus_works() {
@@ -63,5 +64,1017 @@ fn on_finalize_records_metrics() {
         ConsensusManager::on_finalize(1);
         
         assert!(ConsensusManager::block_metrics(1).is_some());
+        assert_eq!(ConsensusManager::block_metrics(1).unwrap().validator_count, AUTHORS.len() as u32);
+    });
+}
+
+#[test]
+fn authorship_tracked_from_slots() {
+    new_test_ext().execute_with(|| {
+        // Slot 1 is authored by 20
+        System::set_block_number(1);
+        CurrentSlot::set(1);
+        assert_eq!(
+            ConsensusManager::on_initialize(1),
+            <() as WeightInfo>::on_finalize().saturating_add(<() as WeightInfo>::note_authorship(0))
+        );
+        assert_eq!(ConsensusManager::validator_metrics(20).blocks_produced, 1);
+        assert_eq!(ConsensusManager::validator_metrics(20).participation_rate, 100);
+
+        // Slots 2 (30) and 3 (10) are skipped; slot 4 is authored by 20 again
+        System::set_block_number(2);
+        CurrentSlot::set(4);
+        assert_eq!(
+            ConsensusManager::on_initialize(2),
+            <() as WeightInfo>::on_finalize().saturating_add(<() as WeightInfo>::note_authorship(2))
+        );
+
+        assert_eq!(ConsensusManager::validator_metrics(20).blocks_produced, 2);
+        let missed = ConsensusManager::validator_metrics(30);
+        assert_eq!((missed.blocks_produced, missed.blocks_missed, missed.participation_rate), (0, 1, 0));
+        assert_eq!(ConsensusManager::validator_metrics(10).blocks_missed, 1);
+
+        // Slot 5 (30) authored on time brings its participation to 50%
+        System::set_block_number(3);
+        CurrentSlot::set(5);
+        ConsensusManager::on_initialize(3);
+        assert_eq!(ConsensusManager::validator_metrics(30).participation_rate, 50);
+
+        // A long stall only charges the most recent slots
+        System::set_block_number(4);
+        CurrentSlot::set(5 + 1 + crate::MAX_MISSED_SLOTS_PER_BLOCK + 30);
+        ConsensusManager::on_initialize(4);
+        let missed: u32 = AUTHORS.iter().map(|a| ConsensusManager::validator_metrics(a).blocks_missed).sum();
+        assert_eq!(missed, 2 + crate::MAX_MISSED_SLOTS_PER_BLOCK as u32);
+    });
+}
+
+/// A header claiming `slot`, sealed by `author` over its unsealed hash
+fn sealed_header(
+    number: u64,
+    parent: H256,
+    state: H256,
+    slot: u64,
+    author: u64,
+) -> frame_system::pallet_prelude::HeaderFor<Test> {
+    use codec::Encode;
+    use sp_consensus_aura::AURA_ENGINE_ID;
+    use sp_runtime::{testing::UintAuthorityId, traits::Header, Digest, DigestItem, RuntimeAppPublic};
+
+    let digest = Digest { logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())] };
+    let mut header = Header::new(number, H256::zero(), state, parent, digest);
+    let signature = UintAuthorityId(author).sign(&header.hash()).unwrap();
+    header.digest_mut().push(DigestItem::Seal(AURA_ENGINE_ID, signature.encode()));
+    header
+}
+
+/// Expected author of `slot`
+fn slot_author(slot: u64) -> u64 {
+    AUTHORS[(slot % AUTHORS.len() as u64) as usize]
+}
+
+/// A child of block 4 at height 5 in slot `state`, distinguished by its state root
+fn fork_header(state: u8) -> frame_system::pallet_prelude::HeaderFor<Test> {
+    let slot = state as u64;
+    sealed_header(5, H256::repeat_byte(4), H256::repeat_byte(state), slot, slot_author(slot))
+}
+
+/// `len` headers building on `root` one block and one slot at a time
+fn fork_branch(
+    root: &frame_system::pallet_prelude::HeaderFor<Test>,
+    len: u64,
+) -> Vec<frame_system::pallet_prelude::HeaderFor<Test>> {
+    use sp_runtime::traits::Header;
+
+    let mut tip = root.clone();
+    (0..len)
+        .map(|_| {
+            let slot = ConsensusManager::header_slot(&tip).unwrap() + 1;
+            tip = sealed_header(tip.number + 1, tip.hash(), H256::zero(), slot, slot_author(slot));
+            tip.clone()
+        })
+        .collect()
+}
+
+#[test]
+fn report_fork_verifies_evidence() {
+    use crate::Error;
+    use frame_support::{assert_noop, pallet_prelude::*};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(10);
+        frame_system::BlockHash::<Test>::insert(4, H256::repeat_byte(4));
+
+        assert_noop!(
+            ConsensusManager::report_fork(RuntimeOrigin::signed(1), fork_header(1), fork_header(2), vec![]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        // The same header twice isn't a fork
+        assert_noop!(
+            ConsensusManager::report_fork(RuntimeOrigin::none(), fork_header(1), fork_header(1), vec![]),
+            Error::<Test>::InvalidForkEvidence
+        );
+        // Siblings of a parent that isn't on our chain
+        frame_system::BlockHash::<Test>::insert(4, H256::repeat_byte(9));
+        assert_noop!(
+            ConsensusManager::report_fork(RuntimeOrigin::none(), fork_header(1), fork_header(2), vec![]),
+            Error::<Test>::InvalidForkEvidence
+        );
+        frame_system::BlockHash::<Test>::insert(4, H256::repeat_byte(4));
+        // A header not sealed by its slot's author
+        let forged = sealed_header(5, H256::repeat_byte(4), H256::repeat_byte(2), 2, 10);
+        assert_noop!(
+            ConsensusManager::report_fork(RuntimeOrigin::none(), fork_header(1), forged, vec![]),
+            Error::<Test>::InvalidForkEvidence
+        );
+        // A branch that doesn't build on the competing header
+        assert_noop!(
+            ConsensusManager::report_fork(
+                RuntimeOrigin::none(),
+                fork_header(1),
+                fork_header(2),
+                fork_branch(&fork_header(3), 1)
+            ),
+            Error::<Test>::InvalidForkEvidence
+        );
+
+        let call = crate::Call::report_fork {
+            header_a: fork_header(1),
+            header_b: fork_header(2),
+            branch: vec![],
+        };
+        assert!(ConsensusManager::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+        assert_ok!(ConsensusManager::report_fork(RuntimeOrigin::none(), fork_header(1), fork_header(2), vec![]));
+        let stats = ConsensusManager::fork_stats();
+        assert_eq!((stats.total_forks, stats.last_fork_block), (1, 5));
+        System::assert_last_event(Event::ForkDetected { block_number: 5, fork_count: 1 }.into());
+
+        // The same heads count once
+        assert_eq!(
+            ConsensusManager::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::Stale.into()
+        );
+        assert_noop!(
+            ConsensusManager::report_fork(RuntimeOrigin::none(), fork_header(2), fork_header(1), vec![]),
+            Error::<Test>::ForkAlreadyReported
+        );
+    });
+}
+
+#[test]
+fn fork_depth_and_competing_heads_recorded() {
+    use crate::{ForkEvent, MAX_RECENT_FORKS};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(10);
+        frame_system::BlockHash::<Test>::insert(4, H256::repeat_byte(4));
+
+        // A shallow two-way fork
+        assert_ok!(ConsensusManager::report_fork(RuntimeOrigin::none(), fork_header(1), fork_header(2), vec![]));
+        // A third head at the same height, with a deeper competing branch
+        System::set_block_number(11);
+        assert_ok!(ConsensusManager::report_fork(
+            RuntimeOrigin::none(),
+            fork_header(1),
+            fork_header(3),
+            fork_branch(&fork_header(3), 3)
+        ));
+
+        assert_eq!(
+            ConsensusManager::recent_forks().into_inner(),
+            vec![
+                ForkEvent { block_number: 5, depth: 1, competing_heads: 2, resolved_at: 10 },
+                ForkEvent { block_number: 5, depth: 4, competing_heads: 3, resolved_at: 11 },
+            ]
+        );
+        let stats = ConsensusManager::fork_stats();
+        assert_eq!((stats.total_forks, stats.deepest_fork), (2, 4));
+
+        // The recent list keeps the newest forks
+        for _ in 0..MAX_RECENT_FORKS {
+            assert_ok!(ConsensusManager::record_fork(RuntimeOrigin::root(), 7));
+        }
+        let recent = ConsensusManager::recent_forks();
+        assert_eq!(recent.len() as u32, MAX_RECENT_FORKS);
+        assert!(recent.iter().all(|fork| fork.block_number == 7 && fork.depth == 1));
+        assert_eq!(ConsensusManager::fork_stats().deepest_fork, 4);
+    });
+}
+
+#[test]
+fn offchain_worker_submits_recorded_fork_evidence() {
+    use codec::{Decode, Encode};
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt};
+
+    let (offchain, _state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        System::set_block_number(13);
+        frame_system::BlockHash::<Test>::insert(4, H256::repeat_byte(4));
+
+        // Nothing recorded: nothing submitted
+        ConsensusManager::offchain_worker(13);
+        assert!(pool_state.read().transactions.is_empty());
+
+        // Invalid evidence is skipped, and a branch still growing waits to settle
+        let growing = (fork_header(1), fork_header(3), fork_branch(&fork_header(3), 1));
+        let evidence = vec![
+            (fork_header(1), fork_header(1), vec![]),
+            (fork_header(1), fork_header(2), vec![]),
+            growing.clone(),
+        ];
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            crate::FORK_EVIDENCE_KEY,
+            &evidence.encode(),
+        );
+        ConsensusManager::offchain_worker(13);
+
+        {
+            let txs = &pool_state.read().transactions;
+            assert_eq!(txs.len(), 1);
+            let xt = Extrinsic::decode(&mut &txs[0][..]).unwrap();
+            assert_eq!(
+                xt.function,
+                RuntimeCall::ConsensusManager(crate::Call::report_fork {
+                    header_a: fork_header(1),
+                    header_b: fork_header(2),
+                    branch: vec![],
+                })
+            );
+        }
+        let pending = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, crate::FORK_EVIDENCE_KEY)
+            .map(|raw| Vec::<crate::ForkEvidence<_>>::decode(&mut &raw[..]).unwrap());
+        assert_eq!(pending, Some(vec![growing]));
+
+        // Once settled it is reported too
+        ConsensusManager::offchain_worker(14);
+        assert_eq!(pool_state.read().transactions.len(), 2);
+
+        // The recorded evidence is consumed
+        assert!(sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, crate::FORK_EVIDENCE_KEY).is_none());
+    });
+}
+
+#[test]
+fn admin_origin_manages_consensus() {
+    use sp_runtime::DispatchError;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_eq!(
+            ConsensusManager::set_consensus(RuntimeOrigin::signed(1), 1),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            ConsensusManager::record_fork(RuntimeOrigin::signed(1), 1),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_ok!(ConsensusManager::set_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(ConsensusManager::consensus_type(), ConsensusType::Babe);
+        assert_ok!(ConsensusManager::record_fork(RuntimeOrigin::root(), 1));
+        assert_eq!(ConsensusManager::fork_stats().total_forks, 1);
+    });
+}
+
+#[test]
+fn switch_proposed_after_breaching_epochs() {
+    use crate::Error;
+    use frame_support::assert_noop;
+
+    let run_to = |to: u64| {
+        for bn in System::block_number() + 1..=to {
+            System::set_block_number(bn);
+            ConsensusManager::on_initialize(bn);
+            ConsensusManager::on_finalize(bn);
+        }
+    };
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ConsensusManager::set_switch_rule(RuntimeOrigin::root(), 1, Some(5_000), None, 0, None),
+            Error::<Test>::InvalidSwitchRule
+        );
+        // Blocks average 6s, so every epoch breaches a 5s limit
+        assert_ok!(ConsensusManager::set_switch_rule(RuntimeOrigin::root(), 1, Some(5_000), None, 2, Some(3)));
+
+        run_to(10);
+        assert_eq!(ConsensusManager::breach_streak(), 1);
+        assert_eq!(ConsensusManager::scheduled_switch(), None);
+
+        run_to(20);
+        System::assert_has_event(
+            Event::ConsensusSwitchProposed { target: ConsensusType::Babe, epochs: 2, scheduled_at: Some(23) }
+                .into(),
+        );
+        assert_eq!(ConsensusManager::consensus_type(), ConsensusType::Aura);
+
+        run_to(23);
+        assert_eq!(ConsensusManager::consensus_type(), ConsensusType::Babe);
+        assert_eq!(ConsensusManager::scheduled_switch(), None);
+        assert_eq!(ConsensusManager::get_consensus_history(), vec![(23, ConsensusType::Babe)]);
+
+        // Clearing the rule stops proposals
+        assert_ok!(ConsensusManager::set_switch_rule(RuntimeOrigin::root(), 0, None, None, 0, None));
+        assert_eq!(ConsensusManager::switch_rule(), None);
+    });
+}
+
+#[test]
+fn missed_slots_breach_switch_rule() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ConsensusManager::set_switch_rule(RuntimeOrigin::root(), 2, None, Some(3), 1, None));
+
+        // A healthy epoch, then one that skips 5 slots
+        for bn in 1..=20u64 {
+            System::set_block_number(bn);
+            CurrentSlot::set(if bn == 20 { 25 } else { bn });
+            ConsensusManager::on_initialize(bn);
+            ConsensusManager::on_finalize(bn);
+            if bn == 10 {
+                assert_eq!(ConsensusManager::breach_streak(), 0);
+            }
+        }
+
+        // Only proposed; nothing is scheduled
+        System::assert_has_event(
+            Event::ConsensusSwitchProposed { target: ConsensusType::Custom, epochs: 1, scheduled_at: None }
+                .into(),
+        );
+        assert_eq!(ConsensusManager::consensus_type(), ConsensusType::Aura);
+    });
+}
+
+#[test]
+fn epochs_summarized_and_raw_metrics_pruned() {
+    let run_to = |to: u64| {
+        for bn in System::block_number() + 1..=to {
+            System::set_block_number(bn);
+            CurrentSlot::set(bn);
+            ConsensusManager::on_initialize(bn);
+            if bn == 5 {
+                assert_ok!(ConsensusManager::record_fork(RuntimeOrigin::root(), 4));
+            }
+            ConsensusManager::on_finalize(bn);
+        }
+    };
+
+    new_test_ext().execute_with(|| {
+        run_to(20);
+        let summary = ConsensusManager::epoch_summary(1).unwrap();
+        assert_eq!((summary.start_block, summary.end_block, summary.blocks), (1, 10, 10));
+        // Block 1 has no predecessor to measure against
+        assert_eq!((summary.avg_block_time_ms, summary.max_block_time_ms), (5400, 6000));
+        assert_eq!((summary.forks, summary.authors), (1, 3));
+        assert_eq!(ConsensusManager::epoch_summary(2).unwrap().forks, 0);
+
+        let mut distribution = ConsensusManager::get_epoch_author_distribution(1);
+        distribution.sort();
+        assert_eq!(distribution, vec![(10, 3), (20, 4), (30, 3)]);
+
+        // The current epoch and one summarized epoch keep their raw metrics
+        assert!(ConsensusManager::block_metrics(1).is_some());
+        run_to(21);
+        assert!(ConsensusManager::block_metrics(1).is_none());
+
+        // Only two summaries are retained
+        run_to(40);
+        assert!(ConsensusManager::epoch_summary(2).is_none());
+        assert!(ConsensusManager::get_epoch_author_distribution(2).is_empty());
+        let epochs: Vec<u32> =
+            ConsensusManager::get_epoch_summaries(5).into_iter().map(|(epoch, _)| epoch).collect();
+        assert_eq!(epochs, vec![4, 3]);
+        assert!(ConsensusManager::block_metrics(20).is_none());
+        assert!(ConsensusManager::block_metrics(21).is_some());
+    });
+}
+
+#[test]
+fn validator_performance_decays_each_era() {
+    use crate::{ValidatorMetrics, ValidatorPerformance};
+
+    new_test_ext().execute_with(|| {
+        // An old incident and a steady producer; the mock keeps half each era
+        ValidatorPerformance::<Test>::insert(
+            1,
+            ValidatorMetrics { blocks_produced: 2, blocks_missed: 8, participation_rate: 20 },
+        );
+        ValidatorPerformance::<Test>::insert(
+            2,
+            ValidatorMetrics { blocks_produced: 4, blocks_missed: 4, participation_rate: 50 },
+        );
+        assert_eq!(
+            ConsensusManager::get_validator_rankings().iter().map(|(v, _)| *v).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+
+        ConsensusManager::close_participation_era(1);
+        assert_eq!(
+            ConsensusManager::validator_metrics(1),
+            ValidatorMetrics { blocks_produced: 1, blocks_missed: 4, participation_rate: 20 }
+        );
+
+        // Recent production outweighs the fading misses
+        ValidatorPerformance::<Test>::mutate(1, |metrics| metrics.blocks_produced += 6);
+        ConsensusManager::close_participation_era(2);
+        assert_eq!(
+            ConsensusManager::validator_metrics(1),
+            ValidatorMetrics { blocks_produced: 3, blocks_missed: 2, participation_rate: 60 }
+        );
+        assert_eq!(ConsensusManager::get_validator_rankings()[0].0, 1);
+
+        // Validators with nothing left age out entirely
+        for era in 3..6 {
+            ConsensusManager::close_participation_era(era);
+        }
+        assert!(!ValidatorPerformance::<Test>::contains_key(2));
+        assert!(ConsensusManager::get_validator_rankings().is_empty());
+    });
+}
+
+#[test]
+fn low_participation_reported_after_grace_period() {
+    use crate::ReceivedHeartbeats;
+
+    // Slots owned by validator 10 (multiples of 3) are skipped until block 40
+    let slot = |bn: u64| if bn <= 40 { bn + (bn - 1) / 2 } else { 59 + (bn - 40) };
+
+    new_test_ext().execute_with(|| {
+        for bn in 1..=60u64 {
+            System::set_block_number(bn);
+            CurrentSlot::set(slot(bn));
+            // Every collator stays online
+            if bn % 20 == 1 {
+                for author in AUTHORS {
+                    ReceivedHeartbeats::<Test>::insert(bn as u32 / 20 + 1, author, bn as u32);
+                }
+            }
+            ConsensusManager::on_initialize(bn);
+            ConsensusManager::on_finalize(bn);
+
+            if bn == 20 {
+                // First low era falls within the grace period
+                System::assert_has_event(
+                    Event::LowParticipation { validator: 10, era: 1, participation_rate: 0, strikes: 1 }
+                        .into(),
+                );
+                assert_eq!(ConsensusManager::low_participation_strikes(20), 0);
+                assert!(LowParticipationReports::get().is_empty());
+            }
+        }
+
+        System::assert_has_event(
+            Event::LowParticipationReported { validator: 10, era: 2, participation_rate: 0 }.into(),
+        );
+        assert_eq!(LowParticipationReports::get(), vec![(10, 2, 0)]);
+
+        // A healthy era clears the strikes
+        assert_eq!(ConsensusManager::low_participation_strikes(10), 0);
+        assert_eq!(ConsensusManager::era_participation(10), Default::default());
+    });
+}
+
+#[test]
+fn consensus_params_published_in_digest() {
+    use crate::{ConsensusParameters, Error};
+    use frame_support::assert_noop;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            ConsensusManager::set_consensus_params(RuntimeOrigin::root(), Some(0), None, None),
+            Error::<Test>::InvalidConsensusParams
+        );
+        // Above MaxValidators
+        assert_noop!(
+            ConsensusManager::set_consensus_params(RuntimeOrigin::root(), None, Some(101), None),
+            Error::<Test>::InvalidConsensusParams
+        );
+        assert_noop!(
+            ConsensusManager::set_consensus_params(RuntimeOrigin::signed(1), Some(6_000), None, None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(ConsensusManager::set_consensus_params(RuntimeOrigin::root(), Some(6_000), Some(10), Some(2)));
+
+        let params = ConsensusParameters {
+            slot_duration_ms: Some(6_000),
+            max_authorities: Some(10),
+            block_velocity: Some(2),
+        };
+        assert_eq!(ConsensusManager::consensus_params(), params);
+        System::assert_last_event(Event::ConsensusParamsChanged { params: params.clone() }.into());
+
+        let published: Vec<_> = System::digest()
+            .logs
+            .iter()
+            .filter_map(ConsensusParameters::from_digest_item)
+            .collect();
+        assert_eq!(published, vec![params]);
+    });
+}
+
+#[test]
+fn scheduled_switch_enacted_or_cancelled() {
+    use crate::Error;
+    use frame_support::assert_noop;
+
+    let run_to = |to: u64| {
+        for bn in System::block_number() + 1..=to {
+            System::set_block_number(bn);
+            ConsensusManager::on_initialize(bn);
+            ConsensusManager::on_finalize(bn);
+        }
+    };
+
+    new_test_ext().execute_with(|| {
+        run_to(1);
+        assert_noop!(
+            ConsensusManager::schedule_consensus_switch(RuntimeOrigin::root(), 1, 1),
+            Error::<Test>::SwitchNotInFuture
+        );
+        assert_noop!(
+            ConsensusManager::cancel_consensus_switch(RuntimeOrigin::root()),
+            Error::<Test>::NoScheduledSwitch
+        );
+
+        assert_ok!(ConsensusManager::schedule_consensus_switch(RuntimeOrigin::root(), 1, 5));
+        assert_noop!(
+            ConsensusManager::schedule_consensus_switch(RuntimeOrigin::root(), 2, 6),
+            Error::<Test>::SwitchAlreadyScheduled
+        );
+
+        // Cancelled before it is due
+        assert_ok!(ConsensusManager::cancel_consensus_switch(RuntimeOrigin::root()));
+        System::assert_last_event(
+            Event::ConsensusSwitchCancelled { consensus_type: ConsensusType::Babe, at_block: 5 }.into(),
+        );
+        run_to(5);
+        assert_eq!(ConsensusManager::consensus_type(), ConsensusType::Aura);
+
+        assert_ok!(ConsensusManager::schedule_consensus_switch(RuntimeOrigin::root(), 2, 8));
+        run_to(7);
+        assert_eq!(ConsensusManager::consensus_type(), ConsensusType::Aura);
+        run_to(8);
+        assert_eq!(ConsensusManager::consensus_type(), ConsensusType::Custom);
+        assert_eq!(ConsensusManager::scheduled_switch(), None);
+        assert_eq!(ConsensusManager::get_consensus_history(), vec![(8, ConsensusType::Custom)]);
+    });
+}
+
+#[test]
+fn consensus_history_evicts_oldest_switch() {
+    new_test_ext().execute_with(|| {
+        // The mock keeps three switches
+        for (bn, consensus_type) in [(1, 1), (2, 0), (3, 2)] {
+            System::set_block_number(bn);
+            assert_ok!(ConsensusManager::set_consensus(RuntimeOrigin::root(), consensus_type));
+        }
+        assert_eq!(ConsensusManager::get_consensus_history().len(), 3);
+
+        System::set_block_number(4);
+        assert_ok!(ConsensusManager::set_consensus(RuntimeOrigin::root(), 0));
+        System::assert_has_event(
+            Event::ConsensusHistoryTruncated { block_number: 1, consensus_type: ConsensusType::Babe }.into(),
+        );
+        assert_eq!(
+            ConsensusManager::get_consensus_history(),
+            vec![(2, ConsensusType::Aura), (3, ConsensusType::Custom), (4, ConsensusType::Aura)]
+        );
+    });
+}
+
+/// A header at height 5 claiming `slot`, distinguished by its state root
+fn slot_header(slot: u64, state: u8) -> frame_system::pallet_prelude::HeaderFor<Test> {
+    sealed_header(5, H256::repeat_byte(4), H256::repeat_byte(state), slot, slot_author(slot))
+}
+
+#[test]
+fn report_equivocation_dispatches_offence() {
+    use crate::{EquivocationProof, Error};
+    use frame_support::assert_noop;
+
+    let proof = |a, b| EquivocationProof { first_header: a, second_header: b };
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+
+        assert_noop!(
+            ConsensusManager::report_equivocation(
+                RuntimeOrigin::signed(1),
+                proof(slot_header(4, 1), slot_header(4, 2))
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        // Same header, different slots, unsealed, or sealed by someone other than the slot's
+        // author
+        let unsealed = |state| {
+            let mut header = slot_header(4, state);
+            header.digest.pop();
+            header
+        };
+        let forged = |state| sealed_header(5, H256::repeat_byte(4), H256::repeat_byte(state), 4, 10);
+        for invalid in [
+            proof(slot_header(4, 1), slot_header(4, 1)),
+            proof(slot_header(4, 1), slot_header(5, 2)),
+            proof(unsealed(1), unsealed(2)),
+            proof(slot_header(4, 1), forged(2)),
+            proof(forged(1), forged(2)),
+        ] {
+            assert_noop!(
+                ConsensusManager::report_equivocation(RuntimeOrigin::root(), invalid),
+                Error::<Test>::InvalidEquivocationProof
+            );
+        }
+
+        // Slot 4 belongs to AUTHORS[1]
+        assert_ok!(ConsensusManager::report_equivocation(
+            RuntimeOrigin::root(),
+            proof(slot_header(4, 1), slot_header(4, 2))
+        ));
+        System::assert_last_event(Event::EquivocationReported { offender: 20, slot: 4 }.into());
+        assert_eq!(ConsensusManager::equivocation(4).map(|o| (o.offender, o.reported_at)), Some((20, 7)));
+        assert_eq!(Offences::get(), vec![(20, 4)]);
+
+        assert_noop!(
+            ConsensusManager::report_equivocation(
+                RuntimeOrigin::root(),
+                proof(slot_header(4, 2), slot_header(4, 3))
+            ),
+            Error::<Test>::EquivocationAlreadyReported
+        );
+    });
+}
+
+#[test]
+fn block_times_measured_and_flagged() {
+    new_test_ext().execute_with(|| {
+        for bn in 1..=2u64 {
+            System::set_block_number(bn);
+            ConsensusManager::on_finalize(bn);
+        }
+        let first = ConsensusManager::block_metrics(1).unwrap();
+        assert_eq!((first.block_time_ms, first.is_slow, first.is_fast), (0, false, false));
+        let second = ConsensusManager::block_metrics(2).unwrap();
+        assert_eq!((second.block_time_ms, second.is_slow, second.is_fast), (6000, false, false));
+
+        // 20% either side of the expected 6s
+        let pace = |block_time_ms| {
+            assert_ok!(ConsensusManager::record_metrics(RuntimeOrigin::root(), 100, 3, block_time_ms));
+            let metrics = ConsensusManager::block_metrics(100).unwrap();
+            (metrics.is_slow, metrics.is_fast)
+        };
+        assert_eq!(pace(7_200), (false, false));
+        assert_eq!(pace(7_300), (true, false));
+        assert_eq!(pace(4_700), (false, true));
+
+        // A published slot duration hint takes precedence
+        assert_ok!(ConsensusManager::set_consensus_params(RuntimeOrigin::root(), Some(12_000), None, None));
+        assert_eq!(pace(7_300), (false, true));
+    });
+}
+
+#[test]
+fn heartbeats_keep_collators_online() {
+    use crate::{Error, Heartbeat};
+    use codec::{Decode, Encode};
+    use frame_support::{assert_noop, pallet_prelude::*};
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+    use sp_runtime::{testing::UintAuthorityId, RuntimeAppPublic};
+
+    let (offchain, _state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    // 99 holds a key but isn't an authority
+    UintAuthorityId::set_all_keys(vec![10u64, 20, 99]);
+
+    ext.execute_with(|| {
+        System::set_block_number(3);
+
+        // One heartbeat per local authority key, once per era
+        ConsensusManager::offchain_worker(3);
+        ConsensusManager::offchain_worker(4);
+        let txs = pool_state.read().transactions.clone();
+        assert_eq!(txs.len(), 2);
+
+        for tx in txs {
+            let xt = Extrinsic::decode(&mut &tx[..]).unwrap();
+            let RuntimeCall::ConsensusManager(call) = xt.function else { panic!("unexpected call") };
+            assert!(ConsensusManager::validate_unsigned(TransactionSource::External, &call).is_ok());
+            let crate::Call::heartbeat { heartbeat, signature } = call else { panic!("unexpected call") };
+            assert_ok!(ConsensusManager::heartbeat(RuntimeOrigin::none(), heartbeat, signature));
+        }
+        System::assert_has_event(Event::HeartbeatReceived { collator: 20, era: 1 }.into());
+        assert_eq!(ConsensusManager::received_heartbeat(1, 10), Some(3));
+
+        // Duplicates, stale eras and bad signatures are rejected
+        let signed = |era, authority: u64| {
+            let heartbeat = Heartbeat { era, block_number: 3, authority: UintAuthorityId(authority) };
+            let signature = heartbeat.authority.sign(&heartbeat.encode()).unwrap();
+            (heartbeat, signature)
+        };
+        let (heartbeat, signature) = signed(1, 10);
+        assert_noop!(
+            ConsensusManager::heartbeat(RuntimeOrigin::none(), heartbeat, signature),
+            Error::<Test>::DuplicateHeartbeat
+        );
+        let (heartbeat, signature) = signed(2, 30);
+        assert_noop!(
+            ConsensusManager::heartbeat(RuntimeOrigin::none(), heartbeat, signature),
+            Error::<Test>::InvalidHeartbeat
+        );
+        let (heartbeat, _) = signed(1, 30);
+        let (_, forged) = signed(1, 20);
+        assert_noop!(
+            ConsensusManager::heartbeat(RuntimeOrigin::none(), heartbeat, forged),
+            Error::<Test>::InvalidHeartbeat
+        );
+
+        // 30 never reported and is offline for the era, scoring zero participation
+        for bn in 4..=20u64 {
+            System::set_block_number(bn);
+            CurrentSlot::set(bn);
+            ConsensusManager::on_initialize(bn);
+            ConsensusManager::on_finalize(bn);
+        }
+        System::assert_has_event(Event::CollatorOffline { collator: 30, era: 1 }.into());
+        System::assert_has_event(
+            Event::LowParticipation { validator: 30, era: 1, participation_rate: 0, strikes: 1 }.into(),
+        );
+        assert_eq!(ConsensusManager::offline_since(30), Some(1));
+        assert_eq!(ConsensusManager::offline_since(10), None);
+        assert_eq!(ConsensusManager::low_participation_strikes(10), 0);
+    });
+}
+
+#[test]
+fn try_state_checks_invariants() {
+    use crate::{
+        BlockMetricsHistory, ConsensusSwitches, Error, ForkStatistics, ValidatorMetrics,
+        ValidatorPerformance,
+    };
+    use frame_support::assert_noop;
+
+    new_test_ext().execute_with(|| {
+        // Raw metrics cover 20 blocks in the mock
+        for bn in 1..=25u64 {
+            System::set_block_number(bn);
+            CurrentSlot::set(bn);
+            ConsensusManager::on_initialize(bn);
+            if bn == 12 {
+                assert_ok!(ConsensusManager::set_consensus(RuntimeOrigin::root(), 1));
+                assert_ok!(ConsensusManager::record_fork(RuntimeOrigin::root(), 11));
+            }
+            ConsensusManager::on_finalize(bn);
+            assert_ok!(ConsensusManager::do_try_state(bn as u32));
+        }
+
+        // Metrics too old to ever be pruned are refused
+        assert_noop!(
+            ConsensusManager::record_metrics(RuntimeOrigin::root(), 5, 3, 6000),
+            Error::<Test>::MetricsOutsideRetention
+        );
+        assert_ok!(ConsensusManager::record_metrics(RuntimeOrigin::root(), 6, 3, 6000));
+
+        // Each kind of inconsistency is caught
+        let metrics = ConsensusManager::block_metrics(6).unwrap();
+        BlockMetricsHistory::<Test>::insert(5, metrics);
+        assert!(ConsensusManager::do_try_state(25).is_err());
+        BlockMetricsHistory::<Test>::remove(5);
+
+        ForkStatistics::<Test>::mutate(|stats| stats.last_fork_block = 12);
+        assert!(ConsensusManager::do_try_state(25).is_err());
+        ForkStatistics::<Test>::mutate(|stats| stats.last_fork_block = 11);
+
+        ConsensusSwitches::<Test>::mutate(|switches| {
+            switches.try_push((13, ConsensusType::Custom)).unwrap()
+        });
+        assert!(ConsensusManager::do_try_state(25).is_err());
+        ConsensusSwitches::<Test>::mutate(|switches| switches.pop());
+
+        ValidatorPerformance::<Test>::insert(99, ValidatorMetrics::default());
+        assert!(ConsensusManager::do_try_state(25).is_err());
+        ValidatorPerformance::<Test>::remove(99);
+
+        assert_ok!(ConsensusManager::do_try_state(25));
+    });
+}
+
+#[test]
+fn collators_jailed_and_released() {
+    use crate::Error;
+    use frame_support::assert_noop;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // The admin can jail and release early
+        assert_ok!(ConsensusManager::jail(RuntimeOrigin::root(), 10));
+        System::assert_last_event(Event::ValidatorJailed { validator: 10, until_era: 2 }.into());
+        assert_noop!(ConsensusManager::jail(RuntimeOrigin::root(), 10), Error::<Test>::AlreadyJailed);
+        assert_eq!(ConsensusManager::eligible_authorities(), vec![20, 30]);
+        assert_ok!(ConsensusManager::unjail(RuntimeOrigin::root(), 10));
+        System::assert_last_event(Event::ValidatorUnjailed { validator: 10 }.into());
+        assert_noop!(ConsensusManager::unjail(RuntimeOrigin::root(), 10), Error::<Test>::NotJailed);
+        assert_noop!(
+            ConsensusManager::jail(RuntimeOrigin::signed(1), 10),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // 30 misses heartbeats for two eras running and is jailed for one
+        for era in 1..=2 {
+            for collator in [10, 20] {
+                crate::ReceivedHeartbeats::<Test>::insert(era, collator, 1);
+            }
+            ConsensusManager::close_participation_era(era);
+        }
+        System::assert_has_event(Event::ValidatorJailed { validator: 30, until_era: 3 }.into());
+        assert_eq!(ConsensusManager::jailed_until(30), Some(3));
+        assert_eq!(ConsensusManager::offline_since(30), None);
+        assert_eq!(ConsensusManager::eligible_authorities(), vec![10, 20]);
+
+        // While jailed it owes no heartbeat, and is released once its cooldown ends
+        System::reset_events();
+        for collator in [10, 20] {
+            crate::ReceivedHeartbeats::<Test>::insert(3, collator, 1);
+        }
+        ConsensusManager::close_participation_era(3);
+        let offline = RuntimeEvent::from(Event::<Test>::CollatorOffline { collator: 30, era: 3 });
+        assert!(!System::events().iter().any(|record| record.event == offline));
+        System::assert_has_event(Event::ValidatorUnjailed { validator: 30 }.into());
+        assert!(!ConsensusManager::is_jailed(&30));
+        assert_eq!(ConsensusManager::eligible_authorities(), AUTHORS.to_vec());
+    });
+}
+
+#[test]
+fn validator_registry_bounds_and_gates_metrics() {
+    use crate::{Error, LowParticipationStrikes};
+    use frame_support::{assert_noop, traits::Get};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(ConsensusManager::validators().into_iter().collect::<Vec<_>>(), AUTHORS.to_vec());
+
+        assert_noop!(
+            ConsensusManager::register_validator(RuntimeOrigin::signed(1), 40),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(ConsensusManager::register_validator(RuntimeOrigin::root(), 40));
+        System::assert_last_event(Event::ValidatorRegistered { validator: 40 }.into());
+        assert_noop!(
+            ConsensusManager::register_validator(RuntimeOrigin::root(), 40),
+            Error::<Test>::AlreadyRegistered
+        );
+
+        // Deregistering drops the validator's metrics
+        CurrentSlot::set(1);
+        ConsensusManager::on_initialize(1);
+        LowParticipationStrikes::<Test>::insert(20, 1);
+        assert_eq!(ConsensusManager::validator_metrics(20).blocks_produced, 1);
+        assert_ok!(ConsensusManager::deregister_validator(RuntimeOrigin::root(), 20));
+        System::assert_last_event(Event::ValidatorDeregistered { validator: 20 }.into());
+        assert!(!crate::ValidatorPerformance::<Test>::contains_key(20));
+        assert!(!crate::EraParticipation::<Test>::contains_key(20));
+        assert_eq!(ConsensusManager::low_participation_strikes(20), 0);
+        assert_noop!(
+            ConsensusManager::deregister_validator(RuntimeOrigin::root(), 20),
+            Error::<Test>::NotRegistered
+        );
+
+        // Unregistered authorities are neither credited, charged, nor expected online
+        System::set_block_number(2);
+        CurrentSlot::set(4);
+        assert_eq!(
+            ConsensusManager::on_initialize(2),
+            <() as WeightInfo>::on_finalize().saturating_add(<() as WeightInfo>::note_authorship(2))
+        );
+        assert!(!crate::ValidatorPerformance::<Test>::contains_key(20));
+        assert_eq!(ConsensusManager::validator_metrics(30).blocks_missed, 1);
+        ConsensusManager::close_participation_era(1);
+        assert_eq!(ConsensusManager::offline_since(20), None);
+        assert_eq!(ConsensusManager::offline_since(30), Some(1));
+        assert_eq!(ConsensusManager::eligible_authorities(), vec![10, 30]);
+        assert_ok!(ConsensusManager::do_try_state(2));
+
+        // The registry is bounded by MaxValidators
+        let max: u32 = <Test as crate::Config>::MaxValidators::get();
+        for validator in 100..100 + max as u64 - 3 {
+            assert_ok!(ConsensusManager::register_validator(RuntimeOrigin::root(), validator));
+        }
+        assert_eq!(ConsensusManager::validators().len() as u32, max);
+        assert_noop!(
+            ConsensusManager::register_validator(RuntimeOrigin::root(), 20),
+            Error::<Test>::TooManyValidators
+        );
+    });
+}
+
+#[test]
+fn experiment_compares_consensus_types() {
+    use crate::{Error, ExperimentReport};
+    use frame_support::assert_noop;
+    use sp_runtime::Permill;
+
+    let run_to = |to: u64| {
+        for bn in System::block_number() + 1..=to {
+            System::set_block_number(bn);
+            CurrentSlot::set(bn);
+            ConsensusManager::on_initialize(bn);
+            ConsensusManager::on_finalize(bn);
+        }
+    };
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ConsensusManager::set_experiment_mode(RuntimeOrigin::root(), false),
+            Error::<Test>::NoExperiment
+        );
+        run_to(1);
+        assert_ok!(ConsensusManager::set_experiment_mode(RuntimeOrigin::root(), true));
+        System::assert_last_event(Event::ExperimentStarted { first_epoch: 2 }.into());
+        assert_noop!(
+            ConsensusManager::set_experiment_mode(RuntimeOrigin::root(), true),
+            Error::<Test>::ExperimentRunning
+        );
+
+        // Epoch 1 started before the experiment; epoch 2 runs under Aura with one fork
+        run_to(15);
+        assert_ok!(ConsensusManager::record_fork(RuntimeOrigin::root(), 15));
+        run_to(20);
+        assert_eq!(ConsensusManager::epoch_summary(1).unwrap().consensus_type, None);
+        assert_eq!(ConsensusManager::epoch_summary(2).unwrap().consensus_type, Some(ConsensusType::Aura));
+
+        // Epoch 3 switches to Babe part way, so only epoch 4 counts for Babe
+        run_to(21);
+        assert_ok!(ConsensusManager::set_consensus(RuntimeOrigin::root(), 1));
+        run_to(40);
+        assert_eq!(ConsensusManager::epoch_summary(3).unwrap().consensus_type, None);
+        assert_eq!(ConsensusManager::epoch_summary(4).unwrap().consensus_type, Some(ConsensusType::Babe));
+
+        assert_ok!(ConsensusManager::set_experiment_mode(RuntimeOrigin::root(), false));
+        System::assert_last_event(Event::ExperimentStopped { epochs: 2 }.into());
+        run_to(50);
+
+        let mut reports = ConsensusManager::get_experiment_reports();
+        reports.sort_by_key(|report| report.consensus_type.clone() as u8);
+        assert_eq!(
+            reports,
+            vec![
+                ExperimentReport {
+                    consensus_type: ConsensusType::Aura,
+                    epochs: 1,
+                    blocks: 10,
+                    avg_block_time_ms: 6000,
+                    fork_rate: Permill::from_percent(10),
+                    missed_slot_rate: Permill::zero(),
+                },
+                ExperimentReport {
+                    consensus_type: ConsensusType::Babe,
+                    epochs: 1,
+                    blocks: 10,
+                    avg_block_time_ms: 6000,
+                    fork_rate: Permill::zero(),
+                    missed_slot_rate: Permill::zero(),
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn genesis_configures_experiment() {
+    use sp_runtime::BuildStorage;
+
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    crate::GenesisConfig::<Test> {
+        consensus_type: 2,
+        validators: vec![30],
+        validator_metrics: vec![(10, 9, 1), (20, 0, 0)],
+        experiment_mode: true,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::new(t).execute_with(|| {
+        assert_eq!(ConsensusManager::consensus_type(), ConsensusType::Custom);
+        assert_eq!(ConsensusManager::validator_metrics(10).participation_rate, 90);
+        assert_eq!(ConsensusManager::validator_metrics(20).participation_rate, 0);
+        assert_eq!(ConsensusManager::validators().into_iter().collect::<Vec<_>>(), vec![10, 20, 30]);
+        assert_eq!(ConsensusManager::experiment_since(), Some(1));
+        assert_ok!(ConsensusManager::do_try_state(0));
     });
 }
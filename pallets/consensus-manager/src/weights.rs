@@ -1,4 +1,10 @@
 //! Weights for pallet_consensus_manager
+//!
+//! Not benchmark output: `ref_time` values are estimates, and proof sizes were derived by hand
+//! from the `MaxEncodedLen` bounds of the storage each call reads in its worst case under this
+//! runtime's configuration (`MaxValidators`, Aura's `MaxAuthorities`). Regenerate on reference
+//! hardware before production with a node built with `--features runtime-benchmarks`:
+//! `benchmark pallet --pallet pallet_consensus_manager --extrinsic '*' --output pallets/consensus-manager/src/weights.rs`.
 
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
@@ -7,48 +13,599 @@ pub trait WeightInfo {
     fn set_consensus() -> Weight;
     fn record_metrics() -> Weight;
     fn record_fork() -> Weight;
-    fn update_validator_metrics() -> Weight;
+    fn note_authorship(m: u32) -> Weight;
+    fn report_fork(d: u32) -> Weight;
+    fn set_switch_rule() -> Weight;
+    fn set_consensus_params() -> Weight;
+    fn schedule_consensus_switch() -> Weight;
+    fn cancel_consensus_switch() -> Weight;
+    fn report_equivocation() -> Weight;
+    fn on_finalize() -> Weight;
+    fn heartbeat() -> Weight;
+    fn jail() -> Weight;
+    fn unjail() -> Weight;
+    fn set_experiment_mode() -> Weight;
+    fn register_validator() -> Weight;
+    fn deregister_validator() -> Weight;
+    fn close_epoch(a: u32) -> Weight;
+    fn close_participation_era(v: u32) -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:0 w:1)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ConsensusSwitches` (r:1 w:1)
+    /// Proof: `ConsensusManager::ConsensusSwitches` (`max_values`: Some(1), `max_size`: Some(502), added: 997, mode: `MaxEncodedLen`)
     fn set_consensus() -> Weight {
-        Weight::from_parts(20_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(2))
+        // Proof Size summary in bytes:
+        //  Estimated: `2515`
+        Weight::from_parts(20_000_000, 2515)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
     }
 
+    /// Storage: `ConsensusManager::ConsensusParams` (r:1 w:0)
+    /// Proof: `ConsensusManager::ConsensusParams` (`max_values`: Some(1), `max_size`: Some(19), added: 514, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::BlockMetricsHistory` (r:0 w:1)
+    /// Proof: `ConsensusManager::BlockMetricsHistory` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
     fn record_metrics() -> Weight {
-        Weight::from_parts(15_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `2000`
+        Weight::from_parts(15_000_000, 2000)
+            .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
 
+    /// Storage: `ConsensusManager::ForkStatistics` (r:1 w:1)
+    /// Proof: `ConsensusManager::ForkStatistics` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::RecentForks` (r:1 w:1)
+    /// Proof: `ConsensusManager::RecentForks` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
     fn record_fork() -> Weight {
-        Weight::from_parts(10_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `3033`
+        Weight::from_parts(10_000_000, 3033)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: `Aura::CurrentSlot` (r:1 w:0)
+    /// Proof: `Aura::CurrentSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::LastSlot` (r:1 w:1)
+    /// Proof: `ConsensusManager::LastSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::Validators` (r:1 w:0)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ValidatorPerformance` (r:17 w:17)
+    /// Proof: `ConsensusManager::ValidatorPerformance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EraParticipation` (r:17 w:17)
+    /// Proof: `ConsensusManager::EraParticipation` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EpochAuthorBlocks` (r:1 w:1)
+    /// Proof: `ConsensusManager::EpochAuthorBlocks` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[0, 16]`.
+    fn note_authorship(m: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3243123`
+        Weight::from_parts(10_000_000, 3243123)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+            .saturating_add(Weight::from_parts(0, 5064).saturating_mul(m.into()))
+    }
+
+    /// Storage: `System::BlockHash` (r:1 w:0)
+    /// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ReportedForks` (r:1 w:1)
+    /// Proof: `ConsensusManager::ReportedForks` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ForkStatistics` (r:1 w:1)
+    /// Proof: `ConsensusManager::ForkStatistics` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::RecentForks` (r:1 w:1)
+    /// Proof: `ConsensusManager::RecentForks` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// The range of component `d` is `[0, 16]`.
+    fn report_fork(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3208795`
+        // Verifying the seals of both competing headers and each branch header dominates
+        Weight::from_parts(125_000_000, 3208795)
+            .saturating_add(Weight::from_parts(52_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    /// Storage: `ConsensusManager::AutoSwitchRule` (r:0 w:1)
+    /// Proof: `ConsensusManager::AutoSwitchRule` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::BreachStreak` (r:0 w:1)
+    /// Proof: `ConsensusManager::BreachStreak` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn set_switch_rule() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `2008`
+        Weight::from_parts(15_000_000, 2008)
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: `ConsensusManager::ConsensusParams` (r:0 w:1)
+    /// Proof: `ConsensusManager::ConsensusParams` (`max_values`: Some(1), `max_size`: Some(19), added: 514, mode: `MaxEncodedLen`)
+    /// Storage: `System::Digest` (r:0 w:1)
+    /// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn set_consensus_params() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1504`
+        Weight::from_parts(15_000_000, 1504)
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: `ConsensusManager::ScheduledSwitch` (r:1 w:1)
+    /// Proof: `ConsensusManager::ScheduledSwitch` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    fn schedule_consensus_switch() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1490`
+        Weight::from_parts(15_000_000, 1490)
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
 
-    fn update_validator_metrics() -> Weight {
-        Weight::from_parts(15_000_000, 0)
+    /// Storage: `ConsensusManager::ScheduledSwitch` (r:1 w:1)
+    /// Proof: `ConsensusManager::ScheduledSwitch` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    fn cancel_consensus_switch() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1490`
+        Weight::from_parts(12_000_000, 1490)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::Equivocations` (r:1 w:1)
+    /// Proof: `ConsensusManager::Equivocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn report_equivocation() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3204016`
+        // Verifying both seals dominates
+        Weight::from_parts(130_000_000, 3204016)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::LastBlockTimestamp` (r:1 w:1)
+    /// Proof: `ConsensusManager::LastBlockTimestamp` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ConsensusParams` (r:1 w:0)
+    /// Proof: `ConsensusManager::ConsensusParams` (`max_values`: Some(1), `max_size`: Some(19), added: 514, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::BlockMetricsHistory` (r:0 w:2)
+    /// Proof: `ConsensusManager::BlockMetricsHistory` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ReportedForks` (r:0 w:1)
+    /// Proof: `ConsensusManager::ReportedForks` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
+    fn on_finalize() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3204033`
+        Weight::from_parts(20_000_000, 3204033)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    /// Storage: `ConsensusManager::Validators` (r:1 w:0)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ReceivedHeartbeats` (r:1 w:1)
+    /// Proof: `ConsensusManager::ReceivedHeartbeats` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::OfflineCollators` (r:0 w:1)
+    /// Proof: `ConsensusManager::OfflineCollators` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn heartbeat() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3236525`
+        Weight::from_parts(40_000_000, 3236525)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: `ConsensusManager::Jailed` (r:1 w:1)
+    /// Proof: `ConsensusManager::Jailed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn jail() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3517`
+        Weight::from_parts(15_000_000, 3517)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `ConsensusManager::Jailed` (r:1 w:1)
+    /// Proof: `ConsensusManager::Jailed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn unjail() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3517`
+        Weight::from_parts(15_000_000, 3517)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `ConsensusManager::ExperimentSince` (r:1 w:1)
+    /// Proof: `ConsensusManager::ExperimentSince` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ExperimentResults` (r:3 w:3)
+    /// Proof: `ConsensusManager::ExperimentResults` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+    fn set_experiment_mode() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `9013`
+        Weight::from_parts(20_000_000, 9013)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    /// Storage: `ConsensusManager::Validators` (r:1 w:1)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    fn register_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `33487`
+        Weight::from_parts(20_000_000, 33487)
+            .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    /// Storage: `ConsensusManager::Validators` (r:1 w:1)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ValidatorPerformance` (r:0 w:1)
+    /// Proof: `ConsensusManager::ValidatorPerformance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EraParticipation` (r:0 w:1)
+    /// Proof: `ConsensusManager::EraParticipation` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::LowParticipationStrikes` (r:0 w:1)
+    /// Proof: `ConsensusManager::LowParticipationStrikes` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::OfflineCollators` (r:0 w:1)
+    /// Proof: `ConsensusManager::OfflineCollators` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn deregister_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `33487`
+        Weight::from_parts(25_000_000, 33487)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ExperimentSince` (r:1 w:0)
+    /// Proof: `ConsensusManager::ExperimentSince` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ExperimentResults` (r:1 w:1)
+    /// Proof: `ConsensusManager::ExperimentResults` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EpochSummaries` (r:1 w:2)
+    /// Proof: `ConsensusManager::EpochSummaries` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::AutoSwitchRule` (r:1 w:0)
+    /// Proof: `ConsensusManager::AutoSwitchRule` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::BreachStreak` (r:1 w:1)
+    /// Proof: `ConsensusManager::BreachStreak` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ScheduledSwitch` (r:0 w:1)
+    /// Proof: `ConsensusManager::ScheduledSwitch` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EpochAuthorBlocks` (r:0 w:100)
+    /// Proof: `ConsensusManager::EpochAuthorBlocks` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// The range of component `a` is `[0, 100]`.
+    fn close_epoch(a: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `8568`
+        Weight::from_parts(25_000_000, 8568)
+            .saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(a.into()))
+    }
+
+    /// Storage: `ConsensusManager::Validators` (r:1 w:0)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ValidatorPerformance` (r:100 w:100)
+    /// Proof: `ConsensusManager::ValidatorPerformance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EraParticipation` (r:100 w:100)
+    /// Proof: `ConsensusManager::EraParticipation` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::LowParticipationStrikes` (r:100 w:200)
+    /// Proof: `ConsensusManager::LowParticipationStrikes` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::Jailed` (r:100 w:100)
+    /// Proof: `ConsensusManager::Jailed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ReceivedHeartbeats` (r:100 w:100)
+    /// Proof: `ConsensusManager::ReceivedHeartbeats` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::OfflineCollators` (r:100 w:100)
+    /// Proof: `ConsensusManager::OfflineCollators` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// The range of component `v` is `[0, 100]`.
+    fn close_participation_era(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3233986`
+        Weight::from_parts(10_000_000, 3233986)
+            .saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 15184).saturating_mul(v.into()))
+    }
 }
 
 impl WeightInfo for () {
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:0 w:1)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ConsensusSwitches` (r:1 w:1)
+    /// Proof: `ConsensusManager::ConsensusSwitches` (`max_values`: Some(1), `max_size`: Some(502), added: 997, mode: `MaxEncodedLen`)
     fn set_consensus() -> Weight {
-        Weight::from_parts(20_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `2515`
+        Weight::from_parts(20_000_000, 2515)
     }
 
+    /// Storage: `ConsensusManager::ConsensusParams` (r:1 w:0)
+    /// Proof: `ConsensusManager::ConsensusParams` (`max_values`: Some(1), `max_size`: Some(19), added: 514, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::BlockMetricsHistory` (r:0 w:1)
+    /// Proof: `ConsensusManager::BlockMetricsHistory` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
     fn record_metrics() -> Weight {
-        Weight::from_parts(15_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `2000`
+        Weight::from_parts(15_000_000, 2000)
     }
 
+    /// Storage: `ConsensusManager::ForkStatistics` (r:1 w:1)
+    /// Proof: `ConsensusManager::ForkStatistics` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::RecentForks` (r:1 w:1)
+    /// Proof: `ConsensusManager::RecentForks` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
     fn record_fork() -> Weight {
-        Weight::from_parts(10_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `3033`
+        Weight::from_parts(10_000_000, 3033)
+    }
+
+    /// Storage: `Aura::CurrentSlot` (r:1 w:0)
+    /// Proof: `Aura::CurrentSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::LastSlot` (r:1 w:1)
+    /// Proof: `ConsensusManager::LastSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::Validators` (r:1 w:0)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ValidatorPerformance` (r:17 w:17)
+    /// Proof: `ConsensusManager::ValidatorPerformance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EraParticipation` (r:17 w:17)
+    /// Proof: `ConsensusManager::EraParticipation` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EpochAuthorBlocks` (r:1 w:1)
+    /// Proof: `ConsensusManager::EpochAuthorBlocks` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[0, 16]`.
+    fn note_authorship(m: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3243123`
+        Weight::from_parts(10_000_000, 3243123)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(0, 5064).saturating_mul(m.into()))
+    }
+
+    /// Storage: `System::BlockHash` (r:1 w:0)
+    /// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ReportedForks` (r:1 w:1)
+    /// Proof: `ConsensusManager::ReportedForks` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ForkStatistics` (r:1 w:1)
+    /// Proof: `ConsensusManager::ForkStatistics` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::RecentForks` (r:1 w:1)
+    /// Proof: `ConsensusManager::RecentForks` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// The range of component `d` is `[0, 16]`.
+    fn report_fork(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3208795`
+        // Verifying the seals of both competing headers and each branch header dominates
+        Weight::from_parts(125_000_000, 3208795)
+            .saturating_add(Weight::from_parts(52_000_000, 0).saturating_mul(d.into()))
+    }
+
+    /// Storage: `ConsensusManager::AutoSwitchRule` (r:0 w:1)
+    /// Proof: `ConsensusManager::AutoSwitchRule` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::BreachStreak` (r:0 w:1)
+    /// Proof: `ConsensusManager::BreachStreak` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn set_switch_rule() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `2008`
+        Weight::from_parts(15_000_000, 2008)
+    }
+
+    /// Storage: `ConsensusManager::ConsensusParams` (r:0 w:1)
+    /// Proof: `ConsensusManager::ConsensusParams` (`max_values`: Some(1), `max_size`: Some(19), added: 514, mode: `MaxEncodedLen`)
+    /// Storage: `System::Digest` (r:0 w:1)
+    /// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn set_consensus_params() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1504`
+        Weight::from_parts(15_000_000, 1504)
+    }
+
+    /// Storage: `ConsensusManager::ScheduledSwitch` (r:1 w:1)
+    /// Proof: `ConsensusManager::ScheduledSwitch` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    fn schedule_consensus_switch() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1490`
+        Weight::from_parts(15_000_000, 1490)
+    }
+
+    /// Storage: `ConsensusManager::ScheduledSwitch` (r:1 w:1)
+    /// Proof: `ConsensusManager::ScheduledSwitch` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    fn cancel_consensus_switch() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1490`
+        Weight::from_parts(12_000_000, 1490)
+    }
+
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::Equivocations` (r:1 w:1)
+    /// Proof: `ConsensusManager::Equivocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn report_equivocation() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3204016`
+        // Verifying both seals dominates
+        Weight::from_parts(130_000_000, 3204016)
+    }
+
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::LastBlockTimestamp` (r:1 w:1)
+    /// Proof: `ConsensusManager::LastBlockTimestamp` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ConsensusParams` (r:1 w:0)
+    /// Proof: `ConsensusManager::ConsensusParams` (`max_values`: Some(1), `max_size`: Some(19), added: 514, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::BlockMetricsHistory` (r:0 w:2)
+    /// Proof: `ConsensusManager::BlockMetricsHistory` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ReportedForks` (r:0 w:1)
+    /// Proof: `ConsensusManager::ReportedForks` (`max_values`: None, `max_size`: Some(269), added: 2744, mode: `MaxEncodedLen`)
+    fn on_finalize() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3204033`
+        Weight::from_parts(20_000_000, 3204033)
+    }
+
+    /// Storage: `ConsensusManager::Validators` (r:1 w:0)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ReceivedHeartbeats` (r:1 w:1)
+    /// Proof: `ConsensusManager::ReceivedHeartbeats` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::OfflineCollators` (r:0 w:1)
+    /// Proof: `ConsensusManager::OfflineCollators` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn heartbeat() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3236525`
+        Weight::from_parts(40_000_000, 3236525)
+    }
+
+    /// Storage: `ConsensusManager::Jailed` (r:1 w:1)
+    /// Proof: `ConsensusManager::Jailed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn jail() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3517`
+        Weight::from_parts(15_000_000, 3517)
+    }
+
+    /// Storage: `ConsensusManager::Jailed` (r:1 w:1)
+    /// Proof: `ConsensusManager::Jailed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn unjail() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3517`
+        Weight::from_parts(15_000_000, 3517)
+    }
+
+    /// Storage: `ConsensusManager::ExperimentSince` (r:1 w:1)
+    /// Proof: `ConsensusManager::ExperimentSince` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ExperimentResults` (r:3 w:3)
+    /// Proof: `ConsensusManager::ExperimentResults` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+    fn set_experiment_mode() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `9013`
+        Weight::from_parts(20_000_000, 9013)
+    }
+
+    /// Storage: `ConsensusManager::Validators` (r:1 w:1)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    fn register_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `33487`
+        Weight::from_parts(20_000_000, 33487)
+    }
+
+    /// Storage: `ConsensusManager::Validators` (r:1 w:1)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ValidatorPerformance` (r:0 w:1)
+    /// Proof: `ConsensusManager::ValidatorPerformance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EraParticipation` (r:0 w:1)
+    /// Proof: `ConsensusManager::EraParticipation` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::LowParticipationStrikes` (r:0 w:1)
+    /// Proof: `ConsensusManager::LowParticipationStrikes` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::OfflineCollators` (r:0 w:1)
+    /// Proof: `ConsensusManager::OfflineCollators` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn deregister_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `33487`
+        Weight::from_parts(25_000_000, 33487)
+    }
+
+    /// Storage: `ConsensusManager::CurrentEpochMetrics` (r:1 w:1)
+    /// Proof: `ConsensusManager::CurrentEpochMetrics` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ExperimentSince` (r:1 w:0)
+    /// Proof: `ConsensusManager::ExperimentSince` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ExperimentResults` (r:1 w:1)
+    /// Proof: `ConsensusManager::ExperimentResults` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EpochSummaries` (r:1 w:2)
+    /// Proof: `ConsensusManager::EpochSummaries` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::AutoSwitchRule` (r:1 w:0)
+    /// Proof: `ConsensusManager::AutoSwitchRule` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::BreachStreak` (r:1 w:1)
+    /// Proof: `ConsensusManager::BreachStreak` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ScheduledSwitch` (r:0 w:1)
+    /// Proof: `ConsensusManager::ScheduledSwitch` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EpochAuthorBlocks` (r:0 w:100)
+    /// Proof: `ConsensusManager::EpochAuthorBlocks` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// The range of component `a` is `[0, 100]`.
+    fn close_epoch(a: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `8568`
+        Weight::from_parts(25_000_000, 8568)
+            .saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(a.into()))
     }
 
-    fn update_validator_metrics() -> Weight {
-        Weight::from_parts(15_000_000, 0)
+    /// Storage: `ConsensusManager::Validators` (r:1 w:0)
+    /// Proof: `ConsensusManager::Validators` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ValidatorPerformance` (r:100 w:100)
+    /// Proof: `ConsensusManager::ValidatorPerformance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::EraParticipation` (r:100 w:100)
+    /// Proof: `ConsensusManager::EraParticipation` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::LowParticipationStrikes` (r:100 w:200)
+    /// Proof: `ConsensusManager::LowParticipationStrikes` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::Jailed` (r:100 w:100)
+    /// Proof: `ConsensusManager::Jailed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ReceivedHeartbeats` (r:100 w:100)
+    /// Proof: `ConsensusManager::ReceivedHeartbeats` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::OfflineCollators` (r:100 w:100)
+    /// Proof: `ConsensusManager::OfflineCollators` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// The range of component `v` is `[0, 100]`.
+    fn close_participation_era(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3233986`
+        Weight::from_parts(10_000_000, 3233986)
+            .saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(v.into()))
+            .saturating_add(Weight::from_parts(0, 15184).saturating_mul(v.into()))
     }
 }
@@ -0,0 +1,32 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Event Hub Runtime API
+//!
+//! Lets the node RPC and OCW query the event hub without decoding raw storage keys.
+
+use codec::Codec;
+use pallet_event_hub::{EventData, TriggerStats};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait EventHubApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Pending event IDs, highest priority first, at most `limit` entries
+        fn pending_events(limit: u32) -> Vec<u64>;
+
+        /// Event data by ID
+        fn event(id: u64) -> Option<EventData<BlockNumber>>;
+
+        /// Trigger IDs owned by an account
+        fn triggers_of(account: AccountId) -> Vec<u64>;
+
+        /// Trigger IDs subscribed to a topic
+        fn triggers_for_topic(topic: u64) -> Vec<u64>;
+
+        /// Activation counters for a trigger
+        fn trigger_stats(trigger_id: u64) -> TriggerStats<BlockNumber>;
+    }
+}
@@ -5,11 +5,128 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as EventHub;
 use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, EnsureOriginWithArg};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+
+/// Submit an OnChain event with a funded submitter, returning the submitter
+fn setup_event<T: Config>(payload_len: u32) -> Result<T::AccountId, BenchmarkError> {
+    let origin = T::SubmitOrigin::try_successful_origin(&EventType::OnChain)
+        .map_err(|_| BenchmarkError::Weightless)?;
+    let who = T::SubmitOrigin::ensure_origin(origin.clone(), &EventType::OnChain)
+        .map_err(|_| BenchmarkError::Weightless)?;
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+
+    EventHub::<T>::submit_event(origin, 0, vec![1u8; payload_len as usize], 1, None)?;
+
+    Ok(who)
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
+    #[benchmark]
+    fn submit_event(p: Linear<0, 512>) -> Result<(), BenchmarkError> {
+        let origin = T::SubmitOrigin::try_successful_origin(&EventType::OnChain)
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let who = T::SubmitOrigin::ensure_origin(origin.clone(), &EventType::OnChain)
+            .map_err(|_| BenchmarkError::Weightless)?;
+        T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+        let payload = vec![1u8; p as usize];
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0, payload, 1, None);
+
+        assert_eq!(NextEventId::<T>::get(), 1);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_trigger() {
+        let caller: T::AccountId = whitelisted_caller();
+        let condition = vec![0u8; 128];
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, 2, None, Some(condition));
+
+        assert!(Triggers::<T>::get(0).is_some());
+    }
+
+    #[benchmark]
+    fn process_event(t: Linear<1, 100>) -> Result<(), BenchmarkError> {
+        let caller = setup_event::<T>(512)?;
+
+        // Fan the event out to `t` triggers with the heaviest action, each emitting a derived
+        // event
+        for _ in 0..t {
+            EventHub::<T>::register_trigger(
+                RawOrigin::Signed(caller.clone()).into(),
+                0,
+                3,
+                Some(1),
+                None,
+            )?;
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0);
+
+        assert!(Events::<T>::get(0).unwrap().processed);
+        assert_eq!(NextEventId::<T>::get(), 1 + t as u64);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn run_delayed_activations(d: Linear<0, 100>) -> Result<(), BenchmarkError> {
+        let caller = setup_event::<T>(512)?;
+
+        // Schedule `d` activations for the next block, each emitting a derived event
+        for trigger_id in 0..d as u64 {
+            EventHub::<T>::register_trigger(
+                RawOrigin::Signed(caller.clone()).into(),
+                0,
+                3,
+                Some(1),
+                None,
+            )?;
+            EventHub::<T>::set_trigger_delay(
+                RawOrigin::Signed(caller.clone()).into(),
+                trigger_id,
+                Some(1u32.into()),
+            )?;
+        }
+        EventHub::<T>::process_event(RawOrigin::Signed(caller).into(), 0)?;
+        let due = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+        #[block]
+        {
+            EventHub::<T>::run_delayed_activations(due);
+        }
+
+        assert!(DelayedActivations::<T>::get(due).is_empty());
+        assert_eq!(NextEventId::<T>::get(), 1 + d as u64);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn deactivate_trigger() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        // Setup: register a trigger
+        EventHub::<T>::register_trigger(RawOrigin::Signed(caller.clone()).into(), 0, 2, None, None)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0);
+
+        assert!(!Triggers::<T>::get(0).unwrap().active);
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(EventHub, crate::mock::new_test_ext(), crate::mock::Test);
 }
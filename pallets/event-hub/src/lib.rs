@@ -19,17 +19,57 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+mod ocw;
+pub use ocw::WEBHOOK_URL_PREFIX;
+
+/// Well-known topics published by the runtime
+///
+/// System topics have the high bit set so they never collide with user-chosen topics.
+pub mod topics {
+    /// Marks a topic as reserved for the runtime
+    pub const SYSTEM_TOPIC_FLAG: u64 = 1 << 63;
+
+    /// `job.completed`: a job reached `Completed`; payload is the SCALE-encoded job ID
+    pub const JOB_COMPLETED: u64 = SYSTEM_TOPIC_FLAG | 1;
+    /// `job.failed`: a job reached `Failed`; payload is the SCALE-encoded job ID
+    pub const JOB_FAILED: u64 = SYSTEM_TOPIC_FLAG | 2;
+    /// `job.verified`: a job reached `Verified`; payload is the SCALE-encoded job ID
+    pub const JOB_VERIFIED: u64 = SYSTEM_TOPIC_FLAG | 3;
+    /// `telemetry.alert`: a telemetry alert threshold was crossed; payload is set by the runtime
+    pub const TELEMETRY_ALERT: u64 = SYSTEM_TOPIC_FLAG | 4;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
-    use frame_system::pallet_prelude::*;
-    use sp_std::vec::Vec;
-    use pallet_job_registry::Pallet as JobRegistry;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, EnsureOriginWithArg, ReservableCurrency, SortedMembers},
+    };
+    use frame_system::{
+        offchain::{CreateBare, CreateSignedTransaction, SignedPayload, SigningTypes},
+        pallet_prelude::*,
+    };
+    use sp_core::H256;
+    use sp_runtime::traits::{IdentifyAccount, SaturatedConversion, Saturating};
+    use codec::DecodeLimit;
+    use sp_std::{boxed::Box, vec::Vec};
+    use pallet_job_registry::{EconomicFlow, JobStatus, OnEconomicFlow, Pallet as JobRegistry};
+    use cumulus_primitives_core::{
+        relay_chain::BlockNumber as RelayBlockNumber, ParaId, XcmpMessageFormat, XcmpMessageHandler,
+    };
+    use xcm::latest::{send_xcm, Asset, Instruction, Location, OriginKind, SendXcm, WeightLimit, Xcm};
+
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
     /// Event types
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[codec(dumb_trait_bound)]
@@ -67,6 +107,41 @@ pub mod pallet {
         }
     }
 
+    /// Event processing priority
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[codec(dumb_trait_bound)]
+    pub enum EventPriority {
+        /// Bulk events (telemetry, bookkeeping)
+        Low,
+        /// Default priority
+        Normal,
+        /// Latency-sensitive events
+        High,
+    }
+
+    impl Default for EventPriority {
+        fn default() -> Self {
+            EventPriority::Normal
+        }
+    }
+
+    impl EventPriority {
+        /// Convert from u8 representation
+        pub fn from_u8(value: u8) -> Result<Self, ()> {
+            match value {
+                0 => Ok(EventPriority::Low),
+                1 => Ok(EventPriority::Normal),
+                2 => Ok(EventPriority::High),
+                _ => Err(()),
+            }
+        }
+
+        /// All priorities, highest first (processing order)
+        pub fn descending() -> [EventPriority; 3] {
+            [EventPriority::High, EventPriority::Normal, EventPriority::Low]
+        }
+    }
+
     /// Trigger action types
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[codec(dumb_trait_bound)]
@@ -77,15 +152,23 @@ pub mod pallet {
         SendXcmMessage,
         /// Execute custom logic
         Custom,
+        /// Publish a derived event on the given topic
+        EmitEvent { topic: u64 },
+        /// POST the event payload to an off-chain endpoint (delivered by the OCW)
+        Webhook { url_hash: H256 },
+        /// Instantiate a job-registry template with the event payload as job input
+        StartJobFromTemplate(u64),
     }
 
     impl TriggerAction {
-        /// Convert from u8 and optional job_id
+        /// Convert from u8 and optional parameter (job_id, topic or template_id)
         pub fn from_u8(value: u8, job_id: Option<u64>) -> Result<Self, ()> {
             match value {
                 0 => Ok(TriggerAction::StartJob(job_id.ok_or(())?)),
                 1 => Ok(TriggerAction::SendXcmMessage),
                 2 => Ok(TriggerAction::Custom),
+                3 => Ok(TriggerAction::EmitEvent { topic: job_id.ok_or(())? }),
+                4 => Ok(TriggerAction::StartJobFromTemplate(job_id.ok_or(())?)),
                 _ => Err(()),
             }
         }
@@ -103,8 +186,38 @@ pub mod pallet {
         pub created_at: BlockNumber,
         /// Whether event has been processed
         pub processed: bool,
-        /// Source parachain ID (for cross-chain events)
+        /// Source parachain ID, derived from the XCM origin (for cross-chain events)
         pub source_para_id: Option<u32>,
+        /// Topic the event was published on (for derived events)
+        pub topic: Option<u64>,
+        /// Number of trigger hops that led to this event
+        pub chain_depth: u32,
+        /// Processing priority
+        pub priority: EventPriority,
+        /// Processing attempts that ended with a failed trigger activation
+        pub attempts: u32,
+        /// Identifier shared by every event and job in one automation chain
+        pub correlation_id: Option<H256>,
+    }
+
+    /// What `submit_event` does once `MaxEvents` events are stored
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[codec(dumb_trait_bound)]
+    pub enum BackpressureMode {
+        /// Reject new events with `MaxEventsReached`
+        Reject,
+        /// Purge the oldest processed event (refunding its deposit) to make room
+        EvictOldestProcessed,
+    }
+
+    /// Event moved out of the pending queue after repeated failures
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct DeadLetter<BlockNumber> {
+        /// Processing attempts made
+        pub attempts: u32,
+        /// Block number when the event was dead-lettered
+        pub dead_lettered_at: BlockNumber,
     }
 
     /// Trigger rule structure
@@ -123,10 +236,201 @@ pub mod pallet {
         pub created_at: BlockNumber,
         /// Whether rule is active
         pub active: bool,
+        /// Consecutive failed activations
+        pub failure_count: u32,
+        /// Minimum blocks between two activations (if any)
+        pub cooldown_blocks: Option<BlockNumber>,
+        /// Block number of the last successful activation
+        pub last_fired: Option<BlockNumber>,
+        /// Source parachains the trigger reacts to (any source if unset)
+        pub source_filter: Option<BoundedVec<u32, ConstU32<16>>>,
+        /// Blocks between a match and the action executing (immediate if unset)
+        pub delay_blocks: Option<BlockNumber>,
+    }
+
+    /// Outcome of a single trigger activation
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ActivationReceipt<BlockNumber> {
+        /// Whether the action succeeded
+        pub succeeded: bool,
+        /// Dispatch error (if the action failed)
+        pub error: Option<DispatchError>,
+        /// Block number of the activation
+        pub block: BlockNumber,
+    }
+
+    /// Wire format of a blob sent by a sibling over XCMP (`ConcatenatedEncodedBlob` pages)
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct CrossChainEventMessage {
+        /// Event payload
+        pub payload: Vec<u8>,
+        /// Optional trace identifier from the sending chain
+        pub correlation_id: Option<H256>,
+    }
+
+    /// Lifetime counters for a single trigger
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    #[scale_info(skip_type_params(T))]
+    pub struct TriggerStats<BlockNumber> {
+        /// Successful activations
+        pub activations: u64,
+        /// Failed activations
+        pub failures: u64,
+        /// Matches skipped because the trigger was cooling down
+        pub suppressed: u64,
+        /// Block number of the last successful activation
+        pub last_fired: Option<BlockNumber>,
+    }
+
+    /// Webhook awaiting delivery by an off-chain worker
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct WebhookRequest<BlockNumber> {
+        /// Trigger that requested the webhook
+        pub trigger_id: u64,
+        /// Event whose payload is delivered
+        pub event_id: u64,
+        /// Hash of the endpoint URL (resolved off-chain)
+        pub url_hash: H256,
+        /// Block number when the webhook was requested
+        pub created_at: BlockNumber,
+    }
+
+    /// Blocks a pending webhook stays with its assigned worker before passing to the next
+    pub const WEBHOOK_REASSIGN_PERIOD: u32 = 10;
+
+    /// Webhook delivery outcome, signed by the key of the worker that delivered it
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct WebhookReport<Public> {
+        /// Webhook request
+        pub webhook_id: u64,
+        /// Whether the endpoint accepted the POST
+        pub delivered: bool,
+        /// Key of the reporting worker
+        pub public: Public,
+    }
+
+    impl<T: SigningTypes> SignedPayload<T> for WebhookReport<T::Public> {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Maximum nesting depth of a condition tree
+    pub const MAX_CONDITION_DEPTH: u32 = 8;
+
+    /// Condition on live chain state
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub enum StateCondition<AccountId, Balance> {
+        /// Free balance of `who` is strictly above `amount`
+        BalanceAbove { who: AccountId, amount: Balance },
+        /// Job `job_id` currently has `status`
+        JobStatusIs { job_id: u64, status: JobStatus },
+    }
+
+    /// Condition tree, SCALE-encoded into a trigger's `condition`
+    ///
+    /// The tree is bounded by the 128-byte condition limit and `MAX_CONDITION_DEPTH`.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub enum Condition<AccountId, Balance> {
+        /// Live chain state
+        State(StateCondition<AccountId, Balance>),
+        /// The event was published on `topic`
+        TopicIs(u64),
+        /// The event came from parachain `para_id`
+        SourceParaIs(u32),
+        /// The SCALE `u128` at `offset` in the event payload is strictly above `threshold`
+        PayloadValueAbove { offset: u32, threshold: u128 },
+        /// Every sub-condition holds
+        And(Vec<Condition<AccountId, Balance>>),
+        /// At least one sub-condition holds
+        Or(Vec<Condition<AccountId, Balance>>),
+        /// The sub-condition does not hold
+        Not(Box<Condition<AccountId, Balance>>),
+    }
+
+    /// Evaluates trigger conditions during activation
+    pub trait ConditionEvaluator<BlockNumber> {
+        /// Whether the encoded condition currently holds for the triggering event
+        fn evaluate(condition: &[u8], event: &EventData<BlockNumber>) -> bool;
+    }
+
+    /// Every condition holds
+    impl<BlockNumber> ConditionEvaluator<BlockNumber> for () {
+        fn evaluate(_condition: &[u8], _event: &EventData<BlockNumber>) -> bool {
+            true
+        }
+    }
+
+    /// Evaluates `Condition` trees against the event, balances and job-registry state
+    ///
+    /// Conditions that fail to decode never hold.
+    pub struct StateConditionEvaluator<T>(PhantomData<T>);
+
+    impl<T: Config> ConditionEvaluator<BlockNumberFor<T>> for StateConditionEvaluator<T> {
+        fn evaluate(condition: &[u8], event: &EventData<BlockNumberFor<T>>) -> bool {
+            let Ok(condition) = Condition::<T::AccountId, BalanceOf<T>>::decode_with_depth_limit(
+                MAX_CONDITION_DEPTH,
+                &mut &condition[..],
+            ) else {
+                return false;
+            };
+
+            Self::holds(&condition, event)
+        }
+    }
+
+    impl<T: Config> StateConditionEvaluator<T> {
+        fn holds(
+            condition: &Condition<T::AccountId, BalanceOf<T>>,
+            event: &EventData<BlockNumberFor<T>>,
+        ) -> bool {
+            match condition {
+                Condition::State(StateCondition::BalanceAbove { who, amount }) => {
+                    T::Currency::free_balance(who) > *amount
+                }
+                Condition::State(StateCondition::JobStatusIs { job_id, status }) => {
+                    JobRegistry::<T>::jobs(job_id).map_or(false, |job| job.status == *status)
+                }
+                Condition::TopicIs(topic) => event.topic == Some(*topic),
+                Condition::SourceParaIs(para_id) => event.source_para_id == Some(*para_id),
+                Condition::PayloadValueAbove { offset, threshold } => event.payload
+                    .get(*offset as usize..)
+                    .and_then(|mut bytes| u128::decode(&mut bytes).ok())
+                    .map_or(false, |value| value > *threshold),
+                Condition::And(conditions) => conditions.iter().all(|c| Self::holds(c, event)),
+                Condition::Or(conditions) => conditions.iter().any(|c| Self::holds(c, event)),
+                Condition::Not(condition) => !Self::holds(condition, event),
+            }
+        }
+    }
+
+    /// State of an HRMP channel managed by this pallet
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[codec(dumb_trait_bound)]
+    pub enum HrmpChannelState {
+        /// Open request sent to the relay chain, awaiting the recipient
+        Requested,
+        /// Inbound request accepted
+        Accepted,
+    }
+
+    /// Encodes relay-chain HRMP calls (pallet and call indices differ per relay chain)
+    pub trait HrmpCallEncoder {
+        /// `hrmp_init_open_channel(recipient, max_capacity, max_message_size)`
+        fn init_open_channel(recipient: u32, max_capacity: u32, max_message_size: u32) -> Vec<u8>;
+        /// `hrmp_accept_open_channel(sender)`
+        fn accept_open_channel(sender: u32) -> Vec<u8>;
     }
 
     #[pallet::config]
-    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> + pallet_job_registry::Config {
+    pub trait Config:
+        frame_system::Config<RuntimeEvent: From<Event<Self>>>
+        + pallet_job_registry::Config
+        + CreateSignedTransaction<Call<Self>>
+        + CreateBare<Call<Self>>
+    {
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: crate::weights::WeightInfo;
 
@@ -134,9 +438,61 @@ pub mod pallet {
         #[pallet::constant]
         type MaxEvents: Get<u32>;
 
+        /// Behaviour when `MaxEvents` events are stored
+        #[pallet::constant]
+        type Backpressure: Get<BackpressureMode>;
+
         /// Maximum number of trigger rules per account
         #[pallet::constant]
         type MaxTriggersPerAccount: Get<u32>;
+
+        /// Origin allowed to submit each (non cross-chain) event type, resolving to the depositor
+        type SubmitOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, EventType, Success = Self::AccountId>;
+
+        /// Origin allowed to submit cross-chain events, resolving to the source parachain ID
+        type CrossChainOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = u32>;
+
+        /// Maximum number of chained trigger hops before derived events are rejected
+        #[pallet::constant]
+        type MaxChainDepth: Get<u32>;
+
+        /// Consecutive failed activations before a trigger is auto-deactivated
+        #[pallet::constant]
+        type MaxTriggerFailures: Get<u32>;
+
+        /// Currency used for event submission deposits
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved per submitted event, refunded on purge
+        #[pallet::constant]
+        type EventDeposit: Get<BalanceOf<Self>>;
+
+        /// Notified when event deposits are reserved and returned
+        type OnEconomicFlow: pallet_job_registry::OnEconomicFlow;
+
+        /// Blocks after creation when anyone may purge a processed event
+        #[pallet::constant]
+        type EventRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Failed processing attempts before an event is dead-lettered
+        #[pallet::constant]
+        type MaxEventAttempts: Get<u32>;
+
+        /// Evaluates trigger conditions; triggers whose condition doesn't hold are skipped
+        type ConditionEvaluator: ConditionEvaluator<BlockNumberFor<Self>>;
+
+        /// Origin allowed to open and accept HRMP channels
+        type HrmpOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Router used to send HRMP management messages to the relay chain
+        type XcmSender: SendXcm;
+
+        /// Relay-chain HRMP call encoding
+        type HrmpCalls: HrmpCallEncoder;
+
+        /// Relay-chain fee withdrawn from the sovereign account for each HRMP call
+        #[pallet::constant]
+        type HrmpFee: Get<u128>;
     }
 
     /// Counter for event IDs
@@ -159,6 +515,30 @@ pub mod pallet {
         EventData<BlockNumberFor<T>>,
     >;
 
+    /// Number of events currently stored
+    #[pallet::storage]
+    #[pallet::getter(fn event_count)]
+    pub type EventCount<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// Processed events, oldest first (eviction candidates)
+    #[pallet::storage]
+    #[pallet::getter(fn processed_queue)]
+    pub type ProcessedQueue<T: Config> = StorageValue<
+        _,
+        BoundedVec<u64, T::MaxEvents>,
+        ValueQuery,
+    >;
+
+    /// Map from EventId to the depositor and reserved amount
+    #[pallet::storage]
+    #[pallet::getter(fn event_deposits)]
+    pub type EventDeposits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        (T::AccountId, BalanceOf<T>),
+    >;
+
     /// Map from TriggerId to TriggerRule
     #[pallet::storage]
     #[pallet::getter(fn triggers)]
@@ -180,10 +560,54 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Maximum number of triggers watching a single event
+    pub const MAX_TRIGGERS_PER_EVENT: u32 = 100;
+
+    /// Pending events `on_initialize` processes each block, highest priority first
+    pub const MAX_EVENTS_PER_BLOCK: usize = 5;
+
     /// Map from EventId to associated trigger IDs
     #[pallet::storage]
     #[pallet::getter(fn event_triggers)]
     pub type EventTriggers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        BoundedVec<u64, ConstU32<MAX_TRIGGERS_PER_EVENT>>,
+        ValueQuery,
+    >;
+
+    /// Activation receipts keyed by (TriggerId, EventId)
+    #[pallet::storage]
+    #[pallet::getter(fn activation_receipts)]
+    pub type ActivationReceipts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Blake2_128Concat,
+        u64,
+        ActivationReceipt<BlockNumberFor<T>>,
+    >;
+
+    /// Counter for webhook request IDs
+    #[pallet::storage]
+    #[pallet::getter(fn next_webhook_id)]
+    pub type NextWebhookId<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// Webhooks awaiting OCW delivery
+    #[pallet::storage]
+    #[pallet::getter(fn pending_webhooks)]
+    pub type PendingWebhooks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        WebhookRequest<BlockNumberFor<T>>,
+    >;
+
+    /// Map from topic to the trigger IDs subscribed to it
+    #[pallet::storage]
+    #[pallet::getter(fn topic_triggers)]
+    pub type TopicTriggers<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         u64,
@@ -191,15 +615,82 @@ pub mod pallet {
         ValueQuery,
     >;
 
-    /// Pending events queue (for OCW processing)
+    /// Per-trigger statistics
+    #[pallet::storage]
+    #[pallet::getter(fn trigger_stats)]
+    pub type TriggerStatistics<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        TriggerStats<BlockNumberFor<T>>,
+        ValueQuery,
+    >;
+
+    /// Map from TriggerId to the topics it is subscribed to
+    #[pallet::storage]
+    #[pallet::getter(fn trigger_subscriptions)]
+    pub type TriggerSubscriptions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        BoundedVec<u64, ConstU32<16>>,
+        ValueQuery,
+    >;
+
+    /// Delayed trigger activations due at a block, as (TriggerId, EventId)
+    #[pallet::storage]
+    #[pallet::getter(fn delayed_activations)]
+    pub type DelayedActivations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(u64, u64), ConstU32<100>>,
+        ValueQuery,
+    >;
+
+    /// Block at which a delayed activation is due, keyed by (TriggerId, EventId)
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_activations)]
+    pub type ScheduledActivations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Blake2_128Concat,
+        u64,
+        BlockNumberFor<T>,
+    >;
+
+    /// Pending events queues by priority (for OCW processing)
     #[pallet::storage]
     #[pallet::getter(fn pending_events)]
-    pub type PendingEvents<T: Config> = StorageValue<
+    pub type PendingEvents<T: Config> = StorageMap<
         _,
+        Blake2_128Concat,
+        EventPriority,
         BoundedVec<u64, T::MaxEvents>,
         ValueQuery,
     >;
 
+    /// Events that repeatedly failed processing
+    #[pallet::storage]
+    #[pallet::getter(fn dead_letters)]
+    pub type DeadLetterEvents<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        DeadLetter<BlockNumberFor<T>>,
+    >;
+
+    /// HRMP channels with target parachains, keyed by para ID
+    #[pallet::storage]
+    #[pallet::getter(fn hrmp_channels)]
+    pub type HrmpChannels<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        HrmpChannelState,
+    >;
+
     /// Statistics
     #[pallet::storage]
     #[pallet::getter(fn event_stats)]
@@ -224,16 +715,54 @@ pub mod pallet {
         EventSubmitted { event_id: u64 },
         /// Trigger registered [trigger_id, event_id, owner]
         TriggerRegistered { trigger_id: u64, event_id: u64, owner: T::AccountId },
-        /// Trigger activated [trigger_id, event_id]
-        TriggerActivated { trigger_id: u64, event_id: u64 },
+        /// Trigger activated [trigger_id, event_id, correlation_id]
+        TriggerActivated { trigger_id: u64, event_id: u64, correlation_id: Option<H256> },
         /// Event processed [event_id]
         EventProcessed { event_id: u64 },
         /// Cross-chain event received [event_id, source_para_id]
         CrossChainEventReceived { event_id: u64, source_para_id: u32 },
         /// Trigger deactivated [trigger_id]
         TriggerDeactivated { trigger_id: u64 },
-        /// Job triggered by event [job_id, event_id]
-        JobTriggered { job_id: u64, event_id: u64 },
+        /// Trigger deleted along with its indexes [trigger_id]
+        TriggerRemoved { trigger_id: u64 },
+        /// Job triggered by event [job_id, event_id, correlation_id]
+        JobTriggered { job_id: u64, event_id: u64, correlation_id: Option<H256> },
+        /// Trigger subscribed to a topic [trigger_id, topic]
+        TriggerSubscribed { trigger_id: u64, topic: u64 },
+        /// Derived event emitted by a trigger [event_id, parent_event_id, topic]
+        DerivedEventEmitted { event_id: u64, parent_event_id: u64, topic: u64 },
+        /// Trigger action failed [trigger_id, event_id, error]
+        TriggerFailed { trigger_id: u64, event_id: u64, error: DispatchError },
+        /// Trigger ownership transferred [trigger_id, from, to]
+        TriggerTransferred { trigger_id: u64, from: T::AccountId, to: T::AccountId },
+        /// Trigger source-para allowlist changed [trigger_id]
+        TriggerSourceFilterSet { trigger_id: u64 },
+        /// Trigger activation delay changed [trigger_id, delay_blocks]
+        TriggerDelaySet { trigger_id: u64, delay_blocks: Option<BlockNumberFor<T>> },
+        /// Trigger activation scheduled for a later block [trigger_id, event_id, at]
+        ActivationScheduled { trigger_id: u64, event_id: u64, at: BlockNumberFor<T> },
+        /// Trigger cooldown changed [trigger_id, cooldown_blocks]
+        TriggerCooldownSet { trigger_id: u64, cooldown_blocks: Option<BlockNumberFor<T>> },
+        /// Activation skipped because the trigger is cooling down [trigger_id, event_id]
+        TriggerSuppressed { trigger_id: u64, event_id: u64 },
+        /// Webhook queued for OCW delivery [webhook_id, trigger_id, event_id]
+        WebhookRequested { webhook_id: u64, trigger_id: u64, event_id: u64 },
+        /// Webhook delivery reported by the OCW [webhook_id, delivered]
+        WebhookReported { webhook_id: u64, delivered: bool },
+        /// Event record deleted [event_id, refund]
+        EventPurged { event_id: u64, refund: BalanceOf<T> },
+        /// Processed event evicted to make room for a new one [event_id, refund]
+        EventEvicted { event_id: u64, refund: BalanceOf<T> },
+        /// Event processing failed and will be retried [event_id, attempts]
+        EventRetryScheduled { event_id: u64, attempts: u32 },
+        /// Event moved to the dead-letter queue [event_id, attempts]
+        EventDeadLettered { event_id: u64, attempts: u32 },
+        /// Dead-lettered event returned to the pending queue [event_id]
+        EventRequeued { event_id: u64 },
+        /// HRMP channel open request sent to the relay chain [recipient]
+        HrmpChannelRequested { recipient: u32 },
+        /// Inbound HRMP channel accepted [sender]
+        HrmpChannelAccepted { sender: u32 },
     }
 
     #[pallet::error]
@@ -260,27 +789,97 @@ pub mod pallet {
         InvalidEventType,
         /// Invalid trigger action
         InvalidTriggerAction,
+        /// Cross-chain events must be submitted through an XCM origin
+        CrossChainOriginRequired,
+        /// Derived event would exceed the maximum chain depth
+        ChainDepthExceeded,
+        /// Too many triggers subscribed to this topic, or topics for this trigger
+        TooManySubscriptions,
+        /// Webhook request not found
+        WebhookNotFound,
+        /// Invalid event priority
+        InvalidPriority,
+        /// Event has not been processed yet
+        EventNotProcessed,
+        /// Retention period has not elapsed
+        RetentionNotElapsed,
+        /// Event is not in the dead-letter queue
+        NotDeadLettered,
+        /// Trigger is already owned by the receiving account
+        AlreadyOwner,
+        /// Too many parachains in a source filter
+        TooManySources,
+        /// Too many activations already scheduled for the target block
+        TooManyDelayedActivations,
+        /// HRMP channel already requested or accepted
+        HrmpChannelExists,
+        /// Failed to send the XCM message to the relay chain
+        XcmSendFailed,
+    }
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// System triggers as (owner, topic, action_u8, action_param)
+        ///
+        /// No events exist at genesis, so each trigger is subscribed to a topic instead of an event.
+        pub triggers: Vec<(T::AccountId, u64, u8, Option<u64>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (owner, topic, action_u8, action_param) in &self.triggers {
+                let action = TriggerAction::from_u8(*action_u8, *action_param)
+                    .expect("genesis trigger action must be valid");
+
+                let trigger_id = Pallet::<T>::do_register_trigger(owner.clone(), u64::MAX, action, None)
+                    .expect("genesis trigger must fit the owner's trigger limit");
+
+                Pallet::<T>::do_subscribe(trigger_id, *topic)
+                    .expect("genesis topic must have room for its triggers");
+            }
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        /// Process pending events
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-            // Process some pending events each block
-            let pending = PendingEvents::<T>::get();
-            let mut processed = 0u32;
-            
-            for event_id in pending.iter().take(5) {
+        /// Run due delayed activations, then process pending events
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let activated = Self::run_delayed_activations(n);
+            let mut weight = <T as Config>::WeightInfo::run_delayed_activations(activated);
+
+            // Process some pending events each block, highest priority first
+            let pending = Self::get_pending_events();
+            weight.saturating_accrue(
+                T::DbWeight::get().reads(EventPriority::descending().len() as u64),
+            );
+
+            for event_id in pending.iter().take(MAX_EVENTS_PER_BLOCK) {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
                 if let Some(event) = Events::<T>::get(event_id) {
                     if !event.processed {
+                        // Charged by fan-out, like the `process_event` call
+                        let triggers = EventTriggers::<T>::decode_len(event_id).unwrap_or(0);
                         let _ = Self::process_event_internal(*event_id);
-                        processed += 1;
+                        weight.saturating_accrue(
+                            <T as Config>::WeightInfo::process_event(triggers as u32),
+                        );
                     }
                 }
             }
 
-            // Return weight based on processed events
-            Weight::from_parts(10_000_000u64 * processed as u64, 0)
+            weight
+        }
+
+        /// Process pending events, then deliver pending webhooks and report the outcome
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            if let Err(e) = ocw::process_pending_events::<T>(block_number) {
+                log::error!(target: "event-hub", "OCW: Error processing events: {:?}", e);
+            }
+            if let Err(e) = ocw::deliver_webhooks::<T>(block_number) {
+                log::error!(target: "event-hub", "OCW: Error delivering webhooks: {:?}", e);
+            }
         }
     }
 
@@ -289,66 +888,54 @@ pub mod pallet {
         /// Submit an event
         ///
         /// # Parameters
-        /// - `origin`: Event submitter
-        /// - `event_type_u8`: Type of event (0=OnChain, 1=CrossChain, 2=Timer, 3=Condition)
+        /// - `origin`: Event submitter, checked against `SubmitOrigin` for the event type
+        /// - `event_type_u8`: Type of event (0=OnChain, 2=Timer, 3=Condition)
         /// - `payload`: Event payload data
-        /// - `source_para_id`: Source parachain ID (for cross-chain events)
+        /// - `priority_u8`: Processing priority (0=Low, 1=Normal, 2=High)
+        /// - `correlation_id`: Optional trace identifier propagated to derived events and jobs
+        ///
+        /// Reserves `EventDeposit`, refunded when the event is purged.
+        /// Cross-chain events cannot be submitted here; see `submit_cross_chain_event`.
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as Config>::WeightInfo::submit_event())]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_event(payload.len() as u32))]
         pub fn submit_event(
             origin: OriginFor<T>,
             event_type_u8: u8,
             payload: Vec<u8>,
-            source_para_id: Option<u32>,
+            priority_u8: u8,
+            correlation_id: Option<H256>,
         ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
-
             // Convert u8 to EventType
             let event_type = EventType::from_u8(event_type_u8)
                 .map_err(|_| Error::<T>::InvalidEventType)?;
 
-            // Validate payload size
-            let bounded_payload: BoundedVec<u8, ConstU32<512>> = payload
-                .try_into()
-                .map_err(|_| Error::<T>::PayloadTooLarge)?;
-
-            // Generate event ID
-            let event_id = NextEventId::<T>::get();
-            NextEventId::<T>::put(event_id.saturating_add(1));
-
-            // Create event
-            let event_data = EventData {
-                event_type: event_type.clone(),
-                payload: bounded_payload,
-                created_at: frame_system::Pallet::<T>::block_number(),
-                processed: false,
-                source_para_id,
-            };
-
-            Events::<T>::insert(event_id, event_data);
+            // Convert u8 to EventPriority
+            let priority = EventPriority::from_u8(priority_u8)
+                .map_err(|_| Error::<T>::InvalidPriority)?;
 
-            // Add to pending queue
-            PendingEvents::<T>::try_mutate(|pending| -> DispatchResult {
-                pending.try_push(event_id).map_err(|_| Error::<T>::MaxEventsReached)?;
-                Ok(())
-            })?;
+            // Cross-chain events must come from a verified XCM origin
+            ensure!(
+                !matches!(event_type, EventType::CrossChain),
+                Error::<T>::CrossChainOriginRequired
+            );
 
-            // Update statistics
-            EventStatistics::<T>::mutate(|stats| {
-                stats.total_events_submitted = stats.total_events_submitted.saturating_add(1);
-                if matches!(event_type, EventType::CrossChain) {
-                    stats.total_cross_chain_events = stats.total_cross_chain_events.saturating_add(1);
-                }
-            });
+            let who = T::SubmitOrigin::ensure_origin(origin, &event_type)?;
 
-            Self::deposit_event(Event::EventSubmitted { event_id });
+            let event_id = Self::do_submit_event(
+                event_type,
+                payload,
+                None,
+                None,
+                0,
+                priority,
+                correlation_id,
+            )?;
 
-            if let Some(para_id) = source_para_id {
-                Self::deposit_event(Event::CrossChainEventReceived {
-                    event_id,
-                    source_para_id: para_id,
-                });
-            }
+            // Reserve the storage deposit
+            let deposit = T::EventDeposit::get();
+            T::Currency::reserve(&who, deposit)?;
+            EventDeposits::<T>::insert(event_id, (who, deposit));
+            T::OnEconomicFlow::on_economic_flow(EconomicFlow::DepositHeld, deposit.saturated_into());
 
             Ok(())
         }
@@ -358,9 +945,11 @@ pub mod pallet {
         /// # Parameters
         /// - `origin`: Rule owner
         /// - `event_id`: Event to watch
-        /// - `action_u8`: Action to take (0=StartJob, 1=SendXcmMessage, 2=Custom)
-        /// - `action_param`: Optional parameter for action (e.g., job_id for StartJob)
-        /// - `condition`: Optional condition
+        /// - `action_u8`: Action to take (0=StartJob, 1=SendXcmMessage, 2=Custom, 3=EmitEvent,
+        ///   4=StartJobFromTemplate)
+        /// - `action_param`: Optional parameter for action (job_id for StartJob, topic for EmitEvent,
+        ///   template_id for StartJobFromTemplate)
+        /// - `condition`: Optional condition checked by `ConditionEvaluator` (e.g. an encoded `Condition`)
         #[pallet::call_index(1)]
         #[pallet::weight(<T as Config>::WeightInfo::register_trigger())]
         pub fn register_trigger(
@@ -376,55 +965,10 @@ pub mod pallet {
             let action = TriggerAction::from_u8(action_u8, action_param)
                 .map_err(|_| Error::<T>::InvalidTriggerAction)?;
 
-            // Validate condition size
-            let bounded_condition = if let Some(cond) = condition {
-                Some(cond.try_into().map_err(|_| Error::<T>::PayloadTooLarge)?)
-            } else {
-                None
-            };
-
-            // Check max triggers
-            let mut account_trigger_list = AccountTriggers::<T>::get(&who);
-            ensure!(
-                (account_trigger_list.len() as u32) < T::MaxTriggersPerAccount::get(),
-                Error::<T>::MaxTriggersReached
-            );
+            Self::do_register_trigger(who, event_id, action, condition)?;
 
-            // Generate trigger ID
-            let trigger_id = NextTriggerId::<T>::get();
-            NextTriggerId::<T>::put(trigger_id.saturating_add(1));
-
-            // Create trigger rule
-            let trigger = TriggerRule {
-                owner: who.clone(),
-                event_id,
-                action,
-                condition: bounded_condition,
-                created_at: frame_system::Pallet::<T>::block_number(),
-                active: true,
-            };
-
-            Triggers::<T>::insert(trigger_id, trigger);
-
-            // Add to account triggers
-            account_trigger_list.try_push(trigger_id)
-                .map_err(|_| Error::<T>::MaxTriggersReached)?;
-            AccountTriggers::<T>::insert(&who, account_trigger_list);
-
-            // Add to event triggers
-            EventTriggers::<T>::try_mutate(event_id, |triggers| -> DispatchResult {
-                triggers.try_push(trigger_id).map_err(|_| Error::<T>::MaxTriggersReached)?;
-                Ok(())
-            })?;
-
-            Self::deposit_event(Event::TriggerRegistered {
-                trigger_id,
-                event_id,
-                owner: who,
-            });
-
-            Ok(())
-        }
+            Ok(())
+        }
 
         /// Process an event and activate triggers
         ///
@@ -432,13 +976,18 @@ pub mod pallet {
         /// - `origin`: Anyone can process (typically OCW)
         /// - `event_id`: Event to process
         #[pallet::call_index(2)]
-        #[pallet::weight(<T as Config>::WeightInfo::process_event())]
+        #[pallet::weight(<T as Config>::WeightInfo::process_event(MAX_TRIGGERS_PER_EVENT))]
         pub fn process_event(
             origin: OriginFor<T>,
             event_id: u64,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let _who = ensure_signed(origin)?;
-            Self::process_event_internal(event_id)
+
+            // Charged for the worst-case fan-out up front, refunded down to the actual one
+            let fan_out = EventTriggers::<T>::decode_len(event_id).unwrap_or(0) as u32;
+            Self::process_event_internal(event_id)?;
+
+            Ok(Some(<T as Config>::WeightInfo::process_event(fan_out)).into())
         }
 
         /// Deactivate a trigger
@@ -464,10 +1013,685 @@ pub mod pallet {
                 Ok(())
             })
         }
+
+        /// Delete a trigger and remove it from every index
+        ///
+        /// # Parameters
+        /// - `origin`: Trigger owner
+        /// - `trigger_id`: Trigger to delete
+        ///
+        /// Trigger rules hold no deposit, so nothing is refunded. Activation receipts are kept
+        /// as history, and already scheduled delayed activations are dropped when they come due.
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_trigger())]
+        pub fn remove_trigger(
+            origin: OriginFor<T>,
+            trigger_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let trigger = Triggers::<T>::get(trigger_id).ok_or(Error::<T>::TriggerNotFound)?;
+            ensure!(trigger.owner == who, Error::<T>::NotAuthorized);
+
+            Triggers::<T>::remove(trigger_id);
+            TriggerStatistics::<T>::remove(trigger_id);
+
+            AccountTriggers::<T>::mutate(&who, |triggers| {
+                triggers.retain(|&id| id != trigger_id);
+            });
+
+            EventTriggers::<T>::mutate(trigger.event_id, |triggers| {
+                triggers.retain(|&id| id != trigger_id);
+            });
+
+            for topic in TriggerSubscriptions::<T>::take(trigger_id) {
+                TopicTriggers::<T>::mutate(topic, |triggers| {
+                    triggers.retain(|&id| id != trigger_id);
+                });
+            }
+
+            Self::deposit_event(Event::TriggerRemoved { trigger_id });
+
+            Ok(())
+        }
+
+        /// Submit a cross-chain event
+        ///
+        /// # Parameters
+        /// - `origin`: XCM origin of the sending parachain
+        /// - `payload`: Event payload data
+        /// - `correlation_id`: Optional trace identifier carried over from the sending chain
+        ///
+        /// The source parachain ID is derived from the origin rather than supplied by the caller.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_cross_chain_event())]
+        pub fn submit_cross_chain_event(
+            origin: OriginFor<T>,
+            payload: Vec<u8>,
+            correlation_id: Option<H256>,
+        ) -> DispatchResult {
+            let source_para_id = T::CrossChainOrigin::ensure_origin(origin)?;
+
+            Self::do_submit_event(
+                EventType::CrossChain,
+                payload,
+                Some(source_para_id),
+                None,
+                0,
+                EventPriority::Normal,
+                correlation_id,
+            )?;
+
+            Ok(())
+        }
+
+        /// Register a webhook trigger rule
+        ///
+        /// # Parameters
+        /// - `origin`: Rule owner
+        /// - `event_id`: Event to watch
+        /// - `url_hash`: Hash of the endpoint URL; OCW operators map it to the real URL
+        /// - `condition`: Optional condition checked by `ConditionEvaluator` (e.g. an encoded `Condition`)
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_trigger())]
+        pub fn register_webhook_trigger(
+            origin: OriginFor<T>,
+            event_id: u64,
+            url_hash: H256,
+            condition: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_register_trigger(who, event_id, TriggerAction::Webhook { url_hash }, condition)?;
+
+            Ok(())
+        }
+
+        /// Report webhook delivery (unsigned, submitted by the OCW)
+        ///
+        /// Only the worker the webhook is assigned to (see `webhook_worker`) may report it.
+        ///
+        /// # Parameters
+        /// - `origin`: None (unsigned)
+        /// - `report`: Webhook request and whether the endpoint accepted the POST
+        /// - `signature`: Signature of the report by the worker's key
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::report_webhook())]
+        pub fn report_webhook(
+            origin: OriginFor<T>,
+            report: WebhookReport<T::Public>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let WebhookReport { webhook_id, delivered, .. } = report;
+            PendingWebhooks::<T>::take(webhook_id).ok_or(Error::<T>::WebhookNotFound)?;
+
+            Self::deposit_event(Event::WebhookReported { webhook_id, delivered });
+
+            Ok(())
+        }
+
+        /// Delete a processed event and refund its deposit
+        ///
+        /// # Parameters
+        /// - `origin`: Depositor, or anyone once the retention period has elapsed
+        /// - `event_id`: Event to purge
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::purge_event())]
+        pub fn purge_event(
+            origin: OriginFor<T>,
+            event_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let event = Events::<T>::get(event_id).ok_or(Error::<T>::EventNotFound)?;
+            ensure!(
+                event.processed || DeadLetterEvents::<T>::contains_key(event_id),
+                Error::<T>::EventNotProcessed
+            );
+
+            let deposit = EventDeposits::<T>::get(event_id);
+            let is_depositor = matches!(&deposit, Some((depositor, _)) if *depositor == who);
+            if !is_depositor {
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(
+                    now >= event.created_at.saturating_add(T::EventRetentionPeriod::get()),
+                    Error::<T>::RetentionNotElapsed
+                );
+            }
+
+            let refund = Self::remove_event(event_id);
+            ProcessedQueue::<T>::mutate(|queue| queue.retain(|&id| id != event_id));
+
+            Self::deposit_event(Event::EventPurged { event_id, refund });
+
+            Ok(())
+        }
+
+        /// Set or clear a trigger's cooldown window
+        ///
+        /// # Parameters
+        /// - `origin`: Trigger owner
+        /// - `trigger_id`: Trigger to update
+        /// - `cooldown_blocks`: Minimum blocks between activations (`None` to disable)
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_trigger_cooldown())]
+        pub fn set_trigger_cooldown(
+            origin: OriginFor<T>,
+            trigger_id: u64,
+            cooldown_blocks: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Triggers::<T>::try_mutate(trigger_id, |maybe_trigger| -> DispatchResult {
+                let trigger = maybe_trigger.as_mut().ok_or(Error::<T>::TriggerNotFound)?;
+                ensure!(trigger.owner == who, Error::<T>::NotAuthorized);
+
+                trigger.cooldown_blocks = cooldown_blocks;
+
+                Self::deposit_event(Event::TriggerCooldownSet { trigger_id, cooldown_blocks });
+                Ok(())
+            })
+        }
+
+        /// Set or clear a trigger's activation delay
+        ///
+        /// # Parameters
+        /// - `origin`: Trigger owner
+        /// - `trigger_id`: Trigger to update
+        /// - `delay_blocks`: Blocks between a match and the action executing (`None` for immediate)
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_trigger_delay())]
+        pub fn set_trigger_delay(
+            origin: OriginFor<T>,
+            trigger_id: u64,
+            delay_blocks: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Triggers::<T>::try_mutate(trigger_id, |maybe_trigger| -> DispatchResult {
+                let trigger = maybe_trigger.as_mut().ok_or(Error::<T>::TriggerNotFound)?;
+                ensure!(trigger.owner == who, Error::<T>::NotAuthorized);
+
+                trigger.delay_blocks = delay_blocks;
+
+                Self::deposit_event(Event::TriggerDelaySet { trigger_id, delay_blocks });
+                Ok(())
+            })
+        }
+
+        /// Restrict a trigger to events from the given source parachains
+        ///
+        /// # Parameters
+        /// - `origin`: Trigger owner
+        /// - `trigger_id`: Trigger to update
+        /// - `source_para_ids`: Allowed source para IDs (`None` to match any source)
+        ///
+        /// With a filter set, events without a source parachain (local events) do not match.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_source_filter())]
+        pub fn set_source_filter(
+            origin: OriginFor<T>,
+            trigger_id: u64,
+            source_para_ids: Option<Vec<u32>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let bounded_filter = if let Some(para_ids) = source_para_ids {
+                Some(para_ids.try_into().map_err(|_| Error::<T>::TooManySources)?)
+            } else {
+                None
+            };
+
+            Triggers::<T>::try_mutate(trigger_id, |maybe_trigger| -> DispatchResult {
+                let trigger = maybe_trigger.as_mut().ok_or(Error::<T>::TriggerNotFound)?;
+                ensure!(trigger.owner == who, Error::<T>::NotAuthorized);
+
+                trigger.source_filter = bounded_filter;
+
+                Self::deposit_event(Event::TriggerSourceFilterSet { trigger_id });
+                Ok(())
+            })
+        }
+
+        /// Return a dead-lettered event to the pending queue
+        ///
+        /// # Parameters
+        /// - `origin`: Event depositor
+        /// - `event_id`: Dead-lettered event
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::requeue_event())]
+        pub fn requeue_event(
+            origin: OriginFor<T>,
+            event_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (depositor, _) = EventDeposits::<T>::get(event_id).ok_or(Error::<T>::NotAuthorized)?;
+            ensure!(depositor == who, Error::<T>::NotAuthorized);
+
+            DeadLetterEvents::<T>::take(event_id).ok_or(Error::<T>::NotDeadLettered)?;
+
+            let mut event = Events::<T>::get(event_id).ok_or(Error::<T>::EventNotFound)?;
+            event.attempts = 0;
+
+            PendingEvents::<T>::try_mutate(event.priority, |pending| -> DispatchResult {
+                pending.try_push(event_id).map_err(|_| Error::<T>::MaxEventsReached)?;
+                Ok(())
+            })?;
+            Events::<T>::insert(event_id, event);
+
+            Self::deposit_event(Event::EventRequeued { event_id });
+
+            Ok(())
+        }
+
+        /// Transfer a trigger to another account
+        ///
+        /// # Parameters
+        /// - `origin`: Current trigger owner
+        /// - `trigger_id`: Trigger to transfer
+        /// - `new_owner`: Account receiving the trigger
+        ///
+        /// Receipts, failure counters and subscriptions stay with the trigger.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_trigger())]
+        pub fn transfer_trigger(
+            origin: OriginFor<T>,
+            trigger_id: u64,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut trigger = Triggers::<T>::get(trigger_id).ok_or(Error::<T>::TriggerNotFound)?;
+            ensure!(trigger.owner == who, Error::<T>::NotAuthorized);
+            ensure!(new_owner != who, Error::<T>::AlreadyOwner);
+
+            // Add to the new owner's list first so a full list leaves state untouched
+            AccountTriggers::<T>::try_mutate(&new_owner, |triggers| -> DispatchResult {
+                triggers.try_push(trigger_id).map_err(|_| Error::<T>::MaxTriggersReached)?;
+                Ok(())
+            })?;
+
+            AccountTriggers::<T>::mutate(&who, |triggers| {
+                triggers.retain(|&id| id != trigger_id);
+            });
+
+            trigger.owner = new_owner.clone();
+            Triggers::<T>::insert(trigger_id, trigger);
+
+            Self::deposit_event(Event::TriggerTransferred { trigger_id, from: who, to: new_owner });
+
+            Ok(())
+        }
+
+        /// Request an HRMP channel to a target parachain
+        ///
+        /// # Parameters
+        /// - `origin`: `HrmpOrigin`
+        /// - `recipient`: Para ID of the parachain the channel carries messages to
+        /// - `max_capacity`: Maximum messages queued in the channel
+        /// - `max_message_size`: Maximum message size in bytes
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_hrmp_channel())]
+        pub fn request_hrmp_channel(
+            origin: OriginFor<T>,
+            recipient: u32,
+            max_capacity: u32,
+            max_message_size: u32,
+        ) -> DispatchResult {
+            T::HrmpOrigin::ensure_origin(origin)?;
+
+            ensure!(!HrmpChannels::<T>::contains_key(recipient), Error::<T>::HrmpChannelExists);
+
+            let call = T::HrmpCalls::init_open_channel(recipient, max_capacity, max_message_size);
+            Self::send_relay_call(call)?;
+
+            HrmpChannels::<T>::insert(recipient, HrmpChannelState::Requested);
+
+            Self::deposit_event(Event::HrmpChannelRequested { recipient });
+
+            Ok(())
+        }
+
+        /// Accept an HRMP channel opened by another parachain
+        ///
+        /// # Parameters
+        /// - `origin`: `HrmpOrigin`
+        /// - `sender`: Para ID that requested the channel
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::accept_hrmp_channel())]
+        pub fn accept_hrmp_channel(
+            origin: OriginFor<T>,
+            sender: u32,
+        ) -> DispatchResult {
+            T::HrmpOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !matches!(HrmpChannels::<T>::get(sender), Some(HrmpChannelState::Accepted)),
+                Error::<T>::HrmpChannelExists
+            );
+
+            Self::send_relay_call(T::HrmpCalls::accept_open_channel(sender))?;
+
+            HrmpChannels::<T>::insert(sender, HrmpChannelState::Accepted);
+
+            Self::deposit_event(Event::HrmpChannelAccepted { sender });
+
+            Ok(())
+        }
+
+        /// Subscribe a trigger to a topic
+        ///
+        /// # Parameters
+        /// - `origin`: Trigger owner
+        /// - `trigger_id`: Trigger to subscribe
+        /// - `topic`: Topic whose derived events should activate the trigger
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::subscribe_trigger())]
+        pub fn subscribe_trigger(
+            origin: OriginFor<T>,
+            trigger_id: u64,
+            topic: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let trigger = Triggers::<T>::get(trigger_id).ok_or(Error::<T>::TriggerNotFound)?;
+            ensure!(trigger.owner == who, Error::<T>::NotAuthorized);
+
+            Self::do_subscribe(trigger_id, topic)?;
+
+            Self::deposit_event(Event::TriggerSubscribed { trigger_id, topic });
+
+            Ok(())
+        }
+    }
+
+    /// Publishes job lifecycle transitions on the `topics::JOB_*` topics
+    impl<T: Config> pallet_job_registry::OnJobStatusChanged for Pallet<T> {
+        fn on_job_status_changed(job_id: u64, _old_status: &JobStatus, new_status: &JobStatus) {
+            let topic = match new_status {
+                JobStatus::Completed => crate::topics::JOB_COMPLETED,
+                JobStatus::Failed => crate::topics::JOB_FAILED,
+                JobStatus::Verified => crate::topics::JOB_VERIFIED,
+                _ => return,
+            };
+
+            Self::publish_system_event(
+                topic,
+                job_id.encode(),
+                JobRegistry::<T>::job_correlation_ids(job_id),
+            );
+        }
+    }
+
+    /// XCMP handler that turns sibling blob pages into cross-chain events
+    ///
+    /// `ConcatenatedEncodedBlob` pages are decoded as a sequence of SCALE-encoded
+    /// `CrossChainEventMessage`s and submitted with the sender's para ID as the verified source.
+    /// All other pages (regular XCM, signals) are passed to `Inner`. Blobs that don't fit in
+    /// `max_weight` or fail to decode are dropped.
+    pub struct XcmpEventHandler<T, Inner>(PhantomData<(T, Inner)>);
+
+    impl<T: Config, Inner: XcmpMessageHandler> XcmpMessageHandler for XcmpEventHandler<T, Inner> {
+        fn handle_xcmp_messages<'a, I: Iterator<Item = (ParaId, RelayBlockNumber, &'a [u8])>>(
+            iter: I,
+            max_weight: Weight,
+        ) -> Weight {
+            let per_event = <T as Config>::WeightInfo::submit_cross_chain_event();
+            let mut used = Weight::zero();
+            let mut passthrough = Vec::new();
+
+            for (sender, sent_at, page) in iter {
+                let mut data = page;
+                if !matches!(
+                    XcmpMessageFormat::decode(&mut data),
+                    Ok(XcmpMessageFormat::ConcatenatedEncodedBlob)
+                ) {
+                    passthrough.push((sender, sent_at, page));
+                    continue;
+                }
+
+                while !data.is_empty() {
+                    let Ok(blob) = Vec::<u8>::decode(&mut data) else { break };
+                    if used.saturating_add(per_event).any_gt(max_weight) {
+                        break;
+                    }
+                    used = used.saturating_add(per_event);
+
+                    let Ok(message) = CrossChainEventMessage::decode(&mut &blob[..]) else { continue };
+                    let _ = Pallet::<T>::do_submit_event(
+                        EventType::CrossChain,
+                        message.payload,
+                        Some(sender.into()),
+                        None,
+                        0,
+                        EventPriority::Normal,
+                        message.correlation_id,
+                    );
+                }
+            }
+
+            used.saturating_add(Inner::handle_xcmp_messages(
+                passthrough.into_iter(),
+                max_weight.saturating_sub(used),
+            ))
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::report_webhook { report, signature } => {
+                    // Only webhooks that are still pending can be reported, once
+                    let Some(request) = PendingWebhooks::<T>::get(report.webhook_id) else {
+                        return InvalidTransaction::Stale.into();
+                    };
+                    // and only by the worker they are assigned to
+                    if !SignedPayload::<T>::verify::<T::AuthorityId>(report, signature.clone()) {
+                        return InvalidTransaction::BadProof.into();
+                    }
+                    let now = frame_system::Pallet::<T>::block_number();
+                    let reporter = report.public.clone().into_account();
+                    if Self::webhook_worker(report.webhook_id, &request, now) != Some(reporter) {
+                        return InvalidTransaction::BadSigner.into();
+                    }
+
+                    ValidTransaction::with_tag_prefix("EventHubWebhook")
+                        .priority(TransactionPriority::MAX / 2)
+                        .and_provides(report.webhook_id)
+                        .longevity(5)
+                        .propagate(true)
+                        .build()
+                }
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
     }
 
     // Helper functions
     impl<T: Config> Pallet<T> {
+        /// Create a trigger rule and index it by owner and event
+        fn do_register_trigger(
+            who: T::AccountId,
+            event_id: u64,
+            action: TriggerAction,
+            condition: Option<Vec<u8>>,
+        ) -> Result<u64, DispatchError> {
+            // Validate condition size
+            let bounded_condition = if let Some(cond) = condition {
+                Some(cond.try_into().map_err(|_| Error::<T>::PayloadTooLarge)?)
+            } else {
+                None
+            };
+
+            // Check max triggers
+            let mut account_trigger_list = AccountTriggers::<T>::get(&who);
+            ensure!(
+                (account_trigger_list.len() as u32) < T::MaxTriggersPerAccount::get(),
+                Error::<T>::MaxTriggersReached
+            );
+
+            // Generate trigger ID
+            let trigger_id = NextTriggerId::<T>::get();
+            NextTriggerId::<T>::put(trigger_id.saturating_add(1));
+
+            // Create trigger rule
+            let trigger = TriggerRule {
+                owner: who.clone(),
+                event_id,
+                action,
+                condition: bounded_condition,
+                created_at: frame_system::Pallet::<T>::block_number(),
+                active: true,
+                failure_count: 0,
+                cooldown_blocks: None,
+                last_fired: None,
+                source_filter: None,
+                delay_blocks: None,
+            };
+
+            Triggers::<T>::insert(trigger_id, trigger);
+
+            // Add to account triggers
+            account_trigger_list.try_push(trigger_id)
+                .map_err(|_| Error::<T>::MaxTriggersReached)?;
+            AccountTriggers::<T>::insert(&who, account_trigger_list);
+
+            // Add to event triggers
+            EventTriggers::<T>::try_mutate(event_id, |triggers| -> DispatchResult {
+                triggers.try_push(trigger_id).map_err(|_| Error::<T>::MaxTriggersReached)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::TriggerRegistered {
+                trigger_id,
+                event_id,
+                owner: who,
+            });
+
+            Ok(trigger_id)
+        }
+
+        /// Subscribe a trigger to a topic, indexing the subscription both ways
+        fn do_subscribe(trigger_id: u64, topic: u64) -> DispatchResult {
+            TopicTriggers::<T>::try_mutate(topic, |triggers| -> DispatchResult {
+                if !triggers.contains(&trigger_id) {
+                    triggers.try_push(trigger_id).map_err(|_| Error::<T>::TooManySubscriptions)?;
+                }
+                Ok(())
+            })?;
+
+            TriggerSubscriptions::<T>::try_mutate(trigger_id, |topics| -> DispatchResult {
+                if !topics.contains(&topic) {
+                    topics.try_push(topic).map_err(|_| Error::<T>::TooManySubscriptions)?;
+                }
+                Ok(())
+            })
+        }
+
+        /// Publish a runtime-originated event on a system topic.
+        ///
+        /// Skipped when nothing subscribes to `topic`, so the event store isn't filled with
+        /// events nobody listens to. Best effort: a full event store drops the event rather
+        /// than failing the caller.
+        pub fn publish_system_event(topic: u64, payload: Vec<u8>, correlation_id: Option<H256>) {
+            if TopicTriggers::<T>::decode_len(topic).unwrap_or(0) == 0 {
+                return;
+            }
+
+            let _ = Self::do_submit_event(
+                EventType::OnChain,
+                payload,
+                None,
+                Some(topic),
+                0,
+                EventPriority::Normal,
+                correlation_id,
+            );
+        }
+
+        /// Store a new event and queue it for processing
+        fn do_submit_event(
+            event_type: EventType,
+            payload: Vec<u8>,
+            source_para_id: Option<u32>,
+            topic: Option<u64>,
+            chain_depth: u32,
+            priority: EventPriority,
+            correlation_id: Option<H256>,
+        ) -> Result<u64, DispatchError> {
+            // Validate payload size
+            let bounded_payload: BoundedVec<u8, ConstU32<512>> = payload
+                .try_into()
+                .map_err(|_| Error::<T>::PayloadTooLarge)?;
+
+            // Make room (or reject) before touching any storage for the new event
+            if EventCount::<T>::get() >= T::MaxEvents::get() {
+                ensure!(
+                    matches!(T::Backpressure::get(), BackpressureMode::EvictOldestProcessed),
+                    Error::<T>::MaxEventsReached
+                );
+                Self::evict_oldest_processed()?;
+            }
+
+            // Generate event ID
+            let event_id = NextEventId::<T>::get();
+
+            // Add to pending queue
+            PendingEvents::<T>::try_mutate(priority, |pending| -> DispatchResult {
+                pending.try_push(event_id).map_err(|_| Error::<T>::MaxEventsReached)?;
+                Ok(())
+            })?;
+
+            NextEventId::<T>::put(event_id.saturating_add(1));
+
+            // Create event
+            let event_data = EventData {
+                event_type: event_type.clone(),
+                payload: bounded_payload,
+                created_at: frame_system::Pallet::<T>::block_number(),
+                processed: false,
+                source_para_id,
+                topic,
+                chain_depth,
+                priority,
+                attempts: 0,
+                correlation_id,
+            };
+
+            Events::<T>::insert(event_id, event_data);
+            EventCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+            // Triggers subscribed to the topic watch the new event
+            if let Some(topic) = topic {
+                EventTriggers::<T>::insert(event_id, TopicTriggers::<T>::get(topic));
+            }
+
+            // Update statistics
+            EventStatistics::<T>::mutate(|stats| {
+                stats.total_events_submitted = stats.total_events_submitted.saturating_add(1);
+                if matches!(event_type, EventType::CrossChain) {
+                    stats.total_cross_chain_events = stats.total_cross_chain_events.saturating_add(1);
+                }
+            });
+
+            Self::deposit_event(Event::EventSubmitted { event_id });
+
+            if let Some(para_id) = source_para_id {
+                Self::deposit_event(Event::CrossChainEventReceived {
+                    event_id,
+                    source_para_id: para_id,
+                });
+            }
+
+            Ok(event_id)
+        }
+
         /// Internal event processing
         fn process_event_internal(event_id: u64) -> DispatchResult {
             let mut event = Events::<T>::get(event_id).ok_or(Error::<T>::EventNotFound)?;
@@ -477,12 +1701,80 @@ pub mod pallet {
             let trigger_ids = EventTriggers::<T>::get(event_id);
 
             // Activate each trigger
+            let mut any_failed = false;
             for trigger_id in trigger_ids.iter() {
+                // Triggers that already succeeded on an earlier attempt are not re-run
+                let already_succeeded = ActivationReceipts::<T>::get(trigger_id, event_id)
+                    .map_or(false, |receipt| receipt.succeeded);
+                if already_succeeded {
+                    continue;
+                }
+
                 if let Some(trigger) = Triggers::<T>::get(trigger_id) {
-                    if trigger.active {
-                        let _ = Self::activate_trigger(*trigger_id, event_id, &trigger);
+                    // Events from sources outside the trigger's allowlist don't match
+                    if !Self::matches_source(&trigger, event.source_para_id) {
+                        continue;
                     }
+
+                    // Conditions are re-evaluated against live state on every attempt
+                    let condition_met = trigger.condition.as_ref()
+                        .map_or(true, |condition| T::ConditionEvaluator::evaluate(condition, &event));
+                    if !condition_met {
+                        continue;
+                    }
+
+                    if trigger.active && Self::is_cooling_down(&trigger) {
+                        TriggerStatistics::<T>::mutate(trigger_id, |stats| {
+                            stats.suppressed = stats.suppressed.saturating_add(1);
+                        });
+                        Self::deposit_event(Event::TriggerSuppressed {
+                            trigger_id: *trigger_id,
+                            event_id,
+                        });
+                    } else if trigger.active {
+                        let outcome = match trigger.delay_blocks {
+                            // Already waiting from an earlier attempt
+                            Some(_) if ScheduledActivations::<T>::contains_key(trigger_id, event_id) => Ok(()),
+                            Some(delay) => {
+                                let scheduled = Self::schedule_activation(*trigger_id, event_id, delay);
+                                if scheduled.is_err() {
+                                    Self::record_activation(*trigger_id, event_id, scheduled);
+                                }
+                                scheduled
+                            }
+                            None => Self::run_activation(
+                                *trigger_id,
+                                event_id,
+                                event.correlation_id,
+                                &trigger,
+                            ),
+                        };
+                        any_failed |= outcome.is_err();
+                    }
+                }
+            }
+
+            let priority = event.priority;
+
+            if any_failed {
+                event.attempts = event.attempts.saturating_add(1);
+                let attempts = event.attempts;
+                Events::<T>::insert(event_id, event);
+
+                if attempts >= T::MaxEventAttempts::get() {
+                    PendingEvents::<T>::mutate(priority, |pending| {
+                        pending.retain(|&id| id != event_id);
+                    });
+                    DeadLetterEvents::<T>::insert(event_id, DeadLetter {
+                        attempts,
+                        dead_lettered_at: frame_system::Pallet::<T>::block_number(),
+                    });
+                    Self::deposit_event(Event::EventDeadLettered { event_id, attempts });
+                } else {
+                    Self::deposit_event(Event::EventRetryScheduled { event_id, attempts });
                 }
+
+                return Ok(());
             }
 
             // Mark event as processed
@@ -490,10 +1782,15 @@ pub mod pallet {
             Events::<T>::insert(event_id, event);
 
             // Remove from pending queue
-            PendingEvents::<T>::mutate(|pending| {
+            PendingEvents::<T>::mutate(priority, |pending| {
                 pending.retain(|&id| id != event_id);
             });
 
+            // Every stored event fits under MaxEvents, so this cannot overflow
+            ProcessedQueue::<T>::mutate(|queue| {
+                let _ = queue.try_push(event_id);
+            });
+
             // Update statistics
             EventStatistics::<T>::mutate(|stats| {
                 stats.total_events_processed = stats.total_events_processed.saturating_add(1);
@@ -504,34 +1801,95 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Activate a trigger
-        fn activate_trigger(
+        /// Activate a trigger and record the outcome
+        fn run_activation(
             trigger_id: u64,
             event_id: u64,
+            correlation_id: Option<H256>,
             trigger: &TriggerRule<T::AccountId, BlockNumberFor<T>>,
         ) -> DispatchResult {
-            // Check condition if present
-            if trigger.condition.is_some() {
-                // In a real implementation, evaluate the condition
-                // For now, we'll assume conditions pass
+            // Roll back any partial changes made by a failing action
+            let outcome = frame_support::storage::with_storage_layer(|| {
+                Self::activate_trigger(trigger_id, event_id, correlation_id, trigger)
+            });
+            Self::record_activation(trigger_id, event_id, outcome);
+            outcome
+        }
+
+        /// Queue a matched trigger to activate after its delay
+        fn schedule_activation(
+            trigger_id: u64,
+            event_id: u64,
+            delay: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            // Due activations run in on_initialize, so the earliest slot is the next block
+            let at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(delay.max(1u32.into()));
+
+            DelayedActivations::<T>::try_mutate(at, |due| -> DispatchResult {
+                due.try_push((trigger_id, event_id))
+                    .map_err(|_| Error::<T>::TooManyDelayedActivations)?;
+                Ok(())
+            })?;
+            ScheduledActivations::<T>::insert(trigger_id, event_id, at);
+
+            Self::deposit_event(Event::ActivationScheduled { trigger_id, event_id, at });
+
+            Ok(())
+        }
+
+        /// Run the delayed activations due at block `n`, returning how many there were
+        pub(crate) fn run_delayed_activations(n: BlockNumberFor<T>) -> u32 {
+            let due = DelayedActivations::<T>::take(n);
+            for &(trigger_id, event_id) in due.iter() {
+                ScheduledActivations::<T>::remove(trigger_id, event_id);
+                Self::run_delayed_activation(trigger_id, event_id);
+            }
+
+            due.len() as u32
+        }
+
+        /// Run a delayed activation that has come due
+        ///
+        /// The matching event has already been processed, so a failure here is only
+        /// recorded against the trigger and does not retry the event.
+        fn run_delayed_activation(trigger_id: u64, event_id: u64) {
+            let Some(trigger) = Triggers::<T>::get(trigger_id) else { return };
+            if !trigger.active {
+                return;
             }
 
+            let correlation_id = Events::<T>::get(event_id).and_then(|event| event.correlation_id);
+            let _ = Self::run_activation(trigger_id, event_id, correlation_id, &trigger);
+        }
+
+        /// Activate a trigger
+        fn activate_trigger(
+            trigger_id: u64,
+            event_id: u64,
+            correlation_id: Option<H256>,
+            trigger: &TriggerRule<T::AccountId, BlockNumberFor<T>>,
+        ) -> DispatchResult {
             // Execute action
             match &trigger.action {
                 TriggerAction::StartJob(job_id) => {
                     // Update job status to InProgress (1 = InProgress)
-                    if let Some(_job) = JobRegistry::<T>::jobs(job_id) {
-                        let _ = JobRegistry::<T>::update_job_status(
-                            frame_system::RawOrigin::Signed(trigger.owner.clone()).into(),
-                            *job_id,
-                            1,
-                        );
-
-                        Self::deposit_event(Event::JobTriggered {
-                            job_id: *job_id,
-                            event_id,
-                        });
+                    JobRegistry::<T>::update_job_status(
+                        frame_system::RawOrigin::Signed(trigger.owner.clone()).into(),
+                        *job_id,
+                        1,
+                    )?;
+
+                    // Tag the job so indexers can follow the chain into job-registry
+                    if let Some(correlation_id) = correlation_id {
+                        JobRegistry::<T>::set_correlation_id(*job_id, correlation_id);
                     }
+
+                    Self::deposit_event(Event::JobTriggered {
+                        job_id: *job_id,
+                        event_id,
+                        correlation_id,
+                    });
                 }
                 TriggerAction::SendXcmMessage => {
                     // XCM message sending would be implemented here
@@ -540,6 +1898,37 @@ pub mod pallet {
                 TriggerAction::Custom => {
                     // Custom logic would be implemented here
                 }
+                TriggerAction::EmitEvent { topic } => {
+                    Self::emit_derived_event(trigger_id, event_id, *topic)?;
+                }
+                TriggerAction::StartJobFromTemplate(template_id) => {
+                    // The triggering event's payload becomes the job input
+                    let event = Events::<T>::get(event_id).ok_or(Error::<T>::EventNotFound)?;
+                    let job_id = JobRegistry::<T>::do_instantiate_template(
+                        trigger.owner.clone(),
+                        *template_id,
+                        event.payload.into_inner(),
+                    )?;
+
+                    if let Some(correlation_id) = correlation_id {
+                        JobRegistry::<T>::set_correlation_id(job_id, correlation_id);
+                    }
+
+                    Self::deposit_event(Event::JobTriggered { job_id, event_id, correlation_id });
+                }
+                TriggerAction::Webhook { url_hash } => {
+                    let webhook_id = NextWebhookId::<T>::get();
+                    NextWebhookId::<T>::put(webhook_id.saturating_add(1));
+
+                    PendingWebhooks::<T>::insert(webhook_id, WebhookRequest {
+                        trigger_id,
+                        event_id,
+                        url_hash: *url_hash,
+                        created_at: frame_system::Pallet::<T>::block_number(),
+                    });
+
+                    Self::deposit_event(Event::WebhookRequested { webhook_id, trigger_id, event_id });
+                }
             }
 
             // Update statistics
@@ -547,14 +1936,162 @@ pub mod pallet {
                 stats.total_triggers_activated = stats.total_triggers_activated.saturating_add(1);
             });
 
-            Self::deposit_event(Event::TriggerActivated { trigger_id, event_id });
+            Self::deposit_event(Event::TriggerActivated { trigger_id, event_id, correlation_id });
+
+            Ok(())
+        }
+
+        /// Remove an event and its indexes, refunding the deposit to whoever paid it
+        fn remove_event(event_id: u64) -> BalanceOf<T> {
+            Events::<T>::remove(event_id);
+            EventTriggers::<T>::remove(event_id);
+            DeadLetterEvents::<T>::remove(event_id);
+            EventCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+            match EventDeposits::<T>::take(event_id) {
+                Some((depositor, amount)) => {
+                    let _ = T::Currency::unreserve(&depositor, amount);
+                    T::OnEconomicFlow::on_economic_flow(
+                        EconomicFlow::DepositReleased,
+                        amount.saturated_into(),
+                    );
+                    amount
+                }
+                None => Default::default(),
+            }
+        }
+
+        /// Purge the oldest processed event to make room for a new one
+        fn evict_oldest_processed() -> DispatchResult {
+            let mut queue = ProcessedQueue::<T>::get();
+            ensure!(!queue.is_empty(), Error::<T>::MaxEventsReached);
+
+            let event_id = queue.remove(0);
+            ProcessedQueue::<T>::put(queue);
+
+            let refund = Self::remove_event(event_id);
+
+            Self::deposit_event(Event::EventEvicted { event_id, refund });
+
+            Ok(())
+        }
+
+        /// Whether the event source passes the trigger's source filter
+        fn matches_source(
+            trigger: &TriggerRule<T::AccountId, BlockNumberFor<T>>,
+            source_para_id: Option<u32>,
+        ) -> bool {
+            match (&trigger.source_filter, source_para_id) {
+                (None, _) => true,
+                (Some(allowed), Some(para_id)) => allowed.contains(&para_id),
+                (Some(_), None) => false,
+            }
+        }
+
+        /// Whether the trigger fired within its cooldown window
+        fn is_cooling_down(trigger: &TriggerRule<T::AccountId, BlockNumberFor<T>>) -> bool {
+            match (trigger.cooldown_blocks, trigger.last_fired) {
+                (Some(cooldown), Some(last_fired)) => {
+                    frame_system::Pallet::<T>::block_number() < last_fired.saturating_add(cooldown)
+                }
+                _ => false,
+            }
+        }
+
+        /// Store the activation receipt and track consecutive failures
+        fn record_activation(trigger_id: u64, event_id: u64, outcome: DispatchResult) {
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let receipt = ActivationReceipt {
+                succeeded: outcome.is_ok(),
+                error: outcome.err(),
+                block: now,
+            };
+            ActivationReceipts::<T>::insert(trigger_id, event_id, receipt);
+
+            TriggerStatistics::<T>::mutate(trigger_id, |stats| {
+                if outcome.is_ok() {
+                    stats.activations = stats.activations.saturating_add(1);
+                    stats.last_fired = Some(now);
+                } else {
+                    stats.failures = stats.failures.saturating_add(1);
+                }
+            });
+
+            Triggers::<T>::mutate(trigger_id, |maybe_trigger| {
+                let Some(trigger) = maybe_trigger.as_mut() else { return };
+
+                match outcome {
+                    Ok(()) => {
+                        trigger.failure_count = 0;
+                        trigger.last_fired = Some(now);
+                    }
+                    Err(error) => {
+                        trigger.failure_count = trigger.failure_count.saturating_add(1);
+                        Self::deposit_event(Event::TriggerFailed { trigger_id, event_id, error });
+
+                        if trigger.failure_count >= T::MaxTriggerFailures::get() {
+                            trigger.active = false;
+                            Self::deposit_event(Event::TriggerDeactivated { trigger_id });
+                        }
+                    }
+                }
+            });
+        }
+
+        /// Publish a derived event on a topic, tagging the payload with the emitting trigger
+        fn emit_derived_event(trigger_id: u64, parent_event_id: u64, topic: u64) -> DispatchResult {
+            let parent = Events::<T>::get(parent_event_id).ok_or(Error::<T>::EventNotFound)?;
+
+            let chain_depth = parent.chain_depth.saturating_add(1);
+            ensure!(chain_depth <= T::MaxChainDepth::get(), Error::<T>::ChainDepthExceeded);
+
+            // Derived payload: emitting trigger ID followed by the parent payload
+            let mut payload = trigger_id.encode();
+            payload.extend_from_slice(&parent.payload);
+            payload.truncate(512);
+
+            let event_id = Self::do_submit_event(
+                EventType::OnChain,
+                payload,
+                None,
+                Some(topic),
+                chain_depth,
+                parent.priority,
+                parent.correlation_id,
+            )?;
+
+            Self::deposit_event(Event::DerivedEventEmitted { event_id, parent_event_id, topic });
 
             Ok(())
         }
 
-        /// Get pending events
+        /// Send an encoded relay-chain call, paying execution from the sovereign account
+        fn send_relay_call(call: Vec<u8>) -> DispatchResult {
+            let fee: Asset = (Location::here(), T::HrmpFee::get()).into();
+
+            let message = Xcm(sp_std::vec![
+                Instruction::WithdrawAsset(fee.clone().into()),
+                Instruction::BuyExecution { fees: fee, weight_limit: WeightLimit::Unlimited },
+                Instruction::Transact {
+                    origin_kind: OriginKind::Native,
+                    fallback_max_weight: None,
+                    call: call.into(),
+                },
+            ]);
+
+            send_xcm::<T::XcmSender>(Location::parent(), message)
+                .map_err(|_| Error::<T>::XcmSendFailed)?;
+
+            Ok(())
+        }
+
+        /// Get pending events, highest priority first
         pub fn get_pending_events() -> Vec<u64> {
-            PendingEvents::<T>::get().to_vec()
+            EventPriority::descending()
+                .into_iter()
+                .flat_map(|priority| PendingEvents::<T>::get(priority).into_inner())
+                .collect()
         }
 
         /// Get statistics
@@ -562,6 +2099,49 @@ pub mod pallet {
             EventStatistics::<T>::get()
         }
 
+        /// Get dead-lettered event IDs
+        pub fn get_dead_letter_events() -> Vec<u64> {
+            DeadLetterEvents::<T>::iter_keys().collect()
+        }
+
+        /// Get event data
+        pub fn get_event(event_id: u64) -> Option<EventData<BlockNumberFor<T>>> {
+            Events::<T>::get(event_id)
+        }
+
+        /// Get triggers subscribed to a topic
+        pub fn get_topic_triggers(topic: u64) -> Vec<u64> {
+            TopicTriggers::<T>::get(topic).to_vec()
+        }
+
+        /// Get webhooks awaiting delivery (for the OCW)
+        pub fn get_pending_webhooks() -> Vec<(u64, WebhookRequest<BlockNumberFor<T>>)> {
+            PendingWebhooks::<T>::iter().collect()
+        }
+
+        /// Worker assigned to deliver and report a webhook as of `now`, if any workers are
+        /// configured. Workers take turns by webhook ID, and a webhook still pending passes to
+        /// the next worker every `WEBHOOK_REASSIGN_PERIOD` blocks.
+        pub fn webhook_worker(
+            webhook_id: u64,
+            request: &WebhookRequest<BlockNumberFor<T>>,
+            now: BlockNumberFor<T>,
+        ) -> Option<T::AccountId> {
+            let workers = <T as pallet_job_registry::Config>::Workers::sorted_members();
+            if workers.is_empty() {
+                return None;
+            }
+
+            let elapsed: u64 = now.saturating_sub(request.created_at).saturated_into();
+            let turn = webhook_id.wrapping_add(elapsed / WEBHOOK_REASSIGN_PERIOD as u64);
+            workers.get((turn % workers.len() as u64) as usize).cloned()
+        }
+
+        /// Get a trigger's statistics
+        pub fn get_trigger_stats(trigger_id: u64) -> TriggerStats<BlockNumberFor<T>> {
+            TriggerStatistics::<T>::get(trigger_id)
+        }
+
         /// Get triggers for an account
         pub fn get_account_triggers(account: &T::AccountId) -> Vec<u64> {
             AccountTriggers::<T>::get(account).to_vec()
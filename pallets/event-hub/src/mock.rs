@@ -1,13 +1,26 @@
 use crate as pallet_event_hub;
-use frame_support::{parameter_types, traits::{ConstU16, ConstU32, ConstU64}};
+use crate::EventType;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU128, ConstU16, ConstU32, ConstU64, EnsureOriginWithArg, IsInVec},
+};
+use frame_system::{offchain::AppCrypto, EnsureRootWithSuccess};
 use sp_core::H256;
-use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, BuildStorage};
+use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+use codec::Encode;
+use core::cell::RefCell;
+use xcm::latest::prelude::*;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
 frame_support::construct_runtime!(
     pub enum Test {
         System: frame_system,
+        Balances: pallet_balances,
         JobRegistry: pallet_job_registry,
         EventHub: pallet_event_hub,
     }
@@ -30,7 +43,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -39,21 +52,188 @@ impl frame_system::Config for Test {
     type MaxConsumers = ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+/// Offchain worker keys, each signing for the account of the same number
+pub struct TestAuthId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type GenericPublic = UintAuthorityId;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = Extrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_signed_transaction<A: AppCrypto<UintAuthorityId, TestSignature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        account: u64,
+        _nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, account, (), ()))
+    }
+}
+
+impl<C> frame_system::offchain::CreateBare<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_bare(call: RuntimeCall) -> Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
+parameter_types! {
+    /// Accounts allowed to claim jobs and deliver webhooks
+    pub Workers: Vec<u64> = vec![7, 8];
+}
+
 impl pallet_job_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type MaxJobsPerAccount = ConstU32<100>;
     type MaxDependencyDepth = ConstU32<10>;
+    type OnJobStatusChanged = EventHub;
+    type ClaimDuration = ConstU64<10>;
+    type AuthorityId = TestAuthId;
+    type Workers = IsInVec<Workers>;
+}
+
+/// Account allowed to submit Condition events
+pub const ORACLE: u64 = 1;
+
+/// Signed origins for every event type; Condition events only from `ORACLE`
+pub struct MockSubmitOrigin;
+
+impl EnsureOriginWithArg<RuntimeOrigin, EventType> for MockSubmitOrigin {
+    type Success = u64;
+
+    fn try_origin(o: RuntimeOrigin, event_type: &EventType) -> Result<u64, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(who))
+                if !matches!(event_type, EventType::Condition) || who == ORACLE => Ok(who),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_event_type: &EventType) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(ORACLE))
+    }
+}
+
+thread_local! {
+    /// XCM messages sent through `MockXcmSender`
+    pub static SENT_XCM: RefCell<Vec<(Location, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+/// Records sent messages instead of routing them
+pub struct MockXcmSender;
+
+impl SendXcm for MockXcmSender {
+    type Ticket = (Location, Xcm<()>);
+
+    fn validate(
+        dest: &mut Option<Location>,
+        msg: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let ticket = (
+            dest.take().ok_or(SendError::MissingArgument)?,
+            msg.take().ok_or(SendError::MissingArgument)?,
+        );
+        Ok((ticket, Assets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        SENT_XCM.with(|sent| sent.borrow_mut().push(ticket));
+        Ok([0u8; 32])
+    }
+}
+
+/// Encodes HRMP calls as (pallet index, call index, args)
+pub struct MockHrmpCalls;
+
+impl crate::HrmpCallEncoder for MockHrmpCalls {
+    fn init_open_channel(recipient: u32, max_capacity: u32, max_message_size: u32) -> Vec<u8> {
+        (60u8, 0u8, recipient, max_capacity, max_message_size).encode()
+    }
+
+    fn accept_open_channel(sender: u32) -> Vec<u8> {
+        (60u8, 1u8, sender).encode()
+    }
+}
+
+pub fn sent_xcm() -> Vec<(Location, Xcm<()>)> {
+    SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+parameter_types! {
+    pub static MaxEvents: u32 = 1000;
+    pub static Backpressure: crate::BackpressureMode = crate::BackpressureMode::Reject;
 }
 
 impl pallet_event_hub::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
-    type MaxEvents = ConstU32<1000>;
+    type MaxEvents = MaxEvents;
+    type Backpressure = Backpressure;
     type MaxTriggersPerAccount = ConstU32<100>;
+    type SubmitOrigin = MockSubmitOrigin;
+    type CrossChainOrigin = EnsureRootWithSuccess<u64, ConstU32<2000>>;
+    type MaxChainDepth = ConstU32<3>;
+    type MaxTriggerFailures = ConstU32<2>;
+    type Currency = Balances;
+    type EventDeposit = ConstU64<10>;
+    type OnEconomicFlow = ();
+    type EventRetentionPeriod = ConstU64<100>;
+    type MaxEventAttempts = ConstU32<3>;
+    type ConditionEvaluator = crate::StateConditionEvaluator<Test>;
+    type HrmpOrigin = frame_system::EnsureRoot<u64>;
+    type XcmSender = MockXcmSender;
+    type HrmpCalls = MockHrmpCalls;
+    type HrmpFee = ConstU128<1_000_000_000>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000), (2, 1_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     t.into()
 }
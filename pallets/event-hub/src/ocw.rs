@@ -0,0 +1,186 @@
+//! Offchain worker logic for event processing and webhook delivery
+//!
+//! Pending events are processed through signed `process_event` transactions. Each pending
+//! webhook is assigned to one worker (see `Pallet::webhook_worker`); the node holding that
+//! worker's key posts it to the URL the operator configured for its URL hash, and reports the
+//! outcome through an unsigned `report_webhook` transaction carrying a payload signed by the
+//! key. Webhooks without a configured URL are left for the next worker.
+
+use crate::{Call, Config, Events, Pallet, WebhookReport, MAX_EVENTS_PER_BLOCK};
+use frame_system::{
+    offchain::{SendSignedTransaction, SendUnsignedTransaction, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use pallet_job_registry::ocw::{post_external_data, worker_keys};
+use sp_core::H256;
+use sp_runtime::{offchain::storage::StorageValueRef, traits::SaturatedConversion};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+/// Persistent offchain storage key prefix mapping webhook URL hashes to URLs
+pub const WEBHOOK_URL_PREFIX: &[u8] = b"pcl::webhook::";
+
+/// Persistent offchain storage key of the events this node submitted processing for, with
+/// the block it did so at
+const SUBMITTED_EVENTS_KEY: &[u8] = b"pcl::submitted_events";
+
+/// Maximum number of events processed per offchain worker run
+const MAX_EVENTS_PER_RUN: usize = 3;
+
+/// Blocks before this node submits processing of a still pending event again
+const RESUBMIT_AFTER_BLOCKS: u32 = 10;
+
+/// Maximum number of webhooks delivered per offchain worker run
+const MAX_WEBHOOKS_PER_RUN: usize = 5;
+
+/// Pending webhook as read from the event hub, with the local key of its assigned worker
+struct PendingWebhook<Public> {
+    webhook_id: u64,
+    url_hash: H256,
+    payload: Vec<u8>,
+    worker: Public,
+}
+
+/// Process pending events through signed transactions
+pub(crate) fn process_pending_events<T: Config>(
+    block_number: BlockNumberFor<T>,
+) -> Result<(), &'static str> {
+    let now: u32 = block_number.saturated_into();
+    let storage = StorageValueRef::persistent(SUBMITTED_EVENTS_KEY);
+    let mut submitted: BTreeMap<u64, u32> = storage.get().ok().flatten().unwrap_or_default();
+
+    let pending_events = fetch_pending_events::<T>(&mut submitted, now)?;
+    storage.set(&submitted);
+    if pending_events.is_empty() {
+        return Ok(());
+    }
+
+    log::debug!(
+        target: "event-hub",
+        "OCW: {} pending events at block {:?}", pending_events.len(), block_number
+    );
+
+    let signer = Signer::<T, T::AuthorityId>::any_account();
+    if !signer.can_sign() {
+        return Err("No signing keys available");
+    }
+
+    for event_id in pending_events.into_iter().take(MAX_EVENTS_PER_RUN) {
+        match signer.send_signed_transaction(|_account| Call::process_event { event_id }) {
+            Some((_, Ok(()))) => {
+                log::info!(target: "event-hub", "OCW: Submitted processing of event {}", event_id);
+                submitted.insert(event_id, now);
+            }
+            _ => log::error!(target: "event-hub", "OCW: Failed to process event {}", event_id),
+        }
+    }
+
+    storage.set(&submitted);
+    Ok(())
+}
+
+/// Fetch unprocessed events, highest priority first, past those `on_initialize` processes
+/// next block. Events this node submitted processing for are skipped for
+/// `RESUBMIT_AFTER_BLOCKS`, and records of events no longer pending are dropped.
+fn fetch_pending_events<T: Config>(
+    submitted: &mut BTreeMap<u64, u32>,
+    now: u32,
+) -> Result<Vec<u64>, &'static str> {
+    let pending = Pallet::<T>::get_pending_events();
+    submitted.retain(|event_id, _| pending.contains(event_id));
+
+    Ok(pending
+        .into_iter()
+        .skip(MAX_EVENTS_PER_BLOCK)
+        .filter(|event_id| Events::<T>::get(event_id).is_some_and(|event| !event.processed))
+        .filter(|event_id| {
+            submitted
+                .get(event_id)
+                .map_or(true, |at| now >= at.saturating_add(RESUBMIT_AFTER_BLOCKS))
+        })
+        .collect())
+}
+
+/// Deliver the pending webhooks assigned to this node's workers and report the outcome on-chain
+pub(crate) fn deliver_webhooks<T: Config>(
+    block_number: BlockNumberFor<T>,
+) -> Result<(), &'static str> {
+    let webhooks = fetch_pending_webhooks::<T>(block_number);
+    if webhooks.is_empty() {
+        return Ok(());
+    }
+
+    log::debug!(
+        target: "event-hub",
+        "OCW: Delivering {} webhooks at block {:?}", webhooks.len(), block_number
+    );
+
+    for webhook in webhooks.into_iter().take(MAX_WEBHOOKS_PER_RUN) {
+        let webhook_id = webhook.webhook_id;
+
+        // URLs are configured by the operator; only their hash lives on-chain. Without one
+        // this node can't deliver, so it doesn't report and the next worker gets its turn.
+        let mut key = WEBHOOK_URL_PREFIX.to_vec();
+        key.extend_from_slice(webhook.url_hash.as_bytes());
+
+        let url = StorageValueRef::persistent(&key).get::<Vec<u8>>().ok().flatten();
+        let url = match url.as_deref().map(sp_std::str::from_utf8) {
+            Some(Ok(url)) => url,
+            Some(Err(_)) => {
+                log::warn!(target: "event-hub", "OCW: Invalid URL for webhook {}", webhook_id);
+                continue;
+            }
+            None => {
+                log::warn!(target: "event-hub", "OCW: No URL for webhook {}", webhook_id);
+                continue;
+            }
+        };
+
+        let delivered = match post_external_data(url, &webhook.payload) {
+            Ok(()) => true,
+            Err(e) => {
+                log::error!(
+                    target: "event-hub",
+                    "OCW: Webhook {} delivery failed: {:?}", webhook_id, e
+                );
+                false
+            }
+        };
+
+        let result = Signer::<T, T::AuthorityId>::any_account()
+            .with_filter(vec![webhook.worker])
+            .send_unsigned_transaction(
+                |account| WebhookReport { webhook_id, delivered, public: account.public.clone() },
+                |report, signature| Call::report_webhook { report, signature },
+            );
+        if !matches!(result, Some((_, Ok(())))) {
+            log::warn!(target: "event-hub", "Failed to submit webhook report");
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetch webhooks awaiting delivery that are assigned to one of this node's workers, with the
+/// payload of their event
+fn fetch_pending_webhooks<T: Config>(now: BlockNumberFor<T>) -> Vec<PendingWebhook<T::Public>> {
+    let workers = worker_keys::<T>();
+    if workers.is_empty() {
+        return Vec::new();
+    }
+
+    Pallet::<T>::get_pending_webhooks()
+        .into_iter()
+        .filter_map(|(webhook_id, request)| {
+            let assigned = Pallet::<T>::webhook_worker(webhook_id, &request, now)?;
+            let (worker, _) = workers.iter().find(|(_, account)| *account == assigned)?;
+            Some(PendingWebhook {
+                webhook_id,
+                url_hash: request.url_hash,
+                payload: Events::<T>::get(request.event_id)
+                    .map(|event| event.payload.into_inner())
+                    .unwrap_or_default(),
+                worker: worker.clone(),
+            })
+        })
+        .collect()
+}
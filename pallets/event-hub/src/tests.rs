@@ -1,5 +1,5 @@
 use crate::{mock::*, Event, EventType, TriggerAction};
-use frame_support::{assert_ok, assert_noop};
+use frame_support::{assert_ok, assert_noop, traits::Hooks};
 use pallet_job_registry::JobStatus;
 
 #[test]
@@ -11,8 +11,9 @@ fn submit_event_works() {
         
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             payload.clone(),
+            1,
             None
         ));
 
@@ -35,11 +36,10 @@ fn submit_cross_chain_event_works() {
         let payload = vec![1, 2, 3];
         let source_para_id = 2000;
         
-        assert_ok!(EventHub::submit_event(
-            RuntimeOrigin::signed(1),
-            EventType::CrossChain,
+        assert_ok!(EventHub::submit_cross_chain_event(
+            RuntimeOrigin::root(),
             payload,
-            Some(source_para_id)
+            None
         ));
 
         let event = EventHub::events(0).unwrap();
@@ -52,6 +52,34 @@ fn submit_cross_chain_event_works() {
     });
 }
 
+#[test]
+fn signed_cross_chain_event_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Signed accounts cannot claim a cross-chain origin
+        assert_noop!(
+            EventHub::submit_event(
+                RuntimeOrigin::signed(1),
+                1,
+                vec![1, 2, 3],
+                1,
+                None
+            ),
+            crate::Error::<Test>::CrossChainOriginRequired
+        );
+
+        assert_noop!(
+            EventHub::submit_cross_chain_event(
+                RuntimeOrigin::signed(1),
+                vec![1, 2, 3],
+                None
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
 #[test]
 fn register_trigger_works() {
     new_test_ext().execute_with(|| {
@@ -60,8 +88,9 @@ fn register_trigger_works() {
         // Submit event first
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             vec![1, 2, 3],
+            1,
             None
         ));
 
@@ -92,8 +121,9 @@ fn register_trigger_with_condition_works() {
         
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             vec![1, 2, 3],
+            1,
             None
         ));
 
@@ -119,8 +149,9 @@ fn process_event_works() {
         // Submit event
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             vec![1, 2, 3],
+            1,
             None
         ));
 
@@ -145,6 +176,7 @@ fn process_event_works() {
         System::assert_has_event(Event::TriggerActivated {
             trigger_id: 0,
             event_id: 0,
+            correlation_id: None,
         }.into());
     });
 }
@@ -156,8 +188,9 @@ fn process_already_processed_event_fails() {
         
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             vec![1, 2, 3],
+            1,
             None
         ));
 
@@ -184,8 +217,9 @@ fn deactivate_trigger_works() {
         
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             vec![1, 2, 3],
+            1,
             None
         ));
 
@@ -215,8 +249,9 @@ fn deactivate_trigger_unauthorized_fails() {
         
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             vec![1, 2, 3],
+            1,
             None
         ));
 
@@ -254,8 +289,9 @@ fn trigger_starts_job() {
         // Submit event
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             vec![1, 2, 3],
+            1,
             None
         ));
 
@@ -280,6 +316,7 @@ fn trigger_starts_job() {
         System::assert_has_event(Event::JobTriggered {
             job_id: 0,
             event_id: 0,
+            correlation_id: None,
         }.into());
     });
 }
@@ -293,8 +330,9 @@ fn get_pending_events_works() {
         for i in 0..3 {
             assert_ok!(EventHub::submit_event(
                 RuntimeOrigin::signed(1),
-                EventType::OnChain,
+                0,
                 vec![i],
+                1,
                 None
             ));
         }
@@ -323,16 +361,16 @@ fn event_statistics_tracked() {
         // Submit events
         assert_ok!(EventHub::submit_event(
             RuntimeOrigin::signed(1),
-            EventType::OnChain,
+            0,
             vec![1],
+            1,
             None
         ));
 
-        assert_ok!(EventHub::submit_event(
-            RuntimeOrigin::signed(1),
-            EventType::CrossChain,
+        assert_ok!(EventHub::submit_cross_chain_event(
+            RuntimeOrigin::root(),
             vec![2],
-            Some(2000)
+            None
         ));
 
         let stats = EventHub::get_statistics();
@@ -360,17 +398,1005 @@ fn on_initialize_processes_events() {
         for i in 0..3 {
             assert_ok!(EventHub::submit_event(
                 RuntimeOrigin::signed(1),
-                EventType::OnChain,
+                0,
                 vec![i],
+                1,
                 None
             ));
         }
 
-        // Call on_initialize
-        let _weight = EventHub::on_initialize(2);
+        // Call on_initialize, charged for each processed event by its trigger fan-out
+        let weight = EventHub::on_initialize(2);
+        let expected = <() as crate::WeightInfo>::run_delayed_activations(0)
+            .saturating_add(<() as crate::WeightInfo>::process_event(0).saturating_mul(3));
+        assert_eq!(weight, expected);
 
         // Some events should be processed
         let stats = EventHub::get_statistics();
         assert!(stats.total_events_processed > 0);
     });
 }
+
+#[test]
+fn trigger_emits_derived_event_on_topic() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![1, 2, 3],
+            1,
+            None
+        ));
+
+        // Trigger 0 watches event 0 and publishes on topic 7
+        assert_ok!(EventHub::register_trigger(
+            RuntimeOrigin::signed(1),
+            0,
+            3,
+            Some(7),
+            None
+        ));
+
+        // Trigger 1 watches topic 7
+        assert_ok!(EventHub::register_trigger(
+            RuntimeOrigin::signed(1),
+            u64::MAX,
+            2,
+            None,
+            None
+        ));
+        assert_ok!(EventHub::subscribe_trigger(RuntimeOrigin::signed(1), 1, 7));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+
+        let derived = EventHub::events(1).unwrap();
+        assert_eq!(derived.topic, Some(7));
+        assert_eq!(derived.chain_depth, 1);
+        assert_eq!(EventHub::event_triggers(1).to_vec(), vec![1]);
+
+        System::assert_has_event(Event::DerivedEventEmitted {
+            event_id: 1,
+            parent_event_id: 0,
+            topic: 7,
+        }.into());
+    });
+}
+
+#[test]
+fn trigger_chain_stops_at_max_depth() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![1],
+            1,
+            None
+        ));
+
+        // Trigger re-publishes onto the topic it is subscribed to
+        assert_ok!(EventHub::register_trigger(
+            RuntimeOrigin::signed(1),
+            0,
+            3,
+            Some(7),
+            None
+        ));
+        assert_ok!(EventHub::subscribe_trigger(RuntimeOrigin::signed(1), 0, 7));
+
+        for event_id in 0..4 {
+            assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), event_id));
+        }
+
+        // MaxChainDepth is 3 in the mock, so the fourth hop is rejected
+        assert_eq!(EventHub::next_event_id(), 4);
+        assert_eq!(EventHub::get_pending_events(), vec![3]);
+        assert_eq!(EventHub::events(3).unwrap().attempts, 1);
+    });
+}
+
+#[test]
+fn failing_trigger_records_receipt_and_deactivates() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for i in 0..2 {
+            assert_ok!(EventHub::submit_event(
+                RuntimeOrigin::signed(1),
+                0,
+                vec![i],
+                1,
+                None
+            ));
+        }
+
+        // Trigger 0 starts a job that does not exist and watches both events
+        assert_ok!(EventHub::register_trigger(
+            RuntimeOrigin::signed(1),
+            0,
+            0,
+            Some(42),
+            None
+        ));
+        crate::EventTriggers::<Test>::insert(1, frame_support::BoundedVec::truncate_from(vec![0u64]));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+
+        let receipt = EventHub::activation_receipts(0, 0).unwrap();
+        assert!(!receipt.succeeded);
+        assert_eq!(
+            receipt.error,
+            Some(pallet_job_registry::Error::<Test>::JobNotFound.into())
+        );
+        assert_eq!(EventHub::triggers(0).unwrap().failure_count, 1);
+        assert!(EventHub::triggers(0).unwrap().active);
+
+        // MaxTriggerFailures is 2 in the mock
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 1));
+        assert!(!EventHub::triggers(0).unwrap().active);
+
+        System::assert_has_event(Event::TriggerDeactivated { trigger_id: 0 }.into());
+    });
+}
+
+#[test]
+fn webhook_trigger_queues_and_reports() {
+    use codec::Encode;
+    use frame_support::pallet_prelude::*;
+    use sp_runtime::testing::{TestSignature, UintAuthorityId};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let url_hash = sp_core::H256::repeat_byte(7);
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![1, 2, 3],
+            1,
+            None
+        ));
+        assert_ok!(EventHub::register_webhook_trigger(
+            RuntimeOrigin::signed(1),
+            0,
+            url_hash,
+            None
+        ));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+
+        let webhook = EventHub::pending_webhooks(0).unwrap();
+        assert_eq!(webhook.url_hash, url_hash);
+        assert_eq!(webhook.event_id, 0);
+
+        let report = |worker: u64| crate::WebhookReport {
+            webhook_id: 0,
+            delivered: true,
+            public: UintAuthorityId(worker),
+        };
+        let call = |worker: u64, signer: u64| crate::Call::report_webhook {
+            report: report(worker),
+            signature: TestSignature(signer, report(worker).encode()),
+        };
+
+        // Only the assigned worker may report, signing with its key
+        assert_eq!(EventHub::webhook_worker(0, &webhook, 1), Some(7));
+        assert!(EventHub::validate_unsigned(TransactionSource::External, &call(7, 7)).is_ok());
+        assert_eq!(
+            EventHub::validate_unsigned(TransactionSource::External, &call(7, 8)),
+            InvalidTransaction::BadProof.into()
+        );
+        assert_eq!(
+            EventHub::validate_unsigned(TransactionSource::External, &call(8, 8)),
+            InvalidTransaction::BadSigner.into()
+        );
+
+        // An undelivered webhook passes to the next worker
+        System::set_block_number(1 + crate::WEBHOOK_REASSIGN_PERIOD as u64);
+        assert!(EventHub::validate_unsigned(TransactionSource::External, &call(8, 8)).is_ok());
+        assert_eq!(
+            EventHub::validate_unsigned(TransactionSource::External, &call(7, 7)),
+            InvalidTransaction::BadSigner.into()
+        );
+
+        // Only unsigned reports are accepted
+        assert_noop!(
+            EventHub::report_webhook(RuntimeOrigin::signed(1), report(8), TestSignature(8, vec![])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        let signature = TestSignature(8, report(8).encode());
+        assert_ok!(EventHub::report_webhook(RuntimeOrigin::none(), report(8), signature));
+        assert!(EventHub::pending_webhooks(0).is_none());
+        assert_eq!(
+            EventHub::validate_unsigned(TransactionSource::External, &call(8, 8)),
+            InvalidTransaction::Stale.into()
+        );
+
+        System::assert_has_event(Event::WebhookReported { webhook_id: 0, delivered: true }.into());
+    });
+}
+
+#[test]
+fn high_priority_events_processed_first() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Six low-priority events, then one high-priority event
+        for i in 0..6 {
+            assert_ok!(EventHub::submit_event(
+                RuntimeOrigin::signed(1),
+                0,
+                vec![i],
+                0,
+                None
+            ));
+        }
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![6],
+            2,
+            None
+        ));
+
+        assert_eq!(EventHub::get_pending_events(), vec![6, 0, 1, 2, 3, 4, 5]);
+
+        // on_initialize processes five events per block
+        EventHub::on_initialize(2);
+
+        assert!(EventHub::events(6).unwrap().processed);
+        assert!(!EventHub::events(5).unwrap().processed);
+        assert_eq!(EventHub::get_pending_events(), vec![4, 5]);
+    });
+}
+
+#[test]
+fn invalid_priority_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            EventHub::submit_event(
+                RuntimeOrigin::signed(1),
+                0,
+                vec![1],
+                9,
+                None
+            ),
+            crate::Error::<Test>::InvalidPriority
+        );
+    });
+}
+
+#[test]
+fn purge_event_refunds_depositor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![1, 2, 3],
+            1,
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(1), 10);
+
+        // Pending events cannot be purged
+        assert_noop!(
+            EventHub::purge_event(RuntimeOrigin::signed(1), 0),
+            crate::Error::<Test>::EventNotProcessed
+        );
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+
+        // Others must wait for the retention period
+        assert_noop!(
+            EventHub::purge_event(RuntimeOrigin::signed(2), 0),
+            crate::Error::<Test>::RetentionNotElapsed
+        );
+
+        assert_ok!(EventHub::purge_event(RuntimeOrigin::signed(1), 0));
+        assert!(EventHub::events(0).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+
+        System::assert_has_event(Event::EventPurged { event_id: 0, refund: 10 }.into());
+    });
+}
+
+#[test]
+fn anyone_can_purge_after_retention() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![1],
+            1,
+            None
+        ));
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+
+        // EventRetentionPeriod is 100 in the mock
+        System::set_block_number(101);
+        assert_ok!(EventHub::purge_event(RuntimeOrigin::signed(2), 0));
+
+        // The refund still goes to the original depositor
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1_000);
+    });
+}
+
+#[test]
+fn repeatedly_failing_event_is_dead_lettered_and_requeued() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![1],
+            1,
+            None
+        ));
+
+        // StartJob on a job that does not exist always fails
+        assert_ok!(EventHub::register_trigger(
+            RuntimeOrigin::signed(1),
+            0,
+            0,
+            Some(42),
+            None
+        ));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        assert_eq!(EventHub::get_pending_events(), vec![0]);
+        System::assert_has_event(Event::EventRetryScheduled { event_id: 0, attempts: 1 }.into());
+
+        // Reactivate the trigger between attempts so every attempt runs it
+        for _ in 0..2 {
+            crate::Triggers::<Test>::mutate(0, |t| t.as_mut().unwrap().active = true);
+            assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        }
+
+        // MaxEventAttempts is 3 in the mock
+        assert!(EventHub::get_pending_events().is_empty());
+        assert_eq!(EventHub::dead_letters(0).unwrap().attempts, 3);
+        System::assert_has_event(Event::EventDeadLettered { event_id: 0, attempts: 3 }.into());
+
+        // Only the depositor can requeue
+        assert_noop!(
+            EventHub::requeue_event(RuntimeOrigin::signed(2), 0),
+            crate::Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(EventHub::requeue_event(RuntimeOrigin::signed(1), 0));
+        assert!(EventHub::dead_letters(0).is_none());
+        assert_eq!(EventHub::events(0).unwrap().attempts, 0);
+        assert_eq!(EventHub::get_pending_events(), vec![0]);
+    });
+}
+
+#[test]
+fn condition_events_require_oracle() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            EventHub::submit_event(
+                RuntimeOrigin::signed(2),
+                3,
+                vec![1],
+                1,
+                None
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // Other event types remain open to any signed account
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(2),
+            2,
+            vec![1],
+            1,
+            None
+        ));
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(ORACLE),
+            3,
+            vec![1],
+            1,
+            None
+        ));
+    });
+}
+
+#[test]
+fn trigger_cooldown_debounces_activations() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // A Custom trigger watching three events
+        assert_ok!(EventHub::register_trigger(
+            RuntimeOrigin::signed(1),
+            u64::MAX,
+            2,
+            None,
+            None
+        ));
+        assert_ok!(EventHub::set_trigger_cooldown(RuntimeOrigin::signed(1), 0, Some(10)));
+
+        for i in 0..3 {
+            assert_ok!(EventHub::submit_event(
+                RuntimeOrigin::signed(1),
+                0,
+                vec![i],
+                1,
+                None
+            ));
+            crate::EventTriggers::<Test>::insert(i as u64, frame_support::BoundedVec::truncate_from(vec![0u64]));
+        }
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 1));
+
+        assert_eq!(EventHub::triggers(0).unwrap().last_fired, Some(1));
+        assert!(EventHub::activation_receipts(0, 1).is_none());
+        System::assert_has_event(Event::TriggerSuppressed { trigger_id: 0, event_id: 1 }.into());
+
+        // Once the window has passed the trigger fires again
+        System::set_block_number(11);
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 2));
+        assert_eq!(EventHub::triggers(0).unwrap().last_fired, Some(11));
+    });
+}
+
+#[test]
+fn correlation_id_propagates_to_derived_events_and_jobs() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let correlation_id = sp_core::H256::repeat_byte(0xab);
+
+        assert_ok!(JobRegistry::submit_job(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3],
+            vec![],
+            100
+        ));
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![1],
+            1,
+            Some(correlation_id)
+        ));
+
+        // Event 0 publishes on topic 7, whose subscriber starts job 0
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 3, Some(7), None));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), u64::MAX, 0, Some(0), None));
+        assert_ok!(EventHub::subscribe_trigger(RuntimeOrigin::signed(1), 1, 7));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 1));
+
+        assert_eq!(EventHub::events(1).unwrap().correlation_id, Some(correlation_id));
+        assert_eq!(JobRegistry::job_correlation_ids(0), Some(correlation_id));
+
+        System::assert_has_event(Event::JobTriggered {
+            job_id: 0,
+            event_id: 1,
+            correlation_id: Some(correlation_id),
+        }.into());
+    });
+}
+
+#[test]
+fn transfer_trigger_moves_ownership() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, None));
+
+        assert_noop!(
+            EventHub::transfer_trigger(RuntimeOrigin::signed(2), 0, 3),
+            crate::Error::<Test>::NotAuthorized
+        );
+
+        // Transferring to the current owner would drop the trigger from its list
+        assert_noop!(
+            EventHub::transfer_trigger(RuntimeOrigin::signed(1), 0, 1),
+            crate::Error::<Test>::AlreadyOwner
+        );
+        assert_eq!(EventHub::get_account_triggers(&1), vec![0]);
+
+        assert_ok!(EventHub::transfer_trigger(RuntimeOrigin::signed(1), 0, 2));
+
+        assert_eq!(EventHub::triggers(0).unwrap().owner, 2);
+        assert!(EventHub::get_account_triggers(&1).is_empty());
+        assert_eq!(EventHub::get_account_triggers(&2), vec![0]);
+
+        // Only the new owner can manage the trigger
+        assert_noop!(
+            EventHub::deactivate_trigger(RuntimeOrigin::signed(1), 0),
+            crate::Error::<Test>::NotAuthorized
+        );
+        assert_ok!(EventHub::deactivate_trigger(RuntimeOrigin::signed(2), 0));
+
+        System::assert_has_event(Event::TriggerTransferred { trigger_id: 0, from: 1, to: 2 }.into());
+    });
+}
+
+#[test]
+fn hrmp_channels_are_governed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            EventHub::request_hrmp_channel(RuntimeOrigin::signed(1), 2004, 8, 1024),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(EventHub::request_hrmp_channel(RuntimeOrigin::root(), 2004, 8, 1024));
+        assert_eq!(EventHub::hrmp_channels(2004), Some(crate::HrmpChannelState::Requested));
+
+        assert_noop!(
+            EventHub::request_hrmp_channel(RuntimeOrigin::root(), 2004, 8, 1024),
+            crate::Error::<Test>::HrmpChannelExists
+        );
+
+        assert_ok!(EventHub::accept_hrmp_channel(RuntimeOrigin::root(), 2005));
+        assert_eq!(EventHub::hrmp_channels(2005), Some(crate::HrmpChannelState::Accepted));
+
+        // Both calls went to the relay chain
+        let sent = sent_xcm();
+        assert_eq!(sent.len(), 2);
+        assert!(sent.iter().all(|(dest, _)| *dest == xcm::latest::Location::parent()));
+
+        System::assert_has_event(Event::HrmpChannelRequested { recipient: 2004 }.into());
+        System::assert_has_event(Event::HrmpChannelAccepted { sender: 2005 }.into());
+    });
+}
+
+#[test]
+fn source_filter_limits_matching_events() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Cross-chain events from para 2000 (the mock CrossChainOrigin)
+        assert_ok!(EventHub::submit_cross_chain_event(RuntimeOrigin::root(), vec![1], None));
+        assert_ok!(EventHub::submit_cross_chain_event(RuntimeOrigin::root(), vec![2], None));
+
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, None));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 1, 2, None, None));
+
+        assert_ok!(EventHub::set_source_filter(RuntimeOrigin::signed(1), 0, Some(vec![2004])));
+        assert_ok!(EventHub::set_source_filter(RuntimeOrigin::signed(1), 1, Some(vec![2000, 2004])));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 1));
+
+        assert!(EventHub::activation_receipts(0, 0).is_none());
+        assert!(EventHub::activation_receipts(1, 1).unwrap().succeeded);
+
+        assert_noop!(
+            EventHub::set_source_filter(RuntimeOrigin::signed(1), 0, Some(vec![0; 17])),
+            crate::Error::<Test>::TooManySources
+        );
+    });
+}
+
+#[test]
+fn template_trigger_starts_job_with_event_payload() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(JobRegistry::create_template(RuntimeOrigin::signed(2), vec![7], 100));
+
+        assert_ok!(EventHub::submit_event(
+            RuntimeOrigin::signed(1),
+            0,
+            vec![4, 5, 6],
+            1,
+            None
+        ));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 4, Some(0), None));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+
+        // The trigger owner owns the new job, whose input is the event payload
+        let job = JobRegistry::jobs(0).unwrap();
+        assert_eq!(job.owner, 1);
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(JobRegistry::job_inputs(0).unwrap().to_vec(), vec![4, 5, 6]);
+
+        System::assert_has_event(Event::JobTriggered {
+            job_id: 0,
+            event_id: 0,
+            correlation_id: None,
+        }.into());
+    });
+}
+
+#[test]
+fn full_event_store_rejects_or_evicts() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MaxEvents::set(2);
+
+        assert_ok!(EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![0], 1, None));
+        assert_ok!(EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![1], 1, None));
+
+        // Rejected without consuming an ID or leaving partial state
+        assert_noop!(
+            EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![2], 1, None),
+            crate::Error::<Test>::MaxEventsReached
+        );
+        assert_eq!(EventHub::next_event_id(), 2);
+
+        // Nothing processed yet, so nothing can be evicted
+        Backpressure::set(crate::BackpressureMode::EvictOldestProcessed);
+        assert_noop!(
+            EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![2], 1, None),
+            crate::Error::<Test>::MaxEventsReached
+        );
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        assert_ok!(EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![2], 1, None));
+
+        assert!(EventHub::events(0).is_none());
+        assert_eq!(EventHub::event_count(), 2);
+        assert_eq!(Balances::reserved_balance(1), 20);
+        System::assert_has_event(Event::EventEvicted { event_id: 0, refund: 10 }.into());
+    });
+}
+
+#[test]
+fn genesis_triggers_are_subscribed_to_topics() {
+    use sp_runtime::BuildStorage;
+
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    crate::GenesisConfig::<Test> {
+        triggers: vec![(1, 5, 2, None), (2, 5, 3, Some(6))],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::new(t).execute_with(|| {
+        assert_eq!(EventHub::topic_triggers(5).to_vec(), vec![0, 1]);
+        assert_eq!(EventHub::triggers(1).unwrap().owner, 2);
+        assert_eq!(EventHub::triggers(1).unwrap().action, TriggerAction::EmitEvent { topic: 6 });
+        assert_eq!(EventHub::get_account_triggers(&1), vec![0]);
+    });
+}
+
+#[test]
+fn state_conditions_gate_activation() {
+    use codec::Encode;
+    use crate::{Condition, StateCondition};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+
+        for i in 0..2 {
+            assert_ok!(EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![i], 1, None));
+        }
+
+        // Account 2 holds 1000, so only the first balance condition holds
+        let rich = Condition::<u64, u64>::State(StateCondition::BalanceAbove { who: 2, amount: 500 }).encode();
+        let richer = Condition::<u64, u64>::State(StateCondition::BalanceAbove { who: 2, amount: 5_000 }).encode();
+        let job_started = Condition::<u64, u64>::State(StateCondition::JobStatusIs {
+            job_id: 0,
+            status: JobStatus::InProgress,
+        }).encode();
+
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, Some(rich)));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, Some(richer)));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 1, 2, None, Some(job_started)));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        assert!(EventHub::activation_receipts(0, 0).unwrap().succeeded);
+        assert!(EventHub::activation_receipts(1, 0).is_none());
+
+        // Job 0 is still Pending
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 1));
+        assert!(EventHub::activation_receipts(2, 1).is_none());
+    });
+}
+
+#[test]
+fn delayed_trigger_activates_after_delay() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![1], 1, None));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, None));
+        assert_ok!(EventHub::set_trigger_delay(RuntimeOrigin::signed(1), 0, Some(5)));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+
+        // Event is processed but the action waits for block 6
+        assert!(EventHub::events(0).unwrap().processed);
+        assert!(EventHub::activation_receipts(0, 0).is_none());
+        assert_eq!(EventHub::scheduled_activations(0, 0), Some(6));
+        System::assert_has_event(Event::ActivationScheduled { trigger_id: 0, event_id: 0, at: 6 }.into());
+
+        System::set_block_number(6);
+        let weight = EventHub::on_initialize(6);
+        assert_eq!(weight, <() as crate::WeightInfo>::run_delayed_activations(1));
+
+        assert!(EventHub::activation_receipts(0, 0).unwrap().succeeded);
+        assert!(EventHub::scheduled_activations(0, 0).is_none());
+        assert!(EventHub::delayed_activations(6).is_empty());
+    });
+}
+
+#[test]
+fn job_lifecycle_is_published_on_system_topics() {
+    use codec::Encode;
+    use crate::topics;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Trigger 0 listens for completed jobs
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), u64::MAX, 2, None, None));
+        assert_ok!(EventHub::subscribe_trigger(RuntimeOrigin::signed(1), 0, topics::JOB_COMPLETED));
+
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 1));
+
+        // InProgress isn't published
+        assert_eq!(EventHub::next_event_id(), 0);
+
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 2));
+
+        let event = EventHub::events(0).unwrap();
+        assert_eq!(event.topic, Some(topics::JOB_COMPLETED));
+        assert_eq!(event.payload.to_vec(), 0u64.encode());
+        assert_eq!(EventHub::event_triggers(0).to_vec(), vec![0]);
+
+        // Nobody listens for failures, so nothing is published
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![2], vec![], 100));
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 1, 4));
+        assert_eq!(EventHub::next_event_id(), 1);
+    });
+}
+
+#[test]
+fn remove_trigger_cleans_indexes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, None));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, None));
+        assert_ok!(EventHub::subscribe_trigger(RuntimeOrigin::signed(1), 0, 7));
+
+        assert_noop!(
+            EventHub::remove_trigger(RuntimeOrigin::signed(2), 0),
+            crate::Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(EventHub::remove_trigger(RuntimeOrigin::signed(1), 0));
+
+        assert!(EventHub::triggers(0).is_none());
+        assert_eq!(EventHub::get_account_triggers(&1), vec![1]);
+        assert_eq!(EventHub::event_triggers(0).to_vec(), vec![1]);
+        assert!(EventHub::topic_triggers(7).is_empty());
+        assert!(EventHub::trigger_subscriptions(0).is_empty());
+
+        System::assert_has_event(Event::TriggerRemoved { trigger_id: 0 }.into());
+    });
+}
+
+#[test]
+fn trigger_statistics_track_outcomes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for i in 0..3 {
+            assert_ok!(EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![i], 1, None));
+        }
+
+        // Trigger 0 succeeds, trigger 1 starts a job that doesn't exist
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, None));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 1, 0, Some(42), None));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        System::set_block_number(2);
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 1));
+
+        let stats = EventHub::get_trigger_stats(0);
+        assert_eq!(stats.activations, 1);
+        assert_eq!(stats.failures, 0);
+        assert_eq!(stats.last_fired, Some(1));
+
+        let stats = EventHub::get_trigger_stats(1);
+        assert_eq!(stats.activations, 0);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.last_fired, None);
+    });
+}
+
+#[test]
+fn composite_conditions_combine_event_and_state() {
+    use codec::Encode;
+    use crate::{Condition, StateCondition};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Cross-chain price updates from para 2000 carrying a u128 value
+        assert_ok!(EventHub::submit_cross_chain_event(RuntimeOrigin::root(), 150u128.encode(), None));
+        assert_ok!(EventHub::submit_cross_chain_event(RuntimeOrigin::root(), 50u128.encode(), None));
+
+        // value > 100 AND source == 2000 AND NOT (balance of 2 > 5000)
+        let condition = Condition::<u64, u64>::And(vec![
+            Condition::PayloadValueAbove { offset: 0, threshold: 100 },
+            Condition::SourceParaIs(2000),
+            Condition::Not(Box::new(Condition::State(StateCondition::BalanceAbove { who: 2, amount: 5_000 }))),
+        ]).encode();
+
+        // (source == 2004) OR (source == 2000)
+        let either_source = Condition::<u64, u64>::Or(vec![
+            Condition::SourceParaIs(2004),
+            Condition::SourceParaIs(2000),
+        ]).encode();
+
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 0, 2, None, Some(condition.clone())));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 1, 2, None, Some(condition)));
+        assert_ok!(EventHub::register_trigger(RuntimeOrigin::signed(1), 1, 2, None, Some(either_source)));
+
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 0));
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(1), 1));
+
+        assert!(EventHub::activation_receipts(0, 0).unwrap().succeeded);
+        assert!(EventHub::activation_receipts(1, 1).is_none());
+        assert!(EventHub::activation_receipts(2, 1).unwrap().succeeded);
+    });
+}
+
+#[test]
+fn xcmp_blob_pages_become_cross_chain_events() {
+    use codec::Encode;
+    use crate::{CrossChainEventMessage, XcmpEventHandler};
+    use cumulus_primitives_core::{
+        relay_chain::BlockNumber as RelayBlockNumber, ParaId, XcmpMessageFormat, XcmpMessageHandler,
+    };
+    use frame_support::weights::Weight;
+
+    /// Counts the pages passed through
+    struct CountingHandler;
+
+    impl XcmpMessageHandler for CountingHandler {
+        fn handle_xcmp_messages<'a, I: Iterator<Item = (ParaId, RelayBlockNumber, &'a [u8])>>(
+            iter: I,
+            _max_weight: Weight,
+        ) -> Weight {
+            Weight::from_parts(iter.count() as u64, 0)
+        }
+    }
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let correlation_id = sp_core::H256::repeat_byte(7);
+        let mut blobs = XcmpMessageFormat::ConcatenatedEncodedBlob.encode();
+        blobs.extend(CrossChainEventMessage { payload: vec![1, 2], correlation_id: None }.encode().encode());
+        blobs.extend(CrossChainEventMessage {
+            payload: vec![3],
+            correlation_id: Some(correlation_id),
+        }.encode().encode());
+        let xcm_page = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+
+        let used = XcmpEventHandler::<Test, CountingHandler>::handle_xcmp_messages(
+            vec![
+                (ParaId::from(2004), 1, &blobs[..]),
+                (ParaId::from(2004), 1, &xcm_page[..]),
+            ].into_iter(),
+            Weight::MAX,
+        );
+
+        let first = EventHub::events(0).unwrap();
+        assert_eq!(first.event_type, EventType::CrossChain);
+        assert_eq!(first.source_para_id, Some(2004));
+        assert_eq!(first.payload.to_vec(), vec![1, 2]);
+        assert_eq!(EventHub::events(1).unwrap().correlation_id, Some(correlation_id));
+
+        // The regular XCM page was handed to the inner handler
+        let per_event = <() as crate::WeightInfo>::submit_cross_chain_event();
+        assert_eq!(used, per_event.saturating_mul(2).saturating_add(Weight::from_parts(1, 0)));
+        System::assert_has_event(Event::CrossChainEventReceived { event_id: 1, source_para_id: 2004 }.into());
+    });
+}
+
+#[test]
+fn offchain_worker_processes_events_and_delivers_webhooks() {
+    use codec::{Decode, Encode};
+    use sp_core::offchain::{
+        testing, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+    };
+    use sp_runtime::testing::{TestSignature, UintAuthorityId};
+
+    let (offchain, state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+
+        let url_hash = sp_core::H256::repeat_byte(7);
+        for _ in 0..=crate::MAX_EVENTS_PER_BLOCK {
+            assert_ok!(EventHub::submit_event(RuntimeOrigin::signed(1), 0, vec![1, 2, 3], 1, None));
+        }
+        assert_ok!(EventHub::register_webhook_trigger(RuntimeOrigin::signed(1), 0, url_hash, None));
+
+        // Events past those `on_initialize` processes next are processed through a signed
+        // transaction, once until the resubmission delay passes
+        EventHub::offchain_worker(1);
+        EventHub::offchain_worker(2);
+        {
+            let txs = &pool_state.read().transactions;
+            assert_eq!(txs.len(), 1);
+            let xt = Extrinsic::decode(&mut &txs[0][..]).unwrap();
+            let event_id = crate::MAX_EVENTS_PER_BLOCK as u64;
+            let expected = RuntimeCall::EventHub(crate::Call::process_event { event_id });
+            assert_eq!(xt.function, expected);
+        }
+        assert_ok!(EventHub::process_event(RuntimeOrigin::signed(7), 0));
+
+        // The queued webhook is assigned to this node's worker, which without a configured URL
+        // leaves it for the next worker
+        EventHub::offchain_worker(3);
+        assert_eq!(pool_state.read().transactions.len(), 1);
+
+        // Once configured, it is posted to the URL and reported unsigned
+        let url = "http://localhost:8080/hook";
+        let mut key = crate::WEBHOOK_URL_PREFIX.to_vec();
+        key.extend_from_slice(url_hash.as_bytes());
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            &key,
+            &url.as_bytes().to_vec().encode(),
+        );
+        state.write().expect_request(testing::PendingRequest {
+            method: "POST".into(),
+            uri: url.into(),
+            headers: vec![("Content-Type".into(), "application/octet-stream".into())],
+            body: vec![1, 2, 3],
+            response: Some(vec![]),
+            sent: true,
+            ..Default::default()
+        });
+
+        EventHub::offchain_worker(3);
+        let txs = pool_state.read().transactions.clone();
+        assert_eq!(txs.len(), 2);
+        let xt = Extrinsic::decode(&mut &txs[1][..]).unwrap();
+        let report = crate::WebhookReport { webhook_id: 0, delivered: true, public: UintAuthorityId(7) };
+        let signature = TestSignature(7, report.encode());
+        assert_eq!(
+            xt.function,
+            RuntimeCall::EventHub(crate::Call::report_webhook { report, signature })
+        );
+
+        // Once passed to the other worker, whose key this node doesn't hold, it isn't delivered
+        EventHub::offchain_worker(1 + crate::WEBHOOK_REASSIGN_PERIOD as u64);
+        assert_eq!(pool_state.read().transactions.len(), 2);
+    });
+}
@@ -1,56 +1,636 @@
 //! Weights for pallet_event_hub
+//!
+//! These are hand-written estimates, not benchmark results. `ref_time` values are placeholders;
+//! proof sizes are worked out by hand from the `MaxEncodedLen` of each storage item the call
+//! touches in its worst case, using the runtime's bounds (`MaxEvents`, `MaxTriggersPerAccount`,
+//! 32-byte account IDs, `u32` block numbers). Before production, replace them with weights
+//! generated from the benchmarks in `benchmarking.rs` on reference hardware, using a node built
+//! with `--features runtime-benchmarks`:
+//! `benchmark pallet --pallet pallet_event_hub --extrinsic '*' --output pallets/event-hub/src/weights.rs`.
 
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
 pub trait WeightInfo {
-    fn submit_event() -> Weight;
+    fn submit_event(p: u32) -> Weight;
     fn register_trigger() -> Weight;
-    fn process_event() -> Weight;
+    fn process_event(t: u32) -> Weight;
+    fn run_delayed_activations(d: u32) -> Weight;
     fn deactivate_trigger() -> Weight;
+    fn submit_cross_chain_event() -> Weight;
+    fn subscribe_trigger() -> Weight;
+    fn report_webhook() -> Weight;
+    fn purge_event() -> Weight;
+    fn requeue_event() -> Weight;
+    fn set_trigger_cooldown() -> Weight;
+    fn transfer_trigger() -> Weight;
+    fn request_hrmp_channel() -> Weight;
+    fn accept_hrmp_channel() -> Weight;
+    fn set_source_filter() -> Weight;
+    fn set_trigger_delay() -> Weight;
+    fn remove_trigger() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-    fn submit_event() -> Weight {
-        Weight::from_parts(30_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(3))
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Events` (r:0 w:2)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:0 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:0 w:1)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:1 w:2)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextEventId` (r:1 w:1)
+    /// Proof: `EventHub::NextEventId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:1)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// The range of component `p` is `[0, 512]`.
+    fn submit_event(p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `173263`
+        Weight::from_parts(38_000_000, 173263)
+            .saturating_add(Weight::from_parts(1_200, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(13))
     }
 
+    /// Storage: `EventHub::AccountTriggers` (r:1 w:1)
+    /// Proof: `EventHub::AccountTriggers` (`max_values`: None, `max_size`: Some(8050), added: 10525, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextTriggerId` (r:1 w:1)
+    /// Proof: `EventHub::NextTriggerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Triggers` (r:0 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:1 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
     fn register_trigger() -> Weight {
-        Weight::from_parts(35_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `15319`
+        Weight::from_parts(35_000_000, 15319)
             .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().writes(4))
     }
 
-    fn process_event() -> Weight {
-        Weight::from_parts(40_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(3))
+    /// Storage: `EventHub::Events` (r:1 w:201)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:1 w:200)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:1)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextEventId` (r:1 w:1)
+    /// Proof: `EventHub::NextEventId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ActivationReceipts` (r:100 w:100)
+    /// Proof: `EventHub::ActivationReceipts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Triggers` (r:100 w:100)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerStatistics` (r:100 w:100)
+    /// Proof: `EventHub::TriggerStatistics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TopicTriggers` (r:100 w:0)
+    /// Proof: `EventHub::TopicTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:100 w:100)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:100 w:100)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:0 w:100)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// The range of component `t` is `[1, 100]`.
+    fn process_event(t: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `171886`
+        Weight::from_parts(30_000_000, 171886)
+            // Worst case: each trigger emits a derived event
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(t.into()))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(t.into())))
+            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(t.into())))
+            .saturating_add(Weight::from_parts(0, 16307).saturating_mul(t.into()))
     }
 
+    /// Storage: `EventHub::DelayedActivations` (r:1 w:1)
+    /// Proof: `EventHub::DelayedActivations` (`max_values`: None, `max_size`: Some(1622), added: 4097, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:1)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextEventId` (r:1 w:1)
+    /// Proof: `EventHub::NextEventId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ScheduledActivations` (r:0 w:100)
+    /// Proof: `EventHub::ScheduledActivations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Triggers` (r:100 w:100)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Events` (r:100 w:200)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ActivationReceipts` (r:0 w:100)
+    /// Proof: `EventHub::ActivationReceipts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerStatistics` (r:100 w:100)
+    /// Proof: `EventHub::TriggerStatistics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TopicTriggers` (r:100 w:0)
+    /// Proof: `EventHub::TopicTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:0 w:200)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:0 w:100)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:100 w:100)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:100 w:100)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `d` is `[0, 100]`.
+    fn run_delayed_activations(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `169607`
+        Weight::from_parts(5_000_000, 169607)
+            // Worst case: each activation emits a derived event
+            .saturating_add(Weight::from_parts(27_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 16847).saturating_mul(d.into()))
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
     fn deactivate_trigger() -> Weight {
-        Weight::from_parts(20_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `3783`
+        Weight::from_parts(20_000_000, 3783)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Events` (r:0 w:2)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:0 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:0 w:1)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:1 w:1)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextEventId` (r:1 w:1)
+    /// Proof: `EventHub::NextEventId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:1)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    fn submit_cross_chain_event() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `170660`
+        Weight::from_parts(30_000_000, 170660)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(11))
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:0)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TopicTriggers` (r:1 w:1)
+    /// Proof: `EventHub::TopicTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerSubscriptions` (r:1 w:1)
+    /// Proof: `EventHub::TriggerSubscriptions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+    fn subscribe_trigger() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `9712`
+        Weight::from_parts(20_000_000, 9712)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: `EventHub::PendingWebhooks` (r:1 w:1)
+    /// Proof: `EventHub::PendingWebhooks` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    fn report_webhook() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3541`
+        Weight::from_parts(15_000_000, 3541)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `EventHub::Events` (r:1 w:1)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:1 w:1)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:1 w:1)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:0 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    fn purge_event() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `92718`
+        Weight::from_parts(30_000_000, 92718)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    /// Storage: `EventHub::EventDeposits` (r:1 w:0)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:1 w:1)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Events` (r:1 w:1)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    fn requeue_event() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `91613`
+        Weight::from_parts(25_000_000, 91613)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    fn set_trigger_cooldown() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3783`
+        Weight::from_parts(20_000_000, 3783)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::AccountTriggers` (r:2 w:2)
+    /// Proof: `EventHub::AccountTriggers` (`max_values`: None, `max_size`: Some(8050), added: 10525, mode: `MaxEncodedLen`)
+    fn transfer_trigger() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `24833`
+        Weight::from_parts(30_000_000, 24833)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: `EventHub::HrmpChannels` (r:1 w:1)
+    /// Proof: `EventHub::HrmpChannels` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Excludes the storage touched by `XcmSender` delivering the relay call.
+    fn request_hrmp_channel() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3486`
+        Weight::from_parts(40_000_000, 3486)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `EventHub::HrmpChannels` (r:1 w:1)
+    /// Proof: `EventHub::HrmpChannels` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Excludes the storage touched by `XcmSender` delivering the relay call.
+    fn accept_hrmp_channel() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3486`
+        Weight::from_parts(40_000_000, 3486)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    fn set_source_filter() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3783`
+        Weight::from_parts(20_000_000, 3783)
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    fn set_trigger_delay() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3783`
+        Weight::from_parts(20_000_000, 3783)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerStatistics` (r:0 w:1)
+    /// Proof: `EventHub::TriggerStatistics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::AccountTriggers` (r:1 w:1)
+    /// Proof: `EventHub::AccountTriggers` (`max_values`: None, `max_size`: Some(8050), added: 10525, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:1 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerSubscriptions` (r:1 w:1)
+    /// Proof: `EventHub::TriggerSubscriptions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TopicTriggers` (r:16 w:16)
+    /// Proof: `EventHub::TopicTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    fn remove_trigger() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `73053`
+        Weight::from_parts(45_000_000, 73053)
+            // Worst case: subscribed to 16 topics
+            .saturating_add(T::DbWeight::get().reads(20))
+            .saturating_add(T::DbWeight::get().writes(21))
+    }
 }
 
 impl WeightInfo for () {
-    fn submit_event() -> Weight {
-        Weight::from_parts(30_000_000, 0)
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Events` (r:0 w:2)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:0 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:0 w:1)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:1 w:2)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextEventId` (r:1 w:1)
+    /// Proof: `EventHub::NextEventId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:1)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// The range of component `p` is `[0, 512]`.
+    fn submit_event(p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `173263`
+        Weight::from_parts(38_000_000, 173263)
+            .saturating_add(Weight::from_parts(1_200, 0).saturating_mul(p.into()))
     }
 
+    /// Storage: `EventHub::AccountTriggers` (r:1 w:1)
+    /// Proof: `EventHub::AccountTriggers` (`max_values`: None, `max_size`: Some(8050), added: 10525, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextTriggerId` (r:1 w:1)
+    /// Proof: `EventHub::NextTriggerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Triggers` (r:0 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:1 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
     fn register_trigger() -> Weight {
-        Weight::from_parts(35_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `15319`
+        Weight::from_parts(35_000_000, 15319)
     }
 
-    fn process_event() -> Weight {
-        Weight::from_parts(40_000_000, 0)
+    /// Storage: `EventHub::Events` (r:1 w:201)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:1 w:200)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:1)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextEventId` (r:1 w:1)
+    /// Proof: `EventHub::NextEventId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ActivationReceipts` (r:100 w:100)
+    /// Proof: `EventHub::ActivationReceipts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Triggers` (r:100 w:100)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerStatistics` (r:100 w:100)
+    /// Proof: `EventHub::TriggerStatistics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TopicTriggers` (r:100 w:0)
+    /// Proof: `EventHub::TopicTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:100 w:100)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:100 w:100)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:0 w:100)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// The range of component `t` is `[1, 100]`.
+    fn process_event(t: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `171886`
+        Weight::from_parts(30_000_000, 171886)
+            // Worst case: each trigger emits a derived event
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(t.into()))
+            .saturating_add(Weight::from_parts(0, 16307).saturating_mul(t.into()))
     }
 
+    /// Storage: `EventHub::DelayedActivations` (r:1 w:1)
+    /// Proof: `EventHub::DelayedActivations` (`max_values`: None, `max_size`: Some(1622), added: 4097, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:1)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextEventId` (r:1 w:1)
+    /// Proof: `EventHub::NextEventId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ScheduledActivations` (r:0 w:100)
+    /// Proof: `EventHub::ScheduledActivations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Triggers` (r:100 w:100)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Events` (r:100 w:200)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ActivationReceipts` (r:0 w:100)
+    /// Proof: `EventHub::ActivationReceipts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerStatistics` (r:100 w:100)
+    /// Proof: `EventHub::TriggerStatistics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TopicTriggers` (r:100 w:0)
+    /// Proof: `EventHub::TopicTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:0 w:200)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:0 w:100)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:100 w:100)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:100 w:100)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `d` is `[0, 100]`.
+    fn run_delayed_activations(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `169607`
+        Weight::from_parts(5_000_000, 169607)
+            // Worst case: each activation emits a derived event
+            .saturating_add(Weight::from_parts(27_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(Weight::from_parts(0, 16847).saturating_mul(d.into()))
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
     fn deactivate_trigger() -> Weight {
-        Weight::from_parts(20_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `3783`
+        Weight::from_parts(20_000_000, 3783)
+    }
+
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Events` (r:0 w:2)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:0 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:0 w:1)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:1 w:1)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::NextEventId` (r:1 w:1)
+    /// Proof: `EventHub::NextEventId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:1)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    fn submit_cross_chain_event() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `170660`
+        Weight::from_parts(30_000_000, 170660)
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:0)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TopicTriggers` (r:1 w:1)
+    /// Proof: `EventHub::TopicTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerSubscriptions` (r:1 w:1)
+    /// Proof: `EventHub::TriggerSubscriptions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+    fn subscribe_trigger() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `9712`
+        Weight::from_parts(20_000_000, 9712)
+    }
+
+    /// Storage: `EventHub::PendingWebhooks` (r:1 w:1)
+    /// Proof: `EventHub::PendingWebhooks` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    fn report_webhook() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3541`
+        Weight::from_parts(15_000_000, 3541)
+    }
+
+    /// Storage: `EventHub::Events` (r:1 w:1)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:1 w:1)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventDeposits` (r:1 w:1)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:0 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventCount` (r:1 w:1)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::ProcessedQueue` (r:1 w:1)
+    /// Proof: `EventHub::ProcessedQueue` (`max_values`: Some(1), `max_size`: Some(80002), added: 80497, mode: `MaxEncodedLen`)
+    fn purge_event() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `92718`
+        Weight::from_parts(30_000_000, 92718)
+    }
+
+    /// Storage: `EventHub::EventDeposits` (r:1 w:0)
+    /// Proof: `EventHub::EventDeposits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::DeadLetterEvents` (r:1 w:1)
+    /// Proof: `EventHub::DeadLetterEvents` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::Events` (r:1 w:1)
+    /// Proof: `EventHub::Events` (`max_values`: None, `max_size`: Some(600), added: 3075, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::PendingEvents` (r:1 w:1)
+    /// Proof: `EventHub::PendingEvents` (`max_values`: None, `max_size`: Some(80019), added: 82494, mode: `MaxEncodedLen`)
+    fn requeue_event() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `91613`
+        Weight::from_parts(25_000_000, 91613)
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    fn set_trigger_cooldown() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3783`
+        Weight::from_parts(20_000_000, 3783)
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::AccountTriggers` (r:2 w:2)
+    /// Proof: `EventHub::AccountTriggers` (`max_values`: None, `max_size`: Some(8050), added: 10525, mode: `MaxEncodedLen`)
+    fn transfer_trigger() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `24833`
+        Weight::from_parts(30_000_000, 24833)
+    }
+
+    /// Storage: `EventHub::HrmpChannels` (r:1 w:1)
+    /// Proof: `EventHub::HrmpChannels` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Excludes the storage touched by `XcmSender` delivering the relay call.
+    fn request_hrmp_channel() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3486`
+        Weight::from_parts(40_000_000, 3486)
+    }
+
+    /// Storage: `EventHub::HrmpChannels` (r:1 w:1)
+    /// Proof: `EventHub::HrmpChannels` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Excludes the storage touched by `XcmSender` delivering the relay call.
+    fn accept_hrmp_channel() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3486`
+        Weight::from_parts(40_000_000, 3486)
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    fn set_source_filter() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3783`
+        Weight::from_parts(20_000_000, 3783)
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    fn set_trigger_delay() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3783`
+        Weight::from_parts(20_000_000, 3783)
+    }
+
+    /// Storage: `EventHub::Triggers` (r:1 w:1)
+    /// Proof: `EventHub::Triggers` (`max_values`: None, `max_size`: Some(318), added: 2793, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerStatistics` (r:0 w:1)
+    /// Proof: `EventHub::TriggerStatistics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::AccountTriggers` (r:1 w:1)
+    /// Proof: `EventHub::AccountTriggers` (`max_values`: None, `max_size`: Some(8050), added: 10525, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventTriggers` (r:1 w:1)
+    /// Proof: `EventHub::EventTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TriggerSubscriptions` (r:1 w:1)
+    /// Proof: `EventHub::TriggerSubscriptions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::TopicTriggers` (r:16 w:16)
+    /// Proof: `EventHub::TopicTriggers` (`max_values`: None, `max_size`: Some(826), added: 3301, mode: `MaxEncodedLen`)
+    fn remove_trigger() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `73053`
+        Weight::from_parts(45_000_000, 73053)
     }
 }
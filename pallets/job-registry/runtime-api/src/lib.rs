@@ -0,0 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Job Registry Runtime API
+//!
+//! Lets the node RPC query jobs without decoding raw storage keys.
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_job_registry::{Job, JobStatus};
+
+sp_api::decl_runtime_apis! {
+    pub trait JobRegistryApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Jobs with IDs from `from` upwards, in ID order, at most `limit` entries
+        fn jobs(from: u64, limit: u32) -> Vec<(u64, Job<AccountId, BlockNumber>)>;
+
+        /// Job by ID
+        fn job(id: u64) -> Option<Job<AccountId, BlockNumber>>;
+
+        /// Pending jobs whose dependencies are met, at most `limit` entries
+        fn ready_jobs(limit: u32) -> Vec<u64>;
+    }
+}
@@ -5,7 +5,8 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as JobRegistry;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::traits::SortedMembers;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 
 #[benchmarks]
 mod benchmarks {
@@ -72,5 +73,53 @@ mod benchmarks {
         assert!(Jobs::<T>::get(0).is_none());
     }
 
+    #[benchmark]
+    fn create_template() {
+        let caller: T::AccountId = whitelisted_caller();
+        let metadata = vec![1u8; 256];
+
+        #[extrinsic_call]
+        create_template(RawOrigin::Signed(caller), metadata, 1000u32.into());
+
+        assert_eq!(NextTemplateId::<T>::get(), 1);
+    }
+
+    #[benchmark]
+    fn instantiate_template() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        // Setup: create a template
+        let _ = JobRegistry::<T>::create_template(
+            RawOrigin::Signed(caller.clone()).into(),
+            vec![1u8; 256],
+            1000u32.into(),
+        );
+
+        #[extrinsic_call]
+        instantiate_template(RawOrigin::Signed(caller), 0, vec![1u8; 512]);
+
+        assert!(JobInputs::<T>::get(0).is_some());
+    }
+
+    #[benchmark]
+    fn claim_job() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Workers::add(&caller);
+
+        // Setup: create a job with an expired claim
+        let _ = JobRegistry::<T>::submit_job(
+            RawOrigin::Signed(caller.clone()).into(),
+            vec![1u8; 256],
+            vec![],
+            1000u32.into(),
+        );
+        JobClaims::<T>::insert(0, (caller.clone(), BlockNumberFor::<T>::from(0u32)));
+
+        #[extrinsic_call]
+        claim_job(RawOrigin::Signed(caller.clone()), 0);
+
+        assert_eq!(JobRegistry::<T>::active_claim(0), Some(caller));
+    }
+
     impl_benchmark_test_suite!(JobRegistry, crate::mock::new_test_ext(), crate::mock::Test);
 }
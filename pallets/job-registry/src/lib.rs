@@ -5,6 +5,8 @@
 //! This pallet manages job registration, tracking, dependencies, and status updates.
 //! It provides the core functionality for job orchestration in PolkaComputeLab.
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -19,14 +21,47 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+pub mod ocw;
+pub mod oracle;
+pub mod sandbox;
+
+/// Keystore key type of the offchain workers submitting job results and event processing
+pub const KEY_TYPE: sp_core::crypto::KeyTypeId = sp_core::crypto::KeyTypeId(*b"pcl!");
+
+/// Offchain worker crypto, sr25519 keys stored under [`KEY_TYPE`]
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Signs offchain worker transactions of a runtime using `MultiSignature`
+    pub struct OcwAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OcwAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, traits::SortedMembers};
     use frame_system::pallet_prelude::*;
+    use sp_core::H256;
+    use sp_runtime::traits::Saturating;
     use sp_std::vec::Vec;
 
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Job status enumeration
@@ -80,8 +115,65 @@ pub mod pallet {
         pub completed_at: Option<BlockNumber>,
     }
 
+    /// Reusable job blueprint, instantiated with a per-job input
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct JobTemplate<AccountId, BlockNumber> {
+        /// Template owner
+        pub owner: AccountId,
+        /// Metadata copied into every instantiated job
+        pub metadata: BoundedVec<u8, ConstU32<256>>,
+        /// Deadline of instantiated jobs, relative to instantiation
+        pub deadline_offset: BlockNumber,
+    }
+
+    /// Hook invoked after every job status transition
+    pub trait OnJobStatusChanged {
+        fn on_job_status_changed(job_id: u64, old_status: &JobStatus, new_status: &JobStatus);
+    }
+
+    impl OnJobStatusChanged for () {
+        fn on_job_status_changed(_job_id: u64, _old_status: &JobStatus, _new_status: &JobStatus) {}
+    }
+
+    impl<A: OnJobStatusChanged, B: OnJobStatusChanged> OnJobStatusChanged for (A, B) {
+        fn on_job_status_changed(job_id: u64, old_status: &JobStatus, new_status: &JobStatus) {
+            A::on_job_status_changed(job_id, old_status, new_status);
+            B::on_job_status_changed(job_id, old_status, new_status);
+        }
+    }
+
+    /// Movement of value reported to economic observers, in the runtime's balance unit
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum EconomicFlow {
+        /// Funds locked in escrow for a job
+        EscrowLocked,
+        /// Escrowed funds released or refunded
+        EscrowReleased,
+        /// Bounty paid out for completed work
+        BountyPaid,
+        /// Storage or participation deposit reserved
+        DepositHeld,
+        /// Deposit returned
+        DepositReleased,
+        /// Fees burned rather than paid to anyone
+        FeesBurned,
+    }
+
+    /// Hook invoked whenever a pallet moves value that marketplace metrics track
+    pub trait OnEconomicFlow {
+        fn on_economic_flow(flow: EconomicFlow, amount: u128);
+    }
+
+    impl OnEconomicFlow for () {
+        fn on_economic_flow(_flow: EconomicFlow, _amount: u128) {}
+    }
+
     #[pallet::config]
-    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+    pub trait Config:
+        frame_system::Config<RuntimeEvent: From<Event<Self>>>
+        + frame_system::offchain::CreateSignedTransaction<Call<Self>>
+    {
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
 
@@ -92,6 +184,20 @@ pub mod pallet {
         /// Maximum job dependency depth
         #[pallet::constant]
         type MaxDependencyDepth: Get<u32>;
+
+        /// Notified of every job status transition
+        type OnJobStatusChanged: OnJobStatusChanged;
+
+        /// Blocks a claim reserves a job for the claiming offchain worker
+        #[pallet::constant]
+        type ClaimDuration: Get<BlockNumberFor<Self>>;
+
+        /// Key the offchain workers sign job claims, results, event processing and webhook
+        /// reports with
+        type AuthorityId: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>;
+
+        /// Accounts whose offchain workers may claim jobs and deliver webhooks
+        type Workers: SortedMembers<Self::AccountId>;
     }
 
     /// Counter for generating unique job IDs
@@ -102,7 +208,7 @@ pub mod pallet {
     /// Map from JobId to Job
     #[pallet::storage]
     #[pallet::getter(fn jobs)]
-    pub type Jobs<T: Config> = StorageMap<
+    pub type Jobs<T: Config> = CountedStorageMap<
         _,
         Blake2_128Concat,
         u64,
@@ -131,6 +237,51 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Counter for generating unique template IDs
+    #[pallet::storage]
+    #[pallet::getter(fn next_template_id)]
+    pub type NextTemplateId<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// Map from TemplateId to JobTemplate
+    #[pallet::storage]
+    #[pallet::getter(fn job_templates)]
+    pub type JobTemplates<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        JobTemplate<T::AccountId, BlockNumberFor<T>>,
+    >;
+
+    /// Map from JobId to the input injected at instantiation
+    #[pallet::storage]
+    #[pallet::getter(fn job_inputs)]
+    pub type JobInputs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        BoundedVec<u8, ConstU32<512>>,
+    >;
+
+    /// Map from JobId to the correlation ID of the automation chain that started it
+    #[pallet::storage]
+    #[pallet::getter(fn job_correlation_ids)]
+    pub type JobCorrelationIds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        H256,
+    >;
+
+    /// Map from JobId to the offchain worker account executing it, and when its claim expires
+    #[pallet::storage]
+    #[pallet::getter(fn job_claims)]
+    pub type JobClaims<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        (T::AccountId, BlockNumberFor<T>),
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -144,6 +295,14 @@ pub mod pallet {
         JobFailed { job_id: u64 },
         /// Job was removed [job_id]
         JobRemoved { job_id: u64 },
+        /// Job template created [template_id, owner]
+        TemplateCreated { template_id: u64, owner: T::AccountId },
+        /// Job instantiated from a template [job_id, template_id]
+        JobInstantiated { job_id: u64, template_id: u64 },
+        /// Job linked to an automation chain [job_id, correlation_id]
+        JobCorrelated { job_id: u64, correlation_id: H256 },
+        /// Job claimed for execution [job_id, claimer, expires_at]
+        JobClaimed { job_id: u64, claimer: T::AccountId, expires_at: BlockNumberFor<T> },
     }
 
     #[pallet::error]
@@ -172,6 +331,26 @@ pub mod pallet {
         TooManyDependencies,
         /// Maximum dependency depth exceeded
         MaxDependencyDepthExceeded,
+        /// Job template not found
+        TemplateNotFound,
+        /// Job input too large
+        InputTooLarge,
+        /// Job is not pending, has unmet dependencies or has expired
+        JobNotClaimable,
+        /// Job is already claimed by an offchain worker
+        JobAlreadyClaimed,
+        /// Account is not an authorized worker
+        NotAWorker,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Execute ready jobs, recording their results for the job verifier's offchain worker
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            if let Err(e) = ocw::process_pending_jobs::<T>(block_number) {
+                log::error!(target: "job-registry", "OCW: Error processing jobs: {:?}", e);
+            }
+        }
     }
 
     #[pallet::call]
@@ -198,57 +377,7 @@ pub mod pallet {
                 .try_into()
                 .map_err(|_| Error::<T>::MetadataTooLarge)?;
 
-            // Validate dependencies
-            let bounded_dependencies: BoundedVec<u64, ConstU32<10>> = dependencies
-                .try_into()
-                .map_err(|_| Error::<T>::TooManyDependencies)?;
-
-            // Validate deadline
-            let current_block = frame_system::Pallet::<T>::block_number();
-            ensure!(deadline > current_block, Error::<T>::DeadlineInPast);
-
-            // Check dependencies exist and validate no circular dependencies
-            for dep_id in bounded_dependencies.iter() {
-                ensure!(Jobs::<T>::contains_key(dep_id), Error::<T>::DependencyNotFound);
-                Self::check_circular_dependency(*dep_id, &bounded_dependencies)?;
-            }
-
-            // Check max jobs per account
-            let mut account_job_list = AccountJobs::<T>::get(&who);
-            ensure!(
-                (account_job_list.len() as u32) < T::MaxJobsPerAccount::get(),
-                Error::<T>::MaxJobsReached
-            );
-
-            // Generate new job ID
-            let job_id = NextJobId::<T>::get();
-            let next_id = job_id.checked_add(1).ok_or(Error::<T>::JobNotFound)?;
-            NextJobId::<T>::put(next_id);
-
-            // Create job
-            let job = Job {
-                owner: who.clone(),
-                metadata: bounded_metadata,
-                dependencies: bounded_dependencies,
-                deadline,
-                status: JobStatus::Pending,
-                submitted_at: current_block,
-                completed_at: None,
-            };
-
-            // Store job
-            Jobs::<T>::insert(job_id, job);
-
-            // Add to account jobs
-            account_job_list.try_push(job_id).map_err(|_| Error::<T>::MaxJobsReached)?;
-            AccountJobs::<T>::insert(&who, account_job_list);
-
-            // Add to pending jobs
-            let mut pending_jobs = JobsByStatus::<T>::get(JobStatus::Pending);
-            let _ = pending_jobs.try_push(job_id);
-            JobsByStatus::<T>::insert(JobStatus::Pending, pending_jobs);
-
-            Self::deposit_event(Event::JobSubmitted { job_id, owner: who });
+            Self::do_submit_job(who, bounded_metadata, dependencies, deadline)?;
 
             Ok(())
         }
@@ -289,6 +418,9 @@ pub mod pallet {
                 if matches!(new_status, JobStatus::Completed | JobStatus::Verified) {
                     job.completed_at = Some(frame_system::Pallet::<T>::block_number());
                 }
+                if matches!(new_status, JobStatus::Verified | JobStatus::Failed) {
+                    JobClaims::<T>::remove(job_id);
+                }
 
                 // Update status index
                 Self::update_job_status_index(job_id, &old_status, &new_status)?;
@@ -306,6 +438,8 @@ pub mod pallet {
                     Self::deposit_event(Event::JobFailed { job_id });
                 }
 
+                T::OnJobStatusChanged::on_job_status_changed(job_id, &old_status, &new_status);
+
                 Ok(())
             })
         }
@@ -336,6 +470,9 @@ pub mod pallet {
 
             // Remove from storage
             Jobs::<T>::remove(job_id);
+            JobCorrelationIds::<T>::remove(job_id);
+            JobInputs::<T>::remove(job_id);
+            JobClaims::<T>::remove(job_id);
 
             // Remove from account jobs
             AccountJobs::<T>::mutate(&who, |jobs| {
@@ -351,10 +488,162 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Create a job template
+        ///
+        /// # Parameters
+        /// - `origin`: The template owner
+        /// - `metadata`: Metadata copied into every instantiated job
+        /// - `deadline_offset`: Blocks between instantiation and the job deadline
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::create_template())]
+        pub fn create_template(
+            origin: OriginFor<T>,
+            metadata: Vec<u8>,
+            deadline_offset: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let bounded_metadata: BoundedVec<u8, ConstU32<256>> = metadata
+                .try_into()
+                .map_err(|_| Error::<T>::MetadataTooLarge)?;
+
+            let template_id = NextTemplateId::<T>::get();
+            NextTemplateId::<T>::put(template_id.saturating_add(1));
+
+            JobTemplates::<T>::insert(template_id, JobTemplate {
+                owner: who.clone(),
+                metadata: bounded_metadata,
+                deadline_offset,
+            });
+
+            Self::deposit_event(Event::TemplateCreated { template_id, owner: who });
+
+            Ok(())
+        }
+
+        /// Instantiate a job from a template
+        ///
+        /// # Parameters
+        /// - `origin`: The owner of the new job
+        /// - `template_id`: The template to instantiate
+        /// - `input`: Job input (stored alongside the job)
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::instantiate_template())]
+        pub fn instantiate_template(
+            origin: OriginFor<T>,
+            template_id: u64,
+            input: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_instantiate_template(who, template_id, input)?;
+
+            Ok(())
+        }
+
+        /// Claim a ready job for execution by the caller's offchain worker
+        ///
+        /// The claim lasts `ClaimDuration` blocks, during which other offchain workers leave
+        /// the job alone and only the claimer may submit its result. An expired claim may be
+        /// taken over. Only accounts in `Workers` may claim, so that no one else can hold
+        /// jobs hostage.
+        ///
+        /// # Parameters
+        /// - `origin`: The offchain worker account, one of `Workers`
+        /// - `job_id`: The job to claim
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::claim_job())]
+        pub fn claim_job(
+            origin: OriginFor<T>,
+            job_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::Workers::contains(&who), Error::<T>::NotAWorker);
+
+            let job = Jobs::<T>::get(job_id).ok_or(Error::<T>::JobNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                job.status == JobStatus::Pending
+                    && job.deadline > now
+                    && Self::are_dependencies_met(job_id),
+                Error::<T>::JobNotClaimable
+            );
+            ensure!(Self::active_claim(job_id).is_none(), Error::<T>::JobAlreadyClaimed);
+
+            let expires_at = now.saturating_add(T::ClaimDuration::get());
+            JobClaims::<T>::insert(job_id, (who.clone(), expires_at));
+
+            Self::deposit_event(Event::JobClaimed { job_id, claimer: who, expires_at });
+
+            Ok(())
+        }
     }
 
     // Helper functions
     impl<T: Config> Pallet<T> {
+        /// Validate and store a new job, returning its ID
+        fn do_submit_job(
+            who: T::AccountId,
+            metadata: BoundedVec<u8, ConstU32<256>>,
+            dependencies: Vec<u64>,
+            deadline: BlockNumberFor<T>,
+        ) -> Result<u64, DispatchError> {
+            // Validate dependencies
+            let bounded_dependencies: BoundedVec<u64, ConstU32<10>> = dependencies
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyDependencies)?;
+
+            // Validate deadline
+            let current_block = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > current_block, Error::<T>::DeadlineInPast);
+
+            // Check dependencies exist and validate no circular dependencies
+            for dep_id in bounded_dependencies.iter() {
+                ensure!(Jobs::<T>::contains_key(dep_id), Error::<T>::DependencyNotFound);
+                Self::check_circular_dependency(*dep_id, &bounded_dependencies)?;
+            }
+
+            // Check max jobs per account
+            let mut account_job_list = AccountJobs::<T>::get(&who);
+            ensure!(
+                (account_job_list.len() as u32) < T::MaxJobsPerAccount::get(),
+                Error::<T>::MaxJobsReached
+            );
+
+            // Generate new job ID
+            let job_id = NextJobId::<T>::get();
+            let next_id = job_id.checked_add(1).ok_or(Error::<T>::JobNotFound)?;
+            NextJobId::<T>::put(next_id);
+
+            // Create job
+            let job = Job {
+                owner: who.clone(),
+                metadata,
+                dependencies: bounded_dependencies,
+                deadline,
+                status: JobStatus::Pending,
+                submitted_at: current_block,
+                completed_at: None,
+            };
+
+            // Store job
+            Jobs::<T>::insert(job_id, job);
+
+            // Add to account jobs
+            account_job_list.try_push(job_id).map_err(|_| Error::<T>::MaxJobsReached)?;
+            AccountJobs::<T>::insert(&who, account_job_list);
+
+            // Add to pending jobs
+            let mut pending_jobs = JobsByStatus::<T>::get(JobStatus::Pending);
+            let _ = pending_jobs.try_push(job_id);
+            JobsByStatus::<T>::insert(JobStatus::Pending, pending_jobs);
+
+            Self::deposit_event(Event::JobSubmitted { job_id, owner: who });
+
+            Ok(job_id)
+        }
+
         /// Check if adding this dependency would create a circular dependency
         fn check_circular_dependency(
             dep_id: u64,
@@ -417,6 +706,35 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Create a job from a template with the given input, returning its ID
+        pub fn do_instantiate_template(
+            who: T::AccountId,
+            template_id: u64,
+            input: Vec<u8>,
+        ) -> Result<u64, DispatchError> {
+            let template = JobTemplates::<T>::get(template_id).ok_or(Error::<T>::TemplateNotFound)?;
+
+            let bounded_input: BoundedVec<u8, ConstU32<512>> = input
+                .try_into()
+                .map_err(|_| Error::<T>::InputTooLarge)?;
+
+            let deadline = frame_system::Pallet::<T>::block_number()
+                .saturating_add(template.deadline_offset);
+
+            let job_id = Self::do_submit_job(who, template.metadata, Vec::new(), deadline)?;
+            JobInputs::<T>::insert(job_id, bounded_input);
+
+            Self::deposit_event(Event::JobInstantiated { job_id, template_id });
+
+            Ok(job_id)
+        }
+
+        /// Link a job to the correlation ID of the automation chain that started it
+        pub fn set_correlation_id(job_id: u64, correlation_id: H256) {
+            JobCorrelationIds::<T>::insert(job_id, correlation_id);
+            Self::deposit_event(Event::JobCorrelated { job_id, correlation_id });
+        }
+
         /// Check if all dependencies for a job are completed
         pub fn are_dependencies_met(job_id: u64) -> bool {
             if let Some(job) = Jobs::<T>::get(job_id) {
@@ -435,6 +753,22 @@ pub mod pallet {
             }
         }
 
+        /// Account holding an unexpired claim on a job, if any
+        pub fn active_claim(job_id: u64) -> Option<T::AccountId> {
+            let now = frame_system::Pallet::<T>::block_number();
+            JobClaims::<T>::get(job_id)
+                .filter(|(_, expires_at)| *expires_at > now)
+                .map(|(claimer, _)| claimer)
+        }
+
+        /// Get jobs with IDs from `from` upwards, in ID order, at most `limit` entries
+        pub fn get_jobs(from: u64, limit: u32) -> Vec<(u64, Job<T::AccountId, BlockNumberFor<T>>)> {
+            (from..NextJobId::<T>::get())
+                .filter_map(|job_id| Jobs::<T>::get(job_id).map(|job| (job_id, job)))
+                .take(limit as usize)
+                .collect()
+        }
+
         /// Get all pending jobs that are ready to execute (dependencies met)
         pub fn get_ready_jobs() -> Vec<u64> {
             let pending_jobs = JobsByStatus::<T>::get(JobStatus::Pending);
@@ -1,10 +1,12 @@
 use crate as pallet_job_registry;
 use frame_support::{
     parameter_types,
-    traits::{ConstU16, ConstU32, ConstU64},
+    traits::{ConstU16, ConstU32, ConstU64, SortedMembers},
 };
+use frame_system::offchain::AppCrypto;
 use sp_core::H256;
 use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
@@ -45,11 +47,74 @@ impl frame_system::Config for Test {
     type MaxConsumers = ConstU32<16>;
 }
 
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+/// Offchain worker keys, each signing for the account of the same number
+pub struct TestAuthId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type GenericPublic = UintAuthorityId;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = Extrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_signed_transaction<A: AppCrypto<UintAuthorityId, TestSignature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        account: u64,
+        _nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, account, (), ()))
+    }
+}
+
+parameter_types! {
+    /// Accounts allowed to claim jobs
+    pub static Workers: Vec<u64> = vec![7, 8];
+}
+
+/// `Workers`, which benchmarks add their caller to
+pub struct TestWorkers;
+
+impl SortedMembers<u64> for TestWorkers {
+    fn sorted_members() -> Vec<u64> {
+        let mut workers = Workers::get();
+        workers.sort();
+        workers
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &u64) {
+        Workers::mutate(|workers| workers.push(*who));
+    }
+}
+
 impl pallet_job_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type MaxJobsPerAccount = ConstU32<100>;
     type MaxDependencyDepth = ConstU32<10>;
+    type OnJobStatusChanged = ();
+    type ClaimDuration = ConstU64<10>;
+    type AuthorityId = TestAuthId;
+    type Workers = TestWorkers;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
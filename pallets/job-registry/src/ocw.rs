@@ -0,0 +1,792 @@
+//! Offchain worker logic for job execution
+//!
+//! The job registry's offchain worker executes ready jobs and records each
+//! [`JobExecutionResult`] in persistent offchain storage. The job verifier's offchain worker
+//! submits recorded results on-chain with a proof, once the job is in progress. Also exposes
+//! the HTTP helpers shared by the offchain workers of other pallets.
+//!
+//! Jobs whose metadata is a [`JobSpec`] run as the spec describes; other jobs produce a
+//! placeholder result.
+//!
+//! So that collators do not all execute the same job, a worker first claims a ready job
+//! on-chain through `claim_job`, and executes it only once its claim is live. Only keys of
+//! accounts in the runtime's `Workers` claim jobs. A per-job [`StorageLock`] keeps
+//! concurrent runs of the same node from claiming or executing a job twice.
+//!
+//! Jobs for a [`JobSpec::External`] executor are delegated to the node, which runs the
+//! executors its operator configured: the offchain worker queues the job in offchain storage and
+//! records the node's outcome on a later run, once the executor finished.
+//!
+//! Runs of consecutive blocks overlap when jobs outlast a block, each on its own offchain
+//! worker thread. The node operator bounds how many of them execute jobs at once, and how long
+//! a job may take, through the [`OcwConfig`] the node writes from its command line.
+//!
+//! Results are cached by job ID along with the state of their submission, and reused when a
+//! job is executed again, after a restart or a lost claim, from the same metadata and input.
+//! Cached results expire after the configured TTL.
+
+use crate::{
+    oracle,
+    sandbox::{self, SandboxLimits},
+    Call, Config, JobInputs, Pallet,
+};
+use codec::{Decode, DecodeAll, Encode};
+use frame_support::traits::SortedMembers;
+use frame_system::{
+    offchain::{AppCrypto, SendSignedTransaction, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_core::H256;
+use sp_runtime::{
+    offchain::{
+        http,
+        storage::StorageValueRef,
+        storage_lock::{BlockAndTime, StorageLock, Time},
+        Duration, Timestamp,
+    },
+    traits::IdentifyAccount,
+    RuntimeAppPublic, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Persistent offchain storage key listing executed jobs whose results await submission
+pub const EXECUTED_JOBS_KEY: &[u8] = b"pcl::executed_jobs";
+
+/// Persistent offchain storage key of this node's [`OcwMetrics`], read by its Prometheus
+/// exporter
+pub const METRICS_KEY: &[u8] = b"pcl::metrics";
+
+/// Persistent offchain storage key prefix of a job's `JobExecutionResult`
+pub const RESULT_KEY_PREFIX: &[u8] = b"pcl::result::";
+
+/// Maximum number of jobs executed per offchain worker run
+pub const MAX_JOBS_PER_RUN: usize = 5;
+
+/// Persistent offchain storage key prefix of a job's lock
+pub const LOCK_KEY_PREFIX: &[u8] = b"pcl::lock::job::";
+
+/// Persistent offchain storage key prefix of the execution slot locks, one per job this node
+/// may execute at once
+pub const SLOT_KEY_PREFIX: &[u8] = b"pcl::lock::slot::";
+
+/// Persistent offchain storage key prefix of a job's [`CachedResult`]
+pub const CACHE_KEY_PREFIX: &[u8] = b"pcl::cache::job::";
+
+/// Persistent offchain storage key of the IDs of the jobs with a cached result, with the UNIX
+/// time in milliseconds they were cached at
+pub const CACHE_INDEX_KEY: &[u8] = b"pcl::cache::index";
+
+/// Persistent offchain storage key of the [`OcwConfig`] set by the node
+pub const OCW_CONFIG_KEY: &[u8] = b"pcl::ocw_config";
+
+/// Blocks after which a job lock expires, once [`LOCK_TIMEOUT_MS`] have passed too. The lock
+/// of a job this node submitted a claim on is kept until then, by when the claim should be
+/// on-chain.
+pub const LOCK_BLOCK_EXPIRATION: u32 = 2;
+
+/// Milliseconds after which a job lock expires, once [`LOCK_BLOCK_EXPIRATION`] blocks have
+/// passed too
+pub const LOCK_TIMEOUT_MS: u64 = 12_000;
+
+/// Persistent offchain storage key of the IPFS gateway URL `ipfs://` modules are fetched from
+pub const IPFS_GATEWAY_KEY: &[u8] = b"pcl::ipfs_gateway";
+
+/// IPFS gateway used when none is configured
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+/// Prefix marking job metadata as a SCALE-encoded [`JobSpec`]
+pub const JOB_SPEC_PREFIX: &[u8] = b"pcl:";
+
+/// Maximum size of a fetched WASM module
+pub const MAX_MODULE_SIZE: usize = 1024 * 1024;
+
+/// Maximum fuel a WASM job may request
+pub const MAX_WASM_FUEL: u64 = 1_000_000_000;
+
+/// Persistent offchain storage key of the names of the external executors the node runs, set
+/// by the node
+pub const EXTERNAL_EXECUTORS_KEY: &[u8] = b"pcl::external::executors";
+
+/// Persistent offchain storage key of the IDs of the jobs queued for the node's external
+/// executors
+pub const EXTERNAL_QUEUE_KEY: &[u8] = b"pcl::external::queue";
+
+/// Persistent offchain storage key prefix of a job's [`ExternalExecution`]
+pub const EXTERNAL_EXECUTION_PREFIX: &[u8] = b"pcl::external::job::";
+
+/// Longest time an external executor may run for
+pub const MAX_EXTERNAL_TIMEOUT_MS: u64 = 600_000;
+
+/// Error of a job waiting for the node's external executor, retried on a later run
+const EXTERNAL_PENDING: &str = "External execution pending";
+
+/// How the offchain worker executes a job, encoded in its metadata after [`JOB_SPEC_PREFIX`]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum JobSpec {
+    /// Run a WASM module in the sandbox, on the job's input
+    Wasm {
+        /// Blake2-256 hash of the module code
+        code_hash: H256,
+        /// Where the code is fetched from: an HTTP(S) URL or `ipfs://<cid>`
+        uri: Vec<u8>,
+        /// Fuel the execution may consume, capped at [`MAX_WASM_FUEL`]
+        fuel: u64,
+    },
+    /// Read a number from redundant HTTP sources; the result is the SCALE-encoded `i128`
+    /// median across the sources that answered
+    OracleFetch {
+        /// URLs queried for the value
+        urls: Vec<Vec<u8>>,
+        /// Dot-separated path of the value in each JSON response, e.g. `data.0.price`
+        path: Vec<u8>,
+        /// Decimal places the value is scaled by before truncating to an integer
+        decimals: u8,
+        /// Sources that must answer for the result to count
+        min_sources: u8,
+    },
+    /// Run a native command or OCI container the node's operator configured under `executor`,
+    /// on the job's input; the result is what it writes to stdout. Only nodes running that
+    /// executor claim the job.
+    External {
+        /// Name of the executor on the nodes
+        executor: Vec<u8>,
+        /// Time the executor may run for, capped at [`MAX_EXTERNAL_TIMEOUT_MS`]
+        timeout_ms: u64,
+    },
+}
+
+impl JobSpec {
+    /// Parse job metadata, `None` if it is not a job spec
+    pub fn from_metadata(metadata: &[u8]) -> Option<Self> {
+        let mut encoded = metadata.strip_prefix(JOB_SPEC_PREFIX)?;
+        Self::decode_all(&mut encoded).ok()
+    }
+
+    /// Job metadata describing this spec
+    pub fn to_metadata(&self) -> Vec<u8> {
+        let mut metadata = JOB_SPEC_PREFIX.to_vec();
+        self.encode_to(&mut metadata);
+        metadata
+    }
+}
+
+/// Execution settings of this node's offchain worker, from the node's command line
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct OcwConfig {
+    /// Jobs executed at once, across the overlapping runs of consecutive blocks
+    pub max_parallel_jobs: u32,
+    /// Time budget of one job execution in milliseconds, bounding its HTTP requests
+    pub job_timeout_ms: u64,
+    /// Milliseconds a result stays cached
+    pub result_cache_ttl_ms: u64,
+}
+
+impl Default for OcwConfig {
+    fn default() -> Self {
+        Self { max_parallel_jobs: 1, job_timeout_ms: 10_000, result_cache_ttl_ms: 604_800_000 }
+    }
+}
+
+/// Execution settings set by the node, or the defaults
+pub fn ocw_config() -> OcwConfig {
+    StorageValueRef::persistent(OCW_CONFIG_KEY)
+        .get::<OcwConfig>()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// State of a job delegated to the node's external executors
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ExternalExecution {
+    /// Queued for the node to run `executor` on `input`
+    Queued { executor: Vec<u8>, input: Vec<u8>, timeout_ms: u64 },
+    /// Being run by the node until `deadline`, a UNIX timestamp in milliseconds
+    Running { deadline: u64 },
+    /// Run by the node; `output` is what the executor wrote to stdout
+    Finished { success: bool, output: Vec<u8> },
+}
+
+/// Persistent offchain storage key of a job's [`ExternalExecution`]
+pub fn external_execution_key(job_id: u64) -> Vec<u8> {
+    let mut key = EXTERNAL_EXECUTION_PREFIX.to_vec();
+    key.extend_from_slice(&job_id.to_le_bytes());
+    key
+}
+
+/// Names of the external executors the node runs
+pub fn external_executors() -> Vec<Vec<u8>> {
+    StorageValueRef::persistent(EXTERNAL_EXECUTORS_KEY)
+        .get::<Vec<Vec<u8>>>()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Job execution result
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct JobExecutionResult {
+    pub job_id: u64,
+    pub result_data: Vec<u8>,
+    pub execution_time_ms: u64,
+    pub success: bool,
+}
+
+/// Submission state of a cached result
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum SubmissionStatus {
+    /// Not submitted yet
+    Pending,
+    /// Submitted to the transaction pool
+    Submitted,
+    /// Submission failed `attempts` times
+    Failed { attempts: u32 },
+}
+
+/// A job's result kept by this node, reused rather than executing the job again
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CachedResult {
+    /// [`job_fingerprint`] of the metadata and input the result was computed from
+    pub fingerprint: H256,
+    pub result: JobExecutionResult,
+    pub submission: SubmissionStatus,
+}
+
+/// Hash identifying what a job computes: its metadata and input
+pub fn job_fingerprint(metadata: &[u8], input: &[u8]) -> H256 {
+    H256((metadata, input).using_encoded(sp_io::hashing::blake2_256))
+}
+
+/// Persistent offchain storage key of a job's cached result
+pub fn cache_key(job_id: u64) -> Vec<u8> {
+    let mut key = CACHE_KEY_PREFIX.to_vec();
+    key.extend_from_slice(&job_id.to_le_bytes());
+    key
+}
+
+/// Result cached for a job by this node
+pub fn cached_result(job_id: u64) -> Option<CachedResult> {
+    StorageValueRef::persistent(&cache_key(job_id)).get().ok().flatten()
+}
+
+/// Cache a job's result, replacing any previous one
+fn cache_result(fingerprint: H256, result: &JobExecutionResult) {
+    let job_id = result.job_id;
+    let cached =
+        CachedResult { fingerprint, result: result.clone(), submission: SubmissionStatus::Pending };
+    StorageValueRef::persistent(&cache_key(job_id)).set(&cached);
+
+    let now = sp_io::offchain::timestamp().unix_millis();
+    let _ = StorageValueRef::persistent(CACHE_INDEX_KEY).mutate(
+        |index: Result<Option<Vec<(u64, u64)>>, _>| -> Result<_, ()> {
+            let mut index = index.ok().flatten().unwrap_or_default();
+            index.retain(|(id, _)| *id != job_id);
+            index.push((job_id, now));
+            Ok(index)
+        },
+    );
+}
+
+/// Record a submission attempt of a job's cached result
+pub fn record_submission(job_id: u64, submitted: bool) {
+    let _ = StorageValueRef::persistent(&cache_key(job_id)).mutate(
+        |cached: Result<Option<CachedResult>, _>| -> Result<_, ()> {
+            let mut cached = cached.ok().flatten().ok_or(())?;
+            cached.submission = match (submitted, cached.submission) {
+                (true, _) => SubmissionStatus::Submitted,
+                (false, SubmissionStatus::Failed { attempts }) => {
+                    SubmissionStatus::Failed { attempts: attempts.saturating_add(1) }
+                }
+                (false, _) => SubmissionStatus::Failed { attempts: 1 },
+            };
+            Ok(cached)
+        },
+    );
+}
+
+/// Drop the results cached longer than `ttl_ms` ago, returning how many were dropped
+pub fn prune_result_cache(ttl_ms: u64) -> usize {
+    let now = sp_io::offchain::timestamp().unix_millis();
+    let index = StorageValueRef::persistent(CACHE_INDEX_KEY);
+    let Ok(Some(cached)) = index.get::<Vec<(u64, u64)>>() else { return 0 };
+    let expired: Vec<u64> = cached
+        .into_iter()
+        .filter(|(_, cached_at)| now.saturating_sub(*cached_at) >= ttl_ms)
+        .map(|(job_id, _)| job_id)
+        .collect();
+    if expired.is_empty() {
+        return 0;
+    }
+
+    // Results cached concurrently are kept for the next run to prune
+    let pruned = index.mutate(|index: Result<Option<Vec<(u64, u64)>>, _>| -> Result<_, ()> {
+        let mut index = index.ok().flatten().unwrap_or_default();
+        index.retain(|(job_id, _)| !expired.contains(job_id));
+        Ok(index)
+    });
+    if pruned.is_err() {
+        return 0;
+    }
+    for job_id in &expired {
+        StorageValueRef::persistent(&cache_key(*job_id)).clear();
+    }
+    expired.len()
+}
+
+/// Running totals of the offchain workers of this node
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct OcwMetrics {
+    /// Jobs executed, successfully or not
+    pub executions: u64,
+    /// Sum of the execution times of those jobs, in milliseconds
+    pub execution_time_ms_total: u64,
+    /// Execution time of the latest job, in milliseconds
+    pub last_execution_time_ms: u64,
+    /// Results submitted on-chain with their proof
+    pub proofs_submitted: u64,
+    /// Results whose submission failed
+    pub proof_submission_failures: u64,
+}
+
+/// Metrics recorded by the offchain workers of this node
+pub fn metrics() -> OcwMetrics {
+    StorageValueRef::persistent(METRICS_KEY)
+        .get::<OcwMetrics>()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Update the metrics of this node, atomically with respect to concurrent workers
+pub fn record_metrics(update: impl FnOnce(&mut OcwMetrics)) {
+    let _ = StorageValueRef::persistent(METRICS_KEY).mutate(
+        |metrics: Result<Option<OcwMetrics>, _>| -> Result<_, ()> {
+            let mut metrics = metrics.ok().flatten().unwrap_or_default();
+            update(&mut metrics);
+            Ok(metrics)
+        },
+    );
+}
+
+/// Persistent offchain storage key of a job's execution result
+pub fn result_key(job_id: u64) -> Vec<u8> {
+    let mut key = RESULT_KEY_PREFIX.to_vec();
+    key.extend_from_slice(&job_id.to_le_bytes());
+    key
+}
+
+/// Jobs executed by this node whose results have not been submitted yet
+pub fn executed_jobs() -> Vec<u64> {
+    StorageValueRef::persistent(EXECUTED_JOBS_KEY)
+        .get::<Vec<u64>>()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Execution result recorded for a job by this node
+pub fn execution_result(job_id: u64) -> Option<JobExecutionResult> {
+    StorageValueRef::persistent(&result_key(job_id)).get().ok().flatten()
+}
+
+/// Forget a job's execution result, once submitted or no longer needed
+pub fn clear_execution_result(job_id: u64) {
+    StorageValueRef::persistent(&result_key(job_id)).clear();
+
+    let mut executed = executed_jobs();
+    executed.retain(|id| *id != job_id);
+    StorageValueRef::persistent(EXECUTED_JOBS_KEY).set(&executed);
+}
+
+/// Deadline of a job lock, passed once both its block and its time limit have passed
+type JobLockDeadline<T> = BlockAndTime<frame_system::Pallet<T>>;
+
+/// Persistent offchain storage key of a job's lock
+pub fn lock_key(job_id: u64) -> Vec<u8> {
+    let mut key = LOCK_KEY_PREFIX.to_vec();
+    key.extend_from_slice(&job_id.to_le_bytes());
+    key
+}
+
+/// Persistent offchain storage key of an execution slot's lock
+pub fn slot_key(slot: u32) -> Vec<u8> {
+    let mut key = SLOT_KEY_PREFIX.to_vec();
+    key.extend_from_slice(&slot.to_le_bytes());
+    key
+}
+
+/// Offchain worker keys in this node's keystore, with the accounts they sign for
+pub fn local_keys<T: Config>() -> Vec<(T::Public, T::AccountId)> {
+    <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+        .into_iter()
+        .map(|key| {
+            let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+                key.into();
+            let public: T::Public = generic.into();
+            (public.clone(), public.into_account())
+        })
+        .collect()
+}
+
+/// Keys in this node's keystore of accounts authorized as workers
+pub fn worker_keys<T: Config>() -> Vec<(T::Public, T::AccountId)> {
+    local_keys::<T>().into_iter().filter(|(_, account)| T::Workers::contains(account)).collect()
+}
+
+/// Claim ready jobs, and execute those claimed by this node, recording their results
+pub(crate) fn process_pending_jobs<T: Config>(
+    block_number: BlockNumberFor<T>,
+) -> Result<(), &'static str> {
+    log::debug!(target: "job-registry", "OCW: Processing pending jobs at block {:?}", block_number);
+
+    // Only workers may claim jobs, so other keys have nothing to execute
+    let (local_publics, local_accounts): (Vec<T::Public>, Vec<T::AccountId>) =
+        worker_keys::<T>().into_iter().unzip();
+    if local_accounts.is_empty() {
+        return Err("No worker keys available");
+    }
+
+    let mut executed = executed_jobs();
+    let ready_jobs = fetch_ready_jobs::<T>(&executed)?;
+
+    // A free execution slot, held for the run and extended per job; with every slot taken by
+    // overlapping runs this run only claims
+    let config = ocw_config();
+    let job_timeout = Duration::from_millis(config.job_timeout_ms);
+    let slot_keys: Vec<Vec<u8>> = (0..config.max_parallel_jobs.max(1)).map(slot_key).collect();
+    let mut slot_locks: Vec<StorageLock<Time>> =
+        slot_keys.iter().map(|key| StorageLock::with_deadline(key, job_timeout)).collect();
+    let mut slot = slot_locks.iter_mut().find_map(|lock| lock.try_lock().ok());
+    let executors = external_executors();
+
+    let pruned = prune_result_cache(config.result_cache_ttl_ms);
+    if pruned > 0 {
+        log::debug!(target: "job-registry", "OCW: Pruned {} cached results", pruned);
+    }
+
+    for job_id in ready_jobs.into_iter().take(MAX_JOBS_PER_RUN) {
+        let key = lock_key(job_id);
+        let mut lock = StorageLock::<JobLockDeadline<T>>::with_block_and_time_deadline(
+            &key,
+            LOCK_BLOCK_EXPIRATION,
+            Duration::from_millis(LOCK_TIMEOUT_MS.max(config.job_timeout_ms)),
+        );
+        // Another run of this node is claiming or executing the job
+        let Ok(guard) = lock.try_lock() else { continue };
+
+        // Jobs for external executors this node doesn't run are left to other collators
+        let metadata = Pallet::<T>::jobs(job_id).map(|job| job.metadata).unwrap_or_default();
+        if let Some(JobSpec::External { executor, .. }) = JobSpec::from_metadata(&metadata) {
+            if !executors.contains(&executor) {
+                continue;
+            }
+        }
+
+        match Pallet::<T>::active_claim(job_id) {
+            Some(claimer) if local_accounts.contains(&claimer) => {}
+            // Claimed by another collator
+            Some(_) => continue,
+            None => {
+                if claim_job::<T>(job_id, &local_publics) {
+                    guard.forget();
+                }
+                continue;
+            }
+        }
+
+        // A result computed before, ahead of a restart or a lost claim, is not computed again
+        let input = JobInputs::<T>::get(job_id).unwrap_or_default();
+        let fingerprint = job_fingerprint(&metadata, &input);
+        if let Some(cached) = cached_result(job_id).filter(|c| c.fingerprint == fingerprint) {
+            log::info!(target: "job-registry", "OCW: Job {} result reused from cache", job_id);
+            StorageValueRef::persistent(&result_key(job_id)).set(&cached.result);
+            if !executed.contains(&job_id) {
+                executed.push(job_id);
+            }
+            continue;
+        }
+
+        let Some(slot) = slot.as_mut() else {
+            log::debug!(target: "job-registry", "OCW: No free execution slot for job {}", job_id);
+            continue;
+        };
+        if slot.extend_lock().is_err() {
+            continue;
+        }
+
+        let deadline = sp_io::offchain::timestamp().add(job_timeout);
+        match execute_job::<T>(job_id, deadline) {
+            Ok(result) => {
+                log::info!(target: "job-registry", "OCW: Job {} executed successfully", job_id);
+                record_metrics(|metrics| {
+                    metrics.executions = metrics.executions.saturating_add(1);
+                    metrics.execution_time_ms_total =
+                        metrics.execution_time_ms_total.saturating_add(result.execution_time_ms);
+                    metrics.last_execution_time_ms = result.execution_time_ms;
+                });
+
+                cache_result(fingerprint, &result);
+                StorageValueRef::persistent(&result_key(job_id)).set(&result);
+                if !executed.contains(&job_id) {
+                    executed.push(job_id);
+                }
+            }
+            Err(EXTERNAL_PENDING) => {
+                log::debug!(target: "job-registry", "OCW: Job {} awaits its executor", job_id);
+            }
+            Err(e) => {
+                log::error!(
+                    target: "job-registry",
+                    "OCW: Job {} execution failed: {:?}", job_id, e
+                );
+            }
+        }
+    }
+
+    StorageValueRef::persistent(EXECUTED_JOBS_KEY).set(&executed);
+    Ok(())
+}
+
+/// Submit a claim on a job with one of the worker keys, returning whether it was submitted
+fn claim_job<T: Config>(job_id: u64, worker_keys: &[T::Public]) -> bool {
+    let signer = Signer::<T, T::AuthorityId>::any_account().with_filter(worker_keys.to_vec());
+    match signer.send_signed_transaction(|_account| Call::claim_job { job_id }) {
+        Some((_, Ok(()))) => {
+            log::info!(target: "job-registry", "OCW: Submitted claim on job {}", job_id);
+            true
+        }
+        _ => {
+            log::error!(target: "job-registry", "OCW: Failed to claim job {}", job_id);
+            false
+        }
+    }
+}
+
+/// Fetch jobs whose dependencies are met and whose deadline has not passed, skipping those
+/// already executed by this node
+fn fetch_ready_jobs<T: Config>(executed: &[u64]) -> Result<Vec<u64>, &'static str> {
+    let now = frame_system::Pallet::<T>::block_number();
+
+    Ok(Pallet::<T>::get_ready_jobs()
+        .into_iter()
+        .filter(|job_id| !executed.contains(job_id))
+        .filter(|job_id| Pallet::<T>::jobs(job_id).is_some_and(|job| job.deadline > now))
+        .collect())
+}
+
+/// Execute a job
+///
+/// Errors leave the job to be retried on a later run. Failures of the job itself are
+/// recorded as an unsuccessful result instead, as retrying would fail again.
+fn execute_job<T: Config>(
+    job_id: u64,
+    deadline: Timestamp,
+) -> Result<JobExecutionResult, &'static str> {
+    let job = Pallet::<T>::jobs(job_id).ok_or("Job not found")?;
+    let input = JobInputs::<T>::get(job_id).unwrap_or_default();
+
+    let start_time = sp_io::offchain::timestamp();
+    let outcome = perform_computation(job_id, &job.metadata, &input, deadline)?;
+    let end_time = sp_io::offchain::timestamp();
+
+    Ok(JobExecutionResult {
+        job_id,
+        success: outcome.is_ok(),
+        result_data: outcome.unwrap_or_default(),
+        execution_time_ms: end_time.diff(&start_time).millis(),
+    })
+}
+
+/// Perform the computation described by a job's metadata, returning its result or `Err` if
+/// the job itself failed
+///
+/// HTTP requests must complete by `deadline`; oracle sources are queried concurrently.
+fn perform_computation(
+    job_id: u64,
+    metadata: &[u8],
+    input: &[u8],
+    deadline: Timestamp,
+) -> Result<Result<Vec<u8>, ()>, &'static str> {
+    log::debug!(target: "job-registry", "OCW: Computing for job {}...", job_id);
+
+    match JobSpec::from_metadata(metadata) {
+        Some(JobSpec::Wasm { code_hash, uri, fuel }) => {
+            let code = fetch_module(code_hash, &uri, deadline)?;
+            let limits = SandboxLimits { fuel: fuel.min(MAX_WASM_FUEL), ..Default::default() };
+            Ok(sandbox::execute(&code, input, &limits).map_err(|e| {
+                log::warn!(target: "job-registry", "OCW: Job {} module failed: {:?}", job_id, e);
+            }))
+        }
+        Some(JobSpec::OracleFetch { urls, path, decimals, min_sources }) => {
+            let urls: Vec<&str> =
+                urls.iter().filter_map(|url| sp_std::str::from_utf8(url).ok()).collect();
+            let values: Vec<i128> = fetch_all(&urls, deadline)
+                .into_iter()
+                .filter_map(|body| oracle::extract(&body, &path, decimals))
+                .collect();
+            if values.len() < (min_sources.max(1) as usize) {
+                return Err("Too few oracle sources answered");
+            }
+            Ok(oracle::median(values).map(|value| value.encode()).ok_or(()))
+        }
+        Some(JobSpec::External { executor, timeout_ms }) => {
+            execute_externally(job_id, executor, input, timeout_ms)
+        }
+        None => {
+            // Deterministic placeholder result, so every worker agrees on it
+            let mut result = Vec::new();
+            result.extend_from_slice(b"Job result for ID: ");
+            result.extend_from_slice(&job_id.to_le_bytes());
+            result.extend_from_slice(&sp_io::hashing::blake2_256(metadata));
+            Ok(Ok(result))
+        }
+    }
+}
+
+/// Delegate a job to the node's external executor, returning its outcome once it finished and
+/// [`EXTERNAL_PENDING`] until then
+fn execute_externally(
+    job_id: u64,
+    executor: Vec<u8>,
+    input: &[u8],
+    timeout_ms: u64,
+) -> Result<Result<Vec<u8>, ()>, &'static str> {
+    let key = external_execution_key(job_id);
+    let mut storage = StorageValueRef::persistent(&key);
+    let now = sp_io::offchain::timestamp().unix_millis();
+    match storage.get::<ExternalExecution>().ok().flatten() {
+        Some(ExternalExecution::Finished { success, output }) => {
+            storage.clear();
+            return Ok(if success { Ok(output) } else { Err(()) });
+        }
+        Some(ExternalExecution::Queued { .. }) => return Err(EXTERNAL_PENDING),
+        Some(ExternalExecution::Running { deadline })
+            if now <= deadline.saturating_add(LOCK_TIMEOUT_MS) =>
+        {
+            return Err(EXTERNAL_PENDING)
+        }
+        // Not queued yet, or left running by a node that restarted since
+        _ => {}
+    }
+
+    storage.set(&ExternalExecution::Queued {
+        executor,
+        input: input.to_vec(),
+        timeout_ms: timeout_ms.min(MAX_EXTERNAL_TIMEOUT_MS),
+    });
+    StorageValueRef::persistent(EXTERNAL_QUEUE_KEY)
+        .mutate(|queue: Result<Option<Vec<u64>>, _>| -> Result<_, ()> {
+            let mut queue = queue.ok().flatten().unwrap_or_default();
+            if !queue.contains(&job_id) {
+                queue.push(job_id);
+            }
+            Ok(queue)
+        })
+        .map_err(|_| "Failed to queue job for its executor")?;
+
+    log::info!(target: "job-registry", "OCW: Job {} queued for its external executor", job_id);
+    Err(EXTERNAL_PENDING)
+}
+
+/// Fetch a WASM module over HTTP or from the IPFS gateway, checking it against its hash
+fn fetch_module(code_hash: H256, uri: &[u8], deadline: Timestamp) -> Result<Vec<u8>, &'static str> {
+    let uri = sp_std::str::from_utf8(uri).map_err(|_| "Invalid module URI")?;
+
+    let response = match uri.strip_prefix("ipfs://") {
+        Some(cid) => {
+            let gateway = StorageValueRef::persistent(IPFS_GATEWAY_KEY)
+                .get::<Vec<u8>>()
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| DEFAULT_IPFS_GATEWAY.as_bytes().to_vec());
+            let mut url = gateway;
+            url.extend_from_slice(cid.as_bytes());
+            let url = sp_std::str::from_utf8(&url).map_err(|_| "Invalid IPFS gateway")?;
+            get_until(url, deadline)
+        }
+        None => get_until(uri, deadline),
+    }
+    .map_err(|_| "Failed to fetch module")?;
+
+    // Stop reading as soon as the body outgrows the limit
+    let code: Vec<u8> = response.body().take(MAX_MODULE_SIZE + 1).collect();
+    if code.len() > MAX_MODULE_SIZE {
+        return Err("Module too large");
+    }
+    if H256(sp_io::hashing::blake2_256(&code)) != code_hash {
+        return Err("Module hash mismatch");
+    }
+
+    Ok(code)
+}
+
+/// HTTP GET of external data
+pub fn fetch_external_data(url: &str) -> Result<Vec<u8>, http::Error> {
+    fetch_until(url, sp_io::offchain::timestamp().add(Duration::from_millis(10_000)))
+}
+
+/// HTTP GETs of several URLs at once, returning the bodies of those answered by `deadline`
+fn fetch_all(urls: &[&str], deadline: Timestamp) -> Vec<Vec<u8>> {
+    let pending: Vec<http::PendingRequest> = urls
+        .iter()
+        .filter_map(|url| http::Request::get(url).deadline(deadline).send().ok())
+        .collect();
+
+    http::PendingRequest::try_wait_all(pending, deadline)
+        .into_iter()
+        .filter_map(|response| match response {
+            Ok(Ok(response)) if response.code == 200 => Some(response.body().collect()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// HTTP GET of external data, failing if not answered by `deadline`
+fn fetch_until(url: &str, deadline: Timestamp) -> Result<Vec<u8>, http::Error> {
+    Ok(get_until(url, deadline)?.body().collect::<Vec<u8>>())
+}
+
+/// HTTP GET answered with status 200 by `deadline`, with its body still unread
+fn get_until(url: &str, deadline: Timestamp) -> Result<http::Response, http::Error> {
+    log::debug!(target: "job-registry", "OCW: Fetching data from {}", url);
+
+    let pending = http::Request::get(url)
+        .deadline(deadline)
+        .send()
+        .map_err(|_| http::Error::IoError)?;
+
+    let response = pending
+        .try_wait(deadline)
+        .map_err(|_| http::Error::DeadlineReached)??;
+
+    if response.code != 200 {
+        log::error!(target: "job-registry", "OCW: HTTP GET failed with code: {}", response.code);
+        return Err(http::Error::Unknown);
+    }
+
+    Ok(response)
+}
+
+/// HTTP POST of a payload to an external endpoint
+pub fn post_external_data(url: &str, body: &[u8]) -> Result<(), http::Error> {
+    log::debug!(target: "job-registry", "OCW: Posting {} bytes to {}", body.len(), url);
+
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(10_000));
+
+    let pending = http::Request::post(url, sp_std::vec![body.to_vec()])
+        .add_header("Content-Type", "application/octet-stream")
+        .deadline(deadline)
+        .send()
+        .map_err(|_| http::Error::IoError)?;
+
+    let response = pending
+        .try_wait(deadline)
+        .map_err(|_| http::Error::DeadlineReached)??;
+
+    if !(200..300).contains(&response.code) {
+        log::error!(target: "job-registry", "OCW: HTTP POST failed with code: {}", response.code);
+        return Err(http::Error::Unknown);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! Value extraction for oracle jobs
+//!
+//! Oracle jobs read a number out of the JSON responses of redundant HTTP sources. Each value
+//! is scaled to a fixed-point integer, and the median across sources is the job's result.
+
+use alloc::string::String;
+use lite_json::{json::NumberValue, JsonValue};
+use sp_std::vec::Vec;
+
+/// Extract the number at the dot-separated `path` of a JSON document, scaled by
+/// `10^decimals` and truncated to an integer
+///
+/// Path segments index into objects by key and into arrays by position. The value may be a
+/// JSON number or a string holding a decimal number.
+pub fn extract(json: &[u8], path: &[u8], decimals: u8) -> Option<i128> {
+    let json = sp_std::str::from_utf8(json).ok()?;
+    let mut value = lite_json::parse_json(json).ok()?;
+
+    for segment in path.split(|b| *b == b'.').filter(|s| !s.is_empty()) {
+        value = match value {
+            JsonValue::Object(entries) => {
+                let key: Vec<char> = sp_std::str::from_utf8(segment).ok()?.chars().collect();
+                entries.into_iter().find(|(k, _)| *k == key)?.1
+            }
+            JsonValue::Array(items) => {
+                let index: usize = sp_std::str::from_utf8(segment).ok()?.parse().ok()?;
+                items.into_iter().nth(index)?
+            }
+            _ => return None,
+        };
+    }
+
+    match value {
+        JsonValue::Number(number) => scale_number(&number, decimals),
+        JsonValue::String(chars) => {
+            let text: String = chars.into_iter().collect();
+            parse_decimal(&text, decimals)
+        }
+        _ => None,
+    }
+}
+
+/// Scale a JSON number by `10^decimals`
+fn scale_number(number: &NumberValue, decimals: u8) -> Option<i128> {
+    let mantissa = (number.integer as i128)
+        .checked_mul(10i128.checked_pow(number.fraction_length)?)?
+        .checked_add(number.fraction as i128)?;
+    let exponent = number.exponent as i64 - number.fraction_length as i64 + decimals as i64;
+    let scaled = shift(mantissa, exponent)?;
+    Some(if number.negative { -scaled } else { scaled })
+}
+
+/// Parse a decimal number such as `-12.5`, scaled by `10^decimals`
+fn parse_decimal(text: &str, decimals: u8) -> Option<i128> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    let mut mantissa: i128 = 0;
+    for c in integer.chars().chain(fraction.chars()) {
+        let digit = c.to_digit(10)? as i128;
+        mantissa = mantissa.checked_mul(10)?.checked_add(digit)?;
+    }
+    let scaled = shift(mantissa, decimals as i64 - fraction.len() as i64)?;
+    Some(if negative { -scaled } else { scaled })
+}
+
+/// Multiply by `10^exponent`, truncating for negative exponents
+fn shift(value: i128, exponent: i64) -> Option<i128> {
+    if exponent >= 0 {
+        value.checked_mul(10i128.checked_pow(u32::try_from(exponent).ok()?)?)
+    } else {
+        // Dividing by more than i128 can hold leaves nothing
+        let divisor = u32::try_from(-exponent).ok().and_then(|e| 10i128.checked_pow(e));
+        Some(divisor.map_or(0, |d| value / d))
+    }
+}
+
+/// Median of the values, the mean of the two middle values rounded down for an even count
+pub fn median(mut values: Vec<i128>) -> Option<i128> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        Some(values[mid])
+    } else {
+        // Halve before adding, so values near the ends of the range don't overflow
+        let (low, high) = (values[mid - 1], values[mid]);
+        Some((low >> 1) + (high >> 1) + (low & high & 1))
+    }
+}
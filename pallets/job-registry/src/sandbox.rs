@@ -0,0 +1,113 @@
+//! Bounded execution of WASM job modules
+//!
+//! Modules run in a wasmi interpreter with fuel metering and a memory limit, and without any
+//! host functions: a module importing anything fails to instantiate. A module exports its
+//! `memory`, an `alloc(len: i32) -> i32` function returning where the input is written, and a
+//! `run(ptr: i32, len: i32) -> i64` function returning its output location packed as
+//! `ptr << 32 | len`.
+
+use sp_std::{vec, vec::Vec};
+use wasmi::{
+    core::TrapCode, Config as EngineConfig, Engine, Linker, Module, Store, StoreLimits,
+    StoreLimitsBuilder,
+};
+
+/// Resources a module execution may use
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SandboxLimits {
+    /// Fuel the execution may consume, roughly one unit per instruction
+    pub fuel: u64,
+    /// Maximum size of the linear memory, in bytes
+    pub max_memory: usize,
+    /// Maximum size of the output, in bytes
+    pub max_output: usize,
+}
+
+impl Default for SandboxLimits {
+    fn default() -> Self {
+        Self { fuel: 10_000_000, max_memory: 16 * 1024 * 1024, max_output: 64 * 1024 }
+    }
+}
+
+/// Why a module execution failed
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SandboxError {
+    /// The code is not a valid WASM module
+    InvalidModule,
+    /// The module imports host functions, or exceeds the limits on instantiation
+    Instantiation,
+    /// The module lacks `memory`, `alloc` or `run`, or they have the wrong type
+    MissingExport,
+    /// The execution ran out of fuel
+    OutOfFuel,
+    /// The execution trapped
+    Trap,
+    /// The input or output lies outside the module's memory
+    OutOfBounds,
+    /// The output exceeds `SandboxLimits::max_output`
+    OutputTooLarge,
+}
+
+struct HostState {
+    limits: StoreLimits,
+}
+
+fn trap(error: wasmi::Error) -> SandboxError {
+    match error.as_trap_code() {
+        Some(TrapCode::OutOfFuel) => SandboxError::OutOfFuel,
+        _ => SandboxError::Trap,
+    }
+}
+
+/// Run `code` on `input` within `limits`, returning its output
+pub fn execute(
+    code: &[u8],
+    input: &[u8],
+    limits: &SandboxLimits,
+) -> Result<Vec<u8>, SandboxError> {
+    let mut config = EngineConfig::default();
+    config.consume_fuel(true);
+    let engine = Engine::new(&config);
+    let module = Module::new(&engine, code).map_err(|_| SandboxError::InvalidModule)?;
+
+    let limits_state = StoreLimitsBuilder::new()
+        .memory_size(limits.max_memory)
+        .instances(1)
+        .memories(1)
+        .tables(1)
+        .trap_on_grow_failure(true)
+        .build();
+    let mut store = Store::new(&engine, HostState { limits: limits_state });
+    store.limiter(|state| &mut state.limits);
+    store.set_fuel(limits.fuel).map_err(|_| SandboxError::Instantiation)?;
+
+    let linker = Linker::<HostState>::new(&engine);
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .and_then(|pre| pre.start(&mut store))
+        .map_err(|_| SandboxError::Instantiation)?;
+
+    let memory = instance.get_memory(&store, "memory").ok_or(SandboxError::MissingExport)?;
+    let alloc = instance
+        .get_typed_func::<i32, i32>(&store, "alloc")
+        .map_err(|_| SandboxError::MissingExport)?;
+    let run = instance
+        .get_typed_func::<(i32, i32), i64>(&store, "run")
+        .map_err(|_| SandboxError::MissingExport)?;
+
+    let input_len = i32::try_from(input.len()).map_err(|_| SandboxError::OutOfBounds)?;
+    let input_ptr = alloc.call(&mut store, input_len).map_err(trap)?;
+    memory
+        .write(&mut store, input_ptr as u32 as usize, input)
+        .map_err(|_| SandboxError::OutOfBounds)?;
+
+    let packed = run.call(&mut store, (input_ptr, input_len)).map_err(trap)? as u64;
+    let (output_ptr, output_len) = ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize);
+    if output_len > limits.max_output {
+        return Err(SandboxError::OutputTooLarge);
+    }
+
+    let mut output = vec![0u8; output_len];
+    memory.read(&store, output_ptr, &mut output).map_err(|_| SandboxError::OutOfBounds)?;
+    Ok(output)
+}
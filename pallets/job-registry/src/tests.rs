@@ -303,3 +303,519 @@ fn get_ready_jobs_works() {
         assert_eq!(ready, vec![1]);
     });
 }
+
+#[test]
+fn get_jobs_pages_in_id_order() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for metadata in 0..4u8 {
+            assert_ok!(JobRegistry::submit_job(
+                RuntimeOrigin::signed(1),
+                vec![metadata],
+                vec![],
+                100
+            ));
+        }
+        // Removed jobs are skipped: in progress, then failed
+        for status in [1, 4] {
+            assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 1, status));
+        }
+        assert_ok!(JobRegistry::remove_job(RuntimeOrigin::signed(1), 1));
+
+        let ids = |from, limit| {
+            JobRegistry::get_jobs(from, limit).into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(0, 2), vec![0, 2]);
+        assert_eq!(ids(3, 10), vec![3]);
+        assert_eq!(ids(4, 10), Vec::<u64>::new());
+        assert_eq!(JobRegistry::get_jobs(2, 1)[0].1.metadata.to_vec(), vec![2]);
+    });
+}
+
+#[test]
+fn instantiate_template_creates_job_with_input() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(10);
+
+        assert_ok!(JobRegistry::create_template(RuntimeOrigin::signed(1), vec![9, 9], 50));
+        System::assert_has_event(Event::TemplateCreated { template_id: 0, owner: 1 }.into());
+
+        assert_ok!(JobRegistry::instantiate_template(RuntimeOrigin::signed(2), 0, vec![1, 2, 3]));
+
+        let job = JobRegistry::jobs(0).unwrap();
+        assert_eq!(job.owner, 2);
+        assert_eq!(job.metadata.to_vec(), vec![9, 9]);
+        assert_eq!(job.deadline, 60);
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(JobRegistry::job_inputs(0).unwrap().to_vec(), vec![1, 2, 3]);
+
+        System::assert_has_event(Event::JobInstantiated { job_id: 0, template_id: 0 }.into());
+
+        assert_noop!(
+            JobRegistry::instantiate_template(RuntimeOrigin::signed(2), 1, vec![]),
+            Error::<Test>::TemplateNotFound
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_executes_ready_jobs_once() {
+    use crate::ocw;
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt};
+    use sp_runtime::testing::UintAuthorityId;
+
+    let (offchain, _state) = testing::TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+
+        // Job 1 waits on job 0, job 2 expires at block 5
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![2], vec![0], 100));
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![3], vec![], 5));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 2));
+
+        JobRegistry::offchain_worker(1);
+        assert_eq!(ocw::executed_jobs(), vec![0, 2]);
+        let result = ocw::execution_result(0).unwrap();
+        assert!(result.success);
+        assert_eq!(ocw::metrics().executions, 2);
+
+        // Executed jobs are not executed again, expired ones not at all
+        ocw::clear_execution_result(2);
+        System::set_block_number(5);
+        JobRegistry::offchain_worker(5);
+        assert_eq!(ocw::executed_jobs(), vec![0]);
+        assert_eq!(ocw::execution_result(0), Some(result));
+        assert!(ocw::execution_result(2).is_none());
+        assert_eq!(ocw::metrics().executions, 2);
+    });
+}
+
+const ECHO_MODULE: &str = r#"
+    (module
+        (memory (export "memory") 1)
+        (func (export "alloc") (param i32) (result i32) (i32.const 1024))
+        (func (export "run") (param $ptr i32) (param $len i32) (result i64)
+            (i64.or
+                (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+                (i64.extend_i32_u (local.get $len)))))
+"#;
+
+#[test]
+fn sandbox_runs_modules_within_limits() {
+    use crate::sandbox::{execute, SandboxError, SandboxLimits};
+
+    let limits = SandboxLimits::default();
+    let echo = wat::parse_str(ECHO_MODULE).unwrap();
+    assert_eq!(execute(&echo, b"hello", &limits), Ok(b"hello".to_vec()));
+
+    // Output past the limit is rejected
+    let small = SandboxLimits { max_output: 4, ..limits };
+    assert_eq!(execute(&echo, b"hello", &small), Err(SandboxError::OutputTooLarge));
+
+    // Endless loops run out of fuel
+    let endless = wat::parse_str(
+        r#"(module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32) (i32.const 0))
+            (func (export "run") (param i32 i32) (result i64) (loop $l (br $l)) (i64.const 0)))"#,
+    )
+    .unwrap();
+    assert_eq!(execute(&endless, &[], &limits), Err(SandboxError::OutOfFuel));
+
+    // No host functions are provided, and memory is bounded
+    let importing = wat::parse_str(
+        r#"(module
+            (import "env" "now" (func))
+            (memory (export "memory") 1))"#,
+    )
+    .unwrap();
+    assert_eq!(execute(&importing, &[], &limits), Err(SandboxError::Instantiation));
+    let tiny = SandboxLimits { max_memory: 1024, ..limits };
+    assert_eq!(execute(&echo, b"hello", &tiny), Err(SandboxError::Instantiation));
+
+    assert_eq!(execute(b"not wasm", &[], &limits), Err(SandboxError::InvalidModule));
+}
+
+#[test]
+fn offchain_worker_runs_wasm_jobs() {
+    use crate::ocw::{self, JobSpec};
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt};
+    use sp_runtime::testing::UintAuthorityId;
+
+    let (offchain, state) = testing::TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+
+        let code = wat::parse_str(ECHO_MODULE).unwrap();
+        let spec = JobSpec::Wasm {
+            code_hash: sp_core::H256(sp_io::hashing::blake2_256(&code)),
+            uri: b"ipfs://bafyecho".to_vec(),
+            fuel: 1_000_000,
+        };
+        assert_eq!(JobSpec::from_metadata(&spec.to_metadata()), Some(spec.clone()));
+        assert_eq!(JobSpec::from_metadata(&[1, 2, 3]), None);
+
+        assert_ok!(JobRegistry::create_template(RuntimeOrigin::signed(1), spec.to_metadata(), 100));
+        let input = b"input".to_vec();
+        assert_ok!(JobRegistry::instantiate_template(RuntimeOrigin::signed(1), 0, input.clone()));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+
+        // The module is fetched from the default IPFS gateway and run on the job's input
+        state.write().expect_request(testing::PendingRequest {
+            method: "GET".into(),
+            uri: "https://ipfs.io/ipfs/bafyecho".into(),
+            response: Some(code),
+            sent: true,
+            ..Default::default()
+        });
+        JobRegistry::offchain_worker(1);
+
+        let result = ocw::execution_result(0).unwrap();
+        assert!(result.success);
+        assert_eq!(result.result_data, input);
+    });
+}
+
+#[test]
+fn oracle_values_are_extracted_and_aggregated() {
+    use crate::oracle::{extract, median};
+
+    let body = br#"{"data": [{"price": 12.345}, {"price": "-0.5"}], "rate": 3e2}"#;
+    assert_eq!(extract(body, b"data.0.price", 2), Some(1234));
+    assert_eq!(extract(body, b"data.1.price", 3), Some(-500));
+    assert_eq!(extract(body, b"rate", 0), Some(300));
+    assert_eq!(extract(body, b"data.2.price", 2), None);
+    assert_eq!(extract(body, b"data", 2), None);
+    assert_eq!(extract(b"not json", b"rate", 0), None);
+
+    assert_eq!(median(vec![5, 1, 3]), Some(3));
+    assert_eq!(median(vec![4, 1, 3, 10]), Some(3));
+    assert_eq!(median(vec![]), None);
+    assert_eq!(median(vec![-3, -2]), Some(-3));
+    assert_eq!(median(vec![i128::MIN, i128::MAX]), Some(-1));
+    assert_eq!(median(vec![i128::MAX, i128::MAX]), Some(i128::MAX));
+    assert_eq!(median(vec![i128::MIN, i128::MIN]), Some(i128::MIN));
+}
+
+#[test]
+fn offchain_worker_runs_oracle_jobs() {
+    use crate::ocw::{self, JobSpec};
+    use codec::Decode;
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt};
+    use sp_runtime::testing::UintAuthorityId;
+
+    let (offchain, state) = testing::TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+
+        let urls = ["https://a.example/p", "https://b.example/p", "https://c.example/p"];
+        let spec = JobSpec::OracleFetch {
+            urls: urls.iter().map(|url| url.as_bytes().to_vec()).collect(),
+            path: b"price".to_vec(),
+            decimals: 2,
+            min_sources: 2,
+        };
+        let metadata = spec.to_metadata();
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), metadata, vec![], 100));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+
+        // One source fails; the median of the others is the result
+        let bodies = [&br#"{"price": 10.5}"#[..], b"oops", br#"{"price": 11}"#];
+        for (url, body) in urls.iter().zip(bodies) {
+            state.write().expect_request(testing::PendingRequest {
+                method: "GET".into(),
+                uri: (*url).into(),
+                response: Some(body.to_vec()),
+                sent: true,
+                ..Default::default()
+            });
+        }
+        JobRegistry::offchain_worker(1);
+
+        let result = ocw::execution_result(0).unwrap();
+        assert!(result.success);
+        assert_eq!(i128::decode(&mut &result.result_data[..]).unwrap(), 1075);
+    });
+}
+
+#[test]
+fn claim_job_reserves_job_until_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![2], vec![0], 100));
+
+        // Jobs with unmet dependencies can't be claimed
+        assert_noop!(
+            JobRegistry::claim_job(RuntimeOrigin::signed(7), 1),
+            Error::<Test>::JobNotClaimable
+        );
+
+        // Only workers may claim jobs
+        assert_noop!(
+            JobRegistry::claim_job(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::NotAWorker
+        );
+
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+        System::assert_last_event(
+            Event::JobClaimed { job_id: 0, claimer: 7, expires_at: 11 }.into(),
+        );
+        assert_eq!(JobRegistry::active_claim(0), Some(7));
+        assert_noop!(
+            JobRegistry::claim_job(RuntimeOrigin::signed(8), 0),
+            Error::<Test>::JobAlreadyClaimed
+        );
+
+        // An expired claim may be taken over
+        System::set_block_number(11);
+        assert_eq!(JobRegistry::active_claim(0), None);
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(8), 0));
+        assert_eq!(JobRegistry::active_claim(0), Some(8));
+
+        // Started jobs can't be claimed, and finished jobs drop their claim
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 1));
+        assert_noop!(
+            JobRegistry::claim_job(RuntimeOrigin::signed(7), 0),
+            Error::<Test>::JobNotClaimable
+        );
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 4));
+        assert!(JobRegistry::job_claims(0).is_none());
+    });
+}
+
+#[test]
+fn offchain_worker_claims_jobs_before_executing() {
+    use crate::ocw;
+    use codec::Decode;
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{
+        testing, OffchainDbExt, OffchainWorkerExt, Timestamp, TransactionPoolExt,
+    };
+    use sp_runtime::testing::UintAuthorityId;
+
+    let (offchain, state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+
+        // Job 1 is claimed by another collator
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![2], vec![], 100));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(8), 1));
+
+        // The unclaimed job is claimed, not executed
+        JobRegistry::offchain_worker(1);
+        let txs = pool_state.read().transactions.clone();
+        assert_eq!(txs.len(), 1);
+        let xt = Extrinsic::decode(&mut &txs[0][..]).unwrap();
+        assert_eq!(xt.function, RuntimeCall::JobRegistry(crate::Call::claim_job { job_id: 0 }));
+        assert!(ocw::executed_jobs().is_empty());
+
+        // The job stays locked while the claim is on its way
+        JobRegistry::offchain_worker(1);
+        assert_eq!(pool_state.read().transactions.len(), 1);
+
+        // Once the claim is on-chain and the lock expired, only the claimed job is executed
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+        System::set_block_number(5);
+        state.write().timestamp = Timestamp::from_unix_millis(ocw::LOCK_TIMEOUT_MS + 1);
+        JobRegistry::offchain_worker(5);
+        assert_eq!(ocw::executed_jobs(), vec![0]);
+        assert_eq!(pool_state.read().transactions.len(), 1);
+    });
+}
+
+#[test]
+fn offchain_worker_executes_only_with_a_free_slot() {
+    use crate::ocw;
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, Timestamp};
+    use sp_runtime::{
+        offchain::{
+            storage::StorageValueRef,
+            storage_lock::{StorageLock, Time},
+            Duration,
+        },
+        testing::UintAuthorityId,
+    };
+
+    let (offchain, state) = testing::TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+
+        let config =
+            ocw::OcwConfig { max_parallel_jobs: 2, job_timeout_ms: 5_000, ..Default::default() };
+        StorageValueRef::persistent(ocw::OCW_CONFIG_KEY).set(&config);
+        assert_eq!(ocw::ocw_config(), config);
+
+        // Overlapping runs hold both slots
+        for slot in 0..2 {
+            let key = ocw::slot_key(slot);
+            let mut lock = StorageLock::<Time>::with_deadline(&key, Duration::from_millis(5_000));
+            lock.try_lock().unwrap().forget();
+        }
+        JobRegistry::offchain_worker(1);
+        assert!(ocw::executed_jobs().is_empty());
+
+        // A slot is free again once its holder outlived the job timeout
+        state.write().timestamp = Timestamp::from_unix_millis(5_001);
+        JobRegistry::offchain_worker(1);
+        assert_eq!(ocw::executed_jobs(), vec![0]);
+    });
+}
+
+#[test]
+fn offchain_worker_delegates_external_jobs_to_the_node() {
+    use crate::ocw::{self, ExternalExecution, JobSpec};
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt};
+    use sp_runtime::{offchain::storage::StorageValueRef, testing::UintAuthorityId};
+
+    let (offchain, _state) = testing::TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+
+        let spec = JobSpec::External { executor: b"render".to_vec(), timeout_ms: 60_000 };
+        let metadata = spec.to_metadata();
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), metadata, vec![], 100));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+        let execution_key = ocw::external_execution_key(0);
+        let execution = StorageValueRef::persistent(&execution_key);
+        let queue = StorageValueRef::persistent(ocw::EXTERNAL_QUEUE_KEY);
+
+        // The node doesn't run the executor
+        JobRegistry::offchain_worker(1);
+        assert_eq!(execution.get::<ExternalExecution>(), Ok(None));
+
+        // Once it does, the job is queued for it, once
+        StorageValueRef::persistent(ocw::EXTERNAL_EXECUTORS_KEY).set(&vec![b"render".to_vec()]);
+        JobRegistry::offchain_worker(1);
+        JobRegistry::offchain_worker(1);
+        assert_eq!(queue.get::<Vec<u64>>(), Ok(Some(vec![0])));
+        assert_eq!(
+            execution.get(),
+            Ok(Some(ExternalExecution::Queued {
+                executor: b"render".to_vec(),
+                input: vec![],
+                timeout_ms: 60_000,
+            }))
+        );
+        assert!(ocw::executed_jobs().is_empty());
+
+        // The executor's output becomes the job's result
+        queue.set(&Vec::<u64>::new());
+        execution.set(&ExternalExecution::Finished { success: true, output: b"frame".to_vec() });
+        JobRegistry::offchain_worker(1);
+        assert_eq!(ocw::executed_jobs(), vec![0]);
+        assert_eq!(ocw::execution_result(0).unwrap().result_data, b"frame".to_vec());
+        assert_eq!(execution.get::<ExternalExecution>(), Ok(None));
+    });
+}
+
+#[test]
+fn offchain_worker_reuses_and_prunes_cached_results() {
+    use crate::ocw::{self, CachedResult, JobExecutionResult, SubmissionStatus};
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, Timestamp};
+    use sp_runtime::{offchain::storage::StorageValueRef, testing::UintAuthorityId};
+
+    let (offchain, state) = testing::TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![2], vec![], 100));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 1));
+
+        // Job 0 was executed before a restart; job 1 before its metadata changed
+        let cached = |job_id: u64, metadata: &[u8]| CachedResult {
+            fingerprint: ocw::job_fingerprint(metadata, &[]),
+            result: JobExecutionResult {
+                job_id,
+                result_data: b"cached".to_vec(),
+                execution_time_ms: 1_000,
+                success: true,
+            },
+            submission: SubmissionStatus::Pending,
+        };
+        StorageValueRef::persistent(&ocw::cache_key(0)).set(&cached(0, &[1]));
+        StorageValueRef::persistent(&ocw::cache_key(1)).set(&cached(1, &[3]));
+
+        JobRegistry::offchain_worker(1);
+        assert_eq!(ocw::executed_jobs(), vec![0, 1]);
+        assert_eq!(ocw::execution_result(0).unwrap().result_data, b"cached".to_vec());
+        assert_ne!(ocw::execution_result(1).unwrap().result_data, b"cached".to_vec());
+        assert_eq!(ocw::metrics().executions, 1);
+        assert_eq!(ocw::cached_result(1).unwrap().result, ocw::execution_result(1).unwrap());
+
+        ocw::record_submission(1, false);
+        ocw::record_submission(1, false);
+        assert_eq!(
+            ocw::cached_result(1).unwrap().submission,
+            SubmissionStatus::Failed { attempts: 2 }
+        );
+        ocw::record_submission(1, true);
+        assert_eq!(ocw::cached_result(1).unwrap().submission, SubmissionStatus::Submitted);
+
+        // Results expire once cached for the TTL
+        let ttl = ocw::OcwConfig::default().result_cache_ttl_ms;
+        state.write().timestamp = Timestamp::from_unix_millis(ttl - 1);
+        assert_eq!(ocw::prune_result_cache(ttl), 0);
+        state.write().timestamp = Timestamp::from_unix_millis(ttl);
+        assert_eq!(ocw::prune_result_cache(ttl), 1);
+        assert!(ocw::cached_result(1).is_none());
+    });
+}
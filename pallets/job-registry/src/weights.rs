@@ -15,6 +15,9 @@ pub trait WeightInfo {
     fn submit_job() -> Weight;
     fn update_job_status() -> Weight;
     fn remove_job() -> Weight;
+    fn create_template() -> Weight;
+    fn instantiate_template() -> Weight;
+    fn claim_job() -> Weight;
 }
 
 /// Default weights.
@@ -37,6 +40,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(3))
     }
+
+    fn create_template() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn instantiate_template() -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    fn claim_job() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
 }
 
 // For tests
@@ -52,4 +73,16 @@ impl WeightInfo for () {
     fn remove_job() -> Weight {
         Weight::from_parts(40_000_000, 0)
     }
+
+    fn create_template() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+    }
+
+    fn instantiate_template() -> Weight {
+        Weight::from_parts(55_000_000, 0)
+    }
+
+    fn claim_job() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+    }
 }
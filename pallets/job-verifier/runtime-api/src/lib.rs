@@ -0,0 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Job Verifier Runtime API
+//!
+//! Lets the node RPC query submitted results and their verification without decoding raw
+//! storage keys.
+
+use sp_std::vec::Vec;
+
+pub use pallet_job_verifier::{JobResult, ProofType, VerificationStatistics};
+
+sp_api::decl_runtime_apis! {
+    pub trait VerifierApi {
+        /// Results awaiting verification (neither verified nor rejected) of jobs with IDs from
+        /// `from` upwards, in ID order, at most `limit` entries
+        fn pending_proofs(from: u64, limit: u32) -> Vec<(u64, JobResult)>;
+
+        /// Result submitted for a job, if any
+        fn job_result(job_id: u64) -> Option<JobResult>;
+
+        /// Proof submission and verification counters
+        fn verification_stats() -> VerificationStatistics;
+    }
+}
@@ -19,17 +19,23 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+mod ocw;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
-    use frame_system::pallet_prelude::*;
+    use frame_system::{offchain::CreateSignedTransaction, pallet_prelude::*};
     use sp_core::H256;
     use sp_runtime::traits::SaturatedConversion;
     use sp_std::vec::Vec;
     use pallet_job_registry::{JobStatus, Pallet as JobRegistry};
 
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Proof type enumeration
@@ -67,16 +73,76 @@ pub mod pallet {
         pub submitted_at: u32,
         /// Whether the result has been verified
         pub verified: bool,
+        /// Whether the proof failed verification; only a new proof can then be verified
+        pub rejected: bool,
+    }
+
+    /// Off-chain index key prefix of submitted proofs
+    pub const PROOF_INDEX_PREFIX: &[u8] = b"pcl::proof::";
+
+    /// Off-chain index key of the latest proof submitted for a job
+    pub fn proof_index_key(job_id: u64) -> Vec<u8> {
+        let mut key = PROOF_INDEX_PREFIX.to_vec();
+        key.extend_from_slice(&job_id.to_le_bytes());
+        key
+    }
+
+    /// A submitted proof as written to the off-chain index, for auditors to retrieve once
+    /// on-chain state is pruned
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+    pub struct IndexedProof<AccountId> {
+        /// Account that submitted the proof
+        pub submitter: AccountId,
+        /// Hash of the result
+        pub result_hash: H256,
+        /// Proof type used
+        pub proof_type: ProofType,
+        /// Proof data
+        pub proof: Vec<u8>,
+        /// Block number when the proof was submitted
+        pub submitted_at: u32,
+    }
+
+    /// Hook invoked whenever proof data is stored for a job
+    pub trait OnProofSubmitted<AccountId> {
+        fn on_proof_submitted(job_id: u64, submitter: &AccountId, proof_size: u32);
+    }
+
+    impl<AccountId> OnProofSubmitted<AccountId> for () {
+        fn on_proof_submitted(_job_id: u64, _submitter: &AccountId, _proof_size: u32) {}
+    }
+
+    /// Hook invoked whenever a verifier accepts or rejects a proof.
+    /// `latency` is the number of blocks since the proof was submitted.
+    pub trait OnProofVerified<AccountId> {
+        fn on_proof_verified(job_id: u64, verifier: &AccountId, latency: u32, accepted: bool);
+    }
+
+    impl<AccountId> OnProofVerified<AccountId> for () {
+        fn on_proof_verified(_job_id: u64, _verifier: &AccountId, _latency: u32, _accepted: bool) {}
     }
 
     #[pallet::config]
-    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> + pallet_job_registry::Config {
+    pub trait Config:
+        frame_system::Config<RuntimeEvent: From<Event<Self>>>
+        + pallet_job_registry::Config
+        + CreateSignedTransaction<Call<Self>>
+    {
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: crate::weights::WeightInfo;
 
         /// Maximum size of proof data
         #[pallet::constant]
         type MaxProofSize: Get<u32>;
+
+        /// Notified of every stored proof and its size
+        type OnProofSubmitted: OnProofSubmitted<Self::AccountId>;
+
+        /// Notified of every proof checked through `verify_proof`
+        type OnProofVerified: OnProofVerified<Self::AccountId>;
+
+        /// Origin allowed to verify proofs, resolving to the verifier's account
+        type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
     }
 
     /// Map from JobId to JobResult
@@ -92,7 +158,7 @@ pub mod pallet {
     /// Map from JobId to proof data (for verification)
     #[pallet::storage]
     #[pallet::getter(fn job_proofs)]
-    pub type JobProofs<T: Config> = StorageMap<
+    pub type JobProofs<T: Config> = CountedStorageMap<
         _,
         Blake2_128Concat,
         u64,
@@ -146,6 +212,18 @@ pub mod pallet {
         ResultHashMismatch,
         /// Invalid proof type
         InvalidProofType,
+        /// Proof already failed verification; a new one must be submitted
+        ProofRejected,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Submit the job results executed by this node, with their proofs
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            if let Err(e) = ocw::submit_job_results::<T>(block_number) {
+                log::error!(target: "job-verifier", "OCW: Error submitting results: {:?}", e);
+            }
+        }
     }
 
     #[pallet::call]
@@ -167,7 +245,7 @@ pub mod pallet {
             proof_type_u8: u8,
             proof_data: Vec<u8>,
         ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
+            let who = ensure_signed(origin)?;
 
             // Convert u8 to ProofType
             let proof_type = ProofType::from_u8(proof_type_u8)
@@ -183,6 +261,11 @@ pub mod pallet {
                 Error::<T>::InvalidJobStatus
             );
 
+            // A job claimed by an offchain worker only takes the claimer's proof
+            if let Some(claimer) = JobRegistry::<T>::active_claim(job_id) {
+                ensure!(claimer == who, Error::<T>::NotAuthorized);
+            }
+
             // Check if already verified
             if let Some(result) = JobResults::<T>::get(job_id) {
                 ensure!(!result.verified, Error::<T>::AlreadyVerified);
@@ -197,13 +280,25 @@ pub mod pallet {
             JobProofs::<T>::insert(job_id, bounded_proof.clone());
 
             // Create result entry
+            let submitted_at = frame_system::Pallet::<T>::block_number().saturated_into();
             let result = JobResult {
                 result_hash,
-                proof_type,
-                submitted_at: frame_system::Pallet::<T>::block_number().saturated_into(),
+                proof_type: proof_type.clone(),
+                submitted_at,
                 verified: false,
+                rejected: false,
             };
 
+            // Keep the proof off-chain on nodes with off-chain indexing enabled
+            let indexed = IndexedProof {
+                submitter: who.clone(),
+                result_hash,
+                proof_type,
+                proof: bounded_proof.to_vec(),
+                submitted_at,
+            };
+            sp_io::offchain_index::set(&proof_index_key(job_id), &indexed.encode());
+
             JobResults::<T>::insert(job_id, result);
 
             // Update statistics
@@ -211,19 +306,23 @@ pub mod pallet {
                 stats.total_proofs_submitted = stats.total_proofs_submitted.saturating_add(1);
             });
 
+            let proof_size = bounded_proof.len() as u32;
+            T::OnProofSubmitted::on_proof_submitted(job_id, &who, proof_size);
+
             Self::deposit_event(Event::ProofSubmitted { job_id, result_hash });
-            Self::deposit_event(Event::ProofStored { 
-                job_id, 
-                proof_size: bounded_proof.len() as u32 
-            });
+            Self::deposit_event(Event::ProofStored { job_id, proof_size });
 
             Ok(())
         }
 
         /// Verify a submitted proof
         ///
+        /// A proof that fails verification is recorded as rejected and emits
+        /// `VerificationFailed`; the call itself still succeeds. A rejected proof can't be
+        /// verified again until a new one is submitted.
+        ///
         /// # Parameters
-        /// - `origin`: Sudo or authorized verifier
+        /// - `origin`: `VerifierOrigin`
         /// - `job_id`: The job ID to verify
         #[pallet::call_index(1)]
         #[pallet::weight(<T as Config>::WeightInfo::verify_proof())]
@@ -231,7 +330,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             job_id: u64,
         ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
+            let who = T::VerifierOrigin::ensure_origin(origin)?;
 
             // Get job
             let job = JobRegistry::<T>::jobs(job_id)
@@ -241,8 +340,9 @@ pub mod pallet {
             let mut result = JobResults::<T>::get(job_id)
                 .ok_or(Error::<T>::JobNotFound)?;
 
-            // Check not already verified
+            // Check not already verified or rejected
             ensure!(!result.verified, Error::<T>::AlreadyVerified);
+            ensure!(!result.rejected, Error::<T>::ProofRejected);
 
             // Get proof data
             let proof_data = JobProofs::<T>::get(job_id)
@@ -255,6 +355,10 @@ pub mod pallet {
                 ProofType::Hash => Self::verify_hash(&result, &proof_data),
             };
 
+            let now: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            let latency = now.saturating_sub(result.submitted_at);
+            T::OnProofVerified::on_proof_verified(job_id, &who, latency, verification_result);
+
             if verification_result {
                 // Mark as verified
                 result.verified = true;
@@ -275,13 +379,19 @@ pub mod pallet {
                 Self::deposit_event(Event::JobVerified { job_id });
                 Ok(())
             } else {
+                // Mark as rejected, so the same proof isn't counted again
+                result.rejected = true;
+                JobResults::<T>::insert(job_id, result);
+
                 // Update statistics
                 VerificationStats::<T>::mutate(|stats| {
                     stats.total_proofs_failed = stats.total_proofs_failed.saturating_add(1);
                 });
 
+                // A rejection is a valid outcome; returning an error would roll back the
+                // statistics and the verifier's rejection count
                 Self::deposit_event(Event::VerificationFailed { job_id });
-                Err(Error::<T>::InvalidProof.into())
+                Ok(())
             }
         }
 
@@ -301,6 +411,7 @@ pub mod pallet {
             JobResults::<T>::try_mutate(job_id, |maybe_result| -> DispatchResult {
                 let result = maybe_result.as_mut().ok_or(Error::<T>::JobNotFound)?;
                 result.verified = true;
+                result.rejected = false;
 
                 // Update job status
                 let job = JobRegistry::<T>::jobs(job_id)
@@ -380,5 +491,15 @@ pub mod pallet {
         pub fn get_stats() -> VerificationStatistics {
             VerificationStats::<T>::get()
         }
+
+        /// Results awaiting verification (neither verified nor rejected) of jobs with IDs from
+        /// `from` upwards, in ID order, at most `limit` entries
+        pub fn get_pending_proofs(from: u64, limit: u32) -> Vec<(u64, JobResult)> {
+            (from..JobRegistry::<T>::next_job_id())
+                .filter_map(|job_id| JobResults::<T>::get(job_id).map(|result| (job_id, result)))
+                .filter(|(_, result)| !result.verified && !result.rejected)
+                .take(limit as usize)
+                .collect()
+        }
     }
 }
@@ -0,0 +1,157 @@
+//! Offchain worker logic for result submission
+//!
+//! Submits the results the job registry's offchain worker executed, with their proof, once
+//! their job is in progress. Results of claimed jobs are signed with the claiming key.
+
+use crate::{Call, Config, JobResults, ProofType};
+use codec::Encode;
+use frame_support::traits::Get;
+use frame_system::{
+    offchain::{ForAny, SendSignedTransaction, SignMessage, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use pallet_job_registry::{
+    ocw::{self as registry_ocw, JobExecutionResult, JobSpec},
+    JobStatus, Pallet as JobRegistry,
+};
+use sp_core::H256;
+use sp_std::{vec, vec::Vec};
+
+/// Submit the recorded results of jobs that are ready for a proof
+pub(crate) fn submit_job_results<T: Config>(
+    block_number: BlockNumberFor<T>,
+) -> Result<(), &'static str> {
+    let executed = registry_ocw::executed_jobs();
+    if executed.is_empty() {
+        return Ok(());
+    }
+
+    let local_keys = registry_ocw::local_keys::<T>();
+    if local_keys.is_empty() {
+        return Err("No signing keys available");
+    }
+
+    log::debug!(
+        target: "job-verifier",
+        "OCW: {} executed jobs awaiting submission at block {:?}", executed.len(), block_number
+    );
+
+    for job_id in executed {
+        let Some(job) = JobRegistry::<T>::jobs(job_id) else {
+            registry_ocw::clear_execution_result(job_id);
+            continue;
+        };
+        if JobResults::<T>::contains_key(job_id) {
+            registry_ocw::clear_execution_result(job_id);
+            continue;
+        }
+        match job.status {
+            // The job owner has not started the job yet
+            JobStatus::Pending => continue,
+            JobStatus::InProgress | JobStatus::Completed => {}
+            JobStatus::Verified | JobStatus::Failed => {
+                registry_ocw::clear_execution_result(job_id);
+                continue;
+            }
+        }
+
+        let Some(result) = registry_ocw::execution_result(job_id) else {
+            registry_ocw::clear_execution_result(job_id);
+            continue;
+        };
+        if !result.success {
+            log::warn!(target: "job-verifier", "OCW: Job {} failed, nothing to submit", job_id);
+            registry_ocw::clear_execution_result(job_id);
+            continue;
+        }
+
+        // A claimed job takes only the claimer's proof; another collator's claim means that
+        // collator executes this attempt
+        let signer = match JobRegistry::<T>::active_claim(job_id) {
+            Some(claimer) => match local_keys.iter().find(|(_, account)| *account == claimer) {
+                Some((public, _)) => {
+                    Signer::<T, T::AuthorityId>::any_account().with_filter(vec![public.clone()])
+                }
+                None => continue,
+            },
+            None => Signer::<T, T::AuthorityId>::any_account(),
+        };
+
+        let result_hash = H256(sp_io::hashing::blake2_256(&result.result_data));
+        let Some((proof_type, proof)) = generate_proof(&signer, &job.metadata, &result, result_hash)
+        else {
+            log::error!(target: "job-verifier", "OCW: Failed to sign result of job {}", job_id);
+            continue;
+        };
+        if proof.len() > T::MaxProofSize::get() as usize {
+            log::warn!(target: "job-verifier", "OCW: Job {} proof exceeds MaxProofSize", job_id);
+            registry_ocw::clear_execution_result(job_id);
+            continue;
+        }
+
+        match submit_job_result::<T>(&signer, job_id, result_hash, proof_type, proof) {
+            Ok(()) => {
+                registry_ocw::record_metrics(|metrics| {
+                    metrics.proofs_submitted = metrics.proofs_submitted.saturating_add(1)
+                });
+                registry_ocw::record_submission(job_id, true);
+                registry_ocw::clear_execution_result(job_id);
+            }
+            Err(e) => {
+                registry_ocw::record_submission(job_id, false);
+                registry_ocw::record_metrics(|metrics| {
+                    metrics.proof_submission_failures =
+                        metrics.proof_submission_failures.saturating_add(1)
+                });
+                log::error!(target: "job-verifier", "OCW: Job {}: {}", job_id, e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate the proof of a result
+///
+/// Oracle and external executor results are signed by this node over `(job_id, result_hash)`,
+/// as nodes may observe different values or not run the executor at all; the proof is the
+/// SCALE-encoded `(public, signature)`. Other results are proven by the result itself, the
+/// preimage of the result hash.
+fn generate_proof<T: Config>(
+    signer: &Signer<T, T::AuthorityId, ForAny>,
+    metadata: &[u8],
+    result: &JobExecutionResult,
+    result_hash: H256,
+) -> Option<(ProofType, Vec<u8>)> {
+    match JobSpec::from_metadata(metadata) {
+        Some(JobSpec::OracleFetch { .. } | JobSpec::External { .. }) => {
+            let message = (result.job_id, result_hash).encode();
+            let (account, signature) = signer.sign_message(&message)?;
+            Some((ProofType::Signature, (account.public, signature).encode()))
+        }
+        _ => Some((ProofType::Hash, result.result_data.clone())),
+    }
+}
+
+/// Submit a job result to the chain
+fn submit_job_result<T: Config>(
+    signer: &Signer<T, T::AuthorityId, ForAny>,
+    job_id: u64,
+    result_hash: H256,
+    proof_type: ProofType,
+    proof: Vec<u8>,
+) -> Result<(), &'static str> {
+    let proof_type_u8 = proof_type as u8;
+    match signer.send_signed_transaction(|_account| Call::submit_proof {
+        job_id,
+        result_hash,
+        proof_type_u8,
+        proof_data: proof.clone(),
+    }) {
+        Some((_, Ok(()))) => {
+            log::info!(target: "job-verifier", "OCW: Submitted result for job {}", job_id);
+            Ok(())
+        }
+        _ => Err("Failed to submit job result"),
+    }
+}
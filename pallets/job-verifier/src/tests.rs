@@ -360,3 +360,214 @@ fn is_verified_helper_works() {
         assert!(JobVerifier::is_verified(0));
     });
 }
+
+#[test]
+fn pending_proofs_are_listed_until_verified() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for job_id in 0..3 {
+            assert_ok!(JobRegistry::submit_job(
+                RuntimeOrigin::signed(1),
+                vec![1, 2, 3],
+                vec![],
+                100
+            ));
+            // 1 = InProgress
+            assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), job_id, 1));
+        }
+
+        // Job 1 never gets a proof; 0 = Signature
+        for job_id in [0, 2] {
+            assert_ok!(JobVerifier::submit_proof(
+                RuntimeOrigin::signed(1),
+                job_id,
+                H256::from([1u8; 32]),
+                0,
+                vec![0u8; 64]
+            ));
+        }
+
+        let pending: Vec<u64> =
+            JobVerifier::get_pending_proofs(0, 10).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(pending, vec![0, 2]);
+        assert_eq!(JobVerifier::get_pending_proofs(1, 10)[0].0, 2);
+        assert_eq!(JobVerifier::get_pending_proofs(0, 1).len(), 1);
+
+        assert_ok!(JobVerifier::verify_proof(RuntimeOrigin::signed(1), 0));
+
+        let pending = JobVerifier::get_pending_proofs(0, 10);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].0, 2);
+        assert!(!pending[0].1.verified);
+    });
+}
+
+#[test]
+fn rejected_proof_is_recorded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(JobRegistry::submit_job(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3],
+            vec![],
+            100
+        ));
+
+        // 1 = InProgress
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 1));
+
+        // Hash proof (2) whose data doesn't match the result hash
+        assert_ok!(JobVerifier::submit_proof(
+            RuntimeOrigin::signed(1),
+            0,
+            H256::from([1u8; 32]),
+            2,
+            b"test result data".to_vec()
+        ));
+
+        // Only verifiers may verify
+        assert_noop!(
+            JobVerifier::verify_proof(RuntimeOrigin::signed(3), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(JobVerifier::verify_proof(
+            RuntimeOrigin::signed(2),
+            0
+        ));
+
+        assert!(!JobVerifier::is_verified(0));
+        assert!(JobVerifier::job_results(0).unwrap().rejected);
+        assert_eq!(JobVerifier::get_stats().total_proofs_failed, 1);
+        System::assert_last_event(Event::VerificationFailed { job_id: 0 }.into());
+
+        // The rejected proof is no longer pending and can't be verified again
+        assert!(JobVerifier::get_pending_proofs(0, 10).is_empty());
+        assert_noop!(
+            JobVerifier::verify_proof(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::ProofRejected
+        );
+        assert_eq!(JobVerifier::get_stats().total_proofs_failed, 1);
+
+        // A new proof can be verified
+        let data = b"test result data".to_vec();
+        let result_hash = H256::from(sp_io::hashing::blake2_256(&data));
+        assert_ok!(JobVerifier::submit_proof(RuntimeOrigin::signed(1), 0, result_hash, 2, data));
+        assert_eq!(JobVerifier::get_pending_proofs(0, 10).len(), 1);
+        assert_ok!(JobVerifier::verify_proof(RuntimeOrigin::signed(1), 0));
+        assert!(JobVerifier::is_verified(0));
+    });
+}
+
+#[test]
+fn offchain_workers_execute_and_submit_job_results() {
+    use codec::Decode;
+    use frame_support::traits::Hooks;
+    use pallet_job_registry::ocw;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+    use sp_runtime::testing::UintAuthorityId;
+
+    let (offchain, _state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1, 2, 3], vec![], 100));
+        assert_ok!(JobRegistry::claim_job(RuntimeOrigin::signed(7), 0));
+
+        // The registry's worker executes the job it claimed and records its result
+        JobRegistry::offchain_worker(1);
+        assert_eq!(ocw::executed_jobs(), vec![0]);
+        let result = ocw::execution_result(0).unwrap();
+
+        // Nothing is submitted until the owner starts the job
+        JobVerifier::offchain_worker(1);
+        assert!(pool_state.read().transactions.is_empty());
+
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 1));
+        JobVerifier::offchain_worker(2);
+
+        let txs = pool_state.read().transactions.clone();
+        assert_eq!(txs.len(), 1);
+        let xt = Extrinsic::decode(&mut &txs[0][..]).unwrap();
+        let RuntimeCall::JobVerifier(crate::Call::submit_proof {
+            job_id,
+            result_hash,
+            proof_type_u8,
+            proof_data,
+        }) = xt.function
+        else {
+            panic!("unexpected call")
+        };
+        assert_eq!(result_hash, H256(sp_io::hashing::blake2_256(&result.result_data)));
+        assert!(ocw::executed_jobs().is_empty());
+        assert!(ocw::execution_result(0).is_none());
+        assert_eq!(ocw::metrics().proofs_submitted, 1);
+        assert_eq!(ocw::metrics().proof_submission_failures, 0);
+
+        // Only the claimer's proof is taken; the submitted hash proof verifies
+        assert_noop!(
+            JobVerifier::submit_proof(
+                RuntimeOrigin::signed(8),
+                job_id,
+                result_hash,
+                proof_type_u8,
+                proof_data.clone()
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(JobVerifier::submit_proof(
+            RuntimeOrigin::signed(7),
+            job_id,
+            result_hash,
+            proof_type_u8,
+            proof_data
+        ));
+        assert_ok!(JobVerifier::verify_proof(RuntimeOrigin::signed(2), job_id));
+        assert!(JobVerifier::is_verified(job_id));
+    });
+}
+
+#[test]
+fn submitted_proofs_are_indexed_offchain() {
+    use codec::Decode;
+
+    let mut ext = new_test_ext();
+    let result_hash = H256::from([1u8; 32]);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 1));
+        assert_ok!(JobVerifier::submit_proof(
+            RuntimeOrigin::signed(1),
+            0,
+            result_hash,
+            2,
+            vec![7, 7, 7]
+        ));
+    });
+    ext.persist_offchain_overlay();
+
+    let raw = ext.offchain_db().get(&crate::proof_index_key(0)).unwrap();
+    let indexed = crate::IndexedProof::<u64>::decode(&mut &raw[..]).unwrap();
+    assert_eq!(
+        indexed,
+        crate::IndexedProof {
+            submitter: 1,
+            result_hash,
+            proof_type: ProofType::Hash,
+            proof: vec![7, 7, 7],
+            submitted_at: 1,
+        }
+    );
+    assert!(ext.offchain_db().get(&crate::proof_index_key(1)).is_none());
+}
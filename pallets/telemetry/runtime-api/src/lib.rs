@@ -0,0 +1,60 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Telemetry Runtime API
+//!
+//! Lets the node RPC and external dashboards query telemetry via `state_call`
+//! instead of decoding raw storage.
+
+use codec::Codec;
+use pallet_telemetry::{
+    BlockPerformance, EraTotals, ExecutionTimePercentiles, HistoryTier, JobMetrics,
+    MetricsSnapshot, ParticipantMetrics, SlaCounters, SystemMetrics, TierSummary,
+    ValidatorPerformance, VerifierMetrics, XcmTrafficMetrics,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait TelemetryApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// System-wide metrics
+        fn system_metrics() -> SystemMetrics;
+
+        /// Metrics for a job, if recorded
+        fn job_metrics(job_id: u64) -> Option<JobMetrics>;
+
+        /// Performance counters for a validator
+        fn validator_performance(validator: AccountId) -> ValidatorPerformance;
+
+        /// Block performance for the last `count` blocks, newest first
+        fn recent_block_performance(count: u32) -> Vec<BlockPerformance>;
+
+        /// p50/p95/p99 job execution times in blocks
+        fn execution_time_percentiles() -> ExecutionTimePercentiles;
+
+        /// Aggregated metrics for a job submitter
+        fn account_metrics(account: AccountId) -> ParticipantMetrics;
+
+        /// Aggregated metrics for a worker
+        fn worker_metrics(worker: AccountId) -> ParticipantMetrics;
+
+        /// System, validator and the last `recent_blocks` block metrics in one SCALE structure
+        fn metrics_snapshot(recent_blocks: u32) -> MetricsSnapshot<AccountId>;
+
+        /// XCMP/XCM traffic counters
+        fn xcm_traffic() -> XcmTrafficMetrics;
+
+        /// Deadline adherence of a submitter's jobs in the last completed epoch
+        fn submitter_sla(account: AccountId) -> SlaCounters;
+
+        /// Latest `count` buckets of a downsampled history tier, newest first
+        fn tier_history(tier: HistoryTier, count: u32) -> Vec<TierSummary>;
+
+        /// Verification counters for a verifier
+        fn verifier_metrics(verifier: AccountId) -> VerifierMetrics;
+
+        /// Counter increments of the latest `count` completed eras, newest first
+        fn era_totals(count: u32) -> Vec<EraTotals>;
+    }
+}
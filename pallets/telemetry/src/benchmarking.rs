@@ -5,11 +5,232 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Telemetry;
 use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get, Hooks};
 use frame_system::RawOrigin;
+use pallet_job_registry::JobStatus;
+use sp_runtime::Percent;
+use sp_std::vec;
+
+/// Submit a job owned by the whitelisted caller, returning its ID
+fn setup_job<T: Config>() -> Result<u64, BenchmarkError> {
+    let caller: T::AccountId = whitelisted_caller();
+    let job_id = pallet_job_registry::NextJobId::<T>::get();
+    pallet_job_registry::Pallet::<T>::submit_job(
+        RawOrigin::Signed(caller).into(),
+        vec![1u8; 256],
+        vec![],
+        1000u32.into(),
+    )?;
+    Ok(job_id)
+}
+
+/// Finished job metrics for a job the registry doesn't know, so pruning removes it
+fn orphan_metrics(job_id: u64) -> JobMetrics {
+    JobMetrics {
+        job_id,
+        start_block: 0,
+        end_block: Some(1),
+        execution_time_blocks: 1,
+        status: JobStatus::Completed,
+        succeeded: true,
+    }
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
+    #[benchmark]
+    fn record_job_metrics() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ReporterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let job_id = setup_job::<T>()?;
+        let worker: T::AccountId = account("worker", 0, 0);
+
+        // First finished record: aggregates, samples and histogram are all updated
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, job_id, 1, Some(50), true, Some(worker.clone()), 1_000);
+
+        assert_eq!(WorkerMetrics::<T>::get(&worker).jobs_run, 1);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn update_validator_performance() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ReporterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let validator: T::AccountId = account("validator", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, validator.clone(), 90, 10);
+
+        assert_eq!(ValidatorPerformanceMap::<T>::get(&validator).blocks_produced, 90);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn update_system_metrics() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[block]
+        {
+            let _ = Telemetry::<T>::trigger_system_metrics_update(RawOrigin::Signed(caller).into());
+        }
+    }
+
+    #[benchmark]
+    fn rollup_epoch(b: Linear<1, 1000>) -> Result<(), BenchmarkError> {
+        for bn in 1..=b {
+            BlockPerformanceHistory::<T>::insert(
+                Telemetry::<T>::block_performance_slot(bn),
+                BlockPerformance {
+                    block_number: bn,
+                    block_time_ms: 12_000,
+                    extrinsic_count: 10,
+                    weight_used: 1_000_000,
+                    block_size_bytes: 1_024,
+                    is_slow: false,
+                    fees_collected: 1_000,
+                    weight_utilization: Percent::from_percent(10),
+                },
+            );
+        }
+        // Every alert fires
+        let origin =
+            T::AlertOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        for metric in 0..3u8 {
+            Telemetry::<T>::set_alert_threshold(origin.clone(), metric, Some(0))?;
+        }
+        EpochJobsFinished::<T>::put(10);
+        EpochJobsFailed::<T>::put(5);
+
+        #[block]
+        {
+            Telemetry::<T>::rollup_epoch(b, b);
+        }
+
+        assert_eq!(EpochSummaries::<T>::get(1).map(|summary| summary.blocks), Some(b));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_alert_threshold() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AlertOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0, Some(50));
+
+        assert_eq!(AlertThresholds::<T>::get(AlertMetric::JobFailureRate), Some(50));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_retention() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, 1, Some(100));
+
+        assert_eq!(RetentionOverrides::<T>::get(RetentionCategory::JobMetrics), Some(100));
+    }
+
+    #[benchmark]
+    fn prune_job_metrics(n: Linear<0, 50>) {
+        for job_id in 0..n as u64 {
+            JobMetricsHistory::<T>::insert(job_id, orphan_metrics(job_id));
+        }
+
+        #[block]
+        {
+            Telemetry::<T>::prune_job_metrics(1, n);
+        }
+
+        assert_eq!(JobMetricsHistory::<T>::iter_keys().count(), 0);
+    }
+
+    #[benchmark]
+    fn sample_storage() {
+        JobMetricsHistory::<T>::insert(0, orphan_metrics(0));
+
+        #[block]
+        {
+            Telemetry::<T>::sample_storage(1);
+        }
+
+        assert_eq!(StorageGrowth::<T>::get(TrackedMap::JobMetrics).map(|s| s.entries), Some(1));
+    }
+
+    #[benchmark]
+    fn set_collection_paused() -> Result<(), BenchmarkError> {
+        let origin =
+            T::CollectionOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, true);
+
+        assert!(CollectionPaused::<T>::get());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn prune_telemetry(n: Linear<0, 500>) {
+        let caller: T::AccountId = whitelisted_caller();
+        // Half expired job metrics, half stale block performance
+        let jobs = n / 2;
+        for job_id in 0..jobs as u64 {
+            JobMetricsHistory::<T>::insert(job_id, orphan_metrics(job_id));
+        }
+        for bn in 0..n - jobs {
+            let perf = BlockPerformance {
+                block_number: bn,
+                block_time_ms: 12_000,
+                extrinsic_count: 10,
+                weight_used: 1_000_000,
+                block_size_bytes: 1_024,
+                is_slow: false,
+                fees_collected: 1_000,
+                weight_utilization: Percent::from_percent(10),
+            };
+            BlockPerformanceHistory::<T>::insert(u32::MAX - bn, perf);
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), n);
+
+        assert_eq!(JobMetricsHistory::<T>::iter_keys().count(), 0);
+        assert_eq!(BlockPerformanceHistory::<T>::iter_keys().count(), 0);
+    }
+
+    #[benchmark]
+    fn on_finalize() {
+        // A block inside an epoch; the boundary rollup is weighed separately
+        let bn = 1u32;
+        LastBlockTimestamp::<T>::put(1);
+
+        #[block]
+        {
+            Telemetry::<T>::on_finalize(bn.into());
+        }
+
+        assert!(Telemetry::<T>::get_block_performance(bn).is_some());
+    }
+
+    #[benchmark]
+    fn snapshot_era() {
+        // End of the first era
+        let end_block = T::EpochLength::get().saturating_mul(T::EpochsPerEra::get()).max(1);
+
+        #[block]
+        {
+            Telemetry::<T>::snapshot_era(end_block);
+        }
+
+        assert!(EraHistory::<T>::contains_key(1));
+    }
+
     impl_benchmark_test_suite!(Telemetry, crate::mock::new_test_ext(), crate::mock::Test);
 }
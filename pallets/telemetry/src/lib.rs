@@ -5,6 +5,8 @@
 //! This pallet collects and exposes metrics for jobs, consensus, and validators.
 //! Metrics can be exported to Prometheus for monitoring and analysis.
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -19,16 +21,66 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+/// Upper bounds (in blocks, inclusive) of the execution time histogram buckets.
+/// A final overflow bucket catches everything above the last bound.
+pub const EXECUTION_TIME_BUCKETS: [u32; 10] = [1, 2, 5, 10, 20, 50, 100, 200, 500, 1000];
+
+/// Number of histogram buckets, including the overflow bucket
+pub const EXECUTION_TIME_BUCKET_COUNT: usize = EXECUTION_TIME_BUCKETS.len() + 1;
+
+/// Upper bounds (in bytes, inclusive) of the proof size histogram buckets.
+/// A final overflow bucket catches everything above the last bound.
+pub const PROOF_SIZE_BUCKETS: [u32; 10] = [64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 65536];
+
+/// Number of proof size buckets, including the overflow bucket
+pub const PROOF_SIZE_BUCKET_COUNT: usize = PROOF_SIZE_BUCKETS.len() + 1;
+
+/// Fixed-point scale of the execution time moving average
+pub const EWMA_SCALE: u64 = 1_000;
+
+/// Offchain (persistent) storage key holding the URL metrics are pushed to, SCALE-encoded
+/// as `Vec<u8>`. Pushing is disabled on nodes where it is unset.
+pub const PUSH_ENDPOINT_KEY: &[u8] = b"telemetry::push_endpoint";
+
+/// Offchain (persistent) storage key holding the push interval in blocks (`u32`)
+pub const PUSH_INTERVAL_KEY: &[u8] = b"telemetry::push_interval";
+
+/// Push interval used when `PUSH_INTERVAL_KEY` is unset
+pub const DEFAULT_PUSH_INTERVAL: u32 = 10;
+
+/// Maximum number of job metrics entries inspected for pruning per block
+pub const MAX_JOB_METRICS_PRUNED_PER_BLOCK: u32 = 50;
+
+/// Maximum number of telemetry entries inspected by a single `prune_telemetry` call
+pub const MAX_TELEMETRY_PRUNED_PER_CALL: u32 = 500;
+
+/// Prefix of the `DigestItem::Other` payload carrying a `MetricsDigest`
+pub const METRICS_DIGEST_ID: [u8; 4] = *b"tlmy";
+
+/// Maximum number of skipped slots charged as misses in a single block
+pub const MAX_MISSED_SLOTS_PER_BLOCK: u64 = 16;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::SaturatedConversion;
-    use pallet_job_registry::{JobStatus, Pallet as JobRegistry};
-    use pallet_consensus_manager::{ConsensusType, Pallet as ConsensusManager};
+    use sp_runtime::{traits::SaturatedConversion, Percent, Permill};
+    use alloc::{format, string::String};
+    use sp_runtime::offchain::{http, storage::StorageValueRef, Duration};
+    use sp_std::{vec, vec::Vec};
+    use pallet_job_registry::{EconomicFlow, Job, JobStatus, Pallet as JobRegistry};
+    use polkacomputelab_primitives::{block_time_ms, ConsensusMetricsProvider, ConsensusType};
+    use cumulus_primitives_core::{
+        relay_chain::BlockNumber as RelayBlockNumber, ParaId, XcmpMessageFormat, XcmpMessageHandler,
+    };
+    use xcm::latest::{Location, SendError, SendResult, SendXcm, Xcm, XcmHash};
+
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Job execution metrics
@@ -61,6 +113,402 @@ pub mod pallet {
         pub last_block_produced: u32,
     }
 
+    /// Rollup of the per-block metrics of one epoch
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct EpochSummary {
+        /// First block of the epoch
+        pub start_block: u32,
+        /// Last block of the epoch
+        pub end_block: u32,
+        /// Blocks with recorded performance
+        pub blocks: u32,
+        /// Shortest block time in milliseconds
+        pub min_block_time_ms: u64,
+        /// Longest block time in milliseconds
+        pub max_block_time_ms: u64,
+        /// Average block time in milliseconds
+        pub avg_block_time_ms: u64,
+        /// Blocks flagged as slow
+        pub slow_blocks: u32,
+        /// Jobs that finished (completed or failed) during the epoch
+        pub jobs_finished: u32,
+        /// Jobs that failed during the epoch
+        pub jobs_failed: u32,
+        /// Finished jobs that completed successfully by their deadline
+        pub jobs_on_time: u32,
+    }
+
+    impl EpochSummary {
+        /// Fold a later summary into this one, as for a longer period covering both
+        pub fn merge(&mut self, other: &EpochSummary) {
+            if other.blocks > 0 {
+                self.min_block_time_ms = if self.blocks == 0 {
+                    other.min_block_time_ms
+                } else {
+                    self.min_block_time_ms.min(other.min_block_time_ms)
+                };
+                let total_time = (self.avg_block_time_ms * self.blocks as u64)
+                    .saturating_add(other.avg_block_time_ms * other.blocks as u64);
+                self.blocks = self.blocks.saturating_add(other.blocks);
+                self.avg_block_time_ms = total_time / self.blocks as u64;
+            }
+            self.start_block = self.start_block.min(other.start_block);
+            self.end_block = self.end_block.max(other.end_block);
+            self.max_block_time_ms = self.max_block_time_ms.max(other.max_block_time_ms);
+            self.slow_blocks = self.slow_blocks.saturating_add(other.slow_blocks);
+            self.jobs_finished = self.jobs_finished.saturating_add(other.jobs_finished);
+            self.jobs_failed = self.jobs_failed.saturating_add(other.jobs_failed);
+            self.jobs_on_time = self.jobs_on_time.saturating_add(other.jobs_on_time);
+        }
+
+        /// Share of finished jobs that completed by their deadline
+        pub fn sla_adherence(&self) -> Percent {
+            SlaCounters { jobs_finished: self.jobs_finished, jobs_on_time: self.jobs_on_time }
+                .adherence()
+        }
+    }
+
+    /// Downsampled history tier, aggregated from epoch summaries
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum HistoryTier {
+        /// `HourlyTierBlocks` per bucket
+        Hourly,
+        /// 24 hourly buckets per bucket
+        Daily,
+    }
+
+    impl HistoryTier {
+        /// Convert from u8 representation
+        pub fn from_u8(value: u8) -> Result<Self, ()> {
+            match value {
+                0 => Ok(HistoryTier::Hourly),
+                1 => Ok(HistoryTier::Daily),
+                _ => Err(()),
+            }
+        }
+
+        fn retention_category(&self) -> RetentionCategory {
+            match self {
+                HistoryTier::Hourly => RetentionCategory::HourlyTier,
+                HistoryTier::Daily => RetentionCategory::DailyTier,
+            }
+        }
+    }
+
+    /// Aggregate of the epochs that ended within one tier bucket
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct TierSummary {
+        /// Bucket index; bucket `n` ends at block `n * blocks per bucket`
+        pub period: u32,
+        /// Epochs folded in
+        pub epochs: u32,
+        /// Merged epoch metrics
+        pub summary: EpochSummary,
+    }
+
+    /// Lifetime values of the monotonic telemetry counters
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct CounterSnapshot {
+        /// Events processed by the event hub
+        pub events_processed: u64,
+        /// Proofs stored by the job verifier
+        pub proofs_submitted: u64,
+        /// Jobs submitted
+        pub jobs_submitted: u64,
+        /// Jobs completed
+        pub jobs_completed: u64,
+        /// Jobs failed
+        pub jobs_failed: u64,
+        /// Jobs verified
+        pub jobs_verified: u64,
+        /// Cross-chain events received
+        pub cross_chain_events: u64,
+    }
+
+    impl CounterSnapshot {
+        /// Increments since an earlier snapshot
+        pub fn since(&self, earlier: &CounterSnapshot) -> CounterSnapshot {
+            CounterSnapshot {
+                events_processed: self.events_processed.saturating_sub(earlier.events_processed),
+                proofs_submitted: self.proofs_submitted.saturating_sub(earlier.proofs_submitted),
+                jobs_submitted: self.jobs_submitted.saturating_sub(earlier.jobs_submitted),
+                jobs_completed: self.jobs_completed.saturating_sub(earlier.jobs_completed),
+                jobs_failed: self.jobs_failed.saturating_sub(earlier.jobs_failed),
+                jobs_verified: self.jobs_verified.saturating_sub(earlier.jobs_verified),
+                cross_chain_events: self
+                    .cross_chain_events
+                    .saturating_sub(earlier.cross_chain_events),
+            }
+        }
+    }
+
+    /// Counter increments over one era
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct EraTotals {
+        /// Era index; era `n` ends at block `n * EpochLength * EpochsPerEra`
+        pub era: u32,
+        /// First block of the era
+        pub start_block: u32,
+        /// Last block of the era
+        pub end_block: u32,
+        /// Counter increments within the era
+        pub counters: CounterSnapshot,
+    }
+
+    impl EraTotals {
+        /// Blocks the era spans, for per-block rates
+        pub fn blocks(&self) -> u32 {
+            self.end_block.saturating_sub(self.start_block).saturating_add(1)
+        }
+    }
+
+    /// Deadline adherence counters over a set of finished jobs
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct SlaCounters {
+        /// Jobs that completed or failed
+        pub jobs_finished: u32,
+        /// Jobs that completed successfully by their deadline
+        pub jobs_on_time: u32,
+    }
+
+    impl SlaCounters {
+        /// Share of finished jobs that were on time; 100% when none finished
+        pub fn adherence(&self) -> Percent {
+            if self.jobs_finished == 0 {
+                return Percent::one();
+            }
+            Percent::from_rational(self.jobs_on_time, self.jobs_finished)
+        }
+
+        fn observe(&mut self, on_time: bool) {
+            self.jobs_finished = self.jobs_finished.saturating_add(1);
+            if on_time {
+                self.jobs_on_time = self.jobs_on_time.saturating_add(1);
+            }
+        }
+    }
+
+    /// A submitter's SLA counters for the epoch they last had a job finish in, and for the
+    /// epoch before that. Rolled over lazily when their next job finishes in a later epoch.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct SubmitterSla {
+        /// Epoch `current` counts
+        pub epoch: u32,
+        /// Counters of `epoch`
+        pub current: SlaCounters,
+        /// Counters of `epoch - 1`
+        pub previous: SlaCounters,
+    }
+
+    /// Metric an alert threshold can be set on, checked against each epoch summary
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum AlertMetric {
+        /// Share of finished jobs that failed, in percent
+        JobFailureRate,
+        /// Average block time in milliseconds
+        AvgBlockTimeMs,
+        /// Number of blocks flagged as slow
+        SlowBlocks,
+    }
+
+    impl AlertMetric {
+        /// Convert from u8 representation
+        pub fn from_u8(value: u8) -> Result<Self, ()> {
+            match value {
+                0 => Ok(AlertMetric::JobFailureRate),
+                1 => Ok(AlertMetric::AvgBlockTimeMs),
+                2 => Ok(AlertMetric::SlowBlocks),
+                _ => Err(()),
+            }
+        }
+
+        /// Value of this metric for an epoch
+        pub fn value_of(&self, summary: &EpochSummary) -> u64 {
+            match self {
+                AlertMetric::JobFailureRate => {
+                    if summary.jobs_finished == 0 {
+                        0
+                    } else {
+                        summary.jobs_failed as u64 * 100 / summary.jobs_finished as u64
+                    }
+                },
+                AlertMetric::AvgBlockTimeMs => summary.avg_block_time_ms,
+                AlertMetric::SlowBlocks => summary.slow_blocks as u64,
+            }
+        }
+    }
+
+    /// Slot-based block authorship, shared with the consensus manager
+    pub use polkacomputelab_primitives::SlotAuthorship;
+
+    /// Hook invoked for every alert raised at an epoch boundary
+    pub trait OnAlert {
+        fn on_alert(epoch: u32, metric: AlertMetric, value: u64, threshold: u64);
+    }
+
+    impl OnAlert for () {
+        fn on_alert(_epoch: u32, _metric: AlertMetric, _value: u64, _threshold: u64) {}
+    }
+
+    /// Structured export of the current metrics, for indexers
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct MetricsSnapshot<AccountId> {
+        /// System-wide metrics
+        pub system: SystemMetrics,
+        /// Performance of every tracked validator
+        pub validators: Vec<(AccountId, ValidatorPerformance)>,
+        /// Most recent block performance, newest first
+        pub recent_blocks: Vec<BlockPerformance>,
+    }
+
+    /// Telemetry history whose retention window can be overridden
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RetentionCategory {
+        /// `BlockPerformanceHistory`, in blocks
+        BlockPerformance,
+        /// `JobMetricsHistory`, in blocks after the job ended
+        JobMetrics,
+        /// Hourly `TierHistory`, in buckets
+        HourlyTier,
+        /// Daily `TierHistory`, in buckets
+        DailyTier,
+    }
+
+    impl RetentionCategory {
+        /// Convert from u8 representation
+        pub fn from_u8(value: u8) -> Result<Self, ()> {
+            match value {
+                0 => Ok(RetentionCategory::BlockPerformance),
+                1 => Ok(RetentionCategory::JobMetrics),
+                2 => Ok(RetentionCategory::HourlyTier),
+                3 => Ok(RetentionCategory::DailyTier),
+                _ => Err(()),
+            }
+        }
+    }
+
+    /// Write position of the execution time ring
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct RingCursor {
+        /// Slot the next sample is written to
+        pub next: u32,
+        /// Number of filled slots
+        pub len: u32,
+    }
+
+    /// Aggregated job metrics for a marketplace participant (submitter or worker)
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct ParticipantMetrics {
+        /// Jobs with recorded metrics
+        pub jobs_run: u32,
+        /// Jobs that succeeded
+        pub jobs_succeeded: u32,
+        /// Sum of execution times (blocks), for the average
+        pub total_latency_blocks: u64,
+        /// Fees paid (submitters) or earned (workers)
+        pub total_fees: u128,
+    }
+
+    impl ParticipantMetrics {
+        /// Share of jobs that succeeded
+        pub fn success_rate(&self) -> Percent {
+            Percent::from_rational(self.jobs_succeeded, self.jobs_run.max(1))
+        }
+
+        /// Average execution time in blocks
+        pub fn average_latency(&self) -> u32 {
+            (self.total_latency_blocks / self.jobs_run.max(1) as u64).saturated_into()
+        }
+
+        fn observe(&mut self, succeeded: bool, latency: u32, fee: u128) {
+            self.jobs_run = self.jobs_run.saturating_add(1);
+            if succeeded {
+                self.jobs_succeeded = self.jobs_succeeded.saturating_add(1);
+            }
+            self.total_latency_blocks = self.total_latency_blocks.saturating_add(latency as u64);
+            self.total_fees = self.total_fees.saturating_add(fee);
+        }
+    }
+
+    /// Compact health summary published in the block header every `DigestInterval` blocks,
+    /// covering the blocks since the previous digest
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct MetricsDigest {
+        /// Block the digest was published in
+        pub block_number: u32,
+        /// Jobs finished (completed or failed) in the interval
+        pub jobs_finished: u32,
+        /// Share of finished jobs that failed
+        pub failure_rate: Percent,
+        /// Average block time in milliseconds over the interval
+        pub avg_block_time_ms: u64,
+    }
+
+    impl MetricsDigest {
+        /// Extract a metrics digest from a header digest item, if it is one
+        pub fn from_digest_item(item: &sp_runtime::DigestItem) -> Option<Self> {
+            let sp_runtime::DigestItem::Other(data) = item else { return None };
+            let payload = data.strip_prefix(&METRICS_DIGEST_ID[..])?;
+            Self::decode(&mut &payload[..]).ok()
+        }
+    }
+
+    /// Running totals for the next `MetricsDigest`
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct DigestAccumulator {
+        /// Blocks finalized since the last digest
+        pub blocks: u32,
+        /// Sum of their block times in milliseconds
+        pub total_block_time_ms: u64,
+        /// Jobs finished since the last digest
+        pub jobs_finished: u32,
+        /// Jobs failed since the last digest
+        pub jobs_failed: u32,
+    }
+
+    /// Proof submissions of one account
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct ProofSizeStats {
+        /// Proofs submitted
+        pub proofs: u32,
+        /// Total proof bytes submitted
+        pub total_bytes: u64,
+        /// Largest proof submitted, in bytes
+        pub max_bytes: u32,
+    }
+
+    /// Proof verifications performed by one verifier
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct VerifierMetrics {
+        /// Proofs checked, accepted or rejected
+        pub verifications: u32,
+        /// Proofs rejected
+        pub rejections: u32,
+        /// Sum of blocks from proof submission to verification, for the average
+        pub total_latency_blocks: u64,
+    }
+
+    impl VerifierMetrics {
+        /// Share of checked proofs that were rejected
+        pub fn rejection_rate(&self) -> Percent {
+            Percent::from_rational(self.rejections, self.verifications.max(1))
+        }
+
+        /// Average blocks from proof submission to verification
+        pub fn average_latency(&self) -> u32 {
+            (self.total_latency_blocks / self.verifications.max(1) as u64).saturated_into()
+        }
+    }
+
+    /// Execution time percentiles (in blocks), resolved to histogram bucket bounds.
+    /// Values in the overflow bucket report `u32::MAX`.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct ExecutionTimePercentiles {
+        pub p50: u32,
+        pub p95: u32,
+        pub p99: u32,
+    }
+
     /// System-wide metrics
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
     pub struct SystemMetrics {
@@ -72,14 +520,24 @@ pub mod pallet {
         pub total_jobs_failed: u64,
         /// Total jobs verified
         pub total_jobs_verified: u64,
-        /// Average job execution time (blocks)
-        pub avg_job_execution_time: u32,
+        /// Job execution time percentiles (blocks)
+        pub execution_time_percentiles: ExecutionTimePercentiles,
         /// Total forks detected
         pub total_forks: u32,
         /// Total consensus switches
         pub total_consensus_switches: u32,
         /// Current consensus type
         pub current_consensus: ConsensusType,
+        /// Funds currently locked in job escrow
+        pub total_escrow_locked: u128,
+        /// Bounties paid out
+        pub total_bounties_paid: u128,
+        /// Deposits currently held
+        pub total_deposits_held: u128,
+        /// Fees burned
+        pub total_fees_burned: u128,
+        /// Share of jobs finished in the last completed epoch that met their deadline
+        pub sla_adherence: Percent,
     }
 
     /// Block performance metrics
@@ -95,22 +553,202 @@ pub mod pallet {
         pub weight_used: u64,
         /// Block size in bytes
         pub block_size_bytes: u32,
+        /// Whether the block took longer than `SlowBlockThresholdMs`
+        pub is_slow: bool,
+        /// Transaction fees collected in the block
+        pub fees_collected: u128,
+        /// Block weight used relative to the maximum block weight, in whichever of
+        /// ref time and proof size is closer to its limit
+        pub weight_utilization: Percent,
+    }
+
+    /// Cross-chain messaging counters
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct XcmTrafficMetrics {
+        /// Inbound XCMP pages carrying XCM
+        pub inbound_xcm_pages: u64,
+        /// Inbound XCMP pages carrying encoded blobs (cross-chain events)
+        pub inbound_blob_pages: u64,
+        /// Inbound XCMP pages in any other format (signals, unknown)
+        pub inbound_other_pages: u64,
+        /// Outbound XCM messages delivered to the router
+        pub outbound_messages: u64,
+        /// Outbound XCM messages the router refused or failed to deliver
+        pub outbound_failures: u64,
+        /// Cross-chain events received by the event hub
+        pub cross_chain_events: u64,
+    }
+
+    /// Storage maps whose entry counts are sampled for growth tracking
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum TrackedMap {
+        /// Job registry jobs
+        Jobs,
+        /// Event hub events
+        Events,
+        /// Job verifier proofs
+        JobProofs,
+        /// Telemetry job metrics history
+        JobMetrics,
+        /// Telemetry block performance ring
+        BlockPerformance,
+        /// Telemetry epoch summaries
+        EpochSummaries,
+    }
+
+    impl TrackedMap {
+        /// Every tracked map, in sampling order
+        pub const ALL: [TrackedMap; 6] = [
+            TrackedMap::Jobs,
+            TrackedMap::Events,
+            TrackedMap::JobProofs,
+            TrackedMap::JobMetrics,
+            TrackedMap::BlockPerformance,
+            TrackedMap::EpochSummaries,
+        ];
+
+        /// Label used in the Prometheus export
+        pub fn name(&self) -> &'static str {
+            match self {
+                TrackedMap::Jobs => "jobs",
+                TrackedMap::Events => "events",
+                TrackedMap::JobProofs => "job_proofs",
+                TrackedMap::JobMetrics => "job_metrics",
+                TrackedMap::BlockPerformance => "block_performance",
+                TrackedMap::EpochSummaries => "epoch_summaries",
+            }
+        }
+    }
+
+    /// Entry counts of tracked maps owned by other pallets
+    pub trait MapEntryCounter {
+        /// Number of entries in `map`, or `None` if this source doesn't count it. Read from a
+        /// live counter; this runs in `on_idle` and must not iterate the map.
+        fn entry_count(map: TrackedMap) -> Option<u32>;
+    }
+
+    impl MapEntryCounter for () {
+        fn entry_count(_map: TrackedMap) -> Option<u32> {
+            None
+        }
+    }
+
+    /// Latest entry count of a tracked map
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct StorageSample {
+        /// Block the sample was taken at
+        pub sampled_at: u32,
+        /// Entries at `sampled_at`
+        pub entries: u32,
+        /// Entries at the previous sample
+        pub previous_entries: u32,
     }
 
     #[pallet::config]
-    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> + pallet_job_registry::Config + pallet_consensus_manager::Config {
+    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> + pallet_job_registry::Config + pallet_timestamp::Config {
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
 
         /// Maximum number of job metrics to store
         #[pallet::constant]
         type MaxJobMetrics: Get<u32>;
+
+        /// Block time (in milliseconds) above which a block is flagged as slow
+        #[pallet::constant]
+        type SlowBlockThresholdMs: Get<u64>;
+
+        /// Number of blocks per telemetry epoch. At each boundary the epoch's block metrics
+        /// are rolled up into an `EpochSummary`.
+        #[pallet::constant]
+        type EpochLength: Get<u32>;
+
+        /// Origin allowed to set alert thresholds
+        type AlertOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Notified of every alert raised
+        type OnAlert: OnAlert;
+
+        /// Slot authorship source for produced/missed block counters
+        type Authorship: SlotAuthorship<Self::AccountId>;
+
+        /// Source of consensus type, fork and switch counts, usually the consensus manager
+        type ConsensusMetrics: ConsensusMetricsProvider;
+
+        /// Share of a validator's performance history kept when a new report is folded in
+        #[pallet::constant]
+        type PerformanceDecay: Get<Percent>;
+
+        /// Number of recent execution time samples kept
+        #[pallet::constant]
+        type ExecutionTimeRingSize: Get<u32>;
+
+        /// Weight of a new sample in the execution time moving average
+        #[pallet::constant]
+        type ExecutionTimeSmoothing: Get<Permill>;
+
+        /// Origin allowed to report job and validator metrics
+        type ReporterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Whether reports from `ReporterOrigin` are dispatched without fees. Takes effect when
+        /// the runtime wraps its payment extension in `pallet_skip_feeless_payment`.
+        #[pallet::constant]
+        type FeelessReporting: Get<bool>;
+
+        /// Default number of blocks block performance is kept for
+        #[pallet::constant]
+        type BlockPerformanceRetention: Get<u32>;
+
+        /// Default number of blocks job metrics are kept for after the job ended
+        #[pallet::constant]
+        type JobMetricsRetention: Get<u32>;
+
+        /// Total cross-chain events received, read from the event hub
+        type CrossChainEvents: Get<u64>;
+
+        /// Total events processed, read from the event hub
+        type EventsProcessed: Get<u64>;
+
+        /// Epochs per era; counter totals are snapshotted at every era boundary.
+        /// 0 disables snapshots.
+        #[pallet::constant]
+        type EpochsPerEra: Get<u32>;
+
+        /// Number of past era totals kept
+        #[pallet::constant]
+        type EraHistoryDepth: Get<u32>;
+
+        /// Blocks between storage growth samples. Samples are taken with leftover block weight,
+        /// so a sample may be delayed past its due block.
+        #[pallet::constant]
+        type StorageSampleInterval: Get<u32>;
+
+        /// Counts the tracked maps owned by other pallets
+        type MapEntryCounter: MapEntryCounter;
+
+        /// Origin allowed to pause and resume heavy metric collection
+        type CollectionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks between `MetricsDigest` header digests; 0 disables them
+        #[pallet::constant]
+        type DigestInterval: Get<u32>;
+
+        /// Blocks per hourly history bucket; a daily bucket spans 24 of them
+        #[pallet::constant]
+        type HourlyTierBlocks: Get<u32>;
+
+        /// Default number of hourly buckets kept
+        #[pallet::constant]
+        type HourlyTierRetention: Get<u32>;
+
+        /// Default number of daily buckets kept
+        #[pallet::constant]
+        type DailyTierRetention: Get<u32>;
     }
 
     /// Job metrics history
     #[pallet::storage]
     #[pallet::getter(fn job_metrics)]
-    pub type JobMetricsHistory<T: Config> = StorageMap<
+    pub type JobMetricsHistory<T: Config> = CountedStorageMap<
         _,
         Blake2_128Concat,
         u64, // job_id
@@ -137,172 +775,467 @@ pub mod pallet {
         ValueQuery,
     >;
 
-    /// Block performance history (last N blocks)
+    /// Block performance ring buffer, keyed by `block_number % retention`. Each block
+    /// overwrites the slot of the block one retention window earlier, so old entries are
+    /// pruned without extra writes. Read through `get_block_performance`, which checks the
+    /// stored block number.
     #[pallet::storage]
-    #[pallet::getter(fn block_performance)]
-    pub type BlockPerformanceHistory<T: Config> = StorageMap<
+    pub type BlockPerformanceHistory<T: Config> = CountedStorageMap<
         _,
-        Blake2_128Concat,
-        u32, // block_number
+        Twox64Concat,
+        u32, // ring slot
         BlockPerformance,
     >;
 
-    /// Job execution time samples (for average calculation)
+    /// Aggregated metrics per job submitter
     #[pallet::storage]
-    #[pallet::getter(fn execution_time_samples)]
-    pub type ExecutionTimeSamples<T: Config> = StorageValue<
+    #[pallet::getter(fn account_metrics)]
+    pub type AccountMetrics<T: Config> = StorageMap<
         _,
-        BoundedVec<u32, ConstU32<1000>>,
+        Blake2_128Concat,
+        T::AccountId,
+        ParticipantMetrics,
         ValueQuery,
     >;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// Job metrics recorded [job_id, execution_time]
-        JobMetricsRecorded { job_id: u64, execution_time_blocks: u32 },
-        /// Validator performance updated [validator, blocks_produced]
-        ValidatorPerformanceUpdated { validator: T::AccountId, blocks_produced: u64 },
-        /// System metrics updated
-        SystemMetricsUpdated,
-        /// Block performance recorded [block_number, block_time_ms]
-        BlockPerformanceRecorded { block_number: u32, block_time_ms: u64 },
-    }
+    /// Aggregated metrics per executing worker
+    #[pallet::storage]
+    #[pallet::getter(fn worker_metrics)]
+    pub type WorkerMetrics<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ParticipantMetrics,
+        ValueQuery,
+    >;
 
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Job not found
-        JobNotFound,
-        /// Metrics not found
-        MetricsNotFound,
-        /// Overflow in calculation
-        ArithmeticOverflow,
-    }
+    /// Job execution time histogram, one counter per `EXECUTION_TIME_BUCKETS` entry plus overflow
+    #[pallet::storage]
+    #[pallet::getter(fn execution_time_histogram)]
+    pub type ExecutionTimeHistogram<T: Config> = StorageValue<
+        _,
+        [u64; EXECUTION_TIME_BUCKET_COUNT],
+        ValueQuery,
+    >;
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        /// Update metrics at the end of each block
-        fn on_finalize(block_number: BlockNumberFor<T>) {
-            let bn: u32 = block_number.saturated_into();
-            
-            // Record block performance
-            let block_perf = BlockPerformance {
-                block_number: bn,
-                block_time_ms: 12000, // Would be actual measured time
-                extrinsic_count: 0, // Would count actual extrinsics
-                weight_used: 0, // Would track actual weight
-                block_size_bytes: 0, // Would track actual size
-            };
-            
-            BlockPerformanceHistory::<T>::insert(bn, block_perf);
+    /// Proof size histogram, one counter per `PROOF_SIZE_BUCKETS` entry plus overflow
+    #[pallet::storage]
+    #[pallet::getter(fn proof_size_histogram)]
+    pub type ProofSizeHistogram<T: Config> = StorageValue<
+        _,
+        [u64; PROOF_SIZE_BUCKET_COUNT],
+        ValueQuery,
+    >;
 
-            // Cleanup old block performance data (keep last 1000 blocks)
-            if bn > 1000_u32 {
-                BlockPerformanceHistory::<T>::remove(bn - 1000_u32);
-            }
+    /// Proof submissions per submitting account
+    #[pallet::storage]
+    #[pallet::getter(fn proof_submitter_stats)]
+    pub type ProofSubmitterStats<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ProofSizeStats,
+        ValueQuery,
+    >;
 
-            // Update system metrics
-            Self::update_system_metrics();
+    /// Verification counters per verifying account
+    #[pallet::storage]
+    #[pallet::getter(fn verifier_metrics)]
+    pub type VerifierStats<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        VerifierMetrics,
+        ValueQuery,
+    >;
 
-            Self::deposit_event(Event::BlockPerformanceRecorded {
-                block_number: bn,
-                block_time_ms: 12000,
-            });
-        }
-    }
+    /// Epoch rollups, keyed by epoch index (epoch `n` ends at block `n * EpochLength`)
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_summary)]
+    pub type EpochSummaries<T: Config> = CountedStorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        EpochSummary,
+    >;
+
+    /// Jobs finished so far in the current epoch
+    #[pallet::storage]
+    pub type EpochJobsFinished<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Jobs failed so far in the current epoch
+    #[pallet::storage]
+    pub type EpochJobsFailed<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Jobs that completed by their deadline so far in the current epoch
+    #[pallet::storage]
+    pub type EpochJobsOnTime<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Per-submitter deadline adherence
+    #[pallet::storage]
+    #[pallet::getter(fn submitter_sla)]
+    pub type SubmitterSlas<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        SubmitterSla,
+        ValueQuery,
+    >;
+
+    /// Alert thresholds; an alert fires when an epoch's metric value exceeds its threshold
+    #[pallet::storage]
+    #[pallet::getter(fn alert_threshold)]
+    pub type AlertThresholds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        AlertMetric,
+        u64,
+    >;
+
+    /// Retention windows overriding the `Config` defaults
+    #[pallet::storage]
+    #[pallet::getter(fn retention_override)]
+    pub type RetentionOverrides<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        RetentionCategory,
+        u32,
+    >;
+
+    /// Raw key the job metrics pruning scan resumes from; `None` starts a new pass
+    #[pallet::storage]
+    pub type JobMetricsPruneCursor<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<128>>>;
+
+    /// Raw key the block performance pruning scan resumes from; `None` starts a new pass
+    #[pallet::storage]
+    pub type BlockPerformancePruneCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<128>>>;
+
+    /// Slot of the previous block, to detect skipped slots
+    #[pallet::storage]
+    #[pallet::getter(fn last_slot)]
+    pub type LastSlot<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Timestamp (ms) of the previous block, used to measure block times
+    #[pallet::storage]
+    #[pallet::getter(fn last_block_timestamp)]
+    pub type LastBlockTimestamp<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Ring of recent job execution times, indexed by slot
+    #[pallet::storage]
+    pub type ExecutionTimeRing<T: Config> = StorageMap<_, Twox64Concat, u32, u32>;
+
+    /// Write position of `ExecutionTimeRing`
+    #[pallet::storage]
+    pub type ExecutionTimeRingCursor<T: Config> = StorageValue<_, RingCursor, ValueQuery>;
+
+    /// Exponentially weighted moving average of job execution times, scaled by `EWMA_SCALE`
+    #[pallet::storage]
+    pub type ExecutionTimeEwma<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// XCMP/XCM message counters, maintained by `XcmTrafficMeter`
+    #[pallet::storage]
+    pub type XcmTraffic<T: Config> = StorageValue<_, XcmTrafficMetrics, ValueQuery>;
+
+    /// Latest entry count sample per tracked map
+    #[pallet::storage]
+    #[pallet::getter(fn storage_sample)]
+    pub type StorageGrowth<T: Config> = StorageMap<_, Twox64Concat, TrackedMap, StorageSample>;
+
+    /// Block of the last storage growth sample
+    #[pallet::storage]
+    pub type LastStorageSample<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Whether heavy collection (block performance, epoch rollups, system metrics refresh and
+    /// storage sampling) is paused. Counters keep updating while paused.
+    #[pallet::storage]
+    #[pallet::getter(fn collection_paused)]
+    pub type CollectionPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Downsampled epoch history per tier, a ring keyed by `period % retention`
+    #[pallet::storage]
+    pub type TierHistory<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        HistoryTier,
+        Twox64Concat,
+        u32,
+        TierSummary,
+    >;
+
+    /// Lifetime counter values at the start of the current era
+    #[pallet::storage]
+    pub type EraBaseline<T: Config> = StorageValue<_, CounterSnapshot, ValueQuery>;
+
+    /// Counter increments of the last `EraHistoryDepth` eras, keyed by era index
+    #[pallet::storage]
+    #[pallet::getter(fn era_totals)]
+    pub type EraHistory<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32,
+        EraTotals,
+    >;
+
+    /// Transaction fees collected so far in the current block
+    #[pallet::storage]
+    pub type BlockFees<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+    /// Totals accumulated since the last metrics digest
+    #[pallet::storage]
+    pub type DigestTotals<T: Config> = StorageValue<_, DigestAccumulator, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Job metrics recorded [job_id, execution_time]
+        JobMetricsRecorded { job_id: u64, execution_time_blocks: u32 },
+        /// Validator performance updated [validator, blocks_produced]
+        ValidatorPerformanceUpdated { validator: T::AccountId, blocks_produced: u64 },
+        /// System metrics updated
+        SystemMetricsUpdated,
+        /// Block performance recorded [block_number, block_time_ms]
+        BlockPerformanceRecorded { block_number: u32, block_time_ms: u64 },
+        /// Block exceeded the slow block threshold [block_number, block_time_ms]
+        SlowBlockDetected { block_number: u32, block_time_ms: u64 },
+        /// Epoch metrics rolled up [epoch, jobs_finished]
+        EpochRolledUp { epoch: u32, jobs_finished: u32 },
+        /// Alert threshold set or cleared [metric, threshold]
+        AlertThresholdSet { metric: AlertMetric, threshold: Option<u64> },
+        /// Retention window overridden or reset to default [category, blocks]
+        RetentionSet { category: RetentionCategory, blocks: Option<u32> },
+        /// Job metrics pruned [job_id]
+        JobMetricsPruned { job_id: u64 },
+        /// Epoch metric exceeded its threshold [epoch, metric, value, threshold]
+        AlertTriggered { epoch: u32, metric: AlertMetric, value: u64, threshold: u64 },
+        /// Storage growth sampled [block_number, total_entries]
+        StorageSampled { block_number: u32, total_entries: u32 },
+        /// Heavy collection paused or resumed [paused]
+        CollectionPauseSet { paused: bool },
+        /// Era counter totals snapshotted [era, events_processed, proofs_submitted]
+        EraSnapshotted { era: u32, events_processed: u64, proofs_submitted: u64 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Job not found
+        JobNotFound,
+        /// Metrics not found
+        MetricsNotFound,
+        /// Overflow in calculation
+        ArithmeticOverflow,
+        /// Unknown alert metric
+        InvalidAlertMetric,
+        /// Unknown retention category
+        InvalidRetentionCategory,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Note slot authorship and reserve the weight `on_finalize` will use, including the
+        /// epoch rollup of a boundary block
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            let bn: u32 = block_number.saturated_into();
+            let missed = Self::note_authorship(bn);
+
+            let mut weight = <T as Config>::WeightInfo::note_authorship(missed);
+            if Self::is_era_boundary(bn) {
+                weight = weight.saturating_add(<T as Config>::WeightInfo::snapshot_era());
+            }
+            if CollectionPaused::<T>::get() {
+                // Timestamp bookkeeping and clearing the epoch counters
+                return weight.saturating_add(T::DbWeight::get().reads_writes(4, 5));
+            }
+
+            weight = weight.saturating_add(<T as Config>::WeightInfo::on_finalize());
+            if Self::is_epoch_boundary(bn) {
+                weight = weight
+                    .saturating_add(<T as Config>::WeightInfo::rollup_epoch(T::EpochLength::get()));
+            }
+            weight
+        }
+
+        /// Sample storage growth when due, then lazily prune job metrics of removed or expired
+        /// jobs, with leftover block weight
+        fn on_idle(block_number: BlockNumberFor<T>, mut remaining_weight: Weight) -> Weight {
+            let bn: u32 = block_number.saturated_into();
+            let mut used = Weight::zero();
+
+            let sample_due =
+                bn.saturating_sub(LastStorageSample::<T>::get()) >= T::StorageSampleInterval::get();
+            if sample_due && !CollectionPaused::<T>::get() {
+                let sample_weight = <T as Config>::WeightInfo::sample_storage();
+                if remaining_weight.all_gte(sample_weight) {
+                    Self::sample_storage(bn);
+                    used = sample_weight;
+                    remaining_weight = remaining_weight.saturating_sub(used);
+                }
+            }
+
+            let per_item = <T as Config>::WeightInfo::prune_job_metrics(1)
+                .saturating_sub(<T as Config>::WeightInfo::prune_job_metrics(0));
+            let base = <T as Config>::WeightInfo::prune_job_metrics(0);
+            if per_item.ref_time() == 0 || remaining_weight.any_lt(base.saturating_add(per_item)) {
+                return used;
+            }
+
+            let spare = remaining_weight.saturating_sub(base);
+            let limit = (spare.ref_time() / per_item.ref_time())
+                .min(spare.proof_size() / per_item.proof_size().max(1))
+                .min(MAX_JOB_METRICS_PRUNED_PER_BLOCK as u64) as u32;
+            let checked = Self::prune_job_metrics(bn, limit);
+
+            used.saturating_add(<T as Config>::WeightInfo::prune_job_metrics(checked))
+        }
+
+        /// Push metrics to the node's configured collector, if any
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            let Ok(Some(endpoint)) = StorageValueRef::persistent(PUSH_ENDPOINT_KEY).get::<Vec<u8>>()
+            else {
+                return;
+            };
+            let interval = StorageValueRef::persistent(PUSH_INTERVAL_KEY)
+                .get::<u32>()
+                .ok()
+                .flatten()
+                .unwrap_or(DEFAULT_PUSH_INTERVAL)
+                .max(1);
+
+            let bn: u32 = block_number.saturated_into();
+            if bn % interval != 0 {
+                return;
+            }
+
+            let Ok(url) = core::str::from_utf8(&endpoint) else {
+                log::warn!(target: "telemetry", "Push endpoint is not valid UTF-8");
+                return;
+            };
+            if let Err(e) = Self::push_metrics(url) {
+                log::warn!(target: "telemetry", "Failed to push metrics to {}: {:?}", url, e);
+            }
+        }
+
+        /// Update metrics at the end of each block
+        fn on_finalize(block_number: BlockNumberFor<T>) {
+            let bn: u32 = block_number.saturated_into();
+            
+            let now: u64 = pallet_timestamp::Pallet::<T>::get().saturated_into();
+            let block_time_ms = block_time_ms(LastBlockTimestamp::<T>::get(), now);
+            LastBlockTimestamp::<T>::put(now);
+            let fees_collected = BlockFees::<T>::take();
+
+            // Counter snapshots are cheap and keep era windows aligned, so they run even
+            // while collection is paused
+            if Self::is_era_boundary(bn) {
+                Self::snapshot_era(bn);
+            }
+
+            if CollectionPaused::<T>::get() {
+                // Start the next epoch's counters afresh rather than folding this epoch into it
+                if Self::is_epoch_boundary(bn) {
+                    EpochJobsFinished::<T>::kill();
+                    EpochJobsFailed::<T>::kill();
+                    EpochJobsOnTime::<T>::kill();
+                }
+                return;
+            }
+
+            let is_slow = block_time_ms > T::SlowBlockThresholdMs::get();
+
+            // Record block performance from what frame_system accounted for this block
+            let block_weight = frame_system::Pallet::<T>::block_weight().total();
+            let block_perf = BlockPerformance {
+                block_number: bn,
+                block_time_ms,
+                extrinsic_count: frame_system::Pallet::<T>::extrinsic_count(),
+                weight_used: block_weight.ref_time(),
+                block_size_bytes: frame_system::Pallet::<T>::all_extrinsics_len(),
+                is_slow,
+                fees_collected,
+                weight_utilization: Self::weight_utilization(block_weight),
+            };
+            
+            BlockPerformanceHistory::<T>::insert(Self::block_performance_slot(bn), block_perf);
+
+            if Self::is_epoch_boundary(bn) {
+                Self::rollup_epoch(bn, T::EpochLength::get());
+            }
+
+            Self::note_digest_block(bn, block_time_ms);
+
+            // Update system metrics
+            Self::update_system_metrics();
+
+            if is_slow {
+                Self::deposit_event(Event::SlowBlockDetected { block_number: bn, block_time_ms });
+            }
+
+            Self::deposit_event(Event::BlockPerformanceRecorded { block_number: bn, block_time_ms });
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Record job metrics
         ///
         /// # Parameters
-        /// - `origin`: Root or authorized account
+        /// - `origin`: `ReporterOrigin`; feeless when `FeelessReporting` is set
         /// - `job_id`: Job ID
         /// - `start_block`: Start block number
         /// - `end_block`: End block number (if completed)
         /// - `succeeded`: Whether job succeeded
+        /// - `worker`: Account that executed the job, if known
+        /// - `fee`: Fee paid by the submitter to the worker
         #[pallet::call_index(0)]
         #[pallet::weight(<T as Config>::WeightInfo::record_job_metrics())]
+        #[pallet::feeless_if(|origin: &OriginFor<T>, _job_id: &u64, _start_block: &u32,
+            _end_block: &Option<u32>, _succeeded: &bool, _worker: &Option<T::AccountId>,
+            _fee: &u128| -> bool { Pallet::<T>::is_feeless_reporter(origin) })]
         pub fn record_job_metrics(
             origin: OriginFor<T>,
             job_id: u64,
             start_block: u32,
             end_block: Option<u32>,
             succeeded: bool,
+            worker: Option<T::AccountId>,
+            fee: u128,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::ReporterOrigin::ensure_origin(origin)?;
 
-            let execution_time = if let Some(end) = end_block {
-                end.saturating_sub(start_block)
-            } else {
-                0
-            };
-
-            let job = JobRegistry::<T>::jobs(job_id).ok_or(Error::<T>::JobNotFound)?;
-
-            let metrics = JobMetrics {
-                job_id,
-                start_block,
-                end_block,
-                execution_time_blocks: execution_time,
-                status: job.status,
-                succeeded,
-            };
-
-            JobMetricsHistory::<T>::insert(job_id, metrics);
-
-            // Add to execution time samples
-            if execution_time > 0 {
-                ExecutionTimeSamples::<T>::try_mutate(|samples| -> DispatchResult {
-                    let _ = samples.try_push(execution_time);
-                    // Keep only last 1000 samples
-                    if samples.len() > 1000 {
-                        samples.remove(0);
-                    }
-                    Ok(())
-                })?;
-            }
-
-            Self::deposit_event(Event::JobMetricsRecorded {
-                job_id,
-                execution_time_blocks: execution_time,
-            });
-
-            Ok(())
+            Self::do_record_job_metrics(job_id, start_block, end_block, succeeded, worker.as_ref(), fee)
         }
 
-        /// Update validator performance
+        /// Update validator performance. The reported counts are a new window added to the
+        /// validator's history, decayed by `PerformanceDecay`.
         ///
         /// # Parameters
-        /// - `origin`: Root
+        /// - `origin`: `ReporterOrigin`; feeless when `FeelessReporting` is set
         /// - `validator`: Validator account
         /// - `blocks_produced`: Blocks produced
         /// - `blocks_missed`: Blocks missed
         #[pallet::call_index(1)]
         #[pallet::weight(<T as Config>::WeightInfo::update_validator_performance())]
+        #[pallet::feeless_if(|origin: &OriginFor<T>, _validator: &T::AccountId,
+            _blocks_produced: &u64, _blocks_missed: &u64| -> bool {
+            Pallet::<T>::is_feeless_reporter(origin)
+        })]
         pub fn update_validator_performance(
             origin: OriginFor<T>,
             validator: T::AccountId,
             blocks_produced: u64,
             blocks_missed: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
-
-            let total_blocks = blocks_produced.saturating_add(blocks_missed);
-            let uptime = if total_blocks > 0 {
-                ((blocks_produced * 100) / total_blocks) as u8
-            } else {
-                0
-            };
-
-            let performance = ValidatorPerformance {
-                blocks_produced,
-                blocks_missed,
-                uptime_percentage: uptime,
-                last_block_produced: frame_system::Pallet::<T>::block_number().saturated_into(),
-            };
+            T::ReporterOrigin::ensure_origin(origin)?;
 
-            ValidatorPerformanceMap::<T>::insert(&validator, performance);
+            let keep = T::PerformanceDecay::get();
+            let now: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            ValidatorPerformanceMap::<T>::mutate(&validator, |perf| {
+                perf.blocks_produced =
+                    keep.mul_floor(perf.blocks_produced).saturating_add(blocks_produced);
+                perf.blocks_missed = keep.mul_floor(perf.blocks_missed).saturating_add(blocks_missed);
+                perf.uptime_percentage = Self::uptime(perf.blocks_produced, perf.blocks_missed);
+                perf.last_block_produced = now;
+            });
 
             Self::deposit_event(Event::ValidatorPerformanceUpdated {
                 validator,
@@ -325,40 +1258,212 @@ pub mod pallet {
             Self::update_system_metrics();
             Ok(())
         }
+
+        /// Set or clear an alert threshold, checked at every epoch boundary
+        ///
+        /// # Parameters
+        /// - `origin`: `AlertOrigin`
+        /// - `metric_u8`: Metric (0=JobFailureRate, 1=AvgBlockTimeMs, 2=SlowBlocks)
+        /// - `threshold`: Value above which the alert fires, `None` to clear
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_alert_threshold())]
+        pub fn set_alert_threshold(
+            origin: OriginFor<T>,
+            metric_u8: u8,
+            threshold: Option<u64>,
+        ) -> DispatchResult {
+            T::AlertOrigin::ensure_origin(origin)?;
+
+            let metric = AlertMetric::from_u8(metric_u8)
+                .map_err(|_| Error::<T>::InvalidAlertMetric)?;
+
+            AlertThresholds::<T>::set(metric, threshold);
+
+            Self::deposit_event(Event::AlertThresholdSet { metric, threshold });
+
+            Ok(())
+        }
+
+        /// Override the retention window of a telemetry history
+        ///
+        /// # Parameters
+        /// - `origin`: Root
+        /// - `category_u8`: History (0=BlockPerformance, 1=JobMetrics, 2=HourlyTier, 3=DailyTier)
+        /// - `blocks`: Retention in blocks, `None` to restore the `Config` default
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_retention())]
+        pub fn set_retention(
+            origin: OriginFor<T>,
+            category_u8: u8,
+            blocks: Option<u32>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let category = RetentionCategory::from_u8(category_u8)
+                .map_err(|_| Error::<T>::InvalidRetentionCategory)?;
+
+            RetentionOverrides::<T>::set(category, blocks);
+
+            Self::deposit_event(Event::RetentionSet { category, blocks });
+
+            Ok(())
+        }
+
+        /// Pause or resume heavy metric collection, e.g. to shed telemetry weight during
+        /// incidents or stress tests
+        ///
+        /// While paused, `on_finalize` records no block performance, rolls up no epochs and
+        /// doesn't refresh system metrics, and no storage growth samples are taken. Job,
+        /// authorship, economic and XCM counters keep updating.
+        ///
+        /// # Parameters
+        /// - `origin`: `CollectionOrigin`
+        /// - `paused`: Whether to pause collection
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_collection_paused())]
+        pub fn set_collection_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            T::CollectionOrigin::ensure_origin(origin)?;
+
+            CollectionPaused::<T>::put(paused);
+
+            Self::deposit_event(Event::CollectionPauseSet { paused });
+
+            Ok(())
+        }
+
+        /// Remove expired job metrics and block performance entries, for when the `on_idle`
+        /// budget can't keep up
+        ///
+        /// Inspects up to `limit` entries (capped at `MAX_TELEMETRY_PRUNED_PER_CALL`), job
+        /// metrics first. Weight for entries not inspected is refunded.
+        ///
+        /// # Parameters
+        /// - `origin`: Any signed account
+        /// - `limit`: Maximum number of entries to inspect
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::prune_telemetry(
+            (*limit).min(MAX_TELEMETRY_PRUNED_PER_CALL)
+        ))]
+        pub fn prune_telemetry(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let limit = limit.min(MAX_TELEMETRY_PRUNED_PER_CALL);
+            let now: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+
+            let mut checked = Self::prune_job_metrics(now, limit);
+            checked = checked.saturating_add(Self::prune_block_performance(now, limit - checked));
+
+            Ok(Some(<T as Config>::WeightInfo::prune_telemetry(checked)).into())
+        }
     }
 
     // Helper functions
     impl<T: Config> Pallet<T> {
+        /// Store metrics for a job and fold them into the participant aggregates
+        pub fn do_record_job_metrics(
+            job_id: u64,
+            start_block: u32,
+            end_block: Option<u32>,
+            succeeded: bool,
+            worker: Option<&T::AccountId>,
+            fee: u128,
+        ) -> DispatchResult {
+            let job = JobRegistry::<T>::jobs(job_id).ok_or(Error::<T>::JobNotFound)?;
+
+            Self::store_job_metrics(
+                job_id, &job, job.status.clone(), start_block, end_block, succeeded, worker, fee,
+            );
+
+            Ok(())
+        }
+
+        /// Write a job's metrics. Aggregates, samples and the histogram only count the first
+        /// record of a job that carries an end block, so re-recording never double counts.
+        #[allow(clippy::too_many_arguments)]
+        fn store_job_metrics(
+            job_id: u64,
+            job: &Job<T::AccountId, BlockNumberFor<T>>,
+            status: JobStatus,
+            start_block: u32,
+            end_block: Option<u32>,
+            succeeded: bool,
+            worker: Option<&T::AccountId>,
+            fee: u128,
+        ) {
+            let execution_time = if let Some(end) = end_block {
+                end.saturating_sub(start_block)
+            } else {
+                0
+            };
+
+            let metrics = JobMetrics {
+                job_id,
+                start_block,
+                end_block,
+                execution_time_blocks: execution_time,
+                status,
+                succeeded,
+            };
+
+            let already_finished = JobMetricsHistory::<T>::get(job_id)
+                .map_or(false, |previous| previous.end_block.is_some());
+            JobMetricsHistory::<T>::insert(job_id, metrics);
+
+            if let (Some(end), false) = (end_block, already_finished) {
+                let owner = &job.owner;
+                AccountMetrics::<T>::mutate(owner, |m| m.observe(succeeded, execution_time, fee));
+                if let Some(worker) = worker {
+                    WorkerMetrics::<T>::mutate(worker, |m| m.observe(succeeded, execution_time, fee));
+                }
+
+                EpochJobsFinished::<T>::mutate(|n| *n = n.saturating_add(1));
+                if !succeeded {
+                    EpochJobsFailed::<T>::mutate(|n| *n = n.saturating_add(1));
+                }
+                if T::DigestInterval::get() > 0 {
+                    DigestTotals::<T>::mutate(|totals| {
+                        totals.jobs_finished = totals.jobs_finished.saturating_add(1);
+                        if !succeeded {
+                            totals.jobs_failed = totals.jobs_failed.saturating_add(1);
+                        }
+                    });
+                }
+
+                let deadline: u32 = job.deadline.saturated_into();
+                let on_time = succeeded && end <= deadline;
+                if on_time {
+                    EpochJobsOnTime::<T>::mutate(|n| *n = n.saturating_add(1));
+                }
+                Self::observe_submitter_sla(owner, on_time);
+
+                if execution_time > 0 {
+                    Self::push_execution_time_sample(execution_time);
+                    Self::observe_execution_time(execution_time);
+                }
+            }
+
+            Self::deposit_event(Event::JobMetricsRecorded {
+                job_id,
+                execution_time_blocks: execution_time,
+            });
+        }
+
         /// Update system-wide metrics
         fn update_system_metrics() {
             let mut metrics = SystemMetricsStorage::<T>::get();
 
-            // Count jobs by status
-            let _submitted = 0u64;
-            let _completed = 0u64;
-            let _failed = 0u64;
-            let _verified = 0u64;
+            // Job IDs are sequential, so the next ID is the number of jobs ever submitted.
+            // Completed, failed and verified counts are kept live by the status hook.
+            metrics.total_jobs_submitted = pallet_job_registry::NextJobId::<T>::get();
 
-            // This would iterate through job registry in a real implementation
-            // For now, we'll use existing stats if available
-            
             // Get consensus info
-            metrics.current_consensus = ConsensusManager::<T>::get_consensus_type();
-            
-            // Get fork stats
-            let fork_stats = ConsensusManager::<T>::get_fork_stats();
-            metrics.total_forks = fork_stats.total_forks;
+            metrics.current_consensus = T::ConsensusMetrics::consensus_type();
+            metrics.total_forks = T::ConsensusMetrics::total_forks();
 
-            // Calculate average execution time
-            let samples = ExecutionTimeSamples::<T>::get();
-            if !samples.is_empty() {
-                let sum: u64 = samples.iter().map(|&x| x as u64).sum();
-                metrics.avg_job_execution_time = (sum / samples.len() as u64) as u32;
-            }
+            metrics.execution_time_percentiles = Self::get_execution_time_percentiles();
 
             // Get consensus switch count
-            let consensus_history = ConsensusManager::<T>::get_consensus_history();
-            metrics.total_consensus_switches = consensus_history.len() as u32;
+            metrics.total_consensus_switches = T::ConsensusMetrics::consensus_switches();
 
             SystemMetricsStorage::<T>::put(metrics);
             Self::deposit_event(Event::SystemMetricsUpdated);
@@ -374,33 +1479,640 @@ pub mod pallet {
             SystemMetricsStorage::<T>::get()
         }
 
+        /// Get XCMP/XCM traffic counters
+        pub fn get_xcm_traffic() -> XcmTrafficMetrics {
+            XcmTrafficMetrics { cross_chain_events: T::CrossChainEvents::get(), ..XcmTraffic::<T>::get() }
+        }
+
+        /// Latest storage growth sample of every tracked map that has been sampled
+        pub fn get_storage_growth() -> Vec<(TrackedMap, StorageSample)> {
+            TrackedMap::ALL
+                .into_iter()
+                .filter_map(|map| StorageGrowth::<T>::get(map).map(|sample| (map, sample)))
+                .collect()
+        }
+
+        /// Record the live entry count of every tracked map as its latest sample.
+        /// Maps neither owned here nor counted by `MapEntryCounter` are skipped.
+        pub(crate) fn sample_storage(bn: u32) {
+            let mut total = 0u32;
+            for map in TrackedMap::ALL {
+                let entries = match map {
+                    TrackedMap::JobMetrics => JobMetricsHistory::<T>::count(),
+                    TrackedMap::BlockPerformance => BlockPerformanceHistory::<T>::count(),
+                    TrackedMap::EpochSummaries => EpochSummaries::<T>::count(),
+                    external => match T::MapEntryCounter::entry_count(external) {
+                        Some(entries) => entries,
+                        None => continue,
+                    },
+                };
+                total = total.saturating_add(entries);
+
+                StorageGrowth::<T>::mutate(map, |sample| {
+                    let previous_entries = sample.as_ref().map_or(0, |s| s.entries);
+                    *sample = Some(StorageSample { sampled_at: bn, entries, previous_entries });
+                });
+            }
+
+            LastStorageSample::<T>::put(bn);
+            Self::deposit_event(Event::StorageSampled { block_number: bn, total_entries: total });
+        }
+
+        /// Get aggregated metrics for a job submitter
+        pub fn get_account_metrics(account: &T::AccountId) -> ParticipantMetrics {
+            AccountMetrics::<T>::get(account)
+        }
+
+        /// Get aggregated metrics for a worker
+        pub fn get_worker_metrics(worker: &T::AccountId) -> ParticipantMetrics {
+            WorkerMetrics::<T>::get(worker)
+        }
+
+        /// Share of the maximum block weight used, in the more saturated dimension
+        fn weight_utilization(used: Weight) -> Percent {
+            let max = T::BlockWeights::get().max_block;
+            Percent::from_rational(used.ref_time(), max.ref_time().max(1))
+                .max(Percent::from_rational(used.proof_size(), max.proof_size().max(1)))
+        }
+
+        /// Get verification metrics for a verifier
+        pub fn get_verifier_metrics(verifier: &T::AccountId) -> VerifierMetrics {
+            VerifierStats::<T>::get(verifier)
+        }
+
         /// Get validator performance
         pub fn get_validator_performance(validator: &T::AccountId) -> ValidatorPerformance {
             ValidatorPerformanceMap::<T>::get(validator)
         }
 
-        /// Get block performance
+        /// Get block performance, if still within the retention window
         pub fn get_block_performance(block_number: u32) -> Option<BlockPerformance> {
-            BlockPerformanceHistory::<T>::get(block_number)
+            BlockPerformanceHistory::<T>::get(Self::block_performance_slot(block_number))
+                .filter(|perf| perf.block_number == block_number)
+        }
+
+        /// Alias of `get_block_performance`
+        pub fn block_performance(block_number: u32) -> Option<BlockPerformance> {
+            Self::get_block_performance(block_number)
+        }
+
+        /// Ring slot of a block. Shrinking the retention leaves the slots above the new size
+        /// in place; they are still bounded by the previous size and never read back.
+        pub(crate) fn block_performance_slot(block_number: u32) -> u32 {
+            block_number % Self::retention(RetentionCategory::BlockPerformance).max(1)
+        }
+
+        /// Credit the current slot's author and charge the expected authors of any slots
+        /// skipped since the previous block. Returns the number of missed slots charged.
+        fn note_authorship(bn: u32) -> u32 {
+            let slot = T::Authorship::current_slot();
+            let last = LastSlot::<T>::get();
+            LastSlot::<T>::put(slot);
+
+            // Nothing to compare against on the first block, or without a slot source
+            let mut missed = 0u32;
+            if last > 0 && slot > last {
+                let first_skipped = (last + 1).max(slot.saturating_sub(MAX_MISSED_SLOTS_PER_BLOCK));
+                for skipped in first_skipped..slot {
+                    if let Some(validator) = T::Authorship::expected_author(skipped) {
+                        ValidatorPerformanceMap::<T>::mutate(&validator, |perf| {
+                            perf.blocks_missed = perf.blocks_missed.saturating_add(1);
+                            perf.uptime_percentage =
+                                Self::uptime(perf.blocks_produced, perf.blocks_missed);
+                        });
+                        missed += 1;
+                    }
+                }
+            }
+
+            if let Some(author) = T::Authorship::expected_author(slot) {
+                ValidatorPerformanceMap::<T>::mutate(&author, |perf| {
+                    perf.blocks_produced = perf.blocks_produced.saturating_add(1);
+                    perf.last_block_produced = bn;
+                    perf.uptime_percentage = Self::uptime(perf.blocks_produced, perf.blocks_missed);
+                });
+            }
+
+            missed
+        }
+
+        fn uptime(produced: u64, missed: u64) -> u8 {
+            let total_blocks = produced.saturating_add(missed);
+            if total_blocks > 0 {
+                (produced.saturating_mul(100) / total_blocks) as u8
+            } else {
+                0
+            }
+        }
+
+        /// Whether `origin` may report metrics without paying fees
+        pub fn is_feeless_reporter(origin: &OriginFor<T>) -> bool {
+            T::FeelessReporting::get() && T::ReporterOrigin::try_origin(origin.clone()).is_ok()
+        }
+
+        /// Retention window of a history, in blocks
+        pub fn retention(category: RetentionCategory) -> u32 {
+            RetentionOverrides::<T>::get(category).unwrap_or_else(|| match category {
+                RetentionCategory::BlockPerformance => T::BlockPerformanceRetention::get(),
+                RetentionCategory::JobMetrics => T::JobMetricsRetention::get(),
+                RetentionCategory::HourlyTier => T::HourlyTierRetention::get(),
+                RetentionCategory::DailyTier => T::DailyTierRetention::get(),
+            })
+        }
+
+        /// Inspect up to `limit` job metrics entries, continuing where the previous call
+        /// stopped, and remove those whose job was removed from the registry or that ended
+        /// longer than the retention window ago. Returns the number of entries inspected.
+        pub(crate) fn prune_job_metrics(now: u32, limit: u32) -> u32 {
+            let retention = Self::retention(RetentionCategory::JobMetrics);
+            let mut iter = match JobMetricsPruneCursor::<T>::take() {
+                Some(raw_key) => JobMetricsHistory::<T>::iter_from(raw_key.into_inner()),
+                None => JobMetricsHistory::<T>::iter(),
+            };
+
+            let mut checked = 0u32;
+            while checked < limit {
+                let Some((job_id, metrics)) = iter.next() else {
+                    // Pass complete; the next call starts over
+                    return checked;
+                };
+                checked += 1;
+
+                let expired = metrics
+                    .end_block
+                    .map_or(false, |end| end.saturating_add(retention) < now);
+                if expired || JobRegistry::<T>::jobs(job_id).is_none() {
+                    JobMetricsHistory::<T>::remove(job_id);
+                    Self::deposit_event(Event::JobMetricsPruned { job_id });
+                }
+            }
+
+            if let Ok(cursor) = BoundedVec::try_from(iter.last_raw_key().to_vec()) {
+                JobMetricsPruneCursor::<T>::put(cursor);
+            }
+            checked
+        }
+
+        /// Fold a block into the digest totals and, every `DigestInterval` blocks, publish them
+        /// as a `MetricsDigest` header digest
+        fn note_digest_block(bn: u32, block_time_ms: u64) {
+            let interval = T::DigestInterval::get();
+            if interval == 0 {
+                return;
+            }
+
+            let mut totals = DigestTotals::<T>::get();
+            totals.blocks = totals.blocks.saturating_add(1);
+            totals.total_block_time_ms = totals.total_block_time_ms.saturating_add(block_time_ms);
+
+            if bn % interval != 0 {
+                DigestTotals::<T>::put(totals);
+                return;
+            }
+
+            let digest = MetricsDigest {
+                block_number: bn,
+                jobs_finished: totals.jobs_finished,
+                failure_rate: Percent::from_rational(totals.jobs_failed, totals.jobs_finished.max(1)),
+                avg_block_time_ms: totals.total_block_time_ms / totals.blocks as u64,
+            };
+            let mut data = METRICS_DIGEST_ID.to_vec();
+            digest.encode_to(&mut data);
+            frame_system::Pallet::<T>::deposit_log(sp_runtime::DigestItem::Other(data));
+
+            DigestTotals::<T>::kill();
+        }
+
+        /// Inspect up to `limit` block performance entries, continuing where the previous call
+        /// stopped, and remove those older than the retention window or left in slots beyond
+        /// a shrunk ring. Returns the number of entries inspected.
+        pub(crate) fn prune_block_performance(now: u32, limit: u32) -> u32 {
+            let retention = Self::retention(RetentionCategory::BlockPerformance).max(1);
+            let mut iter = match BlockPerformancePruneCursor::<T>::take() {
+                Some(raw_key) => BlockPerformanceHistory::<T>::iter_from(raw_key.into_inner()),
+                None => BlockPerformanceHistory::<T>::iter(),
+            };
+
+            let mut checked = 0u32;
+            while checked < limit {
+                let Some((slot, perf)) = iter.next() else {
+                    return checked;
+                };
+                checked += 1;
+
+                if slot >= retention || perf.block_number.saturating_add(retention) <= now {
+                    BlockPerformanceHistory::<T>::remove(slot);
+                }
+            }
+
+            if let Ok(cursor) = BoundedVec::try_from(iter.last_raw_key().to_vec()) {
+                BlockPerformancePruneCursor::<T>::put(cursor);
+            }
+            checked
+        }
+
+        fn is_epoch_boundary(bn: u32) -> bool {
+            let length = T::EpochLength::get();
+            length > 0 && bn > 0 && bn % length == 0
+        }
+
+        /// Blocks per era, 0 when snapshots are disabled
+        fn era_length() -> u32 {
+            T::EpochLength::get().saturating_mul(T::EpochsPerEra::get())
+        }
+
+        fn is_era_boundary(bn: u32) -> bool {
+            let length = Self::era_length();
+            length > 0 && bn > 0 && bn % length == 0
+        }
+
+        /// Current lifetime values of the counters kept in era totals
+        fn counter_snapshot() -> CounterSnapshot {
+            let metrics = SystemMetricsStorage::<T>::get();
+            CounterSnapshot {
+                events_processed: T::EventsProcessed::get(),
+                proofs_submitted: ProofSizeHistogram::<T>::get().iter().sum(),
+                jobs_submitted: pallet_job_registry::NextJobId::<T>::get(),
+                jobs_completed: metrics.total_jobs_completed,
+                jobs_failed: metrics.total_jobs_failed,
+                jobs_verified: metrics.total_jobs_verified,
+                cross_chain_events: T::CrossChainEvents::get(),
+            }
+        }
+
+        /// Store the counter increments of the era ending at `end_block` and start the next
+        /// era from the current values, dropping totals older than `EraHistoryDepth`
+        pub(crate) fn snapshot_era(end_block: u32) {
+            let length = Self::era_length().max(1);
+            let era = end_block / length;
+            let current = Self::counter_snapshot();
+            let counters = current.since(&EraBaseline::<T>::get());
+            EraBaseline::<T>::put(current);
+
+            let (events_processed, proofs_submitted) =
+                (counters.events_processed, counters.proofs_submitted);
+            EraHistory::<T>::insert(era, EraTotals {
+                era,
+                start_block: end_block.saturating_sub(length).saturating_add(1),
+                end_block,
+                counters,
+            });
+            if let Some(expired) = era.checked_sub(T::EraHistoryDepth::get()) {
+                EraHistory::<T>::remove(expired);
+            }
+
+            Self::deposit_event(Event::EraSnapshotted { era, events_processed, proofs_submitted });
+        }
+
+        /// Get the totals of the latest `count` completed eras, newest first
+        pub fn get_era_totals(count: u32) -> Vec<EraTotals> {
+            let length = Self::era_length();
+            if length == 0 {
+                return Vec::new();
+            }
+            let now: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            let latest = now / length;
+            (0..count.min(T::EraHistoryDepth::get()))
+                .map_while(|offset| latest.checked_sub(offset))
+                .filter_map(EraHistory::<T>::get)
+                .collect()
+        }
+
+        /// Summarise the epoch of `length` blocks ending at `end_block`. Raw block metrics age
+        /// out of the ring on their own.
+        pub(crate) fn rollup_epoch(end_block: u32, length: u32) {
+            let start_block = end_block.saturating_sub(length).saturating_add(1);
+
+            let mut summary = EpochSummary {
+                start_block,
+                end_block,
+                min_block_time_ms: u64::MAX,
+                jobs_finished: EpochJobsFinished::<T>::take(),
+                jobs_failed: EpochJobsFailed::<T>::take(),
+                jobs_on_time: EpochJobsOnTime::<T>::take(),
+                ..Default::default()
+            };
+            let mut total_time = 0u64;
+            for perf in (start_block..=end_block).filter_map(Self::get_block_performance) {
+                summary.blocks = summary.blocks.saturating_add(1);
+                summary.min_block_time_ms = summary.min_block_time_ms.min(perf.block_time_ms);
+                summary.max_block_time_ms = summary.max_block_time_ms.max(perf.block_time_ms);
+                total_time = total_time.saturating_add(perf.block_time_ms);
+                if perf.is_slow {
+                    summary.slow_blocks = summary.slow_blocks.saturating_add(1);
+                }
+            }
+            if summary.blocks == 0 {
+                summary.min_block_time_ms = 0;
+            } else {
+                summary.avg_block_time_ms = total_time / summary.blocks as u64;
+            }
+
+            let epoch = end_block / length;
+            Self::check_alerts(epoch, &summary);
+
+            let jobs_finished = summary.jobs_finished;
+            SystemMetricsStorage::<T>::mutate(|metrics| metrics.sla_adherence = summary.sla_adherence());
+            Self::fold_into_tiers(&summary);
+            EpochSummaries::<T>::insert(epoch, summary);
+            Self::deposit_event(Event::EpochRolledUp { epoch, jobs_finished });
+        }
+
+        /// Blocks per bucket of a history tier
+        fn tier_blocks(tier: HistoryTier) -> u32 {
+            let hourly = T::HourlyTierBlocks::get().max(1);
+            match tier {
+                HistoryTier::Hourly => hourly,
+                HistoryTier::Daily => hourly.saturating_mul(24),
+            }
+        }
+
+        /// Merge an epoch summary into the hourly and daily buckets its end block falls in,
+        /// replacing whatever older bucket held the ring slot
+        fn fold_into_tiers(summary: &EpochSummary) {
+            for tier in [HistoryTier::Hourly, HistoryTier::Daily] {
+                let period = summary.end_block.div_ceil(Self::tier_blocks(tier));
+                let slot = period % Self::retention(tier.retention_category()).max(1);
+                TierHistory::<T>::mutate(tier, slot, |entry| match entry {
+                    Some(bucket) if bucket.period == period => {
+                        bucket.epochs = bucket.epochs.saturating_add(1);
+                        bucket.summary.merge(summary);
+                    },
+                    _ => *entry = Some(TierSummary { period, epochs: 1, summary: summary.clone() }),
+                });
+            }
+        }
+
+        /// Get the latest `count` buckets of a history tier, newest first
+        pub fn get_tier_history(tier: HistoryTier, count: u32) -> Vec<TierSummary> {
+            let now: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            let current = now.div_ceil(Self::tier_blocks(tier));
+            let retention = Self::retention(tier.retention_category()).max(1);
+            (0..count.min(retention))
+                .map_while(|offset| current.checked_sub(offset))
+                .filter_map(|period| {
+                    TierHistory::<T>::get(tier, period % retention)
+                        .filter(|bucket| bucket.period == period)
+                })
+                .collect()
+        }
+
+        /// Epoch a block belongs to; epoch `n` ends at block `n * EpochLength`
+        fn epoch_of(bn: u32) -> u32 {
+            bn.div_ceil(T::EpochLength::get().max(1))
+        }
+
+        /// Count a finished job against its submitter's SLA counters for the current epoch
+        fn observe_submitter_sla(owner: &T::AccountId, on_time: bool) {
+            let epoch = Self::epoch_of(frame_system::Pallet::<T>::block_number().saturated_into());
+            SubmitterSlas::<T>::mutate(owner, |sla| {
+                if sla.epoch != epoch {
+                    sla.previous = if sla.epoch.saturating_add(1) == epoch {
+                        core::mem::take(&mut sla.current)
+                    } else {
+                        SlaCounters::default()
+                    };
+                    sla.current = SlaCounters::default();
+                    sla.epoch = epoch;
+                }
+                sla.current.observe(on_time);
+            });
+        }
+
+        /// A submitter's deadline adherence in the last completed epoch
+        pub fn get_submitter_sla(owner: &T::AccountId) -> SlaCounters {
+            let current_epoch =
+                Self::epoch_of(frame_system::Pallet::<T>::block_number().saturated_into());
+            let sla = SubmitterSlas::<T>::get(owner);
+            if sla.epoch.saturating_add(1) == current_epoch {
+                sla.current
+            } else if sla.epoch == current_epoch {
+                sla.previous
+            } else {
+                SlaCounters::default()
+            }
+        }
+
+        /// Raise an alert for every threshold the epoch exceeded
+        fn check_alerts(epoch: u32, summary: &EpochSummary) {
+            for (metric, threshold) in AlertThresholds::<T>::iter() {
+                let value = metric.value_of(summary);
+                if value > threshold {
+                    T::OnAlert::on_alert(epoch, metric, value, threshold);
+                    Self::deposit_event(Event::AlertTriggered { epoch, metric, value, threshold });
+                }
+            }
+        }
+
+        /// Add a sample to the ring (overwriting the oldest once full) and fold it into the
+        /// moving average. O(1) regardless of ring size.
+        fn push_execution_time_sample(blocks: u32) {
+            let size = T::ExecutionTimeRingSize::get().max(1);
+            let mut cursor = ExecutionTimeRingCursor::<T>::get();
+
+            ExecutionTimeRing::<T>::insert(cursor.next, blocks);
+
+            let sample = (blocks as u64).saturating_mul(EWMA_SCALE);
+            ExecutionTimeEwma::<T>::mutate(|ewma| {
+                *ewma = if cursor.len == 0 {
+                    sample
+                } else {
+                    let alpha = T::ExecutionTimeSmoothing::get();
+                    (alpha * sample).saturating_add(alpha.left_from_one() * *ewma)
+                };
+            });
+
+            cursor.next = (cursor.next + 1) % size;
+            cursor.len = cursor.len.saturating_add(1).min(size);
+            ExecutionTimeRingCursor::<T>::put(cursor);
+        }
+
+        /// Recent execution times, oldest first
+        pub fn recent_execution_times() -> Vec<u32> {
+            let cursor = ExecutionTimeRingCursor::<T>::get();
+            let size = T::ExecutionTimeRingSize::get().max(1);
+            let first = if cursor.len < size { 0 } else { cursor.next };
+            (0..cursor.len)
+                .filter_map(|i| ExecutionTimeRing::<T>::get((first + i) % size))
+                .collect()
+        }
+
+        /// Moving average of job execution times, in blocks
+        pub fn get_ewma_execution_time() -> u32 {
+            (ExecutionTimeEwma::<T>::get() / EWMA_SCALE).saturated_into()
+        }
+
+        /// Count an execution time in its histogram bucket
+        fn observe_execution_time(blocks: u32) {
+            let bucket = EXECUTION_TIME_BUCKETS
+                .iter()
+                .position(|&bound| blocks <= bound)
+                .unwrap_or(EXECUTION_TIME_BUCKETS.len());
+            ExecutionTimeHistogram::<T>::mutate(|histogram| {
+                histogram[bucket] = histogram[bucket].saturating_add(1);
+            });
+        }
+
+        /// Execution time at `percentile` (0-100), as the upper bound of the bucket it falls in
+        pub fn execution_time_percentile(percentile: u8) -> u32 {
+            let histogram = ExecutionTimeHistogram::<T>::get();
+            let total: u64 = histogram.iter().sum();
+            if total == 0 {
+                return 0;
+            }
+
+            // Rank of the sample we are looking for, rounded up
+            let rank = (total.saturating_mul(percentile.min(100) as u64) + 99) / 100;
+            let mut seen = 0u64;
+            for (bucket, count) in histogram.iter().enumerate() {
+                seen = seen.saturating_add(*count);
+                if seen >= rank.max(1) {
+                    return EXECUTION_TIME_BUCKETS.get(bucket).copied().unwrap_or(u32::MAX);
+                }
+            }
+            u32::MAX
+        }
+
+        /// Get p50/p95/p99 job execution times
+        pub fn get_execution_time_percentiles() -> ExecutionTimePercentiles {
+            ExecutionTimePercentiles {
+                p50: Self::execution_time_percentile(50),
+                p95: Self::execution_time_percentile(95),
+                p99: Self::execution_time_percentile(99),
+            }
+        }
+
+        /// Get block performance for the last `count` blocks, newest first
+        pub fn get_recent_block_performance(count: u32) -> Vec<BlockPerformance> {
+            let current: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            (0..count)
+                .map_while(|offset| current.checked_sub(offset))
+                .filter_map(Self::get_block_performance)
+                .collect()
         }
 
         /// Get average job execution time
         pub fn get_average_execution_time() -> u32 {
-            let samples = ExecutionTimeSamples::<T>::get();
+            let samples = Self::recent_execution_times();
             if samples.is_empty() {
                 return 0;
             }
-            
+
             let sum: u64 = samples.iter().map(|&x| x as u64).sum();
             (sum / samples.len() as u64) as u32
         }
 
-        /// Export metrics in Prometheus format (for std environment)
+        /// POST the Prometheus text exposition to `url` (e.g. a pushgateway job URL)
+        fn push_metrics(url: &str) -> Result<(), http::Error> {
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(5_000));
+
+            let pending = http::Request::post(url, vec![Self::export_prometheus_metrics().into_bytes()])
+                .add_header("Content-Type", "text/plain; version=0.0.4")
+                .deadline(deadline)
+                .send()
+                .map_err(|_| http::Error::IoError)?;
+
+            let response = pending
+                .try_wait(deadline)
+                .map_err(|_| http::Error::DeadlineReached)??;
+
+            if !(200..300).contains(&response.code) {
+                log::warn!(target: "telemetry", "Metrics push failed with code: {}", response.code);
+                return Err(http::Error::Unknown);
+            }
+
+            Ok(())
+        }
+
+        /// Snapshot of system, validator and the last `recent_blocks` block metrics
+        pub fn metrics_snapshot(recent_blocks: u32) -> MetricsSnapshot<T::AccountId> {
+            MetricsSnapshot {
+                system: Self::get_system_metrics(),
+                validators: ValidatorPerformanceMap::<T>::iter().collect(),
+                recent_blocks: Self::get_recent_block_performance(recent_blocks),
+            }
+        }
+
+        /// Export a metrics snapshot as JSON (for std environment). Accounts are rendered as
+        /// the hex of their SCALE encoding.
         #[cfg(feature = "std")]
+        pub fn export_json_metrics(recent_blocks: u32) -> String {
+            let snapshot = Self::metrics_snapshot(recent_blocks);
+            let system = &snapshot.system;
+
+            let validators = snapshot
+                .validators
+                .iter()
+                .map(|(who, perf)| {
+                    format!(
+                        "{{\"account\":\"0x{}\",\"blocks_produced\":{},\"blocks_missed\":{},\
+                         \"uptime_percentage\":{},\"last_block_produced\":{}}}",
+                        sp_core::hexdisplay::HexDisplay::from(&who.encode()),
+                        perf.blocks_produced,
+                        perf.blocks_missed,
+                        perf.uptime_percentage,
+                        perf.last_block_produced,
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            let blocks = snapshot
+                .recent_blocks
+                .iter()
+                .map(|block| {
+                    format!(
+                        "{{\"block_number\":{},\"block_time_ms\":{},\"extrinsic_count\":{},\
+                         \"weight_used\":{},\"block_size_bytes\":{},\"is_slow\":{},\
+                         \"fees_collected\":{},\"weight_utilization_percent\":{}}}",
+                        block.block_number,
+                        block.block_time_ms,
+                        block.extrinsic_count,
+                        block.weight_used,
+                        block.block_size_bytes,
+                        block.is_slow,
+                        block.fees_collected,
+                        block.weight_utilization.deconstruct(),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            format!(
+                "{{\"system\":{{\"total_jobs_submitted\":{},\"total_jobs_completed\":{},\
+                 \"total_jobs_failed\":{},\"total_jobs_verified\":{},\
+                 \"execution_time_percentiles\":{{\"p50\":{},\"p95\":{},\"p99\":{}}},\
+                 \"total_forks\":{},\"total_consensus_switches\":{},\"current_consensus\":\"{:?}\",\
+                 \"total_escrow_locked\":{},\"total_bounties_paid\":{},\
+                 \"total_deposits_held\":{},\"total_fees_burned\":{},\
+                 \"sla_adherence_percent\":{}}},\
+                 \"validators\":[{}],\"recent_blocks\":[{}]}}",
+                system.total_jobs_submitted,
+                system.total_jobs_completed,
+                system.total_jobs_failed,
+                system.total_jobs_verified,
+                system.execution_time_percentiles.p50,
+                system.execution_time_percentiles.p95,
+                system.execution_time_percentiles.p99,
+                system.total_forks,
+                system.total_consensus_switches,
+                system.current_consensus,
+                system.total_escrow_locked,
+                system.total_bounties_paid,
+                system.total_deposits_held,
+                system.total_fees_burned,
+                system.sla_adherence.deconstruct(),
+                validators,
+                blocks,
+            )
+        }
+
+        /// Export metrics in Prometheus format
         pub fn export_prometheus_metrics() -> String {
             let metrics = Self::get_system_metrics();
-            
-            format!(
+            let xcm = Self::get_xcm_traffic();
+
+            let mut out = format!(
                 "# HELP polkacomputelab_jobs_submitted_total Total jobs submitted\n\
                  # TYPE polkacomputelab_jobs_submitted_total counter\n\
                  polkacomputelab_jobs_submitted_total {}\n\
@@ -417,9 +2129,11 @@ pub mod pallet {
                  # TYPE polkacomputelab_jobs_verified_total counter\n\
                  polkacomputelab_jobs_verified_total {}\n\
                  \n\
-                 # HELP polkacomputelab_avg_job_execution_time_blocks Average job execution time in blocks\n\
-                 # TYPE polkacomputelab_avg_job_execution_time_blocks gauge\n\
-                 polkacomputelab_avg_job_execution_time_blocks {}\n\
+                 # HELP polkacomputelab_job_execution_time_blocks Job execution time percentiles in blocks\n\
+                 # TYPE polkacomputelab_job_execution_time_blocks gauge\n\
+                 polkacomputelab_job_execution_time_blocks{{quantile=\"0.5\"}} {}\n\
+                 polkacomputelab_job_execution_time_blocks{{quantile=\"0.95\"}} {}\n\
+                 polkacomputelab_job_execution_time_blocks{{quantile=\"0.99\"}} {}\n\
                  \n\
                  # HELP polkacomputelab_forks_detected_total Total forks detected\n\
                  # TYPE polkacomputelab_forks_detected_total counter\n\
@@ -427,15 +2141,283 @@ pub mod pallet {
                  \n\
                  # HELP polkacomputelab_consensus_switches_total Total consensus switches\n\
                  # TYPE polkacomputelab_consensus_switches_total counter\n\
-                 polkacomputelab_consensus_switches_total {}\n",
+                 polkacomputelab_consensus_switches_total {}\n\
+                 \n\
+                 # HELP polkacomputelab_escrow_locked Funds currently locked in job escrow\n\
+                 # TYPE polkacomputelab_escrow_locked gauge\n\
+                 polkacomputelab_escrow_locked {}\n\
+                 \n\
+                 # HELP polkacomputelab_bounties_paid_total Bounties paid out\n\
+                 # TYPE polkacomputelab_bounties_paid_total counter\n\
+                 polkacomputelab_bounties_paid_total {}\n\
+                 \n\
+                 # HELP polkacomputelab_deposits_held Deposits currently held\n\
+                 # TYPE polkacomputelab_deposits_held gauge\n\
+                 polkacomputelab_deposits_held {}\n\
+                 \n\
+                 # HELP polkacomputelab_fees_burned_total Fees burned\n\
+                 # TYPE polkacomputelab_fees_burned_total counter\n\
+                 polkacomputelab_fees_burned_total {}\n\
+                 \n\
+                 # HELP polkacomputelab_sla_adherence_percent Jobs finished in the last epoch that met their deadline\n\
+                 # TYPE polkacomputelab_sla_adherence_percent gauge\n\
+                 polkacomputelab_sla_adherence_percent {}\n",
                 metrics.total_jobs_submitted,
                 metrics.total_jobs_completed,
                 metrics.total_jobs_failed,
                 metrics.total_jobs_verified,
-                metrics.avg_job_execution_time,
+                metrics.execution_time_percentiles.p50,
+                metrics.execution_time_percentiles.p95,
+                metrics.execution_time_percentiles.p99,
                 metrics.total_forks,
                 metrics.total_consensus_switches,
-            )
+                metrics.total_escrow_locked,
+                metrics.total_bounties_paid,
+                metrics.total_deposits_held,
+                metrics.total_fees_burned,
+                metrics.sla_adherence.deconstruct(),
+            );
+
+            out.push_str(&format!(
+                "\n\
+                 # HELP polkacomputelab_xcmp_inbound_pages_total Inbound XCMP pages by format\n\
+                 # TYPE polkacomputelab_xcmp_inbound_pages_total counter\n\
+                 polkacomputelab_xcmp_inbound_pages_total{{format=\"xcm\"}} {}\n\
+                 polkacomputelab_xcmp_inbound_pages_total{{format=\"blob\"}} {}\n\
+                 polkacomputelab_xcmp_inbound_pages_total{{format=\"other\"}} {}\n\
+                 \n\
+                 # HELP polkacomputelab_xcm_outbound_messages_total Outbound XCM messages delivered\n\
+                 # TYPE polkacomputelab_xcm_outbound_messages_total counter\n\
+                 polkacomputelab_xcm_outbound_messages_total {}\n\
+                 \n\
+                 # HELP polkacomputelab_xcm_outbound_failures_total Outbound XCM messages that failed to send\n\
+                 # TYPE polkacomputelab_xcm_outbound_failures_total counter\n\
+                 polkacomputelab_xcm_outbound_failures_total {}\n\
+                 \n\
+                 # HELP polkacomputelab_cross_chain_events_total Cross-chain events received\n\
+                 # TYPE polkacomputelab_cross_chain_events_total counter\n\
+                 polkacomputelab_cross_chain_events_total {}\n",
+                xcm.inbound_xcm_pages,
+                xcm.inbound_blob_pages,
+                xcm.inbound_other_pages,
+                xcm.outbound_messages,
+                xcm.outbound_failures,
+                xcm.cross_chain_events,
+            ));
+
+            let growth = Self::get_storage_growth();
+            if !growth.is_empty() {
+                out.push_str(
+                    "\n\
+                     # HELP polkacomputelab_storage_entries Entries in tracked storage maps at the last sample\n\
+                     # TYPE polkacomputelab_storage_entries gauge\n",
+                );
+                for (map, sample) in growth {
+                    out.push_str(&format!(
+                        "polkacomputelab_storage_entries{{map=\"{}\"}} {}\n",
+                        map.name(),
+                        sample.entries,
+                    ));
+                }
+            }
+
+            // Cumulative buckets, as Prometheus histograms expect
+            out.push_str(
+                "\n\
+                 # HELP polkacomputelab_proof_size_bytes Submitted job proof sizes\n\
+                 # TYPE polkacomputelab_proof_size_bytes histogram\n",
+            );
+            let mut cumulative = 0u64;
+            for (bucket, count) in ProofSizeHistogram::<T>::get().iter().enumerate() {
+                cumulative = cumulative.saturating_add(*count);
+                match PROOF_SIZE_BUCKETS.get(bucket) {
+                    Some(bound) => out.push_str(&format!(
+                        "polkacomputelab_proof_size_bytes_bucket{{le=\"{}\"}} {}\n",
+                        bound, cumulative,
+                    )),
+                    None => out.push_str(&format!(
+                        "polkacomputelab_proof_size_bytes_bucket{{le=\"+Inf\"}} {}\n\
+                         polkacomputelab_proof_size_bytes_count {}\n",
+                        cumulative, cumulative,
+                    )),
+                }
+            }
+
+            out
+        }
+    }
+
+    /// Counts cross-chain message traffic passing through `Inner`
+    ///
+    /// As an `XcmpMessageHandler` it classifies inbound pages by format before handing them on;
+    /// as a `SendXcm` router it counts delivered messages and messages the inner router
+    /// refused or failed to deliver. Counters land in `XcmTraffic`.
+    pub struct XcmTrafficMeter<T, Inner>(PhantomData<(T, Inner)>);
+
+    impl<T: Config, Inner: XcmpMessageHandler> XcmpMessageHandler for XcmTrafficMeter<T, Inner> {
+        fn handle_xcmp_messages<'a, I: Iterator<Item = (ParaId, RelayBlockNumber, &'a [u8])>>(
+            iter: I,
+            max_weight: Weight,
+        ) -> Weight {
+            let (mut xcm, mut blob, mut other) = (0u64, 0u64, 0u64);
+            let used = Inner::handle_xcmp_messages(
+                iter.inspect(|(_, _, page)| match XcmpMessageFormat::decode(&mut &page[..]) {
+                    Ok(XcmpMessageFormat::ConcatenatedVersionedXcm) => xcm += 1,
+                    Ok(XcmpMessageFormat::ConcatenatedEncodedBlob) => blob += 1,
+                    _ => other += 1,
+                }),
+                max_weight,
+            );
+
+            if xcm == 0 && blob == 0 && other == 0 {
+                return used;
+            }
+
+            XcmTraffic::<T>::mutate(|traffic| {
+                traffic.inbound_xcm_pages = traffic.inbound_xcm_pages.saturating_add(xcm);
+                traffic.inbound_blob_pages = traffic.inbound_blob_pages.saturating_add(blob);
+                traffic.inbound_other_pages = traffic.inbound_other_pages.saturating_add(other);
+            });
+
+            used.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+        }
+    }
+
+    impl<T: Config, Inner: SendXcm> SendXcm for XcmTrafficMeter<T, Inner> {
+        type Ticket = Inner::Ticket;
+
+        fn validate(
+            destination: &mut Option<Location>,
+            message: &mut Option<Xcm<()>>,
+        ) -> SendResult<Self::Ticket> {
+            Inner::validate(destination, message).inspect_err(|_| {
+                XcmTraffic::<T>::mutate(|traffic| {
+                    traffic.outbound_failures = traffic.outbound_failures.saturating_add(1)
+                });
+            })
+        }
+
+        fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+            let result = Inner::deliver(ticket);
+            XcmTraffic::<T>::mutate(|traffic| match result {
+                Ok(_) => traffic.outbound_messages = traffic.outbound_messages.saturating_add(1),
+                Err(_) => traffic.outbound_failures = traffic.outbound_failures.saturating_add(1),
+            });
+            result
+        }
+    }
+
+    /// Records job metrics and the system job counters automatically from job registry
+    /// status transitions.
+    ///
+    /// `InProgress` marks the start block; `Completed` and `Failed` close the record
+    /// (a job failed straight from `Pending` is measured from submission). `Verified`
+    /// only updates the recorded status.
+    impl<T: Config> pallet_job_registry::OnJobStatusChanged for Pallet<T> {
+        fn on_job_status_changed(job_id: u64, _old_status: &JobStatus, new_status: &JobStatus) {
+            // Called from within the registry's mutation, so the stored job still has the old
+            // status; everything read here is unaffected by the transition.
+            let Some(job) = JobRegistry::<T>::jobs(job_id) else { return };
+            let now: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+
+            SystemMetricsStorage::<T>::mutate(|metrics| match new_status {
+                JobStatus::Completed => {
+                    metrics.total_jobs_completed = metrics.total_jobs_completed.saturating_add(1)
+                },
+                JobStatus::Failed => {
+                    metrics.total_jobs_failed = metrics.total_jobs_failed.saturating_add(1)
+                },
+                JobStatus::Verified => {
+                    metrics.total_jobs_verified = metrics.total_jobs_verified.saturating_add(1)
+                },
+                JobStatus::Pending | JobStatus::InProgress => {},
+            });
+
+            match new_status {
+                JobStatus::InProgress => {
+                    Self::store_job_metrics(
+                        job_id, &job, new_status.clone(), now, None, false, None, 0,
+                    );
+                },
+                JobStatus::Completed | JobStatus::Failed => {
+                    let start = JobMetricsHistory::<T>::get(job_id)
+                        .map(|m| m.start_block)
+                        .unwrap_or_else(|| job.submitted_at.saturated_into());
+                    let succeeded = matches!(new_status, JobStatus::Completed);
+                    Self::store_job_metrics(
+                        job_id, &job, new_status.clone(), start, Some(now), succeeded, None, 0,
+                    );
+                },
+                JobStatus::Verified => {
+                    JobMetricsHistory::<T>::mutate(job_id, |maybe| {
+                        if let Some(metrics) = maybe {
+                            metrics.status = JobStatus::Verified;
+                        }
+                    });
+                },
+                JobStatus::Pending => {},
+            }
+        }
+    }
+
+    /// Folds reported value movements into the economic counters of `SystemMetrics`
+    impl<T: Config> pallet_job_registry::OnEconomicFlow for Pallet<T> {
+        fn on_economic_flow(flow: EconomicFlow, amount: u128) {
+            SystemMetricsStorage::<T>::mutate(|metrics| match flow {
+                EconomicFlow::EscrowLocked => {
+                    metrics.total_escrow_locked = metrics.total_escrow_locked.saturating_add(amount)
+                },
+                EconomicFlow::EscrowReleased => {
+                    metrics.total_escrow_locked = metrics.total_escrow_locked.saturating_sub(amount)
+                },
+                EconomicFlow::BountyPaid => {
+                    metrics.total_bounties_paid = metrics.total_bounties_paid.saturating_add(amount)
+                },
+                EconomicFlow::DepositHeld => {
+                    metrics.total_deposits_held = metrics.total_deposits_held.saturating_add(amount)
+                },
+                EconomicFlow::DepositReleased => {
+                    metrics.total_deposits_held = metrics.total_deposits_held.saturating_sub(amount)
+                },
+                EconomicFlow::FeesBurned => {
+                    metrics.total_fees_burned = metrics.total_fees_burned.saturating_add(amount)
+                },
+            });
+            if flow == EconomicFlow::FeesBurned {
+                BlockFees::<T>::mutate(|fees| *fees = fees.saturating_add(amount));
+            }
+        }
+    }
+
+    /// Buckets stored proof sizes and tallies them per submitter
+    impl<T: Config> pallet_job_verifier::OnProofSubmitted<T::AccountId> for Pallet<T> {
+        fn on_proof_submitted(_job_id: u64, submitter: &T::AccountId, proof_size: u32) {
+            let bucket = PROOF_SIZE_BUCKETS
+                .iter()
+                .position(|&bound| proof_size <= bound)
+                .unwrap_or(PROOF_SIZE_BUCKETS.len());
+            ProofSizeHistogram::<T>::mutate(|histogram| {
+                histogram[bucket] = histogram[bucket].saturating_add(1);
+            });
+
+            ProofSubmitterStats::<T>::mutate(submitter, |stats| {
+                stats.proofs = stats.proofs.saturating_add(1);
+                stats.total_bytes = stats.total_bytes.saturating_add(proof_size as u64);
+                stats.max_bytes = stats.max_bytes.max(proof_size);
+            });
+        }
+    }
+
+    /// Tallies verification outcomes and latency per verifier
+    impl<T: Config> pallet_job_verifier::OnProofVerified<T::AccountId> for Pallet<T> {
+        fn on_proof_verified(_job_id: u64, verifier: &T::AccountId, latency: u32, accepted: bool) {
+            VerifierStats::<T>::mutate(verifier, |stats| {
+                stats.verifications = stats.verifications.saturating_add(1);
+                if !accepted {
+                    stats.rejections = stats.rejections.saturating_add(1);
+                }
+                stats.total_latency_blocks = stats.total_latency_blocks.saturating_add(latency as u64);
+            });
         }
     }
 }
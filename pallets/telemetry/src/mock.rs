@@ -1,15 +1,114 @@
 use crate as pallet_telemetry;
-use frame_support::{parameter_types, traits::{ConstU16, ConstU32, ConstU64}};
+use frame_support::{
+    ord_parameter_types, parameter_types,
+    traits::{ConstBool, ConstU16, ConstU32, ConstU64, EitherOfDiverse, IsInVec},
+};
+use frame_system::{offchain::AppCrypto, EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
-use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, BuildStorage};
+use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Percent, Permill,
+};
+use polkacomputelab_primitives::ConsensusType;
+use xcm::latest::{Assets, Location, SendError, SendResult, SendXcm, Xcm, XcmHash};
 
 type Block = frame_system::mocking::MockBlock<Test>;
+type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+/// Collators taking turns by slot
+pub const AUTHORS: [u64; 3] = [10, 20, 30];
+
+parameter_types! {
+    pub const PerformanceDecay: Percent = Percent::from_percent(50);
+}
+
+ord_parameter_types! {
+    /// Designated metrics reporter (e.g. an OCW key)
+    pub const Reporter: u64 = 99;
+}
+
+parameter_types! {
+    pub static CurrentSlot: u64 = 0;
+    pub const ExecutionTimeSmoothing: Permill = Permill::from_percent(50);
+    pub static CrossChainEvents: u64 = 0;
+    pub static EventsProcessed: u64 = 0;
+    pub static JobProofCount: u32 = 0;
+    pub static Consensus: ConsensusType = ConsensusType::Aura;
+    pub static Forks: u32 = 0;
+    pub static ConsensusSwitches: u32 = 0;
+}
+
+/// Consensus metrics from the `Consensus`, `Forks` and `ConsensusSwitches` stand-ins
+pub struct MockConsensusMetrics;
+
+impl polkacomputelab_primitives::ConsensusMetricsProvider for MockConsensusMetrics {
+    fn consensus_type() -> ConsensusType {
+        Consensus::get()
+    }
+
+    fn total_forks() -> u32 {
+        Forks::get()
+    }
+
+    fn consensus_switches() -> u32 {
+        ConsensusSwitches::get()
+    }
+}
+
+/// Counts registry jobs and the `JobProofCount` stand-in for job proofs; events aren't tracked
+pub struct MockMapEntryCounter;
+
+impl crate::MapEntryCounter for MockMapEntryCounter {
+    fn entry_count(map: crate::TrackedMap) -> Option<u32> {
+        match map {
+            crate::TrackedMap::Jobs => Some(pallet_job_registry::Jobs::<Test>::count()),
+            crate::TrackedMap::JobProofs => Some(JobProofCount::get()),
+            _ => None,
+        }
+    }
+}
+
+pub struct MockAuthorship;
+
+impl crate::SlotAuthorship<u64> for MockAuthorship {
+    fn current_slot() -> u64 {
+        CurrentSlot::get()
+    }
+
+    fn expected_author(slot: u64) -> Option<u64> {
+        Some(AUTHORS[(slot % AUTHORS.len() as u64) as usize])
+    }
+
+    fn authority_count() -> u32 {
+        AUTHORS.len() as u32
+    }
+
+    fn authorities() -> Vec<u64> {
+        AUTHORS.to_vec()
+    }
+}
+
+/// Router that accepts and delivers every message
+pub struct MockRouter;
+
+impl SendXcm for MockRouter {
+    type Ticket = ();
+
+    fn validate(_destination: &mut Option<Location>, _message: &mut Option<Xcm<()>>) -> SendResult<()> {
+        Ok(((), Assets::new()))
+    }
+
+    fn deliver(_ticket: ()) -> Result<XcmHash, SendError> {
+        Ok([0; 32])
+    }
+}
 
 frame_support::construct_runtime!(
     pub enum Test {
         System: frame_system,
         JobRegistry: pallet_job_registry,
-        ConsensusManager: pallet_consensus_manager,
+        Timestamp: pallet_timestamp,
         Telemetry: pallet_telemetry,
     }
 );
@@ -40,23 +139,102 @@ impl frame_system::Config for Test {
     type MaxConsumers = ConstU32<16>;
 }
 
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    /// Accounts allowed to claim jobs
+    pub Workers: Vec<u64> = vec![7];
+}
+
 impl pallet_job_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type MaxJobsPerAccount = ConstU32<100>;
     type MaxDependencyDepth = ConstU32<10>;
+    type OnJobStatusChanged = Telemetry;
+    type ClaimDuration = ConstU64<10>;
+    type AuthorityId = TestAuthId;
+    type Workers = IsInVec<Workers>;
 }
 
-impl pallet_consensus_manager::Config for Test {
-    type RuntimeEvent = RuntimeEvent;
-    type WeightInfo = ();
-    type MaxValidators = ConstU32<100>;
+/// Offchain worker keys, each signing for the account of the same number
+pub struct TestAuthId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type GenericPublic = UintAuthorityId;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = Extrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateBare<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_bare(call: RuntimeCall) -> Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_signed_transaction<A: AppCrypto<UintAuthorityId, TestSignature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        account: u64,
+        _nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, account, (), ()))
+    }
 }
 
 impl pallet_telemetry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type MaxJobMetrics = ConstU32<10000>;
+    type SlowBlockThresholdMs = ConstU64<14000>;
+    type EpochLength = ConstU32<10>;
+    type AlertOrigin = EnsureRoot<u64>;
+    type OnAlert = ();
+    type Authorship = MockAuthorship;
+    type ConsensusMetrics = MockConsensusMetrics;
+    type PerformanceDecay = PerformanceDecay;
+    type ExecutionTimeRingSize = ConstU32<4>;
+    type ExecutionTimeSmoothing = ExecutionTimeSmoothing;
+    type ReporterOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<Reporter, u64>>;
+    type FeelessReporting = ConstBool<true>;
+    type BlockPerformanceRetention = ConstU32<1000>;
+    type JobMetricsRetention = ConstU32<100>;
+    type CrossChainEvents = CrossChainEvents;
+    type EventsProcessed = EventsProcessed;
+    type EpochsPerEra = ConstU32<2>;
+    type EraHistoryDepth = ConstU32<2>;
+    type StorageSampleInterval = ConstU32<5>;
+    type MapEntryCounter = MockMapEntryCounter;
+    type CollectionOrigin = EnsureRoot<u64>;
+    type DigestInterval = ConstU32<5>;
+    type HourlyTierBlocks = ConstU32<20>;
+    type HourlyTierRetention = ConstU32<3>;
+    type DailyTierRetention = ConstU32<2>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -1,6 +1,7 @@
 use crate::{mock::*, Event};
-use frame_support::{assert_ok};
+use frame_support::{assert_ok, traits::Hooks};
 use pallet_job_registry::JobStatus;
+use sp_runtime::Percent;
 
 #[test]
 fn record_job_metrics_works() {
@@ -21,7 +22,9 @@ fn record_job_metrics_works() {
             0, // job_id
             10, // start_block
             Some(20), // end_block
-            true // succeeded
+            true, // succeeded
+            None,
+            0
         ));
 
         let metrics = Telemetry::job_metrics(0).unwrap();
@@ -79,6 +82,24 @@ fn system_metrics_updated() {
     });
 }
 
+#[test]
+fn system_metrics_read_consensus_metrics_provider() {
+    use polkacomputelab_primitives::ConsensusType;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Consensus::set(ConsensusType::Babe);
+        Forks::set(1);
+        ConsensusSwitches::set(1);
+
+        assert_ok!(Telemetry::trigger_system_metrics_update(RuntimeOrigin::signed(1)));
+
+        let metrics = Telemetry::get_system_metrics();
+        assert_eq!(metrics.current_consensus, ConsensusType::Babe);
+        assert_eq!((metrics.total_forks, metrics.total_consensus_switches), (1, 1));
+    });
+}
+
 #[test]
 fn on_finalize_records_block_performance() {
     new_test_ext().execute_with(|| {
@@ -113,7 +134,9 @@ fn average_execution_time_calculated() {
                 i as u64,
                 10,
                 Some(10 + (i + 1) * 10),
-                true
+                true,
+                None,
+                0
             ));
         }
 
@@ -142,10 +165,12 @@ fn execution_time_samples_bounded() {
             0,
             10,
             Some(50),
-            true
+            true,
+            None,
+            0
         ));
 
-        let samples = Telemetry::execution_time_samples();
+        let samples = Telemetry::recent_execution_times();
         assert_eq!(samples.len(), 1);
         assert_eq!(samples[0], 40);
     });
@@ -168,7 +193,9 @@ fn get_job_metrics_works() {
             0,
             5,
             Some(15),
-            true
+            true,
+            None,
+            0
         ));
 
         let metrics = Telemetry::get_job_metrics(0);
@@ -179,6 +206,19 @@ fn get_job_metrics_works() {
     });
 }
 
+#[test]
+fn validator_performance_reports_decay_history() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Telemetry::update_validator_performance(RuntimeOrigin::root(), 1, 10, 30));
+        // The mock keeps half of the previous history
+        assert_ok!(Telemetry::update_validator_performance(RuntimeOrigin::root(), 1, 25, 0));
+
+        let perf = Telemetry::validator_performance(&1);
+        assert_eq!((perf.blocks_produced, perf.blocks_missed), (30, 15));
+        assert_eq!(perf.uptime_percentage, 66);
+    });
+}
+
 #[test]
 fn get_validator_performance_works() {
     new_test_ext().execute_with(|| {
@@ -219,3 +259,913 @@ fn block_performance_cleanup_works() {
         // Block 2 would be removed (1003 - 1000 = 3, so blocks < 3 are removed)
     });
 }
+
+#[test]
+fn block_performance_uses_system_accounting() {
+    new_test_ext().execute_with(|| {
+        use frame_support::{dispatch::DispatchClass, storage::unhashed, weights::Weight};
+
+        System::set_block_number(1);
+
+        // Simulate three applied extrinsics totalling 300 bytes
+        unhashed::put(sp_core::storage::well_known_keys::EXTRINSIC_INDEX, &3u32);
+        System::note_finished_extrinsics();
+        unhashed::put(
+            &frame_support::storage::storage_prefix(b"System", b"AllExtrinsicsLen"),
+            &300u32,
+        );
+        System::register_extra_weight_unchecked(
+            Weight::from_parts(5_000_000, 0),
+            DispatchClass::Normal,
+        );
+
+        Telemetry::on_finalize(1);
+
+        let perf = Telemetry::block_performance(1).unwrap();
+        assert_eq!(perf.extrinsic_count, 3);
+        assert_eq!(perf.block_size_bytes, 300);
+        assert_eq!(perf.weight_used, 5_000_000);
+    });
+}
+
+#[test]
+fn block_performance_tracks_fees_and_utilization() {
+    use frame_support::{dispatch::DispatchClass, traits::Get, weights::Weight};
+    use pallet_job_registry::{EconomicFlow, OnEconomicFlow};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+
+        Telemetry::on_economic_flow(EconomicFlow::FeesBurned, 300);
+        Telemetry::on_economic_flow(EconomicFlow::FeesBurned, 200);
+        // Other flows aren't fees
+        Telemetry::on_economic_flow(EconomicFlow::DepositHeld, 1_000);
+        System::register_extra_weight_unchecked(
+            Weight::from_parts(max_block.ref_time() / 4, max_block.proof_size() / 2),
+            DispatchClass::Normal,
+        );
+        Telemetry::on_finalize(1);
+
+        let perf = Telemetry::block_performance(1).unwrap();
+        assert_eq!(perf.fees_collected, 500);
+        // Proof size is the more saturated dimension
+        assert_eq!(perf.weight_utilization, Percent::from_percent(50));
+
+        // Fees don't carry over into the next block
+        System::set_block_number(2);
+        Telemetry::on_finalize(2);
+        assert_eq!(Telemetry::block_performance(2).unwrap().fees_collected, 0);
+    });
+}
+
+#[test]
+fn block_time_measured_from_timestamps() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(100_000);
+        Telemetry::on_finalize(1);
+        // No previous timestamp to diff against
+        assert_eq!(Telemetry::block_performance(1).unwrap().block_time_ms, 0);
+
+        System::set_block_number(2);
+        Timestamp::set_timestamp(112_000);
+        Telemetry::on_finalize(2);
+        let perf = Telemetry::block_performance(2).unwrap();
+        assert_eq!(perf.block_time_ms, 12_000);
+        assert!(!perf.is_slow);
+
+        // 20s block is above the 14s threshold
+        System::set_block_number(3);
+        Timestamp::set_timestamp(132_000);
+        Telemetry::on_finalize(3);
+        let perf = Telemetry::block_performance(3).unwrap();
+        assert_eq!(perf.block_time_ms, 20_000);
+        assert!(perf.is_slow);
+        System::assert_has_event(Event::SlowBlockDetected {
+            block_number: 3,
+            block_time_ms: 20_000,
+        }.into());
+    });
+}
+
+#[test]
+fn recent_block_performance_newest_first() {
+    new_test_ext().execute_with(|| {
+        for bn in 1..=4 {
+            System::set_block_number(bn);
+            Telemetry::on_finalize(bn);
+        }
+
+        let recent = Telemetry::get_recent_block_performance(3);
+        let numbers: Vec<u32> = recent.iter().map(|p| p.block_number).collect();
+        assert_eq!(numbers, vec![4, 3, 2]);
+
+        // Asking for more than exists stops at genesis
+        assert_eq!(Telemetry::get_recent_block_performance(10).len(), 4);
+    });
+}
+
+#[test]
+fn execution_time_percentiles_from_histogram() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // 98 fast jobs (3 blocks) and two slow ones (150 blocks)
+        for i in 0..100u64 {
+            assert_ok!(JobRegistry::submit_job(
+                RuntimeOrigin::signed(1),
+                vec![i as u8],
+                vec![],
+                1000
+            ));
+            let duration = if i < 98 { 3 } else { 150 };
+            assert_ok!(Telemetry::record_job_metrics(
+                RuntimeOrigin::root(),
+                i,
+                10,
+                Some(10 + duration),
+                true,
+                None,
+                0
+            ));
+        }
+
+        let percentiles = Telemetry::get_execution_time_percentiles();
+        assert_eq!(percentiles.p50, 5);
+        assert_eq!(percentiles.p95, 5);
+        assert_eq!(percentiles.p99, 200);
+
+        assert_ok!(Telemetry::trigger_system_metrics_update(RuntimeOrigin::signed(1)));
+        assert_eq!(Telemetry::get_system_metrics().execution_time_percentiles, percentiles);
+    });
+}
+
+#[test]
+fn participant_metrics_aggregate_by_submitter_and_worker() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        for i in 0..3u64 {
+            assert_ok!(JobRegistry::submit_job(
+                RuntimeOrigin::signed(1),
+                vec![i as u8],
+                vec![],
+                100
+            ));
+        }
+
+        // Worker 7 runs jobs 0 and 1, worker 8 runs job 2 and fails it
+        assert_ok!(Telemetry::record_job_metrics(RuntimeOrigin::root(), 0, 10, Some(20), true, Some(7), 100));
+        assert_ok!(Telemetry::record_job_metrics(RuntimeOrigin::root(), 1, 10, Some(40), true, Some(7), 300));
+        assert_ok!(Telemetry::record_job_metrics(RuntimeOrigin::root(), 2, 10, Some(15), false, Some(8), 50));
+
+        let worker = Telemetry::get_worker_metrics(&7);
+        assert_eq!(worker.jobs_run, 2);
+        assert_eq!(worker.average_latency(), 20);
+        assert_eq!(worker.total_fees, 400);
+        assert_eq!(worker.success_rate(), Percent::from_percent(100));
+
+        let submitter = Telemetry::get_account_metrics(&1);
+        assert_eq!(submitter.jobs_run, 3);
+        assert_eq!(submitter.jobs_succeeded, 2);
+        assert_eq!(submitter.total_fees, 450);
+        assert_eq!(submitter.success_rate(), Percent::from_percent(67));
+
+        // Re-recording a job updates its metrics but is not counted twice
+        assert_ok!(Telemetry::record_job_metrics(RuntimeOrigin::root(), 2, 10, Some(15), false, Some(8), 50));
+        assert_eq!(Telemetry::get_worker_metrics(&8).jobs_run, 1);
+    });
+}
+
+#[test]
+fn job_lifecycle_records_metrics_automatically() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+
+        System::set_block_number(5);
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 1));
+        let metrics = Telemetry::job_metrics(0).unwrap();
+        assert_eq!(metrics.start_block, 5);
+        assert_eq!(metrics.end_block, None);
+
+        System::set_block_number(12);
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 2));
+        let metrics = Telemetry::job_metrics(0).unwrap();
+        assert_eq!(metrics.end_block, Some(12));
+        assert_eq!(metrics.execution_time_blocks, 7);
+        assert_eq!(metrics.status, JobStatus::Completed);
+        assert!(metrics.succeeded);
+
+        // Verification only updates the status; the job is counted once
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 3));
+        assert_eq!(Telemetry::job_metrics(0).unwrap().status, JobStatus::Verified);
+        assert_eq!(Telemetry::get_account_metrics(&1).jobs_run, 1);
+        assert_eq!(Telemetry::recent_execution_times().len(), 1);
+
+        // A job failed before starting is measured from submission
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![2], vec![], 100));
+        System::set_block_number(20);
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 1, 4));
+        let metrics = Telemetry::job_metrics(1).unwrap();
+        assert_eq!(metrics.start_block, 12);
+        assert_eq!(metrics.execution_time_blocks, 8);
+        assert!(!metrics.succeeded);
+    });
+}
+
+#[test]
+fn epoch_rollup_summarises_block_metrics() {
+    new_test_ext().execute_with(|| {
+        // Epoch 1: blocks 1..=10, 12s blocks with one 20s block
+        let mut now = 0u64;
+        for bn in 1..=20u64 {
+            System::set_block_number(bn);
+            now += if bn == 5 { 20_000 } else { 12_000 };
+            Timestamp::set_timestamp(now);
+            if bn == 3 {
+                assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+                assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 4));
+            }
+            Telemetry::on_finalize(bn);
+        }
+
+        let epoch1 = Telemetry::epoch_summary(1).unwrap();
+        assert_eq!((epoch1.start_block, epoch1.end_block), (1, 10));
+        assert_eq!(epoch1.blocks, 10);
+        // Block 1 has no predecessor and records 0
+        assert_eq!(epoch1.min_block_time_ms, 0);
+        assert_eq!(epoch1.max_block_time_ms, 20_000);
+        assert_eq!(epoch1.slow_blocks, 1);
+        assert_eq!(epoch1.jobs_finished, 1);
+
+        let epoch2 = Telemetry::epoch_summary(2).unwrap();
+        assert_eq!(epoch2.avg_block_time_ms, 12_000);
+        assert_eq!(epoch2.jobs_finished, 0);
+
+        System::assert_has_event(Event::EpochRolledUp { epoch: 2, jobs_finished: 0 }.into());
+    });
+}
+
+#[test]
+fn alert_thresholds_fire_at_epoch_boundary() {
+    new_test_ext().execute_with(|| {
+        use crate::AlertMetric;
+        use frame_support::assert_noop;
+
+        assert_noop!(
+            Telemetry::set_alert_threshold(RuntimeOrigin::signed(1), 0, Some(20)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Telemetry::set_alert_threshold(RuntimeOrigin::root(), 9, Some(20)),
+            crate::Error::<Test>::InvalidAlertMetric
+        );
+        // Failure rate above 20%, average block time above 14s
+        assert_ok!(Telemetry::set_alert_threshold(RuntimeOrigin::root(), 0, Some(20)));
+        assert_ok!(Telemetry::set_alert_threshold(RuntimeOrigin::root(), 1, Some(14_000)));
+
+        System::set_block_number(1);
+        for i in 0..4u64 {
+            assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![i as u8], vec![], 100));
+            assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), i, 1));
+        }
+        // One of four jobs fails: 25%
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 4));
+        for i in 1..4u64 {
+            assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), i, 2));
+        }
+
+        for bn in 1..=10u64 {
+            System::set_block_number(bn);
+            Timestamp::set_timestamp(bn * 12_000);
+            Telemetry::on_finalize(bn);
+        }
+
+        System::assert_has_event(Event::AlertTriggered {
+            epoch: 1,
+            metric: AlertMetric::JobFailureRate,
+            value: 25,
+            threshold: 20,
+        }.into());
+        assert!(!System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::Telemetry(Event::AlertTriggered { metric: AlertMetric::AvgBlockTimeMs, .. })
+        )));
+    });
+}
+
+#[test]
+fn authorship_tracks_produced_and_missed_blocks() {
+    new_test_ext().execute_with(|| {
+        // Slot 1 is authored by 20
+        System::set_block_number(1);
+        CurrentSlot::set(1);
+        Telemetry::on_initialize(1);
+        assert_eq!(Telemetry::validator_performance(&20).blocks_produced, 1);
+        assert_eq!(Telemetry::validator_performance(&20).last_block_produced, 1);
+
+        // Slots 2 (30) and 3 (10) are skipped; slot 4 is authored by 20 again
+        System::set_block_number(2);
+        CurrentSlot::set(4);
+        Telemetry::on_initialize(2);
+
+        let perf = Telemetry::validator_performance(&20);
+        assert_eq!(perf.blocks_produced, 2);
+        assert_eq!(perf.uptime_percentage, 100);
+        assert_eq!(Telemetry::validator_performance(&30).blocks_missed, 1);
+        assert_eq!(Telemetry::validator_performance(&10).blocks_missed, 1);
+        assert_eq!(Telemetry::validator_performance(&10).uptime_percentage, 0);
+
+        // A long stall only charges the most recent slots
+        System::set_block_number(3);
+        CurrentSlot::set(4 + 1 + crate::MAX_MISSED_SLOTS_PER_BLOCK + 30);
+        Telemetry::on_initialize(3);
+        let missed: u64 = AUTHORS.iter().map(|a| Telemetry::validator_performance(a).blocks_missed).sum();
+        assert_eq!(missed, 2 + crate::MAX_MISSED_SLOTS_PER_BLOCK);
+    });
+}
+
+#[test]
+fn execution_time_ring_wraps_and_ewma_smooths() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Ring holds 4 samples; smoothing is 50%
+        for i in 0..6u64 {
+            assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![i as u8], vec![], 100));
+            assert_ok!(Telemetry::record_job_metrics(
+                RuntimeOrigin::root(),
+                i,
+                0,
+                Some(10 * (i as u32 + 1)),
+                true,
+                None,
+                0
+            ));
+        }
+
+        assert_eq!(Telemetry::recent_execution_times(), vec![30, 40, 50, 60]);
+        assert_eq!(Telemetry::get_average_execution_time(), 45);
+        // 10 -> 15 -> 22.5 -> 31.25 -> 40.625 -> 50.3125
+        assert_eq!(Telemetry::get_ewma_execution_time(), 50);
+    });
+}
+
+#[test]
+fn retention_windows_prune_history() {
+    new_test_ext().execute_with(|| {
+        use frame_support::weights::Weight;
+
+        System::set_block_number(1);
+        for i in 0..3u64 {
+            assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![i as u8], vec![], 1000));
+            assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), i, 1));
+        }
+        System::set_block_number(5);
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 2));
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 1, 2));
+        assert_ok!(JobRegistry::remove_job(RuntimeOrigin::signed(1), 0));
+
+        // Metrics of the removed job go; job 1 is within the 100 block default
+        System::set_block_number(50);
+        Telemetry::on_idle(50, Weight::from_parts(u64::MAX / 2, u64::MAX / 2));
+        assert!(Telemetry::job_metrics(0).is_none());
+        assert!(Telemetry::job_metrics(1).is_some());
+        System::assert_has_event(Event::JobMetricsPruned { job_id: 0 }.into());
+
+        // Shorter override expires job 1; the running job 2 is kept
+        assert_ok!(Telemetry::set_retention(RuntimeOrigin::root(), 1, Some(10)));
+        Telemetry::on_idle(50, Weight::from_parts(u64::MAX / 2, u64::MAX / 2));
+        assert!(Telemetry::job_metrics(1).is_none());
+        assert!(Telemetry::job_metrics(2).is_some());
+
+        // Block performance retention override
+        assert_ok!(Telemetry::set_retention(RuntimeOrigin::root(), 0, Some(5)));
+        Telemetry::on_finalize(45);
+        assert!(Telemetry::block_performance(45).is_some());
+        Telemetry::on_finalize(50);
+        assert!(Telemetry::block_performance(45).is_none());
+
+        assert_ok!(Telemetry::set_retention(RuntimeOrigin::root(), 0, None));
+        assert_eq!(Telemetry::retention(crate::RetentionCategory::BlockPerformance), 1000);
+    });
+}
+
+#[test]
+fn offchain_worker_pushes_metrics_when_configured() {
+    use codec::Encode;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, StorageKind};
+
+    let (offchain, state) = testing::TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    ext.execute_with(|| {
+        // Not configured: nothing is sent
+        Telemetry::offchain_worker(10);
+
+        let url = "http://localhost:9091/metrics/job/polkacomputelab";
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            crate::PUSH_ENDPOINT_KEY,
+            &url.as_bytes().to_vec().encode(),
+        );
+
+        state.write().expect_request(testing::PendingRequest {
+            method: "POST".into(),
+            uri: url.into(),
+            headers: vec![("Content-Type".into(), "text/plain; version=0.0.4".into())],
+            body: Telemetry::export_prometheus_metrics().into_bytes(),
+            response: Some(vec![]),
+            sent: true,
+            ..Default::default()
+        });
+
+        // Off-interval blocks are skipped, boundary blocks push
+        Telemetry::offchain_worker(11);
+        Telemetry::offchain_worker(20);
+    });
+}
+
+#[test]
+fn designated_reporter_reports_feelessly() {
+    new_test_ext().execute_with(|| {
+        use frame_support::{assert_noop, dispatch::CheckIfFeeless};
+
+        System::set_block_number(1);
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+
+        assert_noop!(
+            Telemetry::record_job_metrics(RuntimeOrigin::signed(1), 0, 1, Some(5), true, None, 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Telemetry::record_job_metrics(RuntimeOrigin::signed(Reporter::get()), 0, 1, Some(5), true, None, 0));
+        assert_ok!(Telemetry::update_validator_performance(RuntimeOrigin::signed(Reporter::get()), 10, 9, 1));
+
+        let call = RuntimeCall::Telemetry(crate::Call::update_validator_performance {
+            validator: 10,
+            blocks_produced: 9,
+            blocks_missed: 1,
+        });
+        assert!(call.is_feeless(&RuntimeOrigin::signed(Reporter::get())));
+        assert!(!call.is_feeless(&RuntimeOrigin::signed(1)));
+    });
+}
+
+#[test]
+fn metrics_snapshot_and_json_export() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Telemetry::update_validator_performance(RuntimeOrigin::root(), 7, 3, 1));
+        for bn in 1..=3 {
+            System::set_block_number(bn);
+            Telemetry::on_finalize(bn);
+        }
+
+        let snapshot = Telemetry::metrics_snapshot(2);
+        assert_eq!(snapshot.validators.len(), 1);
+        assert_eq!(snapshot.validators[0].0, 7);
+        assert_eq!(snapshot.recent_blocks.iter().map(|b| b.block_number).collect::<Vec<_>>(), vec![3, 2]);
+
+        let json = Telemetry::export_json_metrics(2);
+        assert!(json.starts_with("{\"system\":{\"total_jobs_submitted\":0,"));
+        assert!(json.contains("\"account\":\"0x0700000000000000\",\"blocks_produced\":3"));
+        assert!(json.contains("{\"block_number\":3,"));
+        assert!(!json.contains("{\"block_number\":1,"));
+    });
+}
+
+#[test]
+fn block_performance_ring_overwrites_old_slots() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Telemetry::set_retention(RuntimeOrigin::root(), 0, Some(4)));
+
+        for bn in 1..=6 {
+            System::set_block_number(bn);
+            Telemetry::on_finalize(bn);
+        }
+
+        // Blocks 5 and 6 took over the slots of blocks 1 and 2
+        assert!(Telemetry::block_performance(1).is_none());
+        assert!(Telemetry::block_performance(2).is_none());
+        assert_eq!(Telemetry::block_performance(5).unwrap().block_number, 5);
+        assert_eq!(crate::BlockPerformanceHistory::<Test>::iter().count(), 4);
+    });
+}
+
+#[test]
+fn economic_flows_update_system_totals() {
+    use pallet_job_registry::{EconomicFlow, OnEconomicFlow};
+
+    new_test_ext().execute_with(|| {
+        Telemetry::on_economic_flow(EconomicFlow::EscrowLocked, 500);
+        Telemetry::on_economic_flow(EconomicFlow::EscrowReleased, 200);
+        Telemetry::on_economic_flow(EconomicFlow::BountyPaid, 200);
+        Telemetry::on_economic_flow(EconomicFlow::DepositHeld, 10);
+        Telemetry::on_economic_flow(EconomicFlow::DepositHeld, 10);
+        Telemetry::on_economic_flow(EconomicFlow::DepositReleased, 10);
+        Telemetry::on_economic_flow(EconomicFlow::FeesBurned, 7);
+        // Releases never underflow the outstanding totals
+        Telemetry::on_economic_flow(EconomicFlow::DepositReleased, 100);
+
+        let metrics = Telemetry::get_system_metrics();
+        assert_eq!(metrics.total_escrow_locked, 300);
+        assert_eq!(metrics.total_bounties_paid, 200);
+        assert_eq!(metrics.total_deposits_held, 0);
+        assert_eq!(metrics.total_fees_burned, 7);
+
+        assert!(Telemetry::export_prometheus_metrics().contains("polkacomputelab_escrow_locked 300\n"));
+    });
+}
+
+#[test]
+fn xcm_traffic_meter_counts_messages() {
+    use codec::Encode;
+    use cumulus_primitives_core::{ParaId, XcmpMessageFormat, XcmpMessageHandler};
+    use frame_support::weights::Weight;
+    use xcm::latest::{send_xcm, Location, Xcm};
+
+    new_test_ext().execute_with(|| {
+        let xcm_page = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+        let blob_page = XcmpMessageFormat::ConcatenatedEncodedBlob.encode();
+        let signal_page = XcmpMessageFormat::Signals.encode();
+        let pages = vec![
+            (ParaId::from(2000), 1, &xcm_page[..]),
+            (ParaId::from(2000), 1, &blob_page[..]),
+            (ParaId::from(2001), 1, &blob_page[..]),
+            (ParaId::from(2001), 1, &signal_page[..]),
+        ];
+        crate::XcmTrafficMeter::<Test, ()>::handle_xcmp_messages(pages.into_iter(), Weight::MAX);
+
+        assert!(send_xcm::<crate::XcmTrafficMeter<Test, MockRouter>>(Location::parent(), Xcm(vec![])).is_ok());
+        // No route: the empty router refuses the message
+        assert!(send_xcm::<crate::XcmTrafficMeter<Test, ()>>(Location::parent(), Xcm(vec![])).is_err());
+        CrossChainEvents::set(3);
+
+        let traffic = Telemetry::get_xcm_traffic();
+        assert_eq!(traffic.inbound_xcm_pages, 1);
+        assert_eq!(traffic.inbound_blob_pages, 2);
+        assert_eq!(traffic.inbound_other_pages, 1);
+        assert_eq!(traffic.outbound_messages, 1);
+        assert_eq!(traffic.outbound_failures, 1);
+        assert_eq!(traffic.cross_chain_events, 3);
+
+        assert!(Telemetry::export_prometheus_metrics()
+            .contains("polkacomputelab_xcmp_inbound_pages_total{format=\"blob\"} 2\n"));
+    });
+}
+
+#[test]
+fn storage_growth_sampled_with_idle_weight() {
+    use crate::TrackedMap;
+    use frame_support::weights::Weight;
+
+    new_test_ext().execute_with(|| {
+        let plenty = Weight::from_parts(u64::MAX / 2, u64::MAX / 2);
+        for bn in 1..=3 {
+            System::set_block_number(bn);
+            Telemetry::on_finalize(bn);
+        }
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        JobProofCount::set(4);
+
+        // Not due before the interval; when due, an idle budget too small defers the sample
+        Telemetry::on_idle(4, plenty);
+        Telemetry::on_idle(5, Weight::zero());
+        assert!(Telemetry::storage_sample(TrackedMap::Jobs).is_none());
+
+        System::set_block_number(6);
+        Telemetry::on_idle(6, plenty);
+        System::assert_has_event(Event::StorageSampled { block_number: 6, total_entries: 8 }.into());
+        assert_eq!(Telemetry::storage_sample(TrackedMap::BlockPerformance).unwrap().entries, 3);
+        // Events aren't counted by the mock source
+        assert!(Telemetry::storage_sample(TrackedMap::Events).is_none());
+
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![2], vec![], 100));
+        Telemetry::on_idle(11, plenty);
+        let jobs = Telemetry::storage_sample(TrackedMap::Jobs).unwrap();
+        assert_eq!((jobs.sampled_at, jobs.entries, jobs.previous_entries), (11, 2, 1));
+
+        assert!(Telemetry::export_prometheus_metrics()
+            .contains("polkacomputelab_storage_entries{map=\"job_proofs\"} 4\n"));
+    });
+}
+
+#[test]
+fn sla_adherence_tracked_per_epoch_and_submitter() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for (who, deadline) in [(1, 5), (1, 6), (2, 20), (1, 20)] {
+            assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(who), vec![1], vec![], deadline));
+        }
+        System::set_block_number(3);
+        for (who, job_id) in [(1, 0), (1, 1), (2, 2), (1, 3)] {
+            assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(who), job_id, 1));
+        }
+
+        // On time, late, on time, failed
+        for (bn, who, job_id, status) in [(4, 1, 0, 2), (7, 1, 1, 2), (8, 2, 2, 2), (9, 1, 3, 4)] {
+            System::set_block_number(bn);
+            assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(who), job_id, status));
+        }
+
+        System::set_block_number(10);
+        Telemetry::on_finalize(10);
+        let summary = Telemetry::epoch_summary(1).unwrap();
+        assert_eq!((summary.jobs_finished, summary.jobs_on_time), (4, 2));
+        assert_eq!(Telemetry::get_system_metrics().sla_adherence, Percent::from_percent(50));
+        assert!(Telemetry::export_prometheus_metrics().contains("polkacomputelab_sla_adherence_percent 50\n"));
+
+        // Per-submitter figures cover the last completed epoch
+        assert_eq!(Telemetry::get_submitter_sla(&1).jobs_finished, 0);
+        System::set_block_number(11);
+        let sla = Telemetry::get_submitter_sla(&1);
+        assert_eq!((sla.jobs_finished, sla.jobs_on_time), (3, 1));
+        assert_eq!(sla.adherence(), Percent::from_percent(33));
+        assert_eq!(Telemetry::get_submitter_sla(&2).adherence(), Percent::one());
+    });
+}
+
+#[test]
+fn paused_collection_keeps_only_counters() {
+    use frame_support::{assert_noop, weights::Weight};
+    use sp_runtime::DispatchError;
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Telemetry::set_collection_paused(RuntimeOrigin::signed(1), true),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Telemetry::set_collection_paused(RuntimeOrigin::root(), true));
+        System::assert_last_event(Event::CollectionPauseSet { paused: true }.into());
+
+        System::set_block_number(3);
+        assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 4));
+        for bn in 4..=10 {
+            System::set_block_number(bn);
+            Telemetry::on_idle(bn, Weight::from_parts(u64::MAX / 2, u64::MAX / 2));
+            Telemetry::on_finalize(bn);
+        }
+
+        // Job counters still update; heavy paths recorded nothing
+        assert_eq!(Telemetry::get_account_metrics(&1).jobs_run, 1);
+        assert!(Telemetry::block_performance(10).is_none());
+        assert!(Telemetry::epoch_summary(1).is_none());
+        assert!(Telemetry::storage_sample(crate::TrackedMap::Jobs).is_none());
+
+        // The paused epoch's counters don't leak into the next one
+        assert_ok!(Telemetry::set_collection_paused(RuntimeOrigin::root(), false));
+        for bn in 11..=20 {
+            System::set_block_number(bn);
+            Telemetry::on_finalize(bn);
+        }
+        assert!(Telemetry::block_performance(20).is_some());
+        assert_eq!(Telemetry::epoch_summary(2).unwrap().jobs_finished, 0);
+    });
+}
+
+#[test]
+fn proof_sizes_bucketed_and_tallied_per_submitter() {
+    use pallet_job_verifier::OnProofSubmitted;
+
+    new_test_ext().execute_with(|| {
+        Telemetry::on_proof_submitted(0, &1, 64);
+        Telemetry::on_proof_submitted(1, &1, 100);
+        Telemetry::on_proof_submitted(2, &2, 70_000);
+
+        let histogram = Telemetry::proof_size_histogram();
+        assert_eq!(histogram[0], 1);
+        assert_eq!(histogram[1], 1);
+        assert_eq!(histogram[crate::PROOF_SIZE_BUCKET_COUNT - 1], 1);
+
+        let stats = Telemetry::proof_submitter_stats(1);
+        assert_eq!((stats.proofs, stats.total_bytes, stats.max_bytes), (2, 164, 100));
+
+        let export = Telemetry::export_prometheus_metrics();
+        assert!(export.contains("polkacomputelab_proof_size_bytes_bucket{le=\"128\"} 2\n"));
+        assert!(export.contains("polkacomputelab_proof_size_bytes_bucket{le=\"65536\"} 2\n"));
+        assert!(export.contains("polkacomputelab_proof_size_bytes_count 3\n"));
+    });
+}
+
+#[test]
+fn on_initialize_reserves_finalize_weight() {
+    use crate::WeightInfo;
+
+    new_test_ext().execute_with(|| {
+        let finalize = <() as WeightInfo>::on_finalize();
+        let rollup = <() as WeightInfo>::rollup_epoch(10);
+
+        assert!(Telemetry::on_initialize(1).all_gte(finalize));
+        assert!(Telemetry::on_initialize(10).all_gte(finalize.saturating_add(rollup)));
+
+        assert_ok!(Telemetry::set_collection_paused(RuntimeOrigin::root(), true));
+        assert!(Telemetry::on_initialize(10).ref_time() < finalize.ref_time());
+    });
+}
+
+#[test]
+fn metrics_digest_published_every_interval() {
+    use crate::MetricsDigest;
+
+    let digests = || {
+        System::digest().logs.iter().filter_map(MetricsDigest::from_digest_item).collect::<Vec<_>>()
+    };
+
+    new_test_ext().execute_with(|| {
+        let mut now = 0u64;
+        for bn in 1..=5u64 {
+            System::set_block_number(bn);
+            now += 12_000;
+            Timestamp::set_timestamp(now);
+            if bn == 2 {
+                for deadline in [100, 100] {
+                    assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], deadline));
+                }
+                assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 4));
+                assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 1, 1));
+                assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 1, 2));
+            }
+            Telemetry::on_finalize(bn);
+            if bn < 5 {
+                assert!(digests().is_empty());
+            }
+        }
+
+        // The first block has no predecessor, so the average covers 4 x 12s over 5 blocks
+        assert_eq!(
+            digests(),
+            vec![MetricsDigest {
+                block_number: 5,
+                jobs_finished: 2,
+                failure_rate: Percent::from_percent(50),
+                avg_block_time_ms: 9_600,
+            }]
+        );
+        assert_eq!(crate::DigestTotals::<Test>::get(), Default::default());
+    });
+}
+
+#[test]
+fn prune_telemetry_removes_expired_entries_and_refunds() {
+    use crate::WeightInfo;
+
+    new_test_ext().execute_with(|| {
+        for bn in 1..=10 {
+            System::set_block_number(bn);
+            Telemetry::on_finalize(bn);
+        }
+        // Metrics of a job the registry no longer has
+        crate::JobMetricsHistory::<Test>::insert(7, crate::JobMetrics {
+            job_id: 7,
+            start_block: 1,
+            end_block: Some(2),
+            execution_time_blocks: 1,
+            status: JobStatus::Completed,
+            succeeded: true,
+        });
+
+        // Shrinking the ring leaves every recorded block outside the window at block 12
+        assert_ok!(Telemetry::set_retention(RuntimeOrigin::root(), 0, Some(4)));
+        System::set_block_number(12);
+        Telemetry::on_finalize(12);
+
+        let info = Telemetry::prune_telemetry(RuntimeOrigin::signed(1), 1_000).unwrap();
+        assert_eq!(info.actual_weight, Some(<() as WeightInfo>::prune_telemetry(12)));
+        assert!(Telemetry::job_metrics(7).is_none());
+        assert_eq!(crate::BlockPerformanceHistory::<Test>::iter().count(), 1);
+        assert!(Telemetry::block_performance(12).is_some());
+    });
+}
+
+#[test]
+fn system_job_counters_follow_registry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for _ in 0..3 {
+            assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+        }
+        for status in [1, 2, 3] {
+            assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, status));
+        }
+        assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 1, 4));
+        Telemetry::on_finalize(1);
+
+        let metrics = Telemetry::get_system_metrics();
+        assert_eq!(metrics.total_jobs_submitted, 3);
+        assert_eq!(metrics.total_jobs_completed, 1);
+        assert_eq!(metrics.total_jobs_failed, 1);
+        assert_eq!(metrics.total_jobs_verified, 1);
+        assert!(Telemetry::export_prometheus_metrics().contains("polkacomputelab_jobs_submitted_total 3\n"));
+    });
+}
+
+#[test]
+fn tier_history_downsamples_epochs() {
+    use crate::HistoryTier;
+
+    new_test_ext().execute_with(|| {
+        // 8 epochs of 10 blocks: two per hourly bucket, all in the first daily bucket
+        let mut now = 0u64;
+        for bn in 1..=80u64 {
+            System::set_block_number(bn);
+            now += 12_000;
+            Timestamp::set_timestamp(now);
+            if bn == 3 {
+                assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+                assert_ok!(JobRegistry::update_job_status(RuntimeOrigin::signed(1), 0, 4));
+            }
+            Telemetry::on_finalize(bn);
+        }
+
+        // Only 3 hourly buckets are kept; period 4 took over period 1's slot
+        let hourly = Telemetry::get_tier_history(HistoryTier::Hourly, 10);
+        assert_eq!(hourly.iter().map(|bucket| bucket.period).collect::<Vec<_>>(), vec![4, 3, 2]);
+        assert!(hourly.iter().all(|bucket| bucket.epochs == 2 && bucket.summary.blocks == 20));
+        assert_eq!((hourly[2].summary.start_block, hourly[2].summary.end_block), (21, 40));
+        assert_eq!(hourly[2].summary.avg_block_time_ms, 12_000);
+        assert_eq!(hourly[2].summary.jobs_finished, 0);
+
+        let daily = Telemetry::get_tier_history(HistoryTier::Daily, 10);
+        assert_eq!(daily.len(), 1);
+        assert_eq!((daily[0].period, daily[0].epochs), (1, 8));
+        let summary = &daily[0].summary;
+        assert_eq!((summary.start_block, summary.end_block, summary.blocks), (1, 80, 80));
+        // Block 1 has no predecessor and records 0
+        assert_eq!(summary.min_block_time_ms, 0);
+        assert_eq!(summary.avg_block_time_ms, 79 * 12_000 / 80);
+        assert_eq!(summary.jobs_finished, 1);
+    });
+}
+
+#[test]
+fn verifier_metrics_track_latency_and_rejections() {
+    use pallet_job_verifier::OnProofVerified;
+
+    new_test_ext().execute_with(|| {
+        Telemetry::on_proof_verified(0, &5, 2, true);
+        Telemetry::on_proof_verified(1, &5, 6, false);
+        Telemetry::on_proof_verified(2, &5, 4, true);
+        Telemetry::on_proof_verified(3, &5, 0, false);
+
+        let metrics = Telemetry::get_verifier_metrics(&5);
+        assert_eq!((metrics.verifications, metrics.rejections), (4, 2));
+        assert_eq!(metrics.average_latency(), 3);
+        assert_eq!(metrics.rejection_rate(), Percent::from_percent(50));
+
+        // Other verifiers are untouched
+        assert_eq!(Telemetry::verifier_metrics(6), Default::default());
+    });
+}
+
+#[test]
+fn era_totals_snapshot_counter_increments() {
+    use pallet_job_verifier::OnProofSubmitted;
+
+    new_test_ext().execute_with(|| {
+        // Eras are 2 epochs of 10 blocks, and the last 2 are kept
+        let run_era = |era: u64, events: u64, proofs: u64| {
+            for bn in (era - 1) * 20 + 1..=era * 20 {
+                System::set_block_number(bn);
+                if bn % 20 == 5 {
+                    EventsProcessed::set(EventsProcessed::get() + events);
+                    for job_id in 0..proofs {
+                        Telemetry::on_proof_submitted(job_id, &1, 64);
+                    }
+                    assert_ok!(JobRegistry::submit_job(RuntimeOrigin::signed(1), vec![1], vec![], 100));
+                }
+                Telemetry::on_finalize(bn);
+            }
+        };
+
+        run_era(1, 7, 2);
+        let era1 = Telemetry::era_totals(1).unwrap();
+        assert_eq!((era1.start_block, era1.end_block, era1.blocks()), (1, 20, 20));
+        assert_eq!(era1.counters.events_processed, 7);
+        assert_eq!(era1.counters.proofs_submitted, 2);
+        assert_eq!(era1.counters.jobs_submitted, 1);
+        System::assert_has_event(
+            Event::EraSnapshotted { era: 1, events_processed: 7, proofs_submitted: 2 }.into(),
+        );
+
+        // Increments are per era, not lifetime
+        run_era(2, 3, 0);
+        let era2 = Telemetry::era_totals(2).unwrap();
+        assert_eq!((era2.start_block, era2.end_block), (21, 40));
+        assert_eq!(era2.counters.events_processed, 3);
+        assert_eq!(era2.counters.proofs_submitted, 0);
+
+        // Snapshots continue while collection is paused; era 1 falls out of the history
+        assert_ok!(Telemetry::set_collection_paused(RuntimeOrigin::root(), true));
+        run_era(3, 1, 1);
+        assert_eq!(Telemetry::era_totals(1), None);
+        assert_eq!(
+            Telemetry::get_era_totals(5).iter().map(|era| era.era).collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+        assert_eq!(Telemetry::era_totals(3).unwrap().counters.events_processed, 1);
+    });
+}
@@ -1,4 +1,12 @@
 //! Weights for pallet_telemetry
+//!
+//! Estimates rather than benchmark results. `ref_time` values are placeholders, and each proof
+//! size is the sum of the `MaxEncodedLen` of the storage the call can touch in its worst case,
+//! worked out by hand for this runtime (32-byte account IDs, Aura's `MaxAuthorities`, the
+//! `MAX_*` pruning and missed-slot bounds). Storage of the consensus manager, event hub and job
+//! registry read through the `Config` providers is included. Regenerate them on reference
+//! hardware before production, using a node built with `--features runtime-benchmarks`:
+//! `benchmark pallet --pallet pallet_telemetry --extrinsic '*' --output pallets/telemetry/src/weights.rs`.
 
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
@@ -7,38 +15,555 @@ pub trait WeightInfo {
     fn record_job_metrics() -> Weight;
     fn update_validator_performance() -> Weight;
     fn update_system_metrics() -> Weight;
+    fn rollup_epoch(b: u32) -> Weight;
+    fn set_alert_threshold() -> Weight;
+    fn note_authorship(m: u32) -> Weight;
+    fn set_retention() -> Weight;
+    fn prune_job_metrics(n: u32) -> Weight;
+    fn sample_storage() -> Weight;
+    fn set_collection_paused() -> Weight;
+    fn on_finalize() -> Weight;
+    fn prune_telemetry(n: u32) -> Weight;
+    fn snapshot_era() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `JobRegistry::Jobs` (r:1 w:0)
+    /// Proof: `JobRegistry::Jobs` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsHistory` (r:1 w:1)
+    /// Proof: `Telemetry::JobMetricsHistory` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForJobMetricsHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForJobMetricsHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::AccountMetrics` (r:1 w:1)
+    /// Proof: `Telemetry::AccountMetrics` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::WorkerMetrics` (r:1 w:1)
+    /// Proof: `Telemetry::WorkerMetrics` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochJobsFinished` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsFinished` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochJobsFailed` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsFailed` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::DigestTotals` (r:1 w:1)
+    /// Proof: `Telemetry::DigestTotals` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::SubmitterSlas` (r:1 w:1)
+    /// Proof: `Telemetry::SubmitterSlas` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeRingCursor` (r:1 w:1)
+    /// Proof: `Telemetry::ExecutionTimeRingCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeRing` (r:0 w:1)
+    /// Proof: `Telemetry::ExecutionTimeRing` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeEwma` (r:1 w:1)
+    /// Proof: `Telemetry::ExecutionTimeEwma` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeHistogram` (r:1 w:1)
+    /// Proof: `Telemetry::ExecutionTimeHistogram` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
     fn record_job_metrics() -> Weight {
-        Weight::from_parts(25_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(2))
+        // Proof Size summary in bytes:
+        //  Estimated: `17650`
+        Weight::from_parts(30_000_000, 17650)
+            .saturating_add(T::DbWeight::get().reads(12))
+            .saturating_add(T::DbWeight::get().writes(12))
     }
 
+    /// Storage: `Telemetry::ValidatorPerformanceMap` (r:1 w:1)
+    /// Proof: `Telemetry::ValidatorPerformanceMap` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
     fn update_validator_performance() -> Weight {
-        Weight::from_parts(20_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `3534`
+        Weight::from_parts(20_000_000, 3534)
+            .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
 
+    /// Storage: `Telemetry::SystemMetricsStorage` (r:1 w:1)
+    /// Proof: `Telemetry::SystemMetricsStorage` (`max_values`: Some(1), `max_size`: Some(118), added: 613, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::NextJobId` (r:1 w:0)
+    /// Proof: `JobRegistry::NextJobId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ForkStatistics` (r:1 w:0)
+    /// Proof: `ConsensusManager::ForkStatistics` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ConsensusSwitches` (r:1 w:0)
+    /// Proof: `ConsensusManager::ConsensusSwitches` (`max_values`: Some(1), `max_size`: Some(502), added: 997, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeHistogram` (r:1 w:0)
+    /// Proof: `Telemetry::ExecutionTimeHistogram` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
     fn update_system_metrics() -> Weight {
-        Weight::from_parts(30_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(5))
+        // Proof Size summary in bytes:
+        //  Estimated: `4689`
+        Weight::from_parts(30_000_000, 4689)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `Telemetry::EpochJobsFinished` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsFinished` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochJobsFailed` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsFailed` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochJobsOnTime` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsOnTime` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::RetentionOverrides` (r:3 w:0)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::AlertThresholds` (r:4 w:0)
+    /// Proof: `Telemetry::AlertThresholds` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::SystemMetricsStorage` (r:1 w:1)
+    /// Proof: `Telemetry::SystemMetricsStorage` (`max_values`: Some(1), `max_size`: Some(118), added: 613, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::TierHistory` (r:2 w:2)
+    /// Proof: `Telemetry::TierHistory` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochSummaries` (r:1 w:1)
+    /// Proof: `Telemetry::EpochSummaries` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForEpochSummaries` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForEpochSummaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::BlockPerformanceHistory` (r:1000 w:0)
+    /// Proof: `Telemetry::BlockPerformanceHistory` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+    /// Excludes the storage touched by `OnAlert` for each triggered alert.
+    /// The range of component `b` is `[1, 1000]`.
+    fn rollup_epoch(b: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `28746`
+        Weight::from_parts(15_000_000, 28746)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(15))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(8))
+            .saturating_add(Weight::from_parts(0, 2533).saturating_mul(b.into()))
+    }
+
+    /// Storage: `Telemetry::AlertThresholds` (r:0 w:1)
+    /// Proof: `Telemetry::AlertThresholds` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+    fn set_alert_threshold() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3490`
+        Weight::from_parts(15_000_000, 3490)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `Aura::CurrentSlot` (r:1 w:0)
+    /// Proof: `Aura::CurrentSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::LastSlot` (r:1 w:1)
+    /// Proof: `Telemetry::LastSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ValidatorPerformanceMap` (r:17 w:17)
+    /// Proof: `Telemetry::ValidatorPerformanceMap` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[0, 16]`.
+    fn note_authorship(m: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3205039`
+        Weight::from_parts(10_000_000, 3205039)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+            .saturating_add(Weight::from_parts(0, 2544).saturating_mul(m.into()))
+    }
+
+    /// Storage: `Telemetry::RetentionOverrides` (r:0 w:1)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    fn set_retention() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3486`
+        Weight::from_parts(15_000_000, 3486)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `Telemetry::RetentionOverrides` (r:1 w:0)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsPruneCursor` (r:1 w:1)
+    /// Proof: `Telemetry::JobMetricsPruneCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForJobMetricsHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForJobMetricsHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsHistory` (r:50 w:50)
+    /// Proof: `Telemetry::JobMetricsHistory` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::Jobs` (r:50 w:0)
+    /// Proof: `JobRegistry::Jobs` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 50]`.
+    fn prune_job_metrics(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `4610`
+        Weight::from_parts(8_000_000, 4610)
+            .saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5406).saturating_mul(n.into()))
+    }
+
+    /// Storage: `Telemetry::CounterForJobMetricsHistory` (r:1 w:0)
+    /// Proof: `Telemetry::CounterForJobMetricsHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForBlockPerformanceHistory` (r:1 w:0)
+    /// Proof: `Telemetry::CounterForBlockPerformanceHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForEpochSummaries` (r:1 w:0)
+    /// Proof: `Telemetry::CounterForEpochSummaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::CounterForJobs` (r:1 w:0)
+    /// Proof: `JobRegistry::CounterForJobs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventCount` (r:1 w:0)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `JobVerifier::CounterForJobProofs` (r:1 w:0)
+    /// Proof: `JobVerifier::CounterForJobProofs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::StorageGrowth` (r:6 w:6)
+    /// Proof: `Telemetry::StorageGrowth` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::LastStorageSample` (r:0 w:1)
+    /// Proof: `Telemetry::LastStorageSample` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn sample_storage() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `18960`
+        Weight::from_parts(12_000_000, 18960)
+            .saturating_add(T::DbWeight::get().reads(12))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    /// Storage: `Telemetry::CollectionPaused` (r:0 w:1)
+    /// Proof: `Telemetry::CollectionPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    fn set_collection_paused() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1486`
+        Weight::from_parts(12_000_000, 1486)
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::LastBlockTimestamp` (r:1 w:1)
+    /// Proof: `Telemetry::LastBlockTimestamp` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::BlockFees` (r:1 w:1)
+    /// Proof: `Telemetry::BlockFees` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CollectionPaused` (r:1 w:0)
+    /// Proof: `Telemetry::CollectionPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `System::ExtrinsicCount` (r:1 w:0)
+    /// Proof: `System::ExtrinsicCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+    /// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::RetentionOverrides` (r:1 w:0)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::BlockPerformanceHistory` (r:1 w:1)
+    /// Proof: `Telemetry::BlockPerformanceHistory` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForBlockPerformanceHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForBlockPerformanceHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::DigestTotals` (r:1 w:1)
+    /// Proof: `Telemetry::DigestTotals` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+    /// Storage: `System::Digest` (r:1 w:1)
+    /// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `Telemetry::SystemMetricsStorage` (r:1 w:1)
+    /// Proof: `Telemetry::SystemMetricsStorage` (`max_values`: Some(1), `max_size`: Some(118), added: 613, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::NextJobId` (r:1 w:0)
+    /// Proof: `JobRegistry::NextJobId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ForkStatistics` (r:1 w:0)
+    /// Proof: `ConsensusManager::ForkStatistics` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ConsensusSwitches` (r:1 w:0)
+    /// Proof: `ConsensusManager::ConsensusSwitches` (`max_values`: Some(1), `max_size`: Some(502), added: 997, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeHistogram` (r:1 w:0)
+    /// Proof: `Telemetry::ExecutionTimeHistogram` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
+    /// Block performance and system metrics refresh, excluding the epoch rollup
+    fn on_finalize() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `13743`
+        Weight::from_parts(45_000_000, 13743)
+            .saturating_add(T::DbWeight::get().reads(17))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    /// Storage: `Telemetry::RetentionOverrides` (r:2 w:0)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsPruneCursor` (r:1 w:1)
+    /// Proof: `Telemetry::JobMetricsPruneCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::BlockPerformancePruneCursor` (r:1 w:1)
+    /// Proof: `Telemetry::BlockPerformancePruneCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForJobMetricsHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForJobMetricsHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForBlockPerformanceHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForBlockPerformanceHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsHistory` (r:500 w:500)
+    /// Proof: `Telemetry::JobMetricsHistory` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::Jobs` (r:500 w:0)
+    /// Proof: `JobRegistry::Jobs` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+    /// Each inspected entry is charged at the job metrics rate, which dominates a block performance entry.
+    /// The range of component `n` is `[0, 500]`.
+    fn prune_telemetry(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `8230`
+        Weight::from_parts(12_000_000, 8230)
+            .saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5406).saturating_mul(n.into()))
+    }
+
+    /// Storage: `Telemetry::EraBaseline` (r:1 w:1)
+    /// Proof: `Telemetry::EraBaseline` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:0)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ProofSizeHistogram` (r:1 w:0)
+    /// Proof: `Telemetry::ProofSizeHistogram` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::NextJobId` (r:1 w:0)
+    /// Proof: `JobRegistry::NextJobId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::SystemMetricsStorage` (r:1 w:0)
+    /// Proof: `Telemetry::SystemMetricsStorage` (`max_values`: Some(1), `max_size`: Some(118), added: 613, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EraHistory` (r:0 w:2)
+    /// Proof: `Telemetry::EraHistory` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    fn snapshot_era() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3767`
+        Weight::from_parts(20_000_000, 3767)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
 }
 
 impl WeightInfo for () {
+    /// Storage: `JobRegistry::Jobs` (r:1 w:0)
+    /// Proof: `JobRegistry::Jobs` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsHistory` (r:1 w:1)
+    /// Proof: `Telemetry::JobMetricsHistory` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForJobMetricsHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForJobMetricsHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::AccountMetrics` (r:1 w:1)
+    /// Proof: `Telemetry::AccountMetrics` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::WorkerMetrics` (r:1 w:1)
+    /// Proof: `Telemetry::WorkerMetrics` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochJobsFinished` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsFinished` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochJobsFailed` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsFailed` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::DigestTotals` (r:1 w:1)
+    /// Proof: `Telemetry::DigestTotals` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::SubmitterSlas` (r:1 w:1)
+    /// Proof: `Telemetry::SubmitterSlas` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeRingCursor` (r:1 w:1)
+    /// Proof: `Telemetry::ExecutionTimeRingCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeRing` (r:0 w:1)
+    /// Proof: `Telemetry::ExecutionTimeRing` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeEwma` (r:1 w:1)
+    /// Proof: `Telemetry::ExecutionTimeEwma` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeHistogram` (r:1 w:1)
+    /// Proof: `Telemetry::ExecutionTimeHistogram` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
     fn record_job_metrics() -> Weight {
-        Weight::from_parts(25_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `17650`
+        Weight::from_parts(30_000_000, 17650)
     }
 
+    /// Storage: `Telemetry::ValidatorPerformanceMap` (r:1 w:1)
+    /// Proof: `Telemetry::ValidatorPerformanceMap` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
     fn update_validator_performance() -> Weight {
-        Weight::from_parts(20_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `3534`
+        Weight::from_parts(20_000_000, 3534)
     }
 
+    /// Storage: `Telemetry::SystemMetricsStorage` (r:1 w:1)
+    /// Proof: `Telemetry::SystemMetricsStorage` (`max_values`: Some(1), `max_size`: Some(118), added: 613, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::NextJobId` (r:1 w:0)
+    /// Proof: `JobRegistry::NextJobId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ForkStatistics` (r:1 w:0)
+    /// Proof: `ConsensusManager::ForkStatistics` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ConsensusSwitches` (r:1 w:0)
+    /// Proof: `ConsensusManager::ConsensusSwitches` (`max_values`: Some(1), `max_size`: Some(502), added: 997, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeHistogram` (r:1 w:0)
+    /// Proof: `Telemetry::ExecutionTimeHistogram` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
     fn update_system_metrics() -> Weight {
-        Weight::from_parts(30_000_000, 0)
+        // Proof Size summary in bytes:
+        //  Estimated: `4689`
+        Weight::from_parts(30_000_000, 4689)
+    }
+
+    /// Storage: `Telemetry::EpochJobsFinished` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsFinished` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochJobsFailed` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsFailed` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochJobsOnTime` (r:1 w:1)
+    /// Proof: `Telemetry::EpochJobsOnTime` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::RetentionOverrides` (r:3 w:0)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::AlertThresholds` (r:4 w:0)
+    /// Proof: `Telemetry::AlertThresholds` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::SystemMetricsStorage` (r:1 w:1)
+    /// Proof: `Telemetry::SystemMetricsStorage` (`max_values`: Some(1), `max_size`: Some(118), added: 613, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::TierHistory` (r:2 w:2)
+    /// Proof: `Telemetry::TierHistory` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EpochSummaries` (r:1 w:1)
+    /// Proof: `Telemetry::EpochSummaries` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForEpochSummaries` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForEpochSummaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::BlockPerformanceHistory` (r:1000 w:0)
+    /// Proof: `Telemetry::BlockPerformanceHistory` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+    /// Excludes the storage touched by `OnAlert` for each triggered alert.
+    /// The range of component `b` is `[1, 1000]`.
+    fn rollup_epoch(b: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `28746`
+        Weight::from_parts(15_000_000, 28746)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2533).saturating_mul(b.into()))
+    }
+
+    /// Storage: `Telemetry::AlertThresholds` (r:0 w:1)
+    /// Proof: `Telemetry::AlertThresholds` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+    fn set_alert_threshold() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3490`
+        Weight::from_parts(15_000_000, 3490)
+    }
+
+    /// Storage: `Aura::CurrentSlot` (r:1 w:0)
+    /// Proof: `Aura::CurrentSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::LastSlot` (r:1 w:1)
+    /// Proof: `Telemetry::LastSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aura::Authorities` (r:1 w:0)
+    /// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ValidatorPerformanceMap` (r:17 w:17)
+    /// Proof: `Telemetry::ValidatorPerformanceMap` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[0, 16]`.
+    fn note_authorship(m: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3205039`
+        Weight::from_parts(10_000_000, 3205039)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(0, 2544).saturating_mul(m.into()))
+    }
+
+    /// Storage: `Telemetry::RetentionOverrides` (r:0 w:1)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    fn set_retention() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3486`
+        Weight::from_parts(15_000_000, 3486)
+    }
+
+    /// Storage: `Telemetry::RetentionOverrides` (r:1 w:0)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsPruneCursor` (r:1 w:1)
+    /// Proof: `Telemetry::JobMetricsPruneCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForJobMetricsHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForJobMetricsHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsHistory` (r:50 w:50)
+    /// Proof: `Telemetry::JobMetricsHistory` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::Jobs` (r:50 w:0)
+    /// Proof: `JobRegistry::Jobs` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 50]`.
+    fn prune_job_metrics(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `4610`
+        Weight::from_parts(8_000_000, 4610)
+            .saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 5406).saturating_mul(n.into()))
+    }
+
+    /// Storage: `Telemetry::CounterForJobMetricsHistory` (r:1 w:0)
+    /// Proof: `Telemetry::CounterForJobMetricsHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForBlockPerformanceHistory` (r:1 w:0)
+    /// Proof: `Telemetry::CounterForBlockPerformanceHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForEpochSummaries` (r:1 w:0)
+    /// Proof: `Telemetry::CounterForEpochSummaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::CounterForJobs` (r:1 w:0)
+    /// Proof: `JobRegistry::CounterForJobs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventCount` (r:1 w:0)
+    /// Proof: `EventHub::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `JobVerifier::CounterForJobProofs` (r:1 w:0)
+    /// Proof: `JobVerifier::CounterForJobProofs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::StorageGrowth` (r:6 w:6)
+    /// Proof: `Telemetry::StorageGrowth` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::LastStorageSample` (r:0 w:1)
+    /// Proof: `Telemetry::LastStorageSample` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn sample_storage() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `18960`
+        Weight::from_parts(12_000_000, 18960)
+    }
+
+    /// Storage: `Telemetry::CollectionPaused` (r:0 w:1)
+    /// Proof: `Telemetry::CollectionPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    fn set_collection_paused() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1486`
+        Weight::from_parts(12_000_000, 1486)
+    }
+
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::LastBlockTimestamp` (r:1 w:1)
+    /// Proof: `Telemetry::LastBlockTimestamp` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::BlockFees` (r:1 w:1)
+    /// Proof: `Telemetry::BlockFees` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CollectionPaused` (r:1 w:0)
+    /// Proof: `Telemetry::CollectionPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `System::ExtrinsicCount` (r:1 w:0)
+    /// Proof: `System::ExtrinsicCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+    /// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::RetentionOverrides` (r:1 w:0)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::BlockPerformanceHistory` (r:1 w:1)
+    /// Proof: `Telemetry::BlockPerformanceHistory` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForBlockPerformanceHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForBlockPerformanceHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::DigestTotals` (r:1 w:1)
+    /// Proof: `Telemetry::DigestTotals` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+    /// Storage: `System::Digest` (r:1 w:1)
+    /// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `Telemetry::SystemMetricsStorage` (r:1 w:1)
+    /// Proof: `Telemetry::SystemMetricsStorage` (`max_values`: Some(1), `max_size`: Some(118), added: 613, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::NextJobId` (r:1 w:0)
+    /// Proof: `JobRegistry::NextJobId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::CurrentConsensus` (r:1 w:0)
+    /// Proof: `ConsensusManager::CurrentConsensus` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ForkStatistics` (r:1 w:0)
+    /// Proof: `ConsensusManager::ForkStatistics` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+    /// Storage: `ConsensusManager::ConsensusSwitches` (r:1 w:0)
+    /// Proof: `ConsensusManager::ConsensusSwitches` (`max_values`: Some(1), `max_size`: Some(502), added: 997, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ExecutionTimeHistogram` (r:1 w:0)
+    /// Proof: `Telemetry::ExecutionTimeHistogram` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
+    /// Block performance and system metrics refresh, excluding the epoch rollup
+    fn on_finalize() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `13743`
+        Weight::from_parts(45_000_000, 13743)
+    }
+
+    /// Storage: `Telemetry::RetentionOverrides` (r:2 w:0)
+    /// Proof: `Telemetry::RetentionOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsPruneCursor` (r:1 w:1)
+    /// Proof: `Telemetry::JobMetricsPruneCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::BlockPerformancePruneCursor` (r:1 w:1)
+    /// Proof: `Telemetry::BlockPerformancePruneCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForJobMetricsHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForJobMetricsHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::CounterForBlockPerformanceHistory` (r:1 w:1)
+    /// Proof: `Telemetry::CounterForBlockPerformanceHistory` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::JobMetricsHistory` (r:500 w:500)
+    /// Proof: `Telemetry::JobMetricsHistory` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::Jobs` (r:500 w:0)
+    /// Proof: `JobRegistry::Jobs` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+    /// Each inspected entry is charged at the job metrics rate, which dominates a block performance entry.
+    /// The range of component `n` is `[0, 500]`.
+    fn prune_telemetry(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `8230`
+        Weight::from_parts(12_000_000, 8230)
+            .saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 5406).saturating_mul(n.into()))
+    }
+
+    /// Storage: `Telemetry::EraBaseline` (r:1 w:1)
+    /// Proof: `Telemetry::EraBaseline` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+    /// Storage: `EventHub::EventStatistics` (r:1 w:0)
+    /// Proof: `EventHub::EventStatistics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::ProofSizeHistogram` (r:1 w:0)
+    /// Proof: `Telemetry::ProofSizeHistogram` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
+    /// Storage: `JobRegistry::NextJobId` (r:1 w:0)
+    /// Proof: `JobRegistry::NextJobId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::SystemMetricsStorage` (r:1 w:0)
+    /// Proof: `Telemetry::SystemMetricsStorage` (`max_values`: Some(1), `max_size`: Some(118), added: 613, mode: `MaxEncodedLen`)
+    /// Storage: `Telemetry::EraHistory` (r:0 w:2)
+    /// Proof: `Telemetry::EraHistory` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    fn snapshot_era() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3767`
+        Weight::from_parts(20_000_000, 3767)
     }
 }
@@ -0,0 +1,110 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # PolkaComputeLab Primitives
+//!
+//! Types and traits shared between pallets, so a pallet can consume another's data through
+//! a provider trait without depending on that pallet's crate.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Block time as the delta between the previous block's timestamp and `now_ms`. The first
+/// observed block has no predecessor and measures 0.
+pub fn block_time_ms(previous_ms: u64, now_ms: u64) -> u64 {
+    if previous_ms > 0 {
+        now_ms.saturating_sub(previous_ms)
+    } else {
+        0
+    }
+}
+
+/// Consensus algorithm types
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[codec(dumb_trait_bound)]
+pub enum ConsensusType {
+    /// Aura consensus
+    Aura,
+    /// BABE consensus
+    Babe,
+    /// Custom consensus (for experimentation)
+    Custom,
+}
+
+impl Default for ConsensusType {
+    fn default() -> Self {
+        ConsensusType::Aura
+    }
+}
+
+impl ConsensusType {
+    /// Convert from u8 representation
+    pub fn from_u8(value: u8) -> Result<Self, ()> {
+        match value {
+            0 => Ok(ConsensusType::Aura),
+            1 => Ok(ConsensusType::Babe),
+            2 => Ok(ConsensusType::Custom),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Slot-based block authorship, used to track validator performance automatically
+pub trait SlotAuthorship<AccountId> {
+    /// Slot of the block being built
+    fn current_slot() -> u64;
+    /// Account expected to author `slot`
+    fn expected_author(slot: u64) -> Option<AccountId>;
+    /// Size of the current authority set
+    fn authority_count() -> u32;
+    /// Accounts of the current authority set
+    fn authorities() -> Vec<AccountId>;
+    /// Make `slot` the current slot and ensure it has an expected author
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_slot(_slot: u64) {}
+}
+
+impl<AccountId> SlotAuthorship<AccountId> for () {
+    fn current_slot() -> u64 {
+        0
+    }
+
+    fn expected_author(_slot: u64) -> Option<AccountId> {
+        None
+    }
+
+    fn authority_count() -> u32 {
+        0
+    }
+
+    fn authorities() -> Vec<AccountId> {
+        Vec::new()
+    }
+}
+
+/// Consensus metrics exported to other pallets, such as telemetry, without a dependency on
+/// the consensus manager
+pub trait ConsensusMetricsProvider {
+    /// Consensus type currently active
+    fn consensus_type() -> ConsensusType;
+    /// Forks recorded
+    fn total_forks() -> u32;
+    /// Consensus switches in the kept history
+    fn consensus_switches() -> u32;
+}
+
+/// No consensus manager: default consensus and no forks or switches
+impl ConsensusMetricsProvider for () {
+    fn consensus_type() -> ConsensusType {
+        ConsensusType::default()
+    }
+
+    fn total_forks() -> u32 {
+        0
+    }
+
+    fn consensus_switches() -> u32 {
+        0
+    }
+}
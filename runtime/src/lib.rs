@@ -5,10 +5,12 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
 use sp_api::impl_runtime_apis;
+use codec::Encode;
+use frame_system::offchain::AppCrypto;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
     generic, impl_opaque_keys,
-    traits::{AccountIdLookup, BlakeTwo256, Block as BlockT},
+    traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, SaturatedConversion, Verify},
     transaction_validity::{TransactionSource, TransactionValidity},
     ApplyExtrinsicResult,
 };
@@ -33,7 +35,7 @@ pub use pallet_timestamp::Call as TimestampCall;
 // Removed unused imports
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{Perbill, Percent, Permill};
 
 pub use pallet_job_registry;
 pub use pallet_job_verifier;
@@ -113,11 +115,21 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    pallet_skip_feeless_payment::SkipCheckIfFeeless<
+        Runtime,
+        pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    >,
 );
 
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade, before any pallet's `on_runtime_upgrade`.
+/// Check them against live state with `try-runtime on-runtime-upgrade` before releasing.
+///
+/// None yet: the custom pallets start at storage version 1, and chains whose state predates
+/// that must be reset rather than upgraded (see DEPLOYMENT.md).
+pub type Migrations = ();
+
 pub type Executive = frame_executive::Executive<
     Runtime,
     Block,
@@ -162,13 +174,73 @@ impl frame_system::Config for Runtime {
     type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
     type ExtensionsWeightInfo = ();
-    type SingleBlockMigrations = ();
+    type SingleBlockMigrations = Migrations;
     type MultiBlockMigrator = ();
     type PreInherents = ();
     type PostInherents = ();
     type PostTransactions = ();
 }
 
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+/// Unsigned transactions submitted by pallet offchain workers
+impl<C> frame_system::offchain::CreateBare<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    fn create_bare(call: RuntimeCall) -> UncheckedExtrinsic {
+        UncheckedExtrinsic::new_bare(call)
+    }
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+/// Signed transactions submitted by pallet offchain workers, with the `pcl!` keys
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    fn create_signed_transaction<A: AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        public: <Signature as Verify>::Signer,
+        account: AccountId,
+        nonce: Nonce,
+    ) -> Option<UncheckedExtrinsic> {
+        let period = BlockHashCount::get()
+            .checked_next_power_of_two()
+            .map(|c| c / 2)
+            .unwrap_or(2) as u64;
+        let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+        let extra: SignedExtra = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_skip_feeless_payment::SkipCheckIfFeeless::from(
+                pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+            ),
+        );
+        let raw_payload = SignedPayload::new(call, extra)
+            .map_err(|e| log::warn!("Unable to create signed payload: {:?}", e))
+            .ok()?;
+        let signature = raw_payload.using_encoded(|payload| A::sign(payload, public))?;
+        let (call, extra, _) = raw_payload.deconstruct();
+        Some(UncheckedExtrinsic::new_signed(call, Address::Id(account), signature, extra))
+    }
+}
+
 parameter_types! {
     pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
 }
@@ -208,9 +280,24 @@ parameter_types! {
     pub const OperationalFeeMultiplier: u8 = 5;
 }
 
+/// Drops transaction fees (burning them) after reporting the amount to telemetry.
+pub struct BurnFeesWithTelemetry;
+impl frame_support::traits::OnUnbalanced<frame_support::traits::fungible::Credit<AccountId, Balances>>
+    for BurnFeesWithTelemetry
+{
+    fn on_nonzero_unbalanced(amount: frame_support::traits::fungible::Credit<AccountId, Balances>) {
+        use frame_support::traits::Imbalance;
+        <Telemetry as pallet_job_registry::OnEconomicFlow>::on_economic_flow(
+            pallet_job_registry::EconomicFlow::FeesBurned,
+            amount.peek(),
+        );
+        drop(amount);
+    }
+}
+
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
+    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, BurnFeesWithTelemetry>;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type WeightToFee = ConstantMultiplier<Balance, ConstU128<{ 100 * MICROUNIT }>>;
     type LengthToFee = ConstantMultiplier<Balance, ConstU128<{ MICROUNIT }>>;
@@ -218,6 +305,10 @@ impl pallet_transaction_payment::Config for Runtime {
     type WeightInfo = pallet_transaction_payment::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_skip_feeless_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+}
+
 impl pallet_sudo::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -237,7 +328,10 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
     type OutboundXcmpMessageSource = XcmpQueue;
     type DmpQueue = frame_support::traits::EnqueueWithOrigin<(), sp_core::ConstU8<0>>;
     type ReservedDmpWeight = ReservedDmpWeight;
-    type XcmpMessageHandler = XcmpQueue;
+    type XcmpMessageHandler = pallet_telemetry::XcmTrafficMeter<
+        Runtime,
+        pallet_event_hub::XcmpEventHandler<Runtime, XcmpQueue>,
+    >;
     type ReservedXcmpWeight = ReservedXcmpWeight;
     type CheckAssociatedRelayNumber = RelayNumberMonotonicallyIncreases;
     type RelayParentOffset = sp_core::ConstU32<0>;
@@ -278,12 +372,18 @@ parameter_types! {
     pub const BaseXcmWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
 }
 
+/// Routes messages to the relay chain (UMP) or sibling parachains (XCMP), metered by telemetry
+pub type XcmRouter = pallet_telemetry::XcmTrafficMeter<
+    Runtime,
+    (cumulus_primitives_utility::ParentAsUmp<ParachainSystem, (), ()>, XcmpQueue),
+>;
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
     type RuntimeCall = RuntimeCall;
     type XcmSender = ();
     type AssetTransactor = ();
-    type OriginConverter = ();
+    type OriginConverter = xcm_builder::SiblingParachainAsNative<cumulus_pallet_xcm::Origin, RuntimeOrigin>;
     type IsReserve = ();
     type IsTeleporter = ();
     type UniversalLocation = UniversalLocation;
@@ -330,12 +430,40 @@ impl pallet_aura::Config for Runtime {
 parameter_types! {
     pub const MaxJobsPerAccount: u32 = 1000;
     pub const MaxDependencyDepth: u32 = 20;
+    pub const JobClaimDuration: BlockNumber = 20; // ~4 minutes at 12s blocks
 }
 
 impl pallet_job_registry::Config for Runtime {
     type WeightInfo = pallet_job_registry::weights::SubstrateWeight<Runtime>;
     type MaxJobsPerAccount = MaxJobsPerAccount;
     type MaxDependencyDepth = MaxDependencyDepth;
+    type OnJobStatusChanged = (EventHub, Telemetry);
+    type ClaimDuration = JobClaimDuration;
+    type AuthorityId = pallet_job_registry::crypto::OcwAuthId;
+    type Workers = OcwWorkerAccounts;
+}
+
+parameter_types! {
+    /// Accounts (OCW keys) allowed to claim jobs and deliver webhooks (set via governance)
+    pub storage OcwWorkers: Vec<AccountId> = Vec::new();
+}
+
+/// The accounts listed in `OcwWorkers`
+pub struct OcwWorkerAccounts;
+
+impl frame_support::traits::SortedMembers<AccountId> for OcwWorkerAccounts {
+    fn sorted_members() -> Vec<AccountId> {
+        let mut workers = OcwWorkers::get();
+        workers.sort();
+        workers
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &AccountId) {
+        let mut workers = OcwWorkers::get();
+        workers.push(who.clone());
+        OcwWorkers::set(&workers);
+    }
 }
 
 parameter_types! {
@@ -345,35 +473,367 @@ parameter_types! {
 impl pallet_job_verifier::Config for Runtime {
     type WeightInfo = pallet_job_verifier::weights::SubstrateWeight<Runtime>;
     type MaxProofSize = MaxProofSize;
+    type OnProofSubmitted = Telemetry;
+    type OnProofVerified = Telemetry;
+    type VerifierOrigin = frame_system::EnsureSignedBy<ProofVerifierAccounts, AccountId>;
+}
+
+parameter_types! {
+    /// Accounts allowed to verify job proofs (set via governance)
+    pub storage ProofVerifiers: Vec<AccountId> = Vec::new();
+}
+
+/// The accounts listed in `ProofVerifiers`
+pub struct ProofVerifierAccounts;
+
+impl frame_support::traits::SortedMembers<AccountId> for ProofVerifierAccounts {
+    fn sorted_members() -> Vec<AccountId> {
+        let mut verifiers = ProofVerifiers::get();
+        verifiers.sort();
+        verifiers
+    }
 }
 
 parameter_types! {
     pub const MaxValidators: u32 = 1000;
+    pub const MaxConsensusSwitches: u32 = 100;
+    pub const ConsensusMetricsEpochLength: u32 = 300; // ~1 hour at 12s blocks
+    pub const EpochSummaryRetention: u32 = 24 * 30; // ~30 days of epochs
+    pub const RawMetricsEpochs: u32 = 3;
+    pub const ParticipationEraEpochs: u32 = 24; // ~1 day of metrics epochs
+    pub const MinParticipation: u8 = 80;
+    pub const ParticipationGraceEras: u32 = 2;
+    pub const ExpectedBlockTimeMs: u64 = MILLISECS_PER_BLOCK;
+    pub const BlockTimeTolerance: Percent = Percent::from_percent(20);
+    pub const PerformanceDecay: Percent = Percent::from_percent(90); // ~1 week half-life
+    pub const JailAfterMissedEras: u32 = 3;
+    pub const JailCooldownEras: u32 = 7;
 }
 
 impl pallet_consensus_manager::Config for Runtime {
     type WeightInfo = pallet_consensus_manager::weights::SubstrateWeight<Runtime>;
     type MaxValidators = MaxValidators;
+    type MaxConsensusSwitches = MaxConsensusSwitches;
+    type BlockAuthorship = AuraAuthorship;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type MetricsEpochLength = ConsensusMetricsEpochLength;
+    type EpochSummaryRetention = EpochSummaryRetention;
+    type RawMetricsEpochs = RawMetricsEpochs;
+    // No staking or collator selection pallet to act on reports yet; events still flag them
+    type OnLowParticipation = ();
+    type OffenceHandler = ();
+    type JailHandler = ();
+    type ParticipationEraEpochs = ParticipationEraEpochs;
+    type MinParticipation = MinParticipation;
+    type ParticipationGraceEras = ParticipationGraceEras;
+    type BlockTimestamp = Timestamp;
+    type ExpectedBlockTimeMs = ExpectedBlockTimeMs;
+    type BlockTimeTolerance = BlockTimeTolerance;
+    type PerformanceDecay = PerformanceDecay;
+    type JailAfterMissedEras = JailAfterMissedEras;
+    type JailCooldownEras = JailCooldownEras;
+    type HeartbeatId = AuraId;
+    type AuthorityKeys = AuraAuthorship;
+    type HeartbeatAccount = AuraAccount;
 }
 
 parameter_types! {
     pub const MaxEvents: u32 = 10000;
+    pub const EventBackpressure: pallet_event_hub::BackpressureMode =
+        pallet_event_hub::BackpressureMode::EvictOldestProcessed;
     pub const MaxTriggersPerAccount: u32 = 1000;
+    pub const MaxChainDepth: u32 = 8;
+    pub const MaxTriggerFailures: u32 = 5;
+    pub const EventDeposit: Balance = deposit(1, 600);
+    pub const EventRetentionPeriod: BlockNumber = 7 * 24 * 300; // ~7 days at 12s blocks
+    pub const MaxEventAttempts: u32 = 10;
+    pub const HrmpFee: u128 = 1_000_000_000; // 0.1 DOT
+}
+
+/// Well-known event-hub topics (for chain specs and clients)
+pub use pallet_event_hub::topics as event_topics;
+
+/// HRMP calls of the relay chain's `Hrmp` pallet
+pub struct RelayHrmpCalls;
+
+impl RelayHrmpCalls {
+    /// Index of the `Hrmp` pallet in the relay runtime
+    const HRMP_PALLET_INDEX: u8 = 60;
+}
+
+impl pallet_event_hub::HrmpCallEncoder for RelayHrmpCalls {
+    fn init_open_channel(recipient: u32, max_capacity: u32, max_message_size: u32) -> Vec<u8> {
+        (Self::HRMP_PALLET_INDEX, 0u8, recipient, max_capacity, max_message_size).encode()
+    }
+
+    fn accept_open_channel(sender: u32) -> Vec<u8> {
+        (Self::HRMP_PALLET_INDEX, 1u8, sender).encode()
+    }
+}
+
+/// Ensures the origin is a sibling parachain, resolving to its para ID
+pub struct EnsureSiblingParaId;
+
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureSiblingParaId {
+    type Success = u32;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(cumulus_pallet_xcm::Origin::SiblingParachain(para_id)) => Ok(para_id.into()),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(cumulus_pallet_xcm::Origin::SiblingParachain(2000.into()).into())
+    }
+}
+
+parameter_types! {
+    /// Oracle accounts allowed to submit Condition events (set via governance)
+    pub storage ConditionOracles: Vec<AccountId> = Vec::new();
+}
+
+/// Per-event-type submission policy: signed accounts, with Condition events limited to oracles
+pub struct EventSubmitOrigin;
+
+impl frame_support::traits::EnsureOriginWithArg<RuntimeOrigin, pallet_event_hub::EventType>
+    for EventSubmitOrigin
+{
+    type Success = AccountId;
+
+    fn try_origin(
+        o: RuntimeOrigin,
+        event_type: &pallet_event_hub::EventType,
+    ) -> Result<Self::Success, RuntimeOrigin> {
+        use pallet_event_hub::EventType;
+
+        let who = match o.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(who)) => who,
+            _ => return Err(o),
+        };
+
+        match event_type {
+            EventType::OnChain | EventType::Timer => Ok(who),
+            EventType::Condition if ConditionOracles::get().contains(&who) => Ok(who),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(
+        event_type: &pallet_event_hub::EventType,
+    ) -> Result<RuntimeOrigin, ()> {
+        let who: AccountId = frame_benchmarking::whitelisted_caller();
+        if matches!(event_type, pallet_event_hub::EventType::Condition) {
+            ConditionOracles::set(&vec![who.clone()]);
+        }
+        Ok(frame_system::RawOrigin::Signed(who).into())
+    }
 }
 
 impl pallet_event_hub::Config for Runtime {
     type WeightInfo = pallet_event_hub::weights::SubstrateWeight<Runtime>;
     type MaxEvents = MaxEvents;
+    type Backpressure = EventBackpressure;
     type MaxTriggersPerAccount = MaxTriggersPerAccount;
+    type SubmitOrigin = EventSubmitOrigin;
+    type CrossChainOrigin = EnsureSiblingParaId;
+    type MaxChainDepth = MaxChainDepth;
+    type MaxTriggerFailures = MaxTriggerFailures;
+    type Currency = Balances;
+    type EventDeposit = EventDeposit;
+    type EventRetentionPeriod = EventRetentionPeriod;
+    type MaxEventAttempts = MaxEventAttempts;
+    type ConditionEvaluator = pallet_event_hub::StateConditionEvaluator<Runtime>;
+    type HrmpOrigin = EnsureRoot<AccountId>;
+    type XcmSender = XcmRouter;
+    type OnEconomicFlow = Telemetry;
+    type HrmpCalls = RelayHrmpCalls;
+    type HrmpFee = HrmpFee;
+}
+
+/// Publishes telemetry alerts on the event-hub `telemetry.alert` topic, so triggers can react
+/// to them. The payload is the SCALE-encoded `(epoch, metric, value, threshold)`.
+pub struct AlertsToEventHub;
+
+impl pallet_telemetry::OnAlert for AlertsToEventHub {
+    fn on_alert(epoch: u32, metric: pallet_telemetry::AlertMetric, value: u64, threshold: u64) {
+        EventHub::publish_system_event(
+            event_topics::TELEMETRY_ALERT,
+            (epoch, metric, value, threshold).encode(),
+            None,
+        );
+    }
+}
+
+parameter_types! {
+    /// Accounts (typically OCW keys) allowed to report telemetry metrics (set via governance)
+    pub storage TelemetryReporters: Vec<AccountId> = Vec::new();
+}
+
+/// Root, or a signed account listed in `TelemetryReporters`
+pub struct EnsureTelemetryReporter;
+
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureTelemetryReporter {
+    type Success = ();
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(frame_system::RawOrigin::Root) => Ok(()),
+            Ok(frame_system::RawOrigin::Signed(who)) if TelemetryReporters::get().contains(&who) => {
+                Ok(())
+            },
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(frame_system::RawOrigin::Root.into())
+    }
+}
+
+/// Aura authority key, which collators also sign heartbeats with
+pub type AuraId = sp_consensus_aura::sr25519::AuthorityId;
+
+/// Collator account of an Aura key
+pub struct AuraAccount;
+
+impl Convert<AuraId, AccountId> for AuraAccount {
+    fn convert(id: AuraId) -> AccountId {
+        sp_core::sr25519::Public::from(id).into()
+    }
+}
+
+/// Aura slot authorship for the consensus manager's and telemetry's validator counters. Aura
+/// assigns slots round-robin over the authority set, so the expected author of a slot is also
+/// its actual author.
+pub struct AuraAuthorship;
+
+impl pallet_consensus_manager::SlotAuthorship<AccountId> for AuraAuthorship {
+    fn current_slot() -> u64 {
+        *pallet_aura::CurrentSlot::<Runtime>::get()
+    }
+
+    fn expected_author(slot: u64) -> Option<AccountId> {
+        use pallet_consensus_manager::SlotAuthorityKeys;
+
+        Self::expected_key(slot).map(AuraAccount::convert)
+    }
+
+    fn authority_count() -> u32 {
+        pallet_aura::Authorities::<Runtime>::decode_len().unwrap_or(0) as u32
+    }
+
+    fn authorities() -> Vec<AccountId> {
+        pallet_aura::Authorities::<Runtime>::get().into_iter().map(AuraAccount::convert).collect()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_slot(slot: u64) {
+        pallet_aura::CurrentSlot::<Runtime>::put(sp_consensus_aura::Slot::from(slot));
+        if pallet_aura::Authorities::<Runtime>::decode_len().unwrap_or(0) == 0 {
+            let authority = AuraId::from(sp_core::sr25519::Public::from_raw([1u8; 32]));
+            pallet_aura::Authorities::<Runtime>::put(
+                frame_support::BoundedVec::truncate_from(vec![authority]),
+            );
+        }
+    }
+}
+
+impl pallet_consensus_manager::SlotAuthorityKeys<AuraId> for AuraAuthorship {
+    fn expected_key(slot: u64) -> Option<AuraId> {
+        let authorities = pallet_aura::Authorities::<Runtime>::get();
+        let index = slot.checked_rem(authorities.len() as u64)?;
+        authorities.get(index as usize).cloned()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_expected_key(key: AuraId) {
+        pallet_aura::Authorities::<Runtime>::put(frame_support::BoundedVec::truncate_from(vec![key]));
+    }
+}
+
+/// Cross-chain events received by the event hub, for telemetry's traffic counters
+pub struct EventHubCrossChainEvents;
+
+impl frame_support::traits::Get<u64> for EventHubCrossChainEvents {
+    fn get() -> u64 {
+        pallet_event_hub::EventStatistics::<Runtime>::get().total_cross_chain_events
+    }
+}
+
+/// Total events processed by the event hub, for telemetry era totals
+pub struct EventHubEventsProcessed;
+
+impl frame_support::traits::Get<u64> for EventHubEventsProcessed {
+    fn get() -> u64 {
+        pallet_event_hub::EventStatistics::<Runtime>::get().total_events_processed
+    }
+}
+
+/// Entry counts of the job registry, event hub and job verifier maps tracked by telemetry
+pub struct RuntimeMapEntryCounter;
+
+impl pallet_telemetry::MapEntryCounter for RuntimeMapEntryCounter {
+    fn entry_count(map: pallet_telemetry::TrackedMap) -> Option<u32> {
+        use pallet_telemetry::TrackedMap;
+        match map {
+            TrackedMap::Jobs => Some(pallet_job_registry::Jobs::<Runtime>::count()),
+            // The event hub keeps a live count of stored events
+            TrackedMap::Events => Some(pallet_event_hub::EventCount::<Runtime>::get()),
+            TrackedMap::JobProofs => Some(pallet_job_verifier::JobProofs::<Runtime>::count()),
+            _ => None,
+        }
+    }
 }
 
 parameter_types! {
     pub const MaxJobMetrics: u32 = 100000;
+    pub const SlowBlockThresholdMs: u64 = MILLISECS_PER_BLOCK + MILLISECS_PER_BLOCK / 6;
+    pub const TelemetryEpochLength: u32 = 300; // ~1 hour at 12s blocks
+    pub const ExecutionTimeRingSize: u32 = 1000;
+    pub const ExecutionTimeSmoothing: Permill = Permill::from_percent(10);
+    pub const BlockPerformanceRetention: u32 = 1000;
+    pub const JobMetricsRetention: u32 = 7 * 7200; // ~1 week at 12s blocks
+    pub const StorageSampleInterval: u32 = 300; // once per telemetry epoch
+    pub const TelemetryDigestInterval: u32 = 50; // ~10 minutes at 12s blocks
+    pub const HourlyTierBlocks: u32 = 300; // ~1 hour at 12s blocks
+    pub const HourlyTierRetention: u32 = 24 * 30; // ~30 days
+    pub const DailyTierRetention: u32 = 365;
+    pub const TelemetryEpochsPerEra: u32 = 24; // ~1 day
+    pub const TelemetryEraHistoryDepth: u32 = 90;
 }
 
 impl pallet_telemetry::Config for Runtime {
     type WeightInfo = pallet_telemetry::weights::SubstrateWeight<Runtime>;
     type MaxJobMetrics = MaxJobMetrics;
+    type SlowBlockThresholdMs = SlowBlockThresholdMs;
+    type EpochLength = TelemetryEpochLength;
+    type AlertOrigin = EnsureRoot<AccountId>;
+    type OnAlert = AlertsToEventHub;
+    type Authorship = AuraAuthorship;
+    type ConsensusMetrics = ConsensusManager;
+    type PerformanceDecay = PerformanceDecay;
+    type ExecutionTimeRingSize = ExecutionTimeRingSize;
+    type ExecutionTimeSmoothing = ExecutionTimeSmoothing;
+    type ReporterOrigin = EnsureTelemetryReporter;
+    type FeelessReporting = ConstBool<true>;
+    type BlockPerformanceRetention = BlockPerformanceRetention;
+    type JobMetricsRetention = JobMetricsRetention;
+    type CrossChainEvents = EventHubCrossChainEvents;
+    type EventsProcessed = EventHubEventsProcessed;
+    type EpochsPerEra = TelemetryEpochsPerEra;
+    type EraHistoryDepth = TelemetryEraHistoryDepth;
+    type StorageSampleInterval = StorageSampleInterval;
+    type MapEntryCounter = RuntimeMapEntryCounter;
+    type CollectionOrigin = EnsureRoot<AccountId>;
+    type DigestInterval = TelemetryDigestInterval;
+    type HourlyTierBlocks = HourlyTierBlocks;
+    type HourlyTierRetention = HourlyTierRetention;
+    type DailyTierRetention = DailyTierRetention;
 }
 
 use sp_runtime::traits::ConstBool;
@@ -388,6 +848,7 @@ construct_runtime!(
         Timestamp: pallet_timestamp,
         Balances: pallet_balances,
         TransactionPayment: pallet_transaction_payment,
+        SkipFeelessPayment: pallet_skip_feeless_payment,
         Sudo: pallet_sudo,
         
         Aura: pallet_aura,
@@ -406,6 +867,19 @@ construct_runtime!(
     }
 );
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benches {
+    frame_benchmarking::define_benchmarks!(
+        [frame_system, SystemBench::<Runtime>]
+        [frame_benchmarking::baseline, Baseline::<Runtime>]
+        [pallet_job_registry, JobRegistry]
+        [pallet_job_verifier, JobVerifier]
+        [pallet_consensus_manager, ConsensusManager]
+        [pallet_event_hub, EventHub]
+        [pallet_telemetry, Telemetry]
+    );
+}
+
 // Runtime APIs Implementation
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
@@ -525,6 +999,132 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_event_hub_runtime_api::EventHubApi<Block, AccountId, BlockNumber> for Runtime {
+        fn pending_events(limit: u32) -> Vec<u64> {
+            let mut pending = EventHub::get_pending_events();
+            pending.truncate(limit as usize);
+            pending
+        }
+
+        fn event(id: u64) -> Option<pallet_event_hub::EventData<BlockNumber>> {
+            EventHub::get_event(id)
+        }
+
+        fn triggers_of(account: AccountId) -> Vec<u64> {
+            EventHub::get_account_triggers(&account)
+        }
+
+        fn triggers_for_topic(topic: u64) -> Vec<u64> {
+            EventHub::get_topic_triggers(topic)
+        }
+
+        fn trigger_stats(trigger_id: u64) -> pallet_event_hub::TriggerStats<BlockNumber> {
+            EventHub::get_trigger_stats(trigger_id)
+        }
+    }
+
+    impl pallet_job_registry_runtime_api::JobRegistryApi<Block, AccountId, BlockNumber> for Runtime {
+        fn jobs(from: u64, limit: u32) -> Vec<(u64, pallet_job_registry::Job<AccountId, BlockNumber>)> {
+            JobRegistry::get_jobs(from, limit)
+        }
+
+        fn job(id: u64) -> Option<pallet_job_registry::Job<AccountId, BlockNumber>> {
+            JobRegistry::jobs(id)
+        }
+
+        fn ready_jobs(limit: u32) -> Vec<u64> {
+            let mut ready = JobRegistry::get_ready_jobs();
+            ready.truncate(limit as usize);
+            ready
+        }
+    }
+
+    impl pallet_job_verifier_runtime_api::VerifierApi<Block> for Runtime {
+        fn pending_proofs(from: u64, limit: u32) -> Vec<(u64, pallet_job_verifier::JobResult)> {
+            JobVerifier::get_pending_proofs(from, limit)
+        }
+
+        fn job_result(job_id: u64) -> Option<pallet_job_verifier::JobResult> {
+            JobVerifier::job_results(job_id)
+        }
+
+        fn verification_stats() -> pallet_job_verifier::VerificationStatistics {
+            JobVerifier::get_stats()
+        }
+    }
+
+    impl pallet_consensus_manager_runtime_api::ConsensusManagerApi<Block> for Runtime {
+        fn consensus_type() -> pallet_consensus_manager::ConsensusType {
+            ConsensusManager::get_consensus_type()
+        }
+
+        fn epoch_summaries(count: u32) -> Vec<(u32, pallet_consensus_manager::EpochMetricsSummary)> {
+            ConsensusManager::get_epoch_summaries(count)
+        }
+
+        fn fork_stats() -> pallet_consensus_manager::ForkStats {
+            ConsensusManager::get_fork_stats()
+        }
+
+        fn experiment_reports() -> Vec<pallet_consensus_manager::ExperimentReport> {
+            ConsensusManager::get_experiment_reports()
+        }
+    }
+
+    impl pallet_telemetry_runtime_api::TelemetryApi<Block, AccountId> for Runtime {
+        fn system_metrics() -> pallet_telemetry::SystemMetrics {
+            Telemetry::get_system_metrics()
+        }
+
+        fn job_metrics(job_id: u64) -> Option<pallet_telemetry::JobMetrics> {
+            Telemetry::get_job_metrics(job_id)
+        }
+
+        fn validator_performance(validator: AccountId) -> pallet_telemetry::ValidatorPerformance {
+            Telemetry::get_validator_performance(&validator)
+        }
+
+        fn recent_block_performance(count: u32) -> Vec<pallet_telemetry::BlockPerformance> {
+            Telemetry::get_recent_block_performance(count)
+        }
+
+        fn execution_time_percentiles() -> pallet_telemetry::ExecutionTimePercentiles {
+            Telemetry::get_execution_time_percentiles()
+        }
+
+        fn account_metrics(account: AccountId) -> pallet_telemetry::ParticipantMetrics {
+            Telemetry::get_account_metrics(&account)
+        }
+
+        fn worker_metrics(worker: AccountId) -> pallet_telemetry::ParticipantMetrics {
+            Telemetry::get_worker_metrics(&worker)
+        }
+
+        fn metrics_snapshot(recent_blocks: u32) -> pallet_telemetry::MetricsSnapshot<AccountId> {
+            Telemetry::metrics_snapshot(recent_blocks)
+        }
+
+        fn xcm_traffic() -> pallet_telemetry::XcmTrafficMetrics {
+            Telemetry::get_xcm_traffic()
+        }
+
+        fn submitter_sla(account: AccountId) -> pallet_telemetry::SlaCounters {
+            Telemetry::get_submitter_sla(&account)
+        }
+
+        fn tier_history(tier: pallet_telemetry::HistoryTier, count: u32) -> Vec<pallet_telemetry::TierSummary> {
+            Telemetry::get_tier_history(tier, count)
+        }
+
+        fn verifier_metrics(verifier: AccountId) -> pallet_telemetry::VerifierMetrics {
+            Telemetry::get_verifier_metrics(&verifier)
+        }
+
+        fn era_totals(count: u32) -> Vec<pallet_telemetry::EraTotals> {
+            Telemetry::get_era_totals(count)
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
@@ -553,6 +1153,8 @@ impl_runtime_apis! {
         ) {
             use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
             use frame_support::traits::StorageInfoTrait;
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use baseline::Pallet as Baseline;
 
             let mut list = Vec::<BenchmarkList>::new();
             list_benchmarks!(list, extra);
@@ -565,6 +1167,8 @@ impl_runtime_apis! {
             config: frame_benchmarking::BenchmarkConfig
         ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
             use frame_benchmarking::{baseline, Benchmarking, BenchmarkBatch};
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use baseline::Pallet as Baseline;
 
             impl frame_system_benchmarking::Config for Runtime {}
             impl baseline::Config for Runtime {}